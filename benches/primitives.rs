@@ -0,0 +1,216 @@
+#![allow(non_snake_case)]
+//! Criterion benchmarks of the primitives that dominate the cost of
+//! the protocols built on top of zerocaf: the field arithmetic, the
+//! scalar multiplications, the multiscalar multiplications and the
+//! encodings.
+//!
+//! Every benchmark gets the enabled features that affect performance
+//! as its parameter (see `config`), so the runs of several feature
+//! sets don't overwrite each other, and any of them can be compared
+//! against a saved baseline:
+//!
+//! ```sh
+//! cargo bench --bench primitives -- --save-baseline master
+//! RUSTFLAGS="-C target-cpu=native" cargo bench --bench primitives --features asm
+//! cargo bench --bench primitives -- --baseline master
+//! ```
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+
+use zerocaf::edwards::{CompressedEdwardsY, EdwardsPoint};
+use zerocaf::field::FieldElement;
+use zerocaf::ristretto::RistrettoPoint;
+use zerocaf::scalar::Scalar;
+use zerocaf::traits::ops::Square;
+use zerocaf::traits::{MultiscalarMul, VartimeMultiscalarMul};
+
+use rand::rngs::OsRng;
+
+/// Number of terms of the multiscalar multiplications.
+const MSM_SIZES: [usize; 4] = [4, 16, 64, 256];
+
+/// Number of points of the batch encodings.
+const BATCH_SIZE: usize = 256;
+
+/// Get the backend and the enabled features that change the code
+/// being benchmarked, eg. `u64+asm+tables`.
+fn config() -> String {
+    let mut config = String::from("u64");
+    if cfg!(all(
+        feature = "asm",
+        target_arch = "x86_64",
+        target_feature = "bmi2",
+        target_feature = "adx"
+    )) {
+        config.push_str("+asm");
+    }
+    if cfg!(feature = "u32_backend") {
+        config.push_str("+u32");
+    }
+    if cfg!(feature = "precomputed_tables") {
+        config.push_str("+tables");
+    }
+    if cfg!(feature = "parallel") {
+        config.push_str("+parallel");
+    }
+    config
+}
+
+fn random_points(n: usize) -> Vec<EdwardsPoint> {
+    (0..n)
+        .map(|_| EdwardsPoint::new_random_point(&mut OsRng))
+        .collect()
+}
+
+fn random_scalars(n: usize) -> Vec<Scalar> {
+    (0..n).map(|_| Scalar::random(&mut OsRng)).collect()
+}
+
+fn bench_field(c: &mut Criterion) {
+    let config = config();
+    let a = FieldElement::random(&mut OsRng);
+    let b = FieldElement::random(&mut OsRng);
+    let square = a.square();
+
+    let mut group = c.benchmark_group("FieldElement");
+    group.bench_with_input(
+        BenchmarkId::new("mul", &config),
+        &(a, b),
+        |bench, (a, b)| bench.iter(|| black_box(*a) * black_box(*b)),
+    );
+    group.bench_with_input(BenchmarkId::new("square", &config), &a, |bench, a| {
+        bench.iter(|| black_box(*a).square())
+    });
+    group.bench_with_input(BenchmarkId::new("invert", &config), &a, |bench, a| {
+        bench.iter(|| black_box(*a).invert_nonzero())
+    });
+    group.bench_with_input(
+        BenchmarkId::new("inverse_vartime", &config),
+        &a,
+        |bench, a| bench.iter(|| black_box(*a).inverse()),
+    );
+    group.bench_with_input(BenchmarkId::new("sqrt", &config), &square, |bench, a| {
+        bench.iter(|| black_box(*a).sqrt())
+    });
+    group.bench_with_input(BenchmarkId::new("legendre", &config), &a, |bench, a| {
+        bench.iter(|| black_box(*a).legendre_symbol())
+    });
+    group.finish();
+}
+
+fn bench_scalar_mul(c: &mut Criterion) {
+    let config = config();
+    let P = EdwardsPoint::new_random_point(&mut OsRng);
+    let k = Scalar::random(&mut OsRng);
+    let R = RistrettoPoint::new_random_point(&mut OsRng);
+
+    let mut group = c.benchmark_group("Scalar multiplication");
+    group.bench_with_input(
+        BenchmarkId::new("edwards", &config),
+        &(P, k),
+        |bench, (P, k)| bench.iter(|| black_box(P) * black_box(k)),
+    );
+    group.bench_with_input(
+        BenchmarkId::new("edwards_vartime", &config),
+        &(P, k),
+        |bench, (P, k)| bench.iter(|| black_box(P).vartime_mul(black_box(k))),
+    );
+    group.bench_with_input(BenchmarkId::new("edwards_base", &config), &k, |bench, k| {
+        bench.iter(|| EdwardsPoint::mul_base(black_box(k)))
+    });
+    group.bench_with_input(
+        BenchmarkId::new("ristretto", &config),
+        &(R, k),
+        |bench, (R, k)| bench.iter(|| black_box(R) * black_box(k)),
+    );
+    group.finish();
+}
+
+fn bench_multiscalar_mul(c: &mut Criterion) {
+    let config = config();
+    let mut group = c.benchmark_group("Multiscalar multiplication");
+    for n in MSM_SIZES.iter() {
+        let points = random_points(*n);
+        let scalars = random_scalars(*n);
+        let param = format!("{}/{}", config, n);
+        group.throughput(Throughput::Elements(*n as u64));
+        group.bench_with_input(
+            BenchmarkId::new("constant_time", &param),
+            &(&scalars, &points),
+            |bench, (scalars, points)| {
+                bench.iter(|| EdwardsPoint::multiscalar_mul(scalars.iter(), points.iter()))
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("vartime", &param),
+            &(&scalars, &points),
+            |bench, (scalars, points)| {
+                bench.iter(|| EdwardsPoint::vartime_multiscalar_mul(scalars.iter(), points.iter()))
+            },
+        );
+    }
+    group.finish();
+}
+
+fn bench_encodings(c: &mut Criterion) {
+    let config = config();
+    let points = random_points(BATCH_SIZE);
+    let compressed: Vec<CompressedEdwardsY> = points.iter().map(|P| P.compress()).collect();
+    let ristretto = RistrettoPoint::new_random_point(&mut OsRng);
+    let ristretto_compressed = ristretto.compress();
+    let a = FieldElement::random(&mut OsRng);
+
+    let mut group = c.benchmark_group("Encoding");
+    group.bench_with_input(
+        BenchmarkId::new("field_to_bytes", &config),
+        &a,
+        |bench, a| bench.iter(|| black_box(*a).to_bytes()),
+    );
+    group.bench_with_input(
+        BenchmarkId::new("field_from_canonical_bytes", &config),
+        &a.to_bytes(),
+        |bench, bytes| bench.iter(|| FieldElement::from_canonical_bytes(black_box(bytes))),
+    );
+    group.bench_with_input(
+        BenchmarkId::new("edwards_compress", &config),
+        &points[0],
+        |bench, P| bench.iter(|| black_box(P).compress()),
+    );
+    group.bench_with_input(
+        BenchmarkId::new("edwards_decompress", &config),
+        &compressed[0],
+        |bench, P| bench.iter(|| black_box(P).decompress()),
+    );
+    group.bench_with_input(
+        BenchmarkId::new("ristretto_compress", &config),
+        &ristretto,
+        |bench, P| bench.iter(|| black_box(P).compress()),
+    );
+    group.bench_with_input(
+        BenchmarkId::new("ristretto_decompress", &config),
+        &ristretto_compressed,
+        |bench, P| bench.iter(|| black_box(P).decompress()),
+    );
+
+    let param = format!("{}/{}", config, BATCH_SIZE);
+    group.throughput(Throughput::Elements(BATCH_SIZE as u64));
+    group.bench_with_input(
+        BenchmarkId::new("edwards_compress_batch", &param),
+        &points,
+        |bench, points| bench.iter(|| EdwardsPoint::compress_batch(points)),
+    );
+    group.bench_with_input(
+        BenchmarkId::new("edwards_decompress_batch", &param),
+        &compressed,
+        |bench, points| bench.iter(|| CompressedEdwardsY::batch_decompress(points)),
+    );
+    group.finish();
+}
+
+criterion_group!(
+    primitives,
+    bench_field,
+    bench_scalar_mul,
+    bench_multiscalar_mul,
+    bench_encodings,
+);
+criterion_main!(primitives);
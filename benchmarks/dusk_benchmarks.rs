@@ -3,7 +3,7 @@
 #[macro_use]
 extern crate criterion;
 
-use criterion::{criterion_group, criterion_main, Criterion, BenchmarkId};
+use criterion::{black_box, criterion_group, criterion_main, Criterion, BenchmarkId};
 
 use zerocaf::edwards::*;
 use zerocaf::ristretto::*;
@@ -94,7 +94,7 @@ mod field_benches {
 
         c.bench_with_input(
             BenchmarkId::new("Modular Sqrt", "Fixed FieldElements"), &inp2 , |b, &inp| {
-                b.iter(|| inp2.0.mod_sqrt(inp2.1));
+                b.iter(|| inp2.0.sqrt());
             }
         );
 
@@ -478,7 +478,7 @@ mod ristretto_benches {
 
         c.bench_with_input(
             BenchmarkId::new("Ristretto Equalty", "Ristretto Basepoint"), &inputs , |b, &inputs| {
-                b.iter(|| inputs.0 == inputs.0);
+                b.iter(|| inputs.0 == black_box(inputs.0));
             }
         );
     }    
@@ -521,23 +521,13 @@ mod comparaisons {
         let i = P1_EXTENDED;
         let mul = (RISTRETTO_BASEPOINT, D);
 
-        let mut group = c.benchmark_group("Half");
-
-        group.bench_with_input(BenchmarkId::new("Fast Even Half", "Fixed even FieldElement"), &i, 
-            |b, &i| b.iter(|| i.0.T.old_half()));
-        group.bench_with_input(BenchmarkId::new("Constant usage impl", "Fixed even FieldElement"), &i, 
-            |b, &i| b.iter(|| i.0.T.half()));
-        
-        group.finish();
-
-        
         // Equalty
         let mut group = c.benchmark_group("Equalty");
 
         group.bench_with_input(BenchmarkId::new("Compressing", "Fixed Point"), &i, 
             |b, &i| b.iter(|| i.compress() == i.compress()));
         group.bench_with_input(BenchmarkId::new("To Affine", "Fixed Point"), &i, 
-            |b, &i| b.iter(|| i == i));
+            |b, &i| b.iter(|| i == black_box(i)));
         
         group.finish();
 
@@ -0,0 +1,271 @@
+//! Computes the radix `2^52` limbs of the numeric constants of the
+//! `u64` backend from the parameters of the curve, so none of them
+//! has to be transcribed by hand.
+//!
+//! It also encodes the signed limbs of the modulus used by the
+//! safegcd inversion, and the addition chains of the fixed exponents
+//! used by the square root and the Legendre symbol, so they don't
+//! need to go through the generic `Pow`.
+//!
+//! With the `test_curve` feature, the field and the curve get swapped
+//! for the toy ones of `TEST_FIELD_ORDER` and `TEST_D_NUMERATOR`, so
+//! `tests/test_curve.rs` can check the arithmetic over the whole field.
+//!
+//! Everything gets written to `$OUT_DIR/constants.rs`, which is
+//! included by `src/backend/u64/constants.rs`, except for the radix
+//! `2^29` limbs of the `u32` backend, which go to
+//! `$OUT_DIR/constants_u32.rs` for `src/backend/u32/constants.rs`.
+
+use num::{BigUint, Num, One};
+
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+/// `p - 2^252`, where `p` is the order of the field.
+const FIELD_ORDER_OFFSET: &str = "27742317777372353535851937790883648493";
+
+/// `l - 2^249`, where `l` is the order of the prime-order subgroup.
+const SCALAR_ORDER_OFFSET: &str = "14490550575682688738086195780655237219";
+
+/// `d = -D_NUMERATOR / D_DENOMINATOR (mod p)`.
+const D_NUMERATOR: u32 = 126296;
+const D_DENOMINATOR: u32 = 126297;
+
+/// Order of the toy field of the `test_curve` feature. Like `p`, it
+/// is `5 (mod 8)` and `6` is not a square modulo it, as the square
+/// roots and `FieldElement::mod_sqrt` expect.
+const TEST_FIELD_ORDER: u32 = 1021;
+
+/// `d = -TEST_D_NUMERATOR` on the toy curve, which is not a square
+/// while `a - d` is one, so the Ristretto constants exist.
+const TEST_D_NUMERATOR: u32 = 10;
+
+/// Radix of the limbs, and of the Montgomery reductions.
+const LIMB_BITS: usize = 52;
+
+/// Radix of the limbs of the `u32` backend, and of its Montgomery
+/// reductions.
+const LIMB_BITS_U32: usize = 29;
+
+/// Width of the windows of the addition chains, so they multiply by
+/// the odd powers `x, x^3, ..., x^(2^CHAIN_WINDOW - 1)`.
+const CHAIN_WINDOW: usize = 5;
+
+fn big(n: u32) -> BigUint {
+    BigUint::from(n)
+}
+
+fn pow2(k: usize) -> BigUint {
+    BigUint::one() << k
+}
+
+/// Get the low 64 bits of `x`.
+fn low_u64(x: &BigUint) -> u64 {
+    let mut bytes = x.to_bytes_le();
+    bytes.resize(8, 0);
+    let mut buf = [0u8; 8];
+    buf.copy_from_slice(&bytes[..8]);
+    u64::from_le_bytes(buf)
+}
+
+/// Split `x < 2^260` into five limbs in radix `2^52`.
+fn limbs(x: &BigUint) -> [u64; 5] {
+    assert!(x < &pow2(5 * LIMB_BITS));
+    let mask = (1u64 << LIMB_BITS) - 1;
+    let mut res = [0u64; 5];
+    for (i, limb) in res.iter_mut().enumerate() {
+        *limb = low_u64(&(x >> (LIMB_BITS * i))) & mask;
+    }
+    res
+}
+
+/// Split `x < 2^261` into nine limbs in radix `2^29`.
+fn limbs_u32(x: &BigUint) -> [u32; 9] {
+    assert!(x < &pow2(9 * LIMB_BITS_U32));
+    let mask = (1u64 << LIMB_BITS_U32) - 1;
+    let mut res = [0u32; 9];
+    for (i, limb) in res.iter_mut().enumerate() {
+        *limb = (low_u64(&(x >> (LIMB_BITS_U32 * i))) & mask) as u32;
+    }
+    res
+}
+
+/// Compute `-x (mod m)`.
+fn neg(x: &BigUint, m: &BigUint) -> BigUint {
+    (m - x % m) % m
+}
+
+/// Compute `1 / x (mod m)` for a prime `m`.
+fn invert(x: &BigUint, m: &BigUint) -> BigUint {
+    x.modpow(&(m - big(2)), m)
+}
+
+/// Compute `1 / m (mod 2^k)` for an odd `m` and `k <= 64`.
+fn inverse_mod_pow2(m: &BigUint, k: usize) -> u64 {
+    let m0 = low_u64(m);
+    // Newton's iteration doubles the correct low bits of `1 / m0`.
+    let mut inv = 1u64;
+    for _ in 0..6 {
+        inv = inv.wrapping_mul(2u64.wrapping_sub(m0.wrapping_mul(inv)));
+    }
+    if k == 64 {
+        return inv;
+    };
+    inv & ((1u64 << k) - 1)
+}
+
+/// Compute `-1 / m (mod 2^52)` for an odd `m`, the factor of the
+/// Montgomery reductions.
+fn montgomery_factor(m: &BigUint) -> u64 {
+    inverse_mod_pow2(m, 64).wrapping_neg() & ((1u64 << LIMB_BITS) - 1)
+}
+
+/// Get the one of `x` and `-x (mod p)` in `[0, (p - 1) / 2]`.
+fn non_negative(x: &BigUint, p: &BigUint) -> BigUint {
+    if x > &((p - big(1)) >> 1) {
+        return p - x;
+    };
+    x.clone()
+}
+
+/// Get the non-negative square root of `x (mod p)`. Since
+/// `p = 5 (mod 8)`, it's either `x^((p + 3) / 8)` or that times
+/// `sqrt(-1)`.
+fn sqrt(x: &BigUint, p: &BigUint) -> BigUint {
+    let mut root = x.modpow(&((p + big(3)) >> 3), p);
+    if &root * &root % p != x % p {
+        let sqrt_minus_one = big(2).modpow(&((p - big(1)) >> 2), p);
+        root = root * sqrt_minus_one % p;
+    }
+    assert!(&root * &root % p == x % p, "{} is not a square", x);
+    non_negative(&root, p)
+}
+
+/// Encode the sliding-window exponentiation by `e` as the steps
+/// `(s, d)`: square `s` times, then multiply by `x^d` unless `d = 0`.
+/// Every `d` is odd and less than `2^CHAIN_WINDOW`.
+fn addition_chain(e: &BigUint) -> Vec<(u32, u8)> {
+    let bits: Vec<u8> = e.to_str_radix(2).bytes().map(|b| b - b'0').collect();
+    let mut steps = Vec::new();
+    let mut squarings = 0;
+    let mut i = 0;
+    while i < bits.len() {
+        if bits[i] == 0 {
+            squarings += 1;
+            i += 1;
+            continue;
+        };
+        // The longest window starting at `i` that ends on a set bit.
+        let mut j = (i + CHAIN_WINDOW).min(bits.len());
+        while bits[j - 1] == 0 {
+            j -= 1;
+        }
+        let digit = bits[i..j].iter().fold(0u8, |acc, bit| (acc << 1) | bit);
+        // Squaring the initial `1` would be wasted.
+        if !steps.is_empty() {
+            squarings += (j - i) as u32;
+        };
+        steps.push((squarings, digit));
+        squarings = 0;
+        i = j;
+    }
+    if squarings > 0 {
+        steps.push((squarings, 0));
+    };
+    steps
+}
+
+fn main() {
+    let (p, d_numerator, d_denominator) = if env::var_os("CARGO_FEATURE_TEST_CURVE").is_some() {
+        (big(TEST_FIELD_ORDER), TEST_D_NUMERATOR, 1)
+    } else {
+        let p = pow2(252) + BigUint::from_str_radix(FIELD_ORDER_OFFSET, 10).unwrap();
+        (p, D_NUMERATOR, D_DENOMINATOR)
+    };
+    let l = pow2(249) + BigUint::from_str_radix(SCALAR_ORDER_OFFSET, 10).unwrap();
+    let minus_one = &p - big(1);
+    let d = neg(&(big(d_numerator) * invert(&big(d_denominator), &p)), &p);
+    assert!(&p % big(8) == big(5), "the field order must be 5 (mod 8)");
+    assert!(
+        big(6).modpow(&(&minus_one >> 1), &p) == minus_one,
+        "6 must not be a square"
+    );
+
+    let sqrt_minus_one = sqrt(&minus_one, &p);
+    // `a - d = a * d - 1 = -d - 1`, since `a = -1`.
+    let sqrt_a_minus_d = sqrt(&neg(&(&d + big(1)), &p), &p);
+    let elements: Vec<(&str, BigUint)> = vec![
+        ("L", l.clone()),
+        ("R", pow2(260) % &l),
+        ("RR", pow2(520) % &l),
+        ("MU", pow2(508) / &l),
+        ("SCALAR_INVERSE_MOD_TWO", (&l + big(1)) >> 1),
+        ("FIELD_L", p.clone()),
+        ("RR_FIELD", pow2(520) % &p),
+        ("INV_RR", pow2(506) % &p),
+        ("INVERSE_MOD_TWO", (&p + big(1)) >> 1),
+        ("MINUS_ONE", minus_one.clone()),
+        ("MINUS_ONE_HALF", &minus_one >> 1),
+        ("EDWARDS_A", minus_one.clone()),
+        ("EDWARDS_D", d.clone()),
+        ("SQRT_MINUS_ONE", sqrt_minus_one.clone()),
+        // `1 / sqrt(-1) = -sqrt(-1)`.
+        ("INV_SQRT_A", neg(&sqrt_minus_one, &p)),
+        ("INV_SQRT_A_MINUS_D", non_negative(&invert(&sqrt_a_minus_d, &p), &p)),
+        ("SQRT_AD_MINUS_ONE", sqrt_a_minus_d),
+        (
+            "D_PLUS_ONE_OVER_D_MINUS_ONE",
+            (&d + big(1)) * invert(&(&d - big(1)), &p) % &p,
+        ),
+    ];
+
+    let mut src = String::new();
+    src.push_str("// Generated by `build.rs`, do not edit.\n\n");
+    for (name, value) in elements.iter() {
+        let limbs = limbs(value);
+        writeln!(src, "pub(super) const {}: [u64; 5] = {:?};", name, limbs).unwrap();
+    }
+    writeln!(src, "pub(super) const CHAIN_WINDOW: usize = {};", CHAIN_WINDOW).unwrap();
+    let exponents = [
+        ("SQRT_CHAIN", (&p + big(3)) >> 3),
+        ("LEGENDRE_CHAIN", &minus_one >> 1),
+    ];
+    for (name, exponent) in exponents.iter() {
+        let chain = addition_chain(exponent);
+        writeln!(src, "pub(super) const {}: [(u32, u8); {}] = {:?};", name, chain.len(), chain)
+            .unwrap();
+    }
+    for (name, modulus) in [("LFACTOR", &l), ("LFACTOR_FIELD", &p)].iter() {
+        let factor = montgomery_factor(modulus);
+        writeln!(src, "pub(super) const {}: u64 = {};", name, factor).unwrap();
+    }
+
+    // The safegcd inversion works on signed limbs in radix `2^62`.
+    let safegcd_limbs: Vec<i64> = (0..5)
+        .map(|i| (low_u64(&(&p >> (62 * i))) & ((1u64 << 62) - 1)) as i64)
+        .collect();
+    writeln!(src, "pub(super) const SAFEGCD_MODULUS: [i64; 5] = {:?};", safegcd_limbs).unwrap();
+    writeln!(
+        src,
+        "pub(super) const SAFEGCD_MODULUS_INV62: u64 = {};",
+        inverse_mod_pow2(&p, 62)
+    )
+    .unwrap();
+
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("constants.rs");
+    fs::write(out, src).unwrap();
+
+    // The `u32` backend reduces with `R = 2^261`.
+    let mut src = String::new();
+    src.push_str("// Generated by `build.rs`, do not edit.\n\n");
+    for (name, value) in [("L", l.clone()), ("RR", pow2(522) % &l)].iter() {
+        writeln!(src, "pub(super) const {}: [u32; 9] = {:?};", name, limbs_u32(value)).unwrap();
+    }
+    let factor = inverse_mod_pow2(&l, 64).wrapping_neg() & ((1u64 << LIMB_BITS_U32) - 1);
+    writeln!(src, "pub(super) const LFACTOR: u32 = {};", factor).unwrap();
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("constants_u32.rs");
+    fs::write(out, src).unwrap();
+    println!("cargo:rerun-if-changed=build.rs");
+}
@@ -0,0 +1,61 @@
+//! Differential fuzzing of the `FieldElement` arithmetic against
+//! a `num-bigint` implementation of the same operations modulo
+//! `FIELD_L`.
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+
+use num_bigint::BigUint;
+use num_traits::{One, Zero};
+
+use zerocaf::constants::FIELD_L;
+use zerocaf::field::FieldElement;
+use zerocaf::traits::ops::*;
+
+use subtle::Choice;
+
+fn to_biguint(fe: &FieldElement) -> BigUint {
+    BigUint::from_bytes_le(&fe.to_bytes())
+}
+
+fn from_biguint(n: &BigUint) -> FieldElement {
+    let mut bytes = [0u8; 32];
+    let n_bytes = n.to_bytes_le();
+    bytes[..n_bytes.len()].copy_from_slice(&n_bytes);
+    FieldElement::from_bytes(&bytes)
+}
+
+fn assert_same(res: FieldElement, expected: &BigUint) {
+    assert_eq!(to_biguint(&res), *expected);
+}
+
+fuzz_target!(|data: &[u8]| {
+    if data.len() != 64 {
+        return;
+    }
+    let p = to_biguint(&FIELD_L);
+    let a_big = BigUint::from_bytes_le(&data[..32]) % &p;
+    let b_big = BigUint::from_bytes_le(&data[32..]) % &p;
+    let a = from_biguint(&a_big);
+    let b = from_biguint(&b_big);
+
+    assert_same(a + b, &((&a_big + &b_big) % &p));
+    assert_same(a - b, &((&a_big + &p - &b_big) % &p));
+    assert_same(a * b, &((&a_big * &b_big) % &p));
+    assert_same(a.square(), &((&a_big * &a_big) % &p));
+    assert_same(-a, &((&p - &a_big) % &p));
+
+    if !a_big.is_zero() {
+        let inv = a_big.modpow(&(&p - 2u32), &p);
+        assert_same(a.inverse(), &inv);
+    }
+
+    // Euler's criterion tells if `a` has a square root.
+    let is_square = a_big.is_zero() || a_big.modpow(&((&p - 1u32) >> 1), &p).is_one();
+    match a.mod_sqrt(Choice::from(0u8)) {
+        Some(root) => {
+            assert!(is_square);
+            assert_same(root.square(), &a_big);
+        }
+        None => assert!(!is_square),
+    }
+});
@@ -0,0 +1,50 @@
+//! Differential fuzzing of the `Scalar` arithmetic against a
+//! `num-bigint` implementation of the same operations modulo `L`.
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+
+use num_bigint::BigUint;
+
+use zerocaf::constants::L;
+use zerocaf::scalar::Scalar;
+use zerocaf::traits::ops::*;
+
+fn to_biguint(s: &Scalar) -> BigUint {
+    BigUint::from_bytes_le(&s.to_bytes())
+}
+
+fn from_biguint(n: &BigUint) -> Scalar {
+    let mut bytes = [0u8; 32];
+    let n_bytes = n.to_bytes_le();
+    bytes[..n_bytes.len()].copy_from_slice(&n_bytes);
+    Scalar::from_bytes(&bytes)
+}
+
+fn assert_same(res: Scalar, expected: &BigUint) {
+    assert_eq!(to_biguint(&res), *expected);
+}
+
+fuzz_target!(|data: &[u8]| {
+    if data.len() != 64 {
+        return;
+    }
+    let l = to_biguint(&L);
+    let a_big = BigUint::from_bytes_le(&data[..32]) % &l;
+    let b_big = BigUint::from_bytes_le(&data[32..]) % &l;
+    let a = from_biguint(&a_big);
+    let b = from_biguint(&b_big);
+
+    assert_same(a + b, &((&a_big + &b_big) % &l));
+    assert_same(a - b, &((&a_big + &l - &b_big) % &l));
+    assert_same(a * b, &((&a_big * &b_big) % &l));
+    assert_same(a.square(), &((&a_big * &a_big) % &l));
+    assert_same(-a, &((&l - &a_big) % &l));
+
+    // Wide reduction of the raw input.
+    let mut wide = [0u8; 64];
+    wide.copy_from_slice(data);
+    assert_same(
+        Scalar::from_bytes_wide(&wide),
+        &(BigUint::from_bytes_le(data) % &l),
+    );
+});
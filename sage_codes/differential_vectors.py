@@ -0,0 +1,314 @@
+"""Differential test vectors of the Sonny curve, see
+`tests/differential_vectors.rs`.
+
+The curve arithmetic is modeled in plain Python on the birationally
+equivalent Weierstrass curve, so the vectors don't share the Edwards
+formulas of the library. Every value is the decimal string of its
+canonical representative.
+
+The vectors are generated with:
+
+    sage -python sage_codes/differential_vectors.py > tests/differential_vectors.json
+
+or with `python3` in place of `sage -python` where Sage isn't
+available, which skips the checks against Sage.
+
+Before printing them, the script checks without Sage that `p` and
+`l` are prime and that the curve has order `8 * l`: a point of order
+`l` makes `l` divide the order, and `8 * l` is the only multiple of
+`l` within the Hasse bound. When run with Sage (`sage -python`), it
+also checks the vectors against the `GF` and `EllipticCurve` of Sage.
+"""
+
+import json
+import random
+from math import isqrt
+
+try:
+    from sage.all import GF, EllipticCurve, is_prime
+except ImportError:
+    GF = None
+
+p = 2**252 + 27742317777372353535851937790883648493
+l = 2**249 + 14490550575682688738086195780655237219
+a = p - 1
+d = (-126296 * pow(126297, -1, p)) % p
+
+# The Montgomery curve `B v^2 = u^3 + A u^2 + u` of `a` and `d`, and
+# its Weierstrass form `Y^2 = X^3 + a2 X^2 + a4 X`, with `X = u / B`
+# and `Y = v / B`.
+A = 2 * (a + d) * pow(a - d, -1, p) % p
+B = 4 * pow(a - d, -1, p) % p
+a2 = A * pow(B, -1, p) % p
+a4 = pow(B, -2, p)
+
+# Deterministic inputs, so the vectors only change with the script.
+rng = random.Random(0x50AA7)
+
+
+def inv(x):
+    return pow(x, -1, p)
+
+
+def non_negative(x):
+    return x if x <= (p - 1) // 2 else p - x
+
+
+def is_square(x):
+    return x == 0 or pow(x, (p - 1) // 2, p) == 1
+
+
+sqrt_minus_one = non_negative(pow(2, (p - 1) // 4, p))
+
+
+def sqrt(x):
+    """Get the non-negative square root of `x`, or `None`."""
+    if not is_square(x):
+        return None
+    root = pow(x, (p + 3) // 8, p)
+    if root * root % p != x:
+        root = root * sqrt_minus_one % p
+    return non_negative(root)
+
+
+def sqrt_branch(x):
+    """Get the case of `FieldElement::sqrt` taken by `x`."""
+    if not is_square(x):
+        return "non_square"
+    root = pow(x, (p + 3) // 8, p)
+    return "direct" if root * root % p == x else "flipped"
+
+
+# Weierstrass points are `(X, Y)` tuples, with `None` as infinity.
+
+
+def weierstrass_add(P, Q):
+    if P is None:
+        return Q
+    if Q is None:
+        return P
+    (x1, y1), (x2, y2) = P, Q
+    if x1 == x2 and (y1 + y2) % p == 0:
+        return None
+    if P == Q:
+        slope = (3 * x1 * x1 + 2 * a2 * x1 + a4) * inv(2 * y1) % p
+    else:
+        slope = (y2 - y1) * inv(x2 - x1) % p
+    x3 = (slope * slope - a2 - x1 - x2) % p
+    return (x3, (slope * (x1 - x3) - y1) % p)
+
+
+def weierstrass_mul(k, P):
+    res = None
+    for bit in bin(k)[2:]:
+        res = weierstrass_add(res, res)
+        if bit == "1":
+            res = weierstrass_add(res, P)
+    return res
+
+
+def to_weierstrass(point):
+    x, y = point
+    if (x, y) == (0, 1):
+        return None
+    if (x, y) == (0, p - 1):
+        return (0, 0)
+    u = (1 + y) * inv(1 - y) % p
+    v = u * inv(x) % p
+    return (u * inv(B) % p, v * inv(B) % p)
+
+
+def to_edwards(P):
+    if P is None:
+        return (0, 1)
+    if P == (0, 0):
+        return (0, p - 1)
+    u, v = P[0] * B % p, P[1] * B % p
+    return (u * inv(v) % p, (u - 1) * inv(u + 1) % p)
+
+
+def is_on_curve(point):
+    x, y = point
+    xx, yy = x * x % p, y * y % p
+    return (a * xx + yy) % p == (1 + d * xx * yy) % p
+
+
+def add(P, Q):
+    return to_edwards(weierstrass_add(to_weierstrass(P), to_weierstrass(Q)))
+
+
+def mul(k, P):
+    return to_edwards(weierstrass_mul(k, to_weierstrass(P)))
+
+
+def random_point():
+    while True:
+        y = rng.randrange(p)
+        x = sqrt((y * y - 1) * inv(d * y * y - a) % p)
+        if x is not None:
+            return (x if rng.random() < 0.5 else (p - x) % p, y)
+
+
+def field_vectors():
+    inputs = [(0, 0), (1, 0), (0, p - 1), (p - 1, p - 1), ((p - 1) // 2, (p + 1) // 2)]
+    inputs += [(rng.randrange(p), rng.randrange(p)) for _ in range(16)]
+    return [
+        {
+            "a": x,
+            "b": y,
+            "add": (x + y) % p,
+            "sub": (x - y) % p,
+            "mul": x * y % p,
+            "neg": -x % p,
+            "square": x * x % p,
+            "inverse": inv(x) if x != 0 else None,
+        }
+        for x, y in inputs
+    ]
+
+
+def sqrt_vectors():
+    inputs = [0, 1, p - 1, sqrt_minus_one, p - sqrt_minus_one, 2, 4, 6]
+    branches = {"direct": 0, "flipped": 0, "non_square": 0}
+    while min(branches.values()) < 8:
+        x = rng.randrange(p)
+        # Draw half of the inputs from the squares.
+        x = x * x % p if rng.random() < 0.5 else x
+        if branches[sqrt_branch(x)] < 8:
+            branches[sqrt_branch(x)] += 1
+            inputs.append(x)
+    return [{"a": x, "branch": sqrt_branch(x), "root": sqrt(x)} for x in inputs]
+
+
+def sqrt_ratio_i_vectors():
+    inputs = [(0, 0), (0, 1), (1, 0), (1, 1), (p - 1, 1), (1, p - 1), (6, 1)]
+    inputs += [(rng.randrange(p), rng.randrange(p)) for _ in range(16)]
+    vectors = []
+    for u, v in inputs:
+        if u == 0:
+            was_square, root = True, 0
+        elif v == 0:
+            was_square, root = False, 0
+        else:
+            ratio = u * inv(v) % p
+            was_square = is_square(ratio)
+            root = sqrt(ratio if was_square else sqrt_minus_one * ratio % p)
+        vectors.append({"u": u, "v": v, "was_square": was_square, "root": root})
+    return vectors
+
+
+def point_add_vectors():
+    identity, torsion = (0, 1), (0, p - 1)
+    P, Q = random_point(), random_point()
+    inputs = [
+        (identity, identity),
+        (P, identity),
+        (P, torsion),
+        (torsion, torsion),
+        (P, P),
+        (P, ((p - P[0]) % p, P[1])),
+        (P, Q),
+    ]
+    inputs += [(random_point(), random_point()) for _ in range(12)]
+    return [{"p": list(P), "q": list(Q), "sum": list(add(P, Q))} for P, Q in inputs]
+
+
+def scalar_mul_vectors():
+    P = random_point()
+    inputs = [(0, P), (1, P), (2, P), (8, P), (l - 1, P), (l - 1, (0, p - 1))]
+    inputs += [(rng.randrange(l), random_point()) for _ in range(10)]
+    return [
+        {"scalar": k, "point": list(P), "result": list(mul(k, P))} for k, P in inputs
+    ]
+
+
+def is_prime_mr(n):
+    """Miller-Rabin test over the first primes as bases."""
+    bases = [b for b in range(2, 320) if all(b % q for q in range(2, b))]
+    if n < 2 or any(n % b == 0 for b in bases):
+        return n in bases
+    r, s = n - 1, 0
+    while r % 2 == 0:
+        r, s = r // 2, s + 1
+    for b in bases:
+        x = pow(b, r, n)
+        if x in (1, n - 1):
+            continue
+        for _ in range(s - 1):
+            x = x * x % n
+            if x == n - 1:
+                break
+        else:
+            return False
+    return True
+
+
+def order_check():
+    """Check that `p` and `l` are prime and that the curve has order
+    `8 * l`, without Sage."""
+    assert is_prime_mr(p) and is_prime_mr(l)
+    # The order `N` is within `[p + 1 - 2 sqrt(p), p + 1 + 2 sqrt(p)]`,
+    # which only holds the multiple `8 * l` of `l`.
+    low, high = p + 1 - 2 * (isqrt(p) + 1), p + 1 + 2 * (isqrt(p) + 1)
+    assert low <= 8 * l <= high and 7 * l < low and 9 * l > high
+    # `8 * P` has order `l`, so `l` divides `N`.
+    P = to_weierstrass(random_point())
+    x, y = P
+    assert y * y % p == (x**3 + a2 * x * x + a4 * x) % p
+    Q = weierstrass_mul(8, P)
+    assert Q is not None and weierstrass_mul(l, Q) is None
+
+
+def sage_check(vectors):
+    """Check the vectors against the group law of Sage."""
+    F = GF(p)
+    E = EllipticCurve(F, [0, a2, 0, a4, 0])
+    assert is_prime(p) and is_prime(l)
+    assert E.order() == 8 * l
+
+    def to_sage(point):
+        P = to_weierstrass(tuple(point))
+        return E(0) if P is None else E(P[0], P[1])
+
+    assert F(sqrt_minus_one) ** 2 == F(-1)
+    for vector in vectors["sqrt"]:
+        assert F(vector["a"]).is_square() == (vector["root"] is not None)
+        if vector["root"] is not None:
+            assert F(vector["root"]) ** 2 == F(vector["a"])
+    for vector in vectors["point_add"]:
+        assert to_sage(vector["p"]) + to_sage(vector["q"]) == to_sage(vector["sum"])
+    for vector in vectors["scalar_mul"]:
+        assert vector["scalar"] * to_sage(vector["point"]) == to_sage(vector["result"])
+
+
+def stringify(value):
+    """Print the integers as decimal strings, so no JSON parser rounds them."""
+    if isinstance(value, (bool, str)) or value is None:
+        return value
+    if isinstance(value, int):
+        return str(value)
+    if isinstance(value, list):
+        return [stringify(v) for v in value]
+    return {k: stringify(v) for k, v in value.items()}
+
+
+def main():
+    vectors = {
+        "constants": {"p": p, "l": l, "a": a, "d": d, "sqrt_minus_one": sqrt_minus_one},
+        "field": field_vectors(),
+        "sqrt": sqrt_vectors(),
+        "sqrt_ratio_i": sqrt_ratio_i_vectors(),
+        "point_add": point_add_vectors(),
+        "scalar_mul": scalar_mul_vectors(),
+    }
+    for kind in ("point_add", "scalar_mul"):
+        for vector in vectors[kind]:
+            assert all(is_on_curve(tuple(v)) for v in vector.values() if isinstance(v, list))
+    order_check()
+    if GF is not None:
+        sage_check(vectors)
+    print(json.dumps(stringify(vectors), indent=1))
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,230 @@
+// The `MontConfig` derive expands to code that trips these lints.
+#![allow(unexpected_cfgs, non_local_definitions)]
+//! Compatibility layer with the [arkworks](https://arkworks.rs)
+//! ecosystem.
+//!
+//! This module defines the arkworks counterparts of the Sonny
+//! curve types:
+//!
+//! - `Fq`: the base field, modulo `2^252 + 27742317777372353535851937790883648493`.
+//! - `Fr`: the prime-order subgroup scalar field, modulo
+//!   `2^249 + 14490550575682688738086195780655237219`.
+//! - `SonnyConfig`: the Twisted Edwards curve configuration,
+//!   with `EdwardsAffine` and `EdwardsProjective` as its point types.
+//!
+//! And the conversions between them and `FieldElement`, `Scalar`
+//! and `EdwardsPoint`, so the values computed with this library
+//! can be consumed by arkworks-based circuits and vice versa.
+//!
+//! # Examples
+//! ```rust
+//! use zerocaf::ark::*;
+//! use zerocaf::constants::BASEPOINT;
+//! use zerocaf::edwards::EdwardsPoint;
+//! use zerocaf::scalar::Scalar;
+//!
+//! let k = Scalar::from(1234u64);
+//!
+//! let P = EdwardsProjective::from(BASEPOINT) * Fr::from(k);
+//! assert!(EdwardsPoint::from(P) == BASEPOINT * k);
+//! ```
+
+use crate::edwards::EdwardsPoint;
+use crate::field::FieldElement;
+use crate::scalar::Scalar;
+
+use ark_ec::twisted_edwards::{Affine, MontCurveConfig, Projective, TECurveConfig};
+use ark_ec::CurveConfig;
+use ark_ff::fields::{Fp256, MontBackend, MontConfig};
+use ark_ff::{BigInteger, MontFp, PrimeField};
+
+// The `MontConfig` derive relies on the 2021 prelude.
+use core::convert::TryInto;
+
+/// Configuration of the base field of the Sonny curve.
+#[derive(MontConfig)]
+#[modulus = "7237005577332262213973186563042994240857116359379907606001950938285454250989"]
+#[generator = "2"]
+pub struct FqConfig;
+
+/// Base field of the Sonny curve.
+pub type Fq = Fp256<MontBackend<FqConfig, 4>>;
+
+/// Configuration of the scalar field of the prime-order subgroup.
+#[derive(MontConfig)]
+#[modulus = "904625697166532776746648320380374280118162305775999595296348570842476562531"]
+#[generator = "2"]
+pub struct FrConfig;
+
+/// Scalar field of the prime-order subgroup of the Sonny curve.
+pub type Fr = Fp256<MontBackend<FrConfig, 4>>;
+
+/// Twisted Edwards configuration of the Sonny curve:
+/// `-x^2 + y^2 = 1 + d*x^2*y^2` with `d = -126296/126297`.
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct SonnyConfig;
+
+/// Affine representation of a point of the Sonny curve.
+pub type EdwardsAffine = Affine<SonnyConfig>;
+
+/// Extended representation of a point of the Sonny curve.
+pub type EdwardsProjective = Projective<SonnyConfig>;
+
+impl CurveConfig for SonnyConfig {
+    type BaseField = Fq;
+    type ScalarField = Fr;
+
+    /// The cofactor of the curve is `8`.
+    const COFACTOR: &'static [u64] = &[8];
+
+    /// `8^-1 (mod l)`.
+    const COFACTOR_INV: Fr =
+        MontFp!("565391060729082985466655200237733925073851441109999747060217856776547851582");
+}
+
+impl TECurveConfig for SonnyConfig {
+    /// `a = -1`.
+    const COEFF_A: Fq = MontFp!("-1");
+
+    /// `d = -126296/126297 (mod p)`.
+    const COEFF_D: Fq =
+        MontFp!("951605751702391019481481818669129158712512026257330939079110344917983315091");
+
+    /// Affine coordinates of `constants::BASEPOINT`.
+    const GENERATOR: EdwardsAffine = EdwardsAffine::new_unchecked(
+        MontFp!("5543765830694772891887215782191909494666954494493566203295350876317221014088"),
+        MontFp!("4342203346399357328383911937825796544514269815627944563601170562971272550594"),
+    );
+
+    type MontCurveConfig = SonnyConfig;
+
+    #[inline(always)]
+    fn mul_by_a(elem: Fq) -> Fq {
+        -elem
+    }
+}
+
+impl MontCurveConfig for SonnyConfig {
+    /// `A = 2 * (a + d) / (a - d) = 505186`.
+    const COEFF_A: Fq = MontFp!("505186");
+
+    /// `B = 4 / (a - d) = -505188`.
+    const COEFF_B: Fq = MontFp!("-505188");
+
+    type TECurveConfig = SonnyConfig;
+}
+
+impl From<FieldElement> for Fq {
+    fn from(fe: FieldElement) -> Fq {
+        Fq::from_le_bytes_mod_order(&fe.to_bytes())
+    }
+}
+
+impl From<Fq> for FieldElement {
+    fn from(fq: Fq) -> FieldElement {
+        let mut bytes = [0u8; 32];
+        bytes.copy_from_slice(&fq.into_bigint().to_bytes_le());
+        FieldElement::from_bytes(&bytes)
+    }
+}
+
+impl From<Scalar> for Fr {
+    fn from(scalar: Scalar) -> Fr {
+        Fr::from_le_bytes_mod_order(&scalar.to_bytes())
+    }
+}
+
+impl From<Fr> for Scalar {
+    fn from(fr: Fr) -> Scalar {
+        let mut bytes = [0u8; 32];
+        bytes.copy_from_slice(&fr.into_bigint().to_bytes_le());
+        Scalar::from_bytes(&bytes)
+    }
+}
+
+impl From<EdwardsPoint> for EdwardsProjective {
+    /// Both libraries use the Twisted Edwards Extended Coordinates,
+    /// so the coordinates are moved as they are.
+    fn from(point: EdwardsPoint) -> EdwardsProjective {
+        EdwardsProjective::new_unchecked(
+            Fq::from(point.X),
+            Fq::from(point.Y),
+            Fq::from(point.T),
+            Fq::from(point.Z),
+        )
+    }
+}
+
+impl From<EdwardsProjective> for EdwardsPoint {
+    fn from(point: EdwardsProjective) -> EdwardsPoint {
+        EdwardsPoint {
+            X: FieldElement::from(point.x),
+            Y: FieldElement::from(point.y),
+            Z: FieldElement::from(point.z),
+            T: FieldElement::from(point.t),
+        }
+    }
+}
+
+impl From<EdwardsPoint> for EdwardsAffine {
+    fn from(point: EdwardsPoint) -> EdwardsAffine {
+        EdwardsAffine::from(EdwardsProjective::from(point))
+    }
+}
+
+impl From<EdwardsAffine> for EdwardsPoint {
+    fn from(point: EdwardsAffine) -> EdwardsPoint {
+        let x = FieldElement::from(point.x);
+        let y = FieldElement::from(point.y);
+        EdwardsPoint {
+            X: x,
+            Y: y,
+            Z: FieldElement::one(),
+            T: x * y,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants;
+    use crate::traits::Identity;
+
+    use ark_ec::CurveGroup;
+    use ark_ff::Field;
+
+    #[test]
+    fn field_conversions() {
+        let a = FieldElement::from(126296u64) / FieldElement::from(126297u64);
+        assert!(FieldElement::from(Fq::from(a)) == a);
+        assert!(Fq::from(-a) == SonnyConfig::COEFF_D);
+
+        let k = Scalar::minus_one();
+        assert!(Scalar::from(Fr::from(k)) == k);
+        assert!(Fr::from(Scalar::from(8u8)).inverse().unwrap() == SonnyConfig::COFACTOR_INV);
+    }
+
+    #[test]
+    fn generator_matches_basepoint() {
+        let generator = SonnyConfig::GENERATOR;
+        assert!(generator.is_on_curve());
+        assert!(generator.is_in_correct_subgroup_assuming_on_curve());
+        assert!(EdwardsAffine::from(constants::BASEPOINT) == generator);
+        assert!(EdwardsPoint::from(generator) == constants::BASEPOINT);
+    }
+
+    #[test]
+    fn arithmetic_matches() {
+        let k = Scalar::from(1122334455u64);
+        let p = EdwardsProjective::from(constants::BASEPOINT);
+
+        let expected = constants::BASEPOINT * k + constants::BASEPOINT;
+        let res = p * Fr::from(k) + p;
+        assert!(EdwardsPoint::from(res) == expected);
+        assert!(EdwardsPoint::from(res.into_affine()) == expected);
+
+        let identity = EdwardsProjective::from(EdwardsPoint::identity());
+        assert!(identity.into_affine().is_zero());
+    }
+}
@@ -0,0 +1,323 @@
+//! `FieldElement` multiplication with `mulx`, `adcx` and `adox`.
+//!
+//! The radix-`2^52` limbs get packed into four 64-bit words, and each
+//! row of the schoolbook product and of the Montgomery reduction adds
+//! the low halves of its `mulx` products on the OF carry chain
+//! (`adox`) and the high halves on the CF one (`adcx`), so the two
+//! chains run interleaved instead of serializing on a single carry.
+//!
+//! The Montgomery reductions use `R = 2^256`, while the `u64` backend
+//! uses `2^260`, so only the final products are the same on both.
+
+use core::arch::asm;
+
+use crate::backend::serial::constants;
+use crate::backend::serial::field::FieldElement;
+
+/// `l` as four 64-bit words, followed by `-l^-1 (mod 2^64)`.
+const MONTGOMERY_CONSTS: [u64; 5] = [
+    0x5812631a5cf5d3ed,
+    0x14def9dea2f79cd6,
+    0x0000000000000000,
+    0x1000000000000000,
+    0xd2b51da312547e1b,
+];
+
+/// `R^2 (mod l)` with `R = 2^256`, as four 64-bit words.
+const RR: [u64; 4] = [
+    0xa40611e3449c0f01,
+    0xd00e1ba768859347,
+    0xceec73d217f5be65,
+    0x0399411b7c309a3d,
+];
+
+/// Pack the radix-`2^52` limbs of a value `< 2^256` into 64-bit words.
+#[inline(always)]
+fn pack(a: &FieldElement) -> [u64; 4] {
+    [
+        a[0] | (a[1] << 52),
+        (a[1] >> 12) | (a[2] << 40),
+        (a[2] >> 24) | (a[3] << 28),
+        (a[3] >> 36) | (a[4] << 16),
+    ]
+}
+
+/// Unpack 64-bit words holding a value `< 2^256` into radix-`2^52` limbs.
+#[inline(always)]
+fn unpack(w: &[u64; 4]) -> FieldElement {
+    let mask = (1u64 << 52) - 1;
+    FieldElement([
+        w[0] & mask,
+        ((w[0] >> 52) | (w[1] << 12)) & mask,
+        ((w[1] >> 40) | (w[2] << 24)) & mask,
+        ((w[2] >> 28) | (w[3] << 36)) & mask,
+        w[3] >> 16,
+    ])
+}
+
+/// Compute the 512-bit product `a * b`.
+#[inline(always)]
+fn mul_4x4(a: &[u64; 4], b: &[u64; 4]) -> [u64; 8] {
+    let mut t = [0u64; 8];
+    unsafe {
+        asm!(
+            // t[0..5] = a[0] * b, nothing to accumulate yet.
+            "mov rdx, qword ptr [{a}]",
+            "mulx {t1}, {t0}, qword ptr [{b}]",
+            "mulx {t2}, {lo}, qword ptr [{b} + 8]",
+            "add {t1}, {lo}",
+            "mulx {t3}, {lo}, qword ptr [{b} + 16]",
+            "adc {t2}, {lo}",
+            "mulx {t4}, {lo}, qword ptr [{b} + 24]",
+            "adc {t3}, {lo}",
+            "adc {t4}, 0",
+            // t[1..6] += a[1] * b
+            "mov rdx, qword ptr [{a} + 8]",
+            "xor {t5:e}, {t5:e}",
+            "mulx {hi}, {lo}, qword ptr [{b}]",
+            "adox {t1}, {lo}",
+            "adcx {t2}, {hi}",
+            "mulx {hi}, {lo}, qword ptr [{b} + 8]",
+            "adox {t2}, {lo}",
+            "adcx {t3}, {hi}",
+            "mulx {hi}, {lo}, qword ptr [{b} + 16]",
+            "adox {t3}, {lo}",
+            "adcx {t4}, {hi}",
+            "mulx {hi}, {lo}, qword ptr [{b} + 24]",
+            "adox {t4}, {lo}",
+            "adcx {t5}, {hi}",
+            "mov {lo}, 0",
+            "adox {t5}, {lo}",
+            // t[2..7] += a[2] * b
+            "mov rdx, qword ptr [{a} + 16]",
+            "xor {t6:e}, {t6:e}",
+            "mulx {hi}, {lo}, qword ptr [{b}]",
+            "adox {t2}, {lo}",
+            "adcx {t3}, {hi}",
+            "mulx {hi}, {lo}, qword ptr [{b} + 8]",
+            "adox {t3}, {lo}",
+            "adcx {t4}, {hi}",
+            "mulx {hi}, {lo}, qword ptr [{b} + 16]",
+            "adox {t4}, {lo}",
+            "adcx {t5}, {hi}",
+            "mulx {hi}, {lo}, qword ptr [{b} + 24]",
+            "adox {t5}, {lo}",
+            "adcx {t6}, {hi}",
+            "mov {lo}, 0",
+            "adox {t6}, {lo}",
+            // t[3..8] += a[3] * b
+            "mov rdx, qword ptr [{a} + 24]",
+            "xor {t7:e}, {t7:e}",
+            "mulx {hi}, {lo}, qword ptr [{b}]",
+            "adox {t3}, {lo}",
+            "adcx {t4}, {hi}",
+            "mulx {hi}, {lo}, qword ptr [{b} + 8]",
+            "adox {t4}, {lo}",
+            "adcx {t5}, {hi}",
+            "mulx {hi}, {lo}, qword ptr [{b} + 16]",
+            "adox {t5}, {lo}",
+            "adcx {t6}, {hi}",
+            "mulx {hi}, {lo}, qword ptr [{b} + 24]",
+            "adox {t6}, {lo}",
+            "adcx {t7}, {hi}",
+            "mov {lo}, 0",
+            "adox {t7}, {lo}",
+            a = in(reg) a.as_ptr(),
+            b = in(reg) b.as_ptr(),
+            t0 = out(reg) t[0],
+            t1 = out(reg) t[1],
+            t2 = out(reg) t[2],
+            t3 = out(reg) t[3],
+            t4 = out(reg) t[4],
+            t5 = out(reg) t[5],
+            t6 = out(reg) t[6],
+            t7 = out(reg) t[7],
+            lo = out(reg) _,
+            hi = out(reg) _,
+            out("rdx") _,
+            options(pure, readonly, nostack),
+        );
+    }
+    t
+}
+
+/// Compute `t / R (mod l)`, with `R = 2^256`, as a value `< 3 * l`
+/// for any `t < 2 * l * R`.
+#[inline(always)]
+fn montgomery_reduce(t: [u64; 8]) -> [u64; 4] {
+    let [mut t0, mut t1, mut t2, mut t3, mut t4, mut t5, mut t6, mut t7] = t;
+    unsafe {
+        asm!(
+            // t += (t[0] * -l^-1 (mod 2^64)) * l, which clears t[0].
+            "mov rdx, {t0}",
+            "imul rdx, qword ptr [{k} + 32]",
+            "xor {lo:e}, {lo:e}",
+            "mulx {hi}, {lo}, qword ptr [{k}]",
+            "adox {t0}, {lo}",
+            "adcx {t1}, {hi}",
+            "mulx {hi}, {lo}, qword ptr [{k} + 8]",
+            "adox {t1}, {lo}",
+            "adcx {t2}, {hi}",
+            "mulx {hi}, {lo}, qword ptr [{k} + 16]",
+            "adox {t2}, {lo}",
+            "adcx {t3}, {hi}",
+            "mulx {hi}, {lo}, qword ptr [{k} + 24]",
+            "adox {t3}, {lo}",
+            "adcx {t4}, {hi}",
+            "mov {lo}, 0",
+            "adox {t4}, {lo}",
+            "adcx {t5}, {lo}",
+            "adox {t5}, {lo}",
+            "adcx {t6}, {lo}",
+            "adox {t6}, {lo}",
+            "adcx {t7}, {lo}",
+            "adox {t7}, {lo}",
+            // Same with t[1] * 2^64.
+            "mov rdx, {t1}",
+            "imul rdx, qword ptr [{k} + 32]",
+            "xor {lo:e}, {lo:e}",
+            "mulx {hi}, {lo}, qword ptr [{k}]",
+            "adox {t1}, {lo}",
+            "adcx {t2}, {hi}",
+            "mulx {hi}, {lo}, qword ptr [{k} + 8]",
+            "adox {t2}, {lo}",
+            "adcx {t3}, {hi}",
+            "mulx {hi}, {lo}, qword ptr [{k} + 16]",
+            "adox {t3}, {lo}",
+            "adcx {t4}, {hi}",
+            "mulx {hi}, {lo}, qword ptr [{k} + 24]",
+            "adox {t4}, {lo}",
+            "adcx {t5}, {hi}",
+            "mov {lo}, 0",
+            "adox {t5}, {lo}",
+            "adcx {t6}, {lo}",
+            "adox {t6}, {lo}",
+            "adcx {t7}, {lo}",
+            "adox {t7}, {lo}",
+            // Same with t[2] * 2^128.
+            "mov rdx, {t2}",
+            "imul rdx, qword ptr [{k} + 32]",
+            "xor {lo:e}, {lo:e}",
+            "mulx {hi}, {lo}, qword ptr [{k}]",
+            "adox {t2}, {lo}",
+            "adcx {t3}, {hi}",
+            "mulx {hi}, {lo}, qword ptr [{k} + 8]",
+            "adox {t3}, {lo}",
+            "adcx {t4}, {hi}",
+            "mulx {hi}, {lo}, qword ptr [{k} + 16]",
+            "adox {t4}, {lo}",
+            "adcx {t5}, {hi}",
+            "mulx {hi}, {lo}, qword ptr [{k} + 24]",
+            "adox {t5}, {lo}",
+            "adcx {t6}, {hi}",
+            "mov {lo}, 0",
+            "adox {t6}, {lo}",
+            "adcx {t7}, {lo}",
+            "adox {t7}, {lo}",
+            // Same with t[3] * 2^192.
+            "mov rdx, {t3}",
+            "imul rdx, qword ptr [{k} + 32]",
+            "xor {lo:e}, {lo:e}",
+            "mulx {hi}, {lo}, qword ptr [{k}]",
+            "adox {t3}, {lo}",
+            "adcx {t4}, {hi}",
+            "mulx {hi}, {lo}, qword ptr [{k} + 8]",
+            "adox {t4}, {lo}",
+            "adcx {t5}, {hi}",
+            "mulx {hi}, {lo}, qword ptr [{k} + 16]",
+            "adox {t5}, {lo}",
+            "adcx {t6}, {hi}",
+            "mulx {hi}, {lo}, qword ptr [{k} + 24]",
+            "adox {t6}, {lo}",
+            "adcx {t7}, {hi}",
+            "mov {lo}, 0",
+            "adox {t7}, {lo}",
+            k = in(reg) MONTGOMERY_CONSTS.as_ptr(),
+            t0 = inout(reg) t0,
+            t1 = inout(reg) t1,
+            t2 = inout(reg) t2,
+            t3 = inout(reg) t3,
+            t4 = inout(reg) t4,
+            t5 = inout(reg) t5,
+            t6 = inout(reg) t6,
+            t7 = inout(reg) t7,
+            lo = out(reg) _,
+            hi = out(reg) _,
+            out("rdx") _,
+            options(pure, readonly, nostack),
+        );
+    }
+    // The lower words are zero by now.
+    let _ = (t0, t1, t2, t3);
+    [t4, t5, t6, t7]
+}
+
+/// Compute `a * b / R (mod l)` as a value `< 3 * l`, for any
+/// `a * b < 2 * l * R`.
+#[inline(always)]
+fn montgomery_mul(a: &[u64; 4], b: &[u64; 4]) -> [u64; 4] {
+    montgomery_reduce(mul_4x4(a, b))
+}
+
+/// Compute `a * b (mod l)`, for any `a, b < 2^256`.
+///
+/// As `FieldElement::from_bytes` keeps the 256 bits of its input,
+/// `a` gets into the Montgomery form first, which also reduces it
+/// below `2 * l`. Then a Montgomery multiplication by `b` gives
+/// `a * b (mod l)` plus at most `2 * l`, which gets subtracted.
+#[inline(always)]
+pub(crate) fn mul(a: &FieldElement, b: &FieldElement) -> FieldElement {
+    let a_mont = montgomery_mul(&pack(a), &RR);
+    let res = unpack(&montgomery_mul(&a_mont, &pack(b)));
+    res - constants::FIELD_L - constants::FIELD_L
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use num::BigUint;
+    use rand::rngs::OsRng;
+
+    fn to_biguint(a: &FieldElement) -> BigUint {
+        BigUint::from_bytes_le(&a.to_bytes())
+    }
+
+    #[test]
+    fn pack_roundtrip() {
+        let a = FieldElement::random(&mut OsRng);
+        assert!(unpack(&pack(&a)) == a);
+        assert!(unpack(&pack(&FieldElement::minus_one())) == FieldElement::minus_one());
+    }
+
+    #[test]
+    fn mul_matches_biguint() {
+        let l = to_biguint(&constants::FIELD_L);
+        let mut elems = vec![
+            FieldElement::zero(),
+            FieldElement::one(),
+            FieldElement::minus_one(),
+        ];
+        for _ in 0..64 {
+            elems.push(FieldElement::random(&mut OsRng));
+        }
+        for a in elems.iter() {
+            for b in elems.iter().take(8) {
+                let expected = (to_biguint(a) * to_biguint(b)) % &l;
+                assert!(to_biguint(&mul(a, b)) == expected);
+            }
+        }
+    }
+
+    #[test]
+    fn mul_unreduced_inputs() {
+        // `FieldElement::from_bytes` keeps values up to `2^256 - 1`.
+        let a = FieldElement::from_bytes(&[0xff; 32]);
+        let a_int = BigUint::from_bytes_le(&[0xff; 32]);
+        let b = FieldElement::random(&mut OsRng);
+        let l = to_biguint(&constants::FIELD_L);
+        assert!(to_biguint(&mul(&a, &a)) == (&a_int * &a_int) % &l);
+        assert!(to_biguint(&mul(&a, &b)) == (&a_int * to_biguint(&b)) % &l);
+        assert!(to_biguint(&mul(&b, &a)) == (&a_int * to_biguint(&b)) % &l);
+    }
+}
@@ -0,0 +1,10 @@
+//! Hand-written x86_64 assembly for the hot paths of the `u64`
+//! backend.
+//!
+//! It needs the BMI2 (`mulx`) and ADX (`adcx`, `adox`) extensions,
+//! which are checked at compile time, so build with
+//! `RUSTFLAGS="-C target-cpu=native"` or
+//! `RUSTFLAGS="-C target-feature=+bmi2,+adx"` to get it. Otherwise
+//! the `asm` feature has no effect.
+
+pub mod field;
@@ -2,15 +2,71 @@
 //!
 //! On this module you can find the different implementations
 //! done for Finite Fields mathematical-backends.
+//!
+//! The backends are picked with cargo features, and `serial`
+//! points to the selected one, so `field::FieldElement` and
+//! `scalar::Scalar` are its types. `u64_backend` (default) is the
+//! only serial backend, with radix `2^52` limbs and `u128` products.
+//!
+//! The other features are not alternative backends but layers on
+//! top of `u64_backend`, each replacing some of its operations, so
+//! they require it and can be combined with each other:
+//!
+//! - `asm`: x86_64 assembly for the `FieldElement` multiplication.
+//! - `u32_backend`: radix `2^29` limbs with `u64` products for the
+//!   `Scalar` multiplication and squaring.
+//!
+//! `u32_backend` only covers the `Scalar`s, there's no 32-bit backend
+//! for the `FieldElement`s. On targets without native `64x64 -> 128`
+//! multiplication the field arithmetic still works, since the `u128`
+//! products get emulated by the compiler, but it runs slower.
 
 /// The u64 backend contains the implementation of all of the
 /// mathematical base eg. Arithmetics over Finite Fields with
 /// a design specially thought out 64-bit architectures.
+#[cfg(feature = "u64_backend")]
 pub mod u64;
-#[cfg(not(any(feature = "u64_backend")))]
 
-// A backend feature must fair be chosen.
+/// The serial backend selected by the cargo features.
+///
+/// The rest of the crate only goes through this alias, so adding a
+/// backend means adding its module and feature, and pointing `serial`
+/// to it when the feature is enabled.
+#[cfg(feature = "u64_backend")]
+pub use self::u64 as serial;
+
+/// The u32 backend replaces the `Scalar` multiplication and squaring
+/// of the u64 backend by ones with 32-bit limbs and 64-bit products,
+/// when the `u32_backend` feature is enabled.
+#[cfg(feature = "u32_backend")]
+pub mod u32;
+
+/// The vector backend contains the types that perform the same
+/// operation over several independent values at once.
+pub mod vector;
+
+/// The asm backend replaces the `FieldElement` multiplication of the
+/// u64 backend by x86_64 assembly, when the `asm` feature is enabled
+/// and the target supports BMI2 and ADX.
+#[cfg(all(
+    feature = "asm",
+    target_arch = "x86_64",
+    target_feature = "bmi2",
+    target_feature = "adx"
+))]
+pub mod asm;
+
+// A serial backend feature must be chosen.
+#[cfg(not(any(feature = "u64_backend")))]
 compile_error!(
     "no zerocaf backend cargo feature enabled! \
      please enable u64_backend"
 );
+
+// The asm backend only replaces some of the u64 backend operations.
+#[cfg(all(feature = "asm", not(feature = "u64_backend")))]
+compile_error!("the asm feature builds on top of u64_backend, please enable it too");
+
+// The u32 backend only replaces some of the u64 backend operations.
+#[cfg(all(feature = "u32_backend", not(feature = "u64_backend")))]
+compile_error!("the u32_backend feature builds on top of u64_backend, please enable it too");
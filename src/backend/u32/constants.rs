@@ -0,0 +1,18 @@
+//! Limbs in radix `2^29` of the constants of the `Scalar` arithmetic
+//! of the `u32` backend.
+
+/// Limbs of the constants, computed by `build.rs` from the order of
+/// the subgroup.
+mod generated {
+    include!(concat!(env!("OUT_DIR"), "/constants_u32.rs"));
+}
+
+/// `L = 2^249 + 14490550575682688738086195780655237219` in radix `2^29`.
+pub const L: [u32; 9] = generated::L;
+
+/// `LFACTOR` satisfies `L * LFACTOR = -1 (mod 2^29)`, it's the factor
+/// of the Montgomery reductions.
+pub const LFACTOR: u32 = generated::LFACTOR;
+
+/// `RR = (2^261)^2 (mod L)`, the square of the Montgomery modulus.
+pub const RR: [u32; 9] = generated::RR;
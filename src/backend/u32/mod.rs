@@ -0,0 +1,15 @@
+//! Scalar arithmetic of the `u64` backend with 32-bit limbs, for the
+//! targets without native `64x64 -> 128` multiplication, where the
+//! `u128` products of the `u64` backend get emulated.
+//!
+//! The `Scalar` multiplication and squaring work on nine limbs in
+//! radix `2^29` with `u64` products, and the results get packed back
+//! into the radix `2^52` limbs of `Scalar`, so the rest of the crate
+//! doesn't notice the difference.
+//!
+//! Only the `Scalar`s are covered: there's no 32-bit `FieldElement`
+//! arithmetic, so the field operations, which most of the curve
+//! arithmetic is made of, keep emulating the `u128` products.
+
+pub mod constants;
+pub mod scalar;
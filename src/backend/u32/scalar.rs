@@ -0,0 +1,256 @@
+//! `Scalar` multiplication and squaring mod
+//! `2^249 + 14490550575682688738086195780655237219` with nine 29-bit
+//! unsigned limbs represented in radix `2^29`.
+//!
+//! The products are computed with `u64` products only, and reduced
+//! with two Montgomery reductions of modulus `R = 2^261`, following
+//! the `Scalar29` of the
+//! [curve25519-dalek repository](https://github.com/dalek-cryptography/curve25519-dalek).
+
+use crate::backend::u32::constants;
+use crate::backend::u64::scalar::Scalar;
+
+/// Mask of the 29 bits of a limb.
+const MASK: u32 = (1u32 << 29) - 1;
+
+/// u32 * u32 = u64 inline func multiply helper
+#[inline(always)]
+fn m(x: u32, y: u32) -> u64 {
+    (x as u64) * (y as u64)
+}
+
+/// Split a `Scalar` into nine limbs in radix `2^29`.
+fn pack(a: &Scalar) -> [u32; 9] {
+    let words = a.to_u64_words();
+    let mut res = [0u32; 9];
+    for (i, limb) in res.iter_mut().enumerate() {
+        let (w, shift) = ((29 * i) / 64, (29 * i) % 64);
+        let mut bits = words[w] >> shift;
+        // The limb spans two words.
+        if shift > 64 - 29 && w < 3 {
+            bits |= words[w + 1] << (64 - shift);
+        };
+        *limb = (bits as u32) & MASK;
+    }
+    res
+}
+
+/// Join nine limbs in radix `2^29` holding a value `< 2^256` into
+/// a `Scalar`.
+fn unpack(limbs: &[u32; 9]) -> Scalar {
+    let mut words = [0u64; 4];
+    for (i, limb) in limbs.iter().enumerate() {
+        let (w, shift) = ((29 * i) / 64, (29 * i) % 64);
+        words[w] |= (*limb as u64) << shift;
+        if shift > 64 - 29 && w < 3 {
+            words[w + 1] |= (*limb as u64) >> (64 - shift);
+        };
+    }
+
+    let mask = (1u64 << 52) - 1;
+    Scalar([
+        words[0] & mask,
+        ((words[0] >> 52) | (words[1] << 12)) & mask,
+        ((words[1] >> 40) | (words[2] << 24)) & mask,
+        ((words[2] >> 28) | (words[3] << 36)) & mask,
+        words[3] >> 16,
+    ])
+}
+
+/// Compute `a - l` if `a >= l`, or `a` otherwise, in constant time.
+fn sub_l(a: &[u32; 9]) -> [u32; 9] {
+    let l = &constants::L;
+    let mut difference = [0u32; 9];
+
+    let mut borrow: u32 = 0;
+    for i in 0..9 {
+        borrow = a[i].wrapping_sub(l[i] + (borrow >> 31));
+        difference[i] = borrow & MASK;
+    }
+
+    // Add `l` back if the difference is negative.
+    let underflow_mask = ((borrow >> 31) ^ 1).wrapping_sub(1);
+    let mut carry: u32 = 0;
+    for i in 0..9 {
+        carry = (carry >> 29) + difference[i] + (l[i] & underflow_mask);
+        difference[i] = carry & MASK;
+    }
+    difference
+}
+
+/// Compute `a * b`.
+///
+/// This operation returns a double precision result stored on a
+/// `[u64; 17]`, where every limb is the sum of at most nine 58-bit
+/// products.
+fn mul_internal(a: &[u32; 9], b: &[u32; 9]) -> [u64; 17] {
+    let mut res = [0u64; 17];
+    for i in 0..9 {
+        for j in 0..9 {
+            res[i + j] += m(a[i], b[j]);
+        }
+    }
+    res
+}
+
+/// Compute `a^2`, only computing once the products that appear
+/// twice.
+fn square_internal(a: &[u32; 9]) -> [u64; 17] {
+    let mut res = [0u64; 17];
+    for i in 0..9 {
+        res[2 * i] += m(a[i], a[i]);
+        for j in (i + 1)..9 {
+            res[i + j] += m(2 * a[i], a[j]);
+        }
+    }
+    res
+}
+
+/// Compute `limbs / R (mod l)`, where R is the Montgomery modulus
+/// `2^261`, as a value `< 2^261`.
+///
+/// For any `limbs < 2^261 * k * l`, the result is `< (k + 1) * l`.
+fn montgomery_reduce(limbs: &[u64; 17]) -> [u32; 9] {
+    let l = &constants::L;
+
+    // The first half computes the Montgomery adjustment factor `n`,
+    // and adds `n * l` to make `limbs` divisible by `R`.
+    let mut n = [0u32; 9];
+    let mut carry = 0u64;
+    for i in 0..9 {
+        let mut sum = carry + limbs[i];
+        for j in 0..i {
+            sum += m(n[j], l[i - j]);
+        }
+        n[i] = (sum as u32).wrapping_mul(constants::LFACTOR) & MASK;
+        carry = (sum + m(n[i], l[0])) >> 29;
+    }
+
+    // `limbs` is divisible by `R` now, so the result is its upper half.
+    let mut res = [0u32; 9];
+    for i in 9..17 {
+        let mut sum = carry + limbs[i];
+        for j in (i - 8)..9 {
+            sum += m(n[j], l[i - j]);
+        }
+        res[i - 9] = (sum as u32) & MASK;
+        carry = sum >> 29;
+    }
+    res[8] = carry as u32;
+    res
+}
+
+/// Compute `limbs (mod l)` for the product `limbs` of two values
+/// `< 2^256`.
+///
+/// The first reduction gives `limbs / R (mod l)` as a value `< 5 * l`,
+/// and multiplying it by `R^2` with the second one gives `limbs (mod l)`
+/// as a value `< 2 * l`, so a single subtraction of `l` finishes.
+fn reduce(limbs: &[u64; 17]) -> Scalar {
+    let res = montgomery_reduce(limbs);
+    let res = montgomery_reduce(&mul_internal(&res, &constants::RR));
+    unpack(&sub_l(&res))
+}
+
+/// Compute `a * b (mod l)`.
+pub fn mul(a: &Scalar, b: &Scalar) -> Scalar {
+    reduce(&mul_internal(&pack(a), &pack(b)))
+}
+
+/// Compute `a^2 (mod l)`.
+pub fn square(a: &Scalar) -> Scalar {
+    reduce(&square_internal(&pack(a)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::u64::constants as constants_u64;
+
+    use num::BigUint;
+    use rand::rngs::OsRng;
+
+    fn big(a: &Scalar) -> BigUint {
+        BigUint::from_bytes_le(&a.to_bytes())
+    }
+
+    fn big_limbs(limbs: &[u32; 9]) -> BigUint {
+        limbs.iter().rev().fold(BigUint::from(0u8), |acc, limb| {
+            (acc << 29) + BigUint::from(*limb)
+        })
+    }
+
+    /// Edge cases, followed by random `Scalar`s.
+    fn scalars() -> Vec<Scalar> {
+        let mut scalars = vec![
+            Scalar::zero(),
+            Scalar::one(),
+            Scalar::from(2u8),
+            Scalar::minus_one(),
+            -Scalar::from(2u8),
+            Scalar::two_pow_k(248),
+        ];
+        scalars.extend((0..32).map(|_| Scalar::random(&mut OsRng)));
+        scalars
+    }
+
+    #[test]
+    fn constants() {
+        let l = big(&constants_u64::L);
+        assert_eq!(big_limbs(&constants::L), l);
+        assert!(constants::L.iter().all(|limb| *limb <= MASK));
+        let r = BigUint::from(1u8) << 261;
+        assert_eq!(big_limbs(&constants::RR), &r * &r % &l);
+        let lfactor = (&l * BigUint::from(constants::LFACTOR)) % (BigUint::from(1u8) << 29);
+        assert_eq!(lfactor, (BigUint::from(1u8) << 29) - BigUint::from(1u8));
+    }
+
+    #[test]
+    fn pack_unpack() {
+        for a in scalars() {
+            let limbs = pack(&a);
+            assert!(limbs.iter().all(|limb| *limb <= MASK));
+            assert_eq!(big_limbs(&limbs), big(&a));
+            assert!(unpack(&limbs) == a);
+        }
+        // Values up to `2^256` get through as well.
+        let mut limbs = [MASK; 9];
+        limbs[8] = (1u32 << 24) - 1;
+        assert_eq!(unpack(&limbs).to_u64_words(), [u64::MAX; 4]);
+        assert!(pack(&unpack(&limbs)) == limbs);
+    }
+
+    #[test]
+    fn mul_vs_u64() {
+        let l = big(&constants_u64::L);
+        for a in scalars() {
+            for b in scalars().iter().take(8) {
+                let res = mul(&a, b);
+                // The Montgomery multiplication of the `u64` backend.
+                assert!(res == (a.unpack() * b.unpack()).pack());
+                assert_eq!(big(&res), big(&a) * big(b) % &l);
+            }
+        }
+    }
+
+    #[test]
+    fn square_vs_u64() {
+        let l = big(&constants_u64::L);
+        for a in scalars() {
+            let res = square(&a);
+            assert!(res == a.unpack().square().pack());
+            assert!(res == mul(&a, &a));
+            assert_eq!(big(&res), big(&a) * big(&a) % &l);
+        }
+    }
+
+    #[test]
+    fn unreduced_inputs() {
+        // `L` itself is used as a multiplier, eg. to clear the
+        // prime-order component of a point.
+        let l = constants_u64::L;
+        let a = Scalar::random(&mut OsRng);
+        assert!(mul(&l, &a) == Scalar::zero());
+        assert!(square(&l) == Scalar::zero());
+    }
+}
@@ -0,0 +1,1663 @@
+//! Precomputed `EdwardsBasepointTableRadix16` of the `BASEPOINT`.
+//!
+//! Generated with `cargo run --bin gen_basepoint_table`, do not edit.
+
+use crate::edwards::{EdwardsBasepointTableRadix16, EdwardsPoint};
+use crate::field::FieldElement;
+use crate::window::LookupTable;
+
+/// Table of the multiples of the `BASEPOINT` used by
+/// `EdwardsPoint::mul_base`, with all of the points in affine form.
+#[rustfmt::skip]
+pub static EDWARDS_BASEPOINT_TABLE: EdwardsBasepointTableRadix16 = EdwardsBasepointTableRadix16([
+    LookupTable([
+        EdwardsPoint {
+            X: FieldElement([276718085098056, 1646536057461434, 2704687245600312, 2630386667454967, 13476148227069]),
+            Y: FieldElement([1303868825475266, 3250718520537114, 2702159777242978, 2702159776422297, 10555311626649]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([3634527586288175, 2006028620404053, 3424252198034825, 2478951925947079, 4567251727358]),
+        },
+        EdwardsPoint {
+            X: FieldElement([3952819836805451, 3608173022897048, 166306763174400, 4428907319678257, 13801875063148]),
+            Y: FieldElement([2416309975801701, 3075223279118825, 2792099914507555, 3765854470987338, 10396487366685]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([4051746840506153, 1643734455010820, 1143427820312612, 4054362386139733, 13652367534762]),
+        },
+        EdwardsPoint {
+            X: FieldElement([813876609308341, 1728323486619438, 3951310893298620, 2801761978206886, 3520373526008]),
+            Y: FieldElement([1952645710701952, 1757669271315047, 4019905374456593, 695171825951702, 5691510361005]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([1628144183416791, 812646916743001, 2401096071533748, 16892852300352, 8134388607746]),
+        },
+        EdwardsPoint {
+            X: FieldElement([4450186912884840, 2688045145156890, 1799524108687765, 381679860237228, 12192252374341]),
+            Y: FieldElement([4069813056355752, 2570798986735147, 3228848439827516, 4196669997117091, 5876339908752]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([3710326441450170, 3602004858445688, 930601334541656, 569144445371027, 10963743436804]),
+        },
+        EdwardsPoint {
+            X: FieldElement([1059632703244016, 2926499263949724, 3999969590852697, 3777705036544105, 10335114615479]),
+            Y: FieldElement([3313793392247560, 715378878927984, 2218227762614426, 504357317844774, 5281433381884]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([1328684767592500, 2594029584905760, 921281055311236, 1968602804967126, 3803202593864]),
+        },
+        EdwardsPoint {
+            X: FieldElement([867605214875079, 4236560480166800, 1919382647166437, 4051901105400236, 3751814858863]),
+            Y: FieldElement([4419400059982476, 1994361414172177, 316798927035521, 1144332636164422, 12198267787866]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([3060642979353246, 2507164125770979, 3508321429923515, 2648165562390024, 3832709152801]),
+        },
+        EdwardsPoint {
+            X: FieldElement([1257763111735056, 4407557723578025, 1231959381829332, 4092032981199885, 6223619063241]),
+            Y: FieldElement([1973355030987202, 2432650921386359, 883467217081617, 3296695140083762, 17269948706615]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([3628600692339359, 3167435582349040, 1604909949008414, 4422880781207775, 15295702429720]),
+        },
+        EdwardsPoint {
+            X: FieldElement([2080655798740091, 1727227183389393, 2781301048587036, 3624846245348641, 13814367117789]),
+            Y: FieldElement([3269198614698043, 199693516362685, 2556030210912092, 3963298064383175, 17107407542536]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([3648198462182033, 588348801431423, 686514014058211, 164710501058211, 3483132383912]),
+        },
+    ]),
+    LookupTable([
+        EdwardsPoint {
+            X: FieldElement([1779271615847454, 2170325225982865, 512683248632205, 2391610557233987, 13239053741591]),
+            Y: FieldElement([3826543913510238, 1869142955546376, 211721480007749, 779922552767602, 15597288609123]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([1290493376361287, 3010788893006362, 3219324034271510, 4197664822583256, 7311070033603]),
+        },
+        EdwardsPoint {
+            X: FieldElement([2084718068215299, 2056445072060540, 554029568967217, 2079841129178626, 1939770717353]),
+            Y: FieldElement([3583227163978814, 2439421254086380, 3484024269543320, 388416537578143, 5671026789333]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([652066695103786, 3654955895974633, 4275412183688272, 1400464375522335, 8872592668549]),
+        },
+        EdwardsPoint {
+            X: FieldElement([384380539261640, 2230072878596518, 2008726827371594, 2378279825517378, 17037554020962]),
+            Y: FieldElement([2127592533781490, 1976434729268916, 329687654445801, 4196995310030, 3623824534036]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([1274750016583449, 3445886881156978, 113131829252791, 2438403384674895, 8342147692193]),
+        },
+        EdwardsPoint {
+            X: FieldElement([912098974403440, 228296992075364, 2580889203621051, 2312017606587242, 5726822446578]),
+            Y: FieldElement([535800383320362, 881244359962396, 2816286922423680, 2228549038202957, 950861247791]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([1337496617580063, 1039033334375581, 3126410219835632, 3474279808053536, 8347116529674]),
+        },
+        EdwardsPoint {
+            X: FieldElement([4207273940398457, 3307211359715484, 3182494178914458, 1116103976776652, 9030398495305]),
+            Y: FieldElement([720217395926669, 1846418264144433, 3017113444121495, 348745916715982, 16164549945888]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([1908762076657679, 4419303769393734, 1081529131607431, 2993616599090155, 13839142039160]),
+        },
+        EdwardsPoint {
+            X: FieldElement([312896159345957, 3379467444765995, 4192646972661492, 3261289256358018, 16394086979080]),
+            Y: FieldElement([3577882385708087, 4262522292419816, 1686012006989514, 3250174649785903, 12919517770862]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([3961776291641272, 2232893883177150, 3759854670225560, 1143861230671351, 16663142849264]),
+        },
+        EdwardsPoint {
+            X: FieldElement([2890726859955465, 1608460542468718, 3322748399205468, 2064432716897300, 11998268334518]),
+            Y: FieldElement([1497718160584515, 627318916141748, 3796072932664172, 2914141821041447, 2815612682694]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([1061943867595926, 2130625297420325, 2155278498580049, 3156611387859065, 1825604341809]),
+        },
+        EdwardsPoint {
+            X: FieldElement([4070400339412363, 2705597125603742, 282997756644512, 3747335938899971, 920386049131]),
+            Y: FieldElement([781812224992223, 1121579734590217, 2969508948330479, 1793022659194910, 1089693484996]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([187571279196806, 4245199725767709, 4478516744746189, 1696698647017659, 15406171116171]),
+        },
+    ]),
+    LookupTable([
+        EdwardsPoint {
+            X: FieldElement([2221427679664423, 1897709039969790, 133172349633277, 1649995181427677, 6793063407721]),
+            Y: FieldElement([3300469000486646, 2873664624367582, 2520337139957701, 685045192986178, 11044660808095]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([908280110897853, 1319447921519037, 1968846441787995, 736777180634370, 14286263740271]),
+        },
+        EdwardsPoint {
+            X: FieldElement([3473242245860014, 4072622498263374, 2070446498614713, 3656240996679074, 9234902763301]),
+            Y: FieldElement([1742771238307300, 3704445659197140, 3550039918811359, 776991948400451, 302999573359]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([4497624444289323, 2295567644542269, 2946640733748119, 2645328378725104, 8963908925640]),
+        },
+        EdwardsPoint {
+            X: FieldElement([2954959996730858, 3980053027833022, 2663459815656408, 1079060577408974, 5275015027947]),
+            Y: FieldElement([426975984012718, 2995180531043812, 211253498665861, 2366608223794125, 11080237130766]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([110938918736509, 1242313859983014, 4306100180388048, 4199145049398136, 461778746818]),
+        },
+        EdwardsPoint {
+            X: FieldElement([193394545916278, 927781589533843, 3795640842988304, 913487235988702, 8541751750803]),
+            Y: FieldElement([4166669552386522, 243214555710527, 3811322502485089, 4301293628554988, 13719675987194]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([1041709959422114, 1775868965147777, 1793192557908145, 4373664591111673, 9872567234322]),
+        },
+        EdwardsPoint {
+            X: FieldElement([3493074689808853, 3616816693185832, 1498072227365270, 4328200034985234, 3216090292928]),
+            Y: FieldElement([2644120237077719, 2580266622429181, 266033130283378, 1271283758786750, 2811073433959]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([3246758188869352, 1052059182471396, 1677657372397102, 2197216634899507, 16105391714083]),
+        },
+        EdwardsPoint {
+            X: FieldElement([2200688273897467, 4277008375073410, 1190518407594877, 3377553998247818, 15047758664451]),
+            Y: FieldElement([536417892448809, 2210102229412392, 1905826097492768, 1792405179795056, 17221433139493]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([511422188358470, 662790670554120, 2553277804311716, 4364465201357667, 6215982916962]),
+        },
+        EdwardsPoint {
+            X: FieldElement([3322260083012573, 292428768901791, 4079660861093975, 4456061929609199, 9184725890052]),
+            Y: FieldElement([2041436674571577, 3217856470005764, 2574182683836042, 654813216596666, 4742701424424]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([2705037067226719, 492936312977869, 24247963282684, 1331616701925867, 8495416531791]),
+        },
+        EdwardsPoint {
+            X: FieldElement([3156940915484893, 1382873616219974, 3434182457481498, 2712245063591964, 13794550838293]),
+            Y: FieldElement([2440123485582887, 572073212018663, 64390629620116, 946763556427058, 2368669101030]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([3615894913830080, 1598256390359732, 3845815032215965, 2254098788995617, 14379255678593]),
+        },
+    ]),
+    LookupTable([
+        EdwardsPoint {
+            X: FieldElement([1393363820141173, 596659971628283, 2786433621858898, 2955306352361078, 4505418150560]),
+            Y: FieldElement([3136078210318333, 1447941876150740, 8459404372082, 1466194295551578, 2895190825458]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([2306931107052609, 1404315050088886, 4078626332847388, 2454022395524182, 6136998082520]),
+        },
+        EdwardsPoint {
+            X: FieldElement([4239814945628579, 3789590409054963, 3115688067842692, 2007594757786872, 11332503812177]),
+            Y: FieldElement([448418309815499, 3019360285596518, 55461696342736, 1453177538773985, 14887711858758]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([2376518746614546, 1104872374372156, 2506104752216721, 3739718895774873, 10174625730548]),
+        },
+        EdwardsPoint {
+            X: FieldElement([2294811406654659, 1074148059645915, 357438819979993, 3240737321252053, 14129511391241]),
+            Y: FieldElement([18032867550934, 2536413355256104, 2278902193799510, 3125054041245225, 14384293656837]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([3783463350551065, 3799705390697907, 3203643198349816, 1729003041886117, 774041571756]),
+        },
+        EdwardsPoint {
+            X: FieldElement([408355418479026, 4164512357132574, 1552477419936515, 69897612834228, 2379684569582]),
+            Y: FieldElement([3086027353929523, 3963015580891619, 940132793119240, 1632867331947136, 9830126901221]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([1460362136502705, 3540765609615227, 2569070464851762, 42319988156478, 6709310483035]),
+        },
+        EdwardsPoint {
+            X: FieldElement([3064465958989337, 4388917960146743, 1044053464220801, 1107333212705185, 7138862368345]),
+            Y: FieldElement([186885378750617, 2113824590933304, 3254879380721945, 3832759871695973, 1239441218569]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([2384976391522322, 3502416895840618, 3610984985333299, 1136755729804624, 13600929025986]),
+        },
+        EdwardsPoint {
+            X: FieldElement([355893618141434, 3131075929639704, 831156697035411, 2358038069830890, 16306388681337]),
+            Y: FieldElement([1392577787072589, 1975459669013473, 1882720314945975, 3511949143242448, 4844415504784]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([2205713453549738, 4246476704328490, 2533897395420469, 3101678968551, 14950766430587]),
+        },
+        EdwardsPoint {
+            X: FieldElement([310661551514162, 3285952852651691, 1565383195156657, 4099273019818541, 1660102924955]),
+            Y: FieldElement([4159458952611703, 766820988177255, 1941328136080247, 3128942404180834, 16300713413626]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([2044211714536720, 1403490674459573, 2411230662404724, 2410870365200676, 15644470038697]),
+        },
+        EdwardsPoint {
+            X: FieldElement([1417079033521149, 1483970995268187, 3303659479656674, 2279462399161795, 4568677670746]),
+            Y: FieldElement([2389878028306144, 442699293919592, 1996471102365154, 1988712256805711, 14693694986768]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([1953104524543383, 63171704825845, 308086723796874, 2737312734265807, 815092333289]),
+        },
+    ]),
+    LookupTable([
+        EdwardsPoint {
+            X: FieldElement([4356393182538450, 1836791973820411, 4340059020288292, 3916038362881956, 11348047862969]),
+            Y: FieldElement([2132559024694607, 4343813844190075, 2445005605297270, 2654985823648144, 12626804659885]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([2608134886508104, 2604175792884647, 2207122881766061, 340296338802306, 7458395970605]),
+        },
+        EdwardsPoint {
+            X: FieldElement([4347907895702940, 981455052824193, 70743659226022, 246493724916296, 7226783732171]),
+            Y: FieldElement([1770242588125935, 3381308438434719, 1879694233016571, 373746567023889, 1591962593438]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([1414700504944798, 124760884900764, 551780190061771, 773896170990386, 5106855366825]),
+        },
+        EdwardsPoint {
+            X: FieldElement([3932180964309857, 2270301500933043, 2540200826509130, 1732022157009804, 3114115796583]),
+            Y: FieldElement([1720766107487850, 2550373711349207, 598156335548747, 4381033210562304, 587079249149]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([2363334497679131, 435755866487239, 3164586396789957, 2553616605557372, 10668623643632]),
+        },
+        EdwardsPoint {
+            X: FieldElement([1635574993775396, 2716250253579935, 552961510192647, 4372837616487120, 1930672286322]),
+            Y: FieldElement([4352392066625748, 1446382593922017, 3200357181368150, 2524370394663811, 14844812607989]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([3134267105846702, 4139886722407044, 320004105972090, 3348635874488148, 7108231669510]),
+        },
+        EdwardsPoint {
+            X: FieldElement([1234323154468474, 875373114476182, 1659702626789256, 1352876203681199, 10636031455776]),
+            Y: FieldElement([2122749921951836, 3268815282111190, 1107670765999252, 1395941309298841, 12711452173179]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([4349745852282061, 2110681110929655, 547746661335134, 1727664617300397, 4730437512025]),
+        },
+        EdwardsPoint {
+            X: FieldElement([4423627610049119, 620503135429193, 1725195987037903, 147697316378418, 14367994270269]),
+            Y: FieldElement([276750000695242, 2733125109571358, 2878289838168607, 3792898548374803, 3007796588316]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([4229791994026439, 3567769131903314, 496166609104125, 937549402629775, 16060854629267]),
+        },
+        EdwardsPoint {
+            X: FieldElement([3355428848442663, 3639864931207344, 4427183713603732, 2269488265987803, 15249806849258]),
+            Y: FieldElement([2347638626129633, 1732489629809504, 2432418934985973, 2648080921611729, 1562730788261]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([3848099559129027, 404677937845072, 2259629578573574, 626551395395750, 12954820637434]),
+        },
+        EdwardsPoint {
+            X: FieldElement([95334339746143, 1989535646406448, 567278584768816, 2992610714451526, 13660877910161]),
+            Y: FieldElement([759373159515239, 907214441451615, 2835911330139398, 4423265846602559, 17102223539527]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([1835236023019239, 111142998379254, 1239425308028910, 1642255887947512, 9019949691044]),
+        },
+    ]),
+    LookupTable([
+        EdwardsPoint {
+            X: FieldElement([3767960501476408, 2077125102624671, 1834354740529032, 2305413391654735, 14322983231916]),
+            Y: FieldElement([3693998064525809, 2504169418950115, 2594708868414585, 3121672133670436, 4288195086808]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([1127766778349453, 2224570194077631, 337526281645975, 1062855463015571, 2108035017702]),
+        },
+        EdwardsPoint {
+            X: FieldElement([1450985608265598, 4206729055335349, 2922447001946296, 637946548133735, 15761150427072]),
+            Y: FieldElement([866881297599441, 2609989032053219, 903437136229831, 1655300149977473, 13559451708399]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([701586695377771, 1929381003653257, 2036998318340640, 292687613664770, 1288596027252]),
+        },
+        EdwardsPoint {
+            X: FieldElement([4401456389709876, 2984570703464591, 2384936816125745, 2064037572552025, 14444871464928]),
+            Y: FieldElement([3837070435085632, 1470934556821108, 716023192164028, 2231830796584058, 12890781510518]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([1232402777760728, 1055629445858601, 768853207606784, 979358848273238, 10315485531931]),
+        },
+        EdwardsPoint {
+            X: FieldElement([3131477439036974, 2714782650788769, 2131320329922523, 3169420454535299, 2813826277072]),
+            Y: FieldElement([3956986343702210, 116565949926464, 3627577521666176, 1358611370431132, 15253082454047]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([1098653705831227, 3004784813443186, 3298660336338215, 1104360797225134, 643364351658]),
+        },
+        EdwardsPoint {
+            X: FieldElement([4455234589739704, 2920651325082341, 734161371981466, 3700434025022861, 10573022495509]),
+            Y: FieldElement([4067060702855386, 353499221173479, 2628694210301699, 3652615346220552, 13092621939253]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([900942978500340, 55605860470847, 4016987373414983, 335745906785105, 11401008376720]),
+        },
+        EdwardsPoint {
+            X: FieldElement([854092114797429, 2577760914715743, 3450750403422507, 3767768216378528, 14395430420101]),
+            Y: FieldElement([8397238856377, 2400435791965118, 867607626785323, 2771748584292200, 11866749121234]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([2780138664353818, 4006480278485991, 270216965321885, 2173296944864293, 11140106517949]),
+        },
+        EdwardsPoint {
+            X: FieldElement([591301513827481, 907796819547268, 470027412154691, 3933355486940053, 12529807844346]),
+            Y: FieldElement([3649923098537343, 339145792194706, 4093287099027294, 2838034144684596, 1547734560126]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([2905500134673932, 1901179347406823, 1181942955776037, 2526509651544619, 649451807504]),
+        },
+        EdwardsPoint {
+            X: FieldElement([586374886093574, 2625795565422660, 2316500500540247, 2552177903653033, 13457296163022]),
+            Y: FieldElement([4502987953476928, 2365739318768351, 1807886343955906, 782661227424262, 14500619080111]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([139724562108170, 2549005764774865, 4100226957823928, 1020271068731204, 10555747159395]),
+        },
+    ]),
+    LookupTable([
+        EdwardsPoint {
+            X: FieldElement([868992745718672, 1175045991666678, 1633207153133196, 1121905911131024, 983902532731]),
+            Y: FieldElement([3724254852481439, 389343168322858, 3537951081855437, 2734140676772374, 6263684508489]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([4319686455456769, 2699889807874133, 3316547592248831, 2603693509712287, 7697871824178]),
+        },
+        EdwardsPoint {
+            X: FieldElement([297411646891885, 59022783811423, 1394724944251813, 1729597861764407, 2374621024103]),
+            Y: FieldElement([2730085415492461, 2940159189669714, 440470213766177, 3928104628793744, 7442273864347]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([2566980945989552, 1160746498605443, 865743768220395, 3704877312529599, 1370851912302]),
+        },
+        EdwardsPoint {
+            X: FieldElement([1047729753574787, 1177170039499972, 3692717748205604, 1888889490132935, 3550823680033]),
+            Y: FieldElement([694071038131517, 4253026347643551, 2895615144554250, 1049439889159778, 7764254895367]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([4037493828806337, 1086259204460487, 3314876192693942, 4171713896170181, 2077882210103]),
+        },
+        EdwardsPoint {
+            X: FieldElement([756311721744734, 615912287711197, 1012682358367655, 3198046539556057, 7965998105957]),
+            Y: FieldElement([4379991599400027, 2853258659636221, 4360795496079994, 3855624633657362, 12620963832714]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([2129495716930821, 2300874952491856, 139283900263977, 3255939602676202, 17167943048909]),
+        },
+        EdwardsPoint {
+            X: FieldElement([3450557178959651, 3498690732730198, 2220179039638392, 2193805468364838, 4963628933600]),
+            Y: FieldElement([493429305313724, 3513703783753831, 595970642216954, 2389611778037213, 15682443515619]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([3771468704056140, 4274527656779211, 2607314303996270, 4114149700644561, 12734216030517]),
+        },
+        EdwardsPoint {
+            X: FieldElement([4218125815959819, 2572098069608440, 15832360387242, 471091985484903, 14844413388074]),
+            Y: FieldElement([2846798800353171, 322172514187728, 173113610057416, 479037694156663, 9788395772799]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([1333525585233948, 157151289800288, 937881040498664, 1748417715644414, 10517582877755]),
+        },
+        EdwardsPoint {
+            X: FieldElement([2912896733070263, 1938293388914190, 2129154371102039, 3703930261774118, 16714164450270]),
+            Y: FieldElement([29911523571778, 214774883860504, 3088312228529978, 1016523571749230, 16376082143021]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([2550369118957299, 2196485343740748, 4126881146238781, 649280408259832, 11591735154905]),
+        },
+        EdwardsPoint {
+            X: FieldElement([648344254691962, 2266194825266954, 3083284295660464, 4392566699532106, 7907659198541]),
+            Y: FieldElement([2769045613744276, 3791950688757375, 2000247354306556, 4231000185968938, 15428802683469]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([1504440576750101, 1715320063484267, 4296907134859592, 704197115401135, 8789657922668]),
+        },
+    ]),
+    LookupTable([
+        EdwardsPoint {
+            X: FieldElement([927644323284393, 2567068758677715, 2137254960732736, 262085213059713, 15138432658728]),
+            Y: FieldElement([3113178994568323, 33652127250064, 3625899857683661, 1083706685016723, 10332650209759]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([1962153007285209, 4005261663838066, 1943821237808244, 2323369345613037, 9879154271683]),
+        },
+        EdwardsPoint {
+            X: FieldElement([758518364095995, 4370312190008338, 3862377274646800, 388900439890069, 8106641948800]),
+            Y: FieldElement([591333509421767, 900029648417584, 2796765528575794, 4047574182610505, 10635871180825]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([2792876719290265, 225233822007891, 3376701106722337, 4365948630056960, 15785957848510]),
+        },
+        EdwardsPoint {
+            X: FieldElement([2371944961323471, 1911329716848626, 2634759202811911, 3732418419105402, 12698391809274]),
+            Y: FieldElement([4348915443993603, 1472929570172357, 477350130127034, 2277576156371266, 6728686645224]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([1205189222371012, 2423973622400411, 20585536325462, 128738244574134, 2194373498452]),
+        },
+        EdwardsPoint {
+            X: FieldElement([3078394512138766, 3079726442228603, 1794036131223618, 467255451227994, 6632247952532]),
+            Y: FieldElement([2260793913541672, 4423742627177764, 1334176682945432, 1415495103141012, 14189132705470]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([2609231736146781, 1769187451719660, 2316013805681494, 1954210278318588, 11257773187687]),
+        },
+        EdwardsPoint {
+            X: FieldElement([4006835643459629, 1802610416245948, 1524112713641130, 4186192771978589, 9003985202508]),
+            Y: FieldElement([763643038179453, 3109892140849706, 151935952155407, 2443824804502736, 13853144461040]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([1906398225533317, 3792028950789631, 683021091416435, 1526535372253981, 8184002346684]),
+        },
+        EdwardsPoint {
+            X: FieldElement([1903239399115813, 2856939764000610, 4394400531739028, 2062261970708208, 11966799750871]),
+            Y: FieldElement([698212129540580, 496721464867206, 1810307382362152, 3004710814713848, 15446532003080]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([2288421170015259, 3381979832481285, 3877633345972020, 383094481913285, 5821114405341]),
+        },
+        EdwardsPoint {
+            X: FieldElement([121003178221359, 2139069021048964, 3086917440291033, 1945285581605722, 8136514167119]),
+            Y: FieldElement([2079570116898358, 174750288823237, 645947942745721, 3660945534409262, 7729553215133]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([1947356608355379, 3887777841085309, 346058258826067, 2092691317598314, 624491107608]),
+        },
+        EdwardsPoint {
+            X: FieldElement([1983504509207615, 4147492009572941, 1728691225971034, 2722862159395776, 1636255299417]),
+            Y: FieldElement([1009922782819734, 573085972198333, 1193875779336941, 3968353006334690, 13174029462588]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([3629448158651688, 2245001555578911, 153076132118822, 2220580810591147, 10974877427077]),
+        },
+    ]),
+    LookupTable([
+        EdwardsPoint {
+            X: FieldElement([1397937075155260, 1645664110054207, 762883226428292, 579281250156834, 7716379303036]),
+            Y: FieldElement([4180419921028791, 2311161109200094, 1233008784347518, 334382575167700, 16739223388087]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([1873934619387321, 2005612338751620, 902259799922949, 1766091375134799, 10606290597004]),
+        },
+        EdwardsPoint {
+            X: FieldElement([2305163035519340, 3813385833054972, 3192929217991258, 3627798312105777, 10894400517906]),
+            Y: FieldElement([185826446897964, 2158031459469981, 3819317854201665, 1952472891090822, 16128834415387]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([118000130413102, 2530008086437948, 2354065132065524, 4166803496201610, 7790914256526]),
+        },
+        EdwardsPoint {
+            X: FieldElement([1776227748655678, 1727321950729074, 2978955393380182, 1877475034120681, 6136742439142]),
+            Y: FieldElement([731142682772076, 2833957047130121, 2670475197932654, 4353750771626107, 3850226716105]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([409989287858290, 3878302115464123, 3326461991603407, 1355886115875768, 12787983414803]),
+        },
+        EdwardsPoint {
+            X: FieldElement([120915143804325, 927913774632366, 1147387480735426, 3012926326012628, 9175418415844]),
+            Y: FieldElement([875038327090913, 2636482664306608, 3971618423520349, 2411962101118073, 16039317378099]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([4217415913992971, 808133078604896, 693026181635775, 379965041911147, 14721353211022]),
+        },
+        EdwardsPoint {
+            X: FieldElement([1512309593308407, 2021664481110272, 4114795183081796, 142164943412504, 11037969507386]),
+            Y: FieldElement([1307137660917682, 964036266562614, 1032821886314513, 649494881091287, 11976668412930]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([2637759429745035, 766265483536768, 3163054391083041, 309822152167810, 6842793597794]),
+        },
+        EdwardsPoint {
+            X: FieldElement([899003561504544, 3339330676009177, 2228808425416386, 3632598226806474, 16069321968046]),
+            Y: FieldElement([3012566344361331, 829030344288217, 4009104638192471, 2544481154260401, 3842157831818]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([1866572332396444, 4106563396702855, 782545200757064, 2541437834159716, 14897497360349]),
+        },
+        EdwardsPoint {
+            X: FieldElement([1735359324389486, 1161823905659820, 625342101636306, 1514628954169122, 8362222673639]),
+            Y: FieldElement([2908441558817639, 4385340254143479, 2761044269458403, 2647159709907540, 15630223888172]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([4374057583245765, 1141744166548970, 2923310638664514, 4357372607370462, 7059099788719]),
+        },
+        EdwardsPoint {
+            X: FieldElement([406933758254161, 4052179130963339, 825495390348654, 2167959987432169, 13833735329692]),
+            Y: FieldElement([202093947986263, 1969118715215449, 3449430800768609, 2686864727400915, 9592723379218]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([338125398547523, 4192959942532155, 808385552076820, 4033826980396943, 10300732906441]),
+        },
+    ]),
+    LookupTable([
+        EdwardsPoint {
+            X: FieldElement([2657287924911748, 3350395380664350, 1357807824712731, 3470846396508057, 4254124128681]),
+            Y: FieldElement([1875396164780988, 3351807947325742, 416850189970703, 1482475991391616, 3907650118613]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([1657532054692281, 152260426350097, 4257584587578748, 298721264764714, 14840856141906]),
+        },
+        EdwardsPoint {
+            X: FieldElement([1522308574590468, 107556791515955, 828485047846975, 3798788652027169, 15224156055604]),
+            Y: FieldElement([2589853939118536, 116934571854966, 2004036708785005, 1877391592774568, 14511809899903]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([2071876566754467, 1362784134341437, 1479789378561639, 1362392250420621, 6661473561625]),
+        },
+        EdwardsPoint {
+            X: FieldElement([4355873555530837, 1501673401420747, 3027765307261458, 4482951800662960, 2166320568247]),
+            Y: FieldElement([2244936611738821, 4238517639225632, 1349189384425654, 3579000248354149, 9540597043939]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([3589594631509274, 1159638502893981, 1297301087445374, 87162436767282, 15848612688776]),
+        },
+        EdwardsPoint {
+            X: FieldElement([4497024380705110, 1987305037009598, 2720286048894862, 1658637054058262, 9840403889032]),
+            Y: FieldElement([899945873249804, 3588511168395794, 2620100258866687, 1770319666851245, 3359779042736]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([4868147728175, 4502511383083498, 1201057264798853, 2272311223520500, 6221951311714]),
+        },
+        EdwardsPoint {
+            X: FieldElement([1855509898503944, 3910167105993140, 1821588908463699, 2510512384068370, 12723906954409]),
+            Y: FieldElement([2310443467030600, 1539107524618498, 2674825997892114, 53694772307646, 1863299442821]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([2566485701909225, 2608321804294018, 3091057473813311, 444113817650201, 15732105848667]),
+        },
+        EdwardsPoint {
+            X: FieldElement([903281884663453, 532982263378387, 2778440038019895, 1344989209170246, 5608090770713]),
+            Y: FieldElement([1416028573633074, 1734839220547429, 3605935806564095, 94207428605273, 14415614788144]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([2925970072460931, 3578900153030101, 653606819869724, 3650619749459934, 6924680436461]),
+        },
+        EdwardsPoint {
+            X: FieldElement([3163923185100089, 2079556151069167, 692161810029473, 3412715296532468, 14219209224549]),
+            Y: FieldElement([1863242352102020, 1035419646194623, 352241365751411, 744523056331844, 8564533119510]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([3503215733623641, 685869006180639, 3990638071717646, 4340075105590258, 2322636932714]),
+        },
+        EdwardsPoint {
+            X: FieldElement([4182369200711615, 2300022511549455, 1346166194957878, 428309776036727, 3570810787366]),
+            Y: FieldElement([3633952524633605, 959289648081476, 2138817050240940, 949411726488245, 15211600520626]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([2058110010732874, 1888188893303580, 817691758837874, 491201120385929, 5752179498935]),
+        },
+    ]),
+    LookupTable([
+        EdwardsPoint {
+            X: FieldElement([1168614381519379, 2176219067935566, 4316324993137478, 239908827927619, 17002734459018]),
+            Y: FieldElement([3637993638034014, 3086320072909520, 372481582163579, 1759521373354073, 11464417326215]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([2687881870564337, 3074701855974092, 3029865172978867, 2931611747062155, 7365419128158]),
+        },
+        EdwardsPoint {
+            X: FieldElement([552590518845240, 516185212757220, 1741803288958340, 3045088997723840, 6075016504870]),
+            Y: FieldElement([1259732075582285, 4152637808600501, 4312815012964113, 3930618952892527, 2474805128753]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([1023825288455421, 2358844004463468, 3566120745471843, 4184115328211755, 13453028640620]),
+        },
+        EdwardsPoint {
+            X: FieldElement([4327679472892951, 4282901321018412, 634893648724989, 1674821353720242, 2723065174536]),
+            Y: FieldElement([4016238573936363, 3383908694535776, 4229497988440235, 1095465180459262, 3021990892937]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([2623051447183923, 241897872091545, 1540822182366037, 3765838672908010, 10176414983101]),
+        },
+        EdwardsPoint {
+            X: FieldElement([2879886735419081, 3575385602418609, 338582162149875, 1781950332462789, 14402091604227]),
+            Y: FieldElement([2710991887685991, 2524008581756945, 4060549974450042, 4268629849086116, 8701147587103]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([1998999956382421, 2151579802008005, 1016907062953892, 1101157461537238, 1514400148321]),
+        },
+        EdwardsPoint {
+            X: FieldElement([1635623736229553, 2748614608941858, 1919043115222689, 3812459179566990, 13798533743012]),
+            Y: FieldElement([830317666769569, 3705873921124054, 4118828310206568, 739713504268628, 12701528226138]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([3696319267504129, 3016089615495451, 3742223197721728, 2551350925653728, 3586348130909]),
+        },
+        EdwardsPoint {
+            X: FieldElement([852314699226955, 3790665537642240, 3952113762070894, 1367246506460006, 6317967149161]),
+            Y: FieldElement([2113181570912221, 1083983869316992, 3444984696118158, 196790449325344, 6656454044475]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([2211326033198793, 1949095474153800, 3620555270081177, 1523526948011772, 11864316518895]),
+        },
+        EdwardsPoint {
+            X: FieldElement([3130741119122440, 2236183681125389, 101714063580181, 1625189673385139, 8767840481430]),
+            Y: FieldElement([1404086799606649, 3162290586114034, 2426104662192670, 39200240675319, 10793979118837]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([2973779993493266, 769487550612126, 1416463676796330, 4392827719352260, 9275612240637]),
+        },
+        EdwardsPoint {
+            X: FieldElement([634456242777103, 4379862206905933, 3675074512514701, 1336192020899995, 7777863033726]),
+            Y: FieldElement([1063418737081649, 2195670787828113, 2807833647446003, 2887705205768899, 14807762249356]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([2649585717459685, 2376827518182616, 1031327237091933, 77469327930817, 959612302321]),
+        },
+    ]),
+    LookupTable([
+        EdwardsPoint {
+            X: FieldElement([285386619382217, 3462710400904339, 3690710732361886, 1366637111183074, 2180833222021]),
+            Y: FieldElement([2792438912765885, 2361929087956202, 909544478164328, 4362609526021152, 14726765349589]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([3432947772348638, 998090999555272, 3973579859623601, 3250868580773951, 5339865110073]),
+        },
+        EdwardsPoint {
+            X: FieldElement([3575735075285759, 1971539640605778, 3428281891419930, 857424735090871, 14906063367521]),
+            Y: FieldElement([133690759651211, 344791286585525, 1611441231381145, 4271849398216357, 7465418417631]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([238203069747491, 3692628855080775, 2347831820560994, 1733416651642339, 9348906964125]),
+        },
+        EdwardsPoint {
+            X: FieldElement([3530641928641542, 1363278465754403, 1975737113639149, 2060628601855229, 7759599567032]),
+            Y: FieldElement([282566954094332, 4386735260178036, 3322887217821671, 2707629867466347, 6414087585643]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([3934717833977190, 2908502446424727, 3552657953156095, 2611634661326426, 9964483384708]),
+        },
+        EdwardsPoint {
+            X: FieldElement([706908866968233, 412031264328211, 1894830441813924, 1508706201383586, 16649462875638]),
+            Y: FieldElement([2531650315999981, 2656154677409186, 3047795191791450, 3821683104806724, 9643978896468]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([1086406534396812, 3883953716174291, 4461921106564445, 3795614483969524, 9506388860080]),
+        },
+        EdwardsPoint {
+            X: FieldElement([489595268028503, 830496291476239, 1138180250106781, 223276926385803, 5682108231985]),
+            Y: FieldElement([2105028582874917, 2774912192559178, 4304788330060910, 1045411359929406, 14840971335100]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([3931595869899090, 3751893168290902, 3470660299097847, 2026807145145372, 7359718456887]),
+        },
+        EdwardsPoint {
+            X: FieldElement([1273240514371575, 1342632582321275, 1574735259582243, 3930367149020472, 13363442409692]),
+            Y: FieldElement([3131499080730316, 3051615209150694, 2778961436527351, 1256811609232208, 584219318933]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([1236327997050405, 203745345067385, 3007779759506666, 1007110531950412, 17459140516237]),
+        },
+        EdwardsPoint {
+            X: FieldElement([740840088861231, 2130792147624330, 3792064737457531, 2722255797372160, 15296698371704]),
+            Y: FieldElement([1884620207311500, 3308846217642982, 3990172507782029, 2636168281962806, 10094292746801]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([2166039867983242, 3555709446996438, 3850647544465508, 3603522591311254, 13528302518173]),
+        },
+        EdwardsPoint {
+            X: FieldElement([3303325530950189, 3172719170049543, 2269136871923448, 3192328771969093, 14493656178527]),
+            Y: FieldElement([2846192686159328, 2901997434608767, 2782276574238948, 311777831668890, 6442972902664]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([3782691119808551, 3292517442808293, 1180340488167523, 1065551335094612, 7684445521319]),
+        },
+    ]),
+    LookupTable([
+        EdwardsPoint {
+            X: FieldElement([923374584839828, 1976748973779773, 3698881914919667, 3244949503404067, 2399874014353]),
+            Y: FieldElement([3483562284076292, 563599513986363, 2531597508150333, 3508719682986864, 5020282790478]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([3130727095339626, 282138548375398, 2764211612689461, 2082477773045791, 7489298101539]),
+        },
+        EdwardsPoint {
+            X: FieldElement([2947838233487938, 3017408259922770, 3200737869470853, 4474303460191785, 10835980527472]),
+            Y: FieldElement([3387309413734177, 3342761222681356, 2030707390665115, 3864138308691917, 12300972920116]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([3760096345580605, 3736817121228282, 1764871061076357, 2030061469178501, 2435467520776]),
+        },
+        EdwardsPoint {
+            X: FieldElement([1465576239880301, 2913677633971827, 1446965295830953, 800560848685267, 17172373642755]),
+            Y: FieldElement([942406316927365, 4354463942060636, 4133146533742480, 3746699718949051, 14169552262231]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([3758423239726774, 1167249525759617, 2082869037705336, 2631543421027628, 7214323572621]),
+        },
+        EdwardsPoint {
+            X: FieldElement([232554160143228, 116886858381305, 905237357189490, 3092447826017382, 2113812064097]),
+            Y: FieldElement([2852095427153406, 4169687306164888, 1567635339089587, 2303982691576993, 11040102993757]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([1455172079119109, 797908733188305, 955731856545704, 4210652702249429, 7503933220503]),
+        },
+        EdwardsPoint {
+            X: FieldElement([3709274768711147, 2834016742602710, 3534606919524543, 2923623483789796, 2092712158702]),
+            Y: FieldElement([1589277505903553, 4491082656435718, 2365014657918860, 1091010000960007, 3451963914022]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([186501592823280, 3835602774317559, 1553858776166596, 2703234796349132, 12147823964968]),
+        },
+        EdwardsPoint {
+            X: FieldElement([951284740161011, 3404562522515378, 1712526398596901, 1324107068801705, 7029819851493]),
+            Y: FieldElement([45993768499411, 2335104903047394, 2760913616513250, 1742860274650330, 13007607679402]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([1820328188866022, 2273423579624762, 733387197501118, 150082976894784, 13888151197557]),
+        },
+        EdwardsPoint {
+            X: FieldElement([2807656984229906, 4371309087342086, 4397446392812904, 208901629412639, 15847122959784]),
+            Y: FieldElement([1072611442326624, 2901750194707596, 3594348140354276, 707772877525955, 16665510045151]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([796084397518487, 3710774391702452, 4242224663397817, 2339141253111130, 9010595442148]),
+        },
+        EdwardsPoint {
+            X: FieldElement([1906576147924, 3999379166355924, 1041792391880602, 2746467537786661, 8185859532305]),
+            Y: FieldElement([3067703015571656, 4137847891753283, 2978610368526657, 2911519834865803, 4369767013646]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([2977773182908675, 3784358352037844, 3995657268355918, 3204894181211151, 17589019887148]),
+        },
+    ]),
+    LookupTable([
+        EdwardsPoint {
+            X: FieldElement([1636960892668190, 2675198957128948, 1562453981798722, 2748675644341934, 2312503732537]),
+            Y: FieldElement([2459494468590512, 2977012397568385, 1415575918318899, 4021817473780290, 9872101215684]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([4498176700360644, 3894271599150921, 2170636250507296, 3033617526559107, 15264469567965]),
+        },
+        EdwardsPoint {
+            X: FieldElement([2088844642748206, 65581194830512, 73059101759127, 2515987055934549, 2406191726606]),
+            Y: FieldElement([4161385740180479, 3392139580580210, 3978767661209755, 2631871316330794, 17419116548716]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([2181152780923827, 628095138963924, 2427933190485989, 1706706084019043, 13140887412427]),
+        },
+        EdwardsPoint {
+            X: FieldElement([800058214962726, 3429392643175801, 1274327331884109, 3446887912620097, 7151527331933]),
+            Y: FieldElement([280188246488333, 116062115367392, 3925385440282894, 392596348365989, 17177385263190]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([525319286707510, 3730428731829096, 4081336112774614, 2637902057654409, 6589209249481]),
+        },
+        EdwardsPoint {
+            X: FieldElement([1526332537884639, 1479246544825126, 4099498989359888, 2911880175884351, 9593574835195]),
+            Y: FieldElement([2397893022100331, 834815659610861, 3500029276032261, 1554218769674278, 6554080933613]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([595783526699351, 3606090306697701, 1449967019731262, 872567619903853, 7647079926428]),
+        },
+        EdwardsPoint {
+            X: FieldElement([1155120745499250, 3780106796223701, 1171951935564699, 206479690181623, 12442237098707]),
+            Y: FieldElement([209862767302644, 1736819734000655, 976873935488008, 945547308766801, 1590180957683]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([2894449910751627, 3756101060864202, 3001706276553492, 2252465901578304, 16357925376668]),
+        },
+        EdwardsPoint {
+            X: FieldElement([72722871562354, 1501904247508696, 1905216221976860, 712587764131657, 2315548121443]),
+            Y: FieldElement([3513975146797666, 4144899102745613, 1307436503115269, 4260705380011574, 9211157440158]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([2410166794672207, 1225027346651347, 4054634932715150, 4002704711294858, 10160809886534]),
+        },
+        EdwardsPoint {
+            X: FieldElement([2827153987711328, 4386084061989845, 1448063053280005, 1229228041165488, 8075589517385]),
+            Y: FieldElement([258221724376269, 636150821571319, 1334926823620494, 3220969997082115, 15503915813031]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([527418110316526, 2107454145040774, 2579103854259160, 3798589816295679, 13109711015596]),
+        },
+        EdwardsPoint {
+            X: FieldElement([948276883215156, 3260701325273523, 1891476623722659, 4405898543039517, 12475963243839]),
+            Y: FieldElement([3244595452346999, 814184270597596, 1935524866574780, 2494548413301585, 3478495917716]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([14318753973858, 1056008022483869, 2256495644897987, 1613172304927322, 2365355940882]),
+        },
+    ]),
+    LookupTable([
+        EdwardsPoint {
+            X: FieldElement([3837481808152080, 174983189363897, 3047186046405251, 3531574664591986, 14175515613377]),
+            Y: FieldElement([3139932465977247, 12240038270584, 2890977877801685, 632328428445232, 12920577059037]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([845141336642983, 3983391379481242, 3962173410675785, 3504634702091786, 9576634384425]),
+        },
+        EdwardsPoint {
+            X: FieldElement([2042270517895439, 1356705454457073, 3840526586777976, 1667993889462235, 4899883362730]),
+            Y: FieldElement([378635607271446, 3392455030053720, 1089947359938358, 3577789856872458, 1687085920565]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([1660280976384957, 1135063342174721, 3530107253957549, 3185855639345769, 5773310046459]),
+        },
+        EdwardsPoint {
+            X: FieldElement([1123944470112368, 1894814643332739, 2418388127705865, 594599613421761, 6197128465911]),
+            Y: FieldElement([96705210837708, 2280800241196461, 4041552132069153, 2316080228089430, 1756708920033]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([4077249220910184, 2460202268902253, 1136147309531011, 4057208149581275, 8235528509532]),
+        },
+        EdwardsPoint {
+            X: FieldElement([818904651632478, 4357465914666221, 1199664775384532, 927844400913019, 703196496173]),
+            Y: FieldElement([18682881033546, 1932235395958927, 1701366717951536, 3656328865102615, 17375188393610]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([3771910397681129, 1368799151458, 4338402933859474, 1118965049054727, 15881750680718]),
+        },
+        EdwardsPoint {
+            X: FieldElement([528461219980000, 2283825128800031, 1693549627807951, 3736617951169602, 214186537621]),
+            Y: FieldElement([4273136143038880, 47492436833900, 996467867196325, 2432100288819665, 7536466858240]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([3081000057820488, 4224491450616575, 1448195451031123, 3876597722863999, 13636347733506]),
+        },
+        EdwardsPoint {
+            X: FieldElement([3212263355544635, 1733200858968844, 1953207895173549, 1469444824474859, 12841960470191]),
+            Y: FieldElement([1311133188659864, 3086399147028659, 3635971343516925, 1321292759386137, 16467742832740]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([3240372310727306, 3671495948783576, 1243898753450417, 116855489827420, 7666335223916]),
+        },
+        EdwardsPoint {
+            X: FieldElement([3767155921703931, 984805259932971, 3162448011684667, 4040766365123722, 5507644880136]),
+            Y: FieldElement([1199381676708763, 1755141032462408, 1749797315823282, 1012982005317974, 1513978333811]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([2800261103841961, 2885818848191836, 3008663809631136, 2502575226700239, 17286777617902]),
+        },
+        EdwardsPoint {
+            X: FieldElement([561291685461467, 399108691224382, 2895557851141050, 3772680027428478, 9658695867901]),
+            Y: FieldElement([3971254158155617, 3141870972145471, 3397992099784696, 1686872945595740, 6571673436360]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([4247680435523354, 2024634679043598, 365288695124227, 843782184680028, 7900042474517]),
+        },
+    ]),
+    LookupTable([
+        EdwardsPoint {
+            X: FieldElement([230408298350934, 3337328609200343, 575499125035803, 3227735904026157, 11251202656580]),
+            Y: FieldElement([1936620724916957, 1217779326031229, 996080050385469, 4289759901976242, 1539733809112]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([2987609744451581, 301453562385635, 3913962830433935, 1199810101786474, 4939909224560]),
+        },
+        EdwardsPoint {
+            X: FieldElement([177650766338869, 624461180604289, 3300885645778345, 3847934064082455, 3336186040748]),
+            Y: FieldElement([2656563682950265, 4049765627397937, 4086018424798008, 424618273727723, 8203901251458]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([3814618074766332, 1445513685508015, 4333678275300180, 3252316371571322, 13250195821336]),
+        },
+        EdwardsPoint {
+            X: FieldElement([4301771002406111, 224026440321827, 4494756813334151, 3531382243999599, 191744651384]),
+            Y: FieldElement([1050615789728881, 3979864842117217, 678585769275227, 3395777992829801, 8283010920838]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([136308061148872, 294702670944461, 4501018932131986, 1942053068576481, 4788098309139]),
+        },
+        EdwardsPoint {
+            X: FieldElement([2361618886897516, 2837919021174118, 2061395847026089, 4419065021862038, 5399464571341]),
+            Y: FieldElement([1912713257889177, 2576557214108502, 939371203163263, 366353651265705, 12245416630119]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([360939939165850, 425017753482461, 720181771124705, 4429310338714763, 3745933463982]),
+        },
+        EdwardsPoint {
+            X: FieldElement([4409072984220596, 1587462020157518, 3166520339394828, 393845464302173, 2657603056616]),
+            Y: FieldElement([130093919597612, 4208499228323387, 1095870044654679, 596927305118008, 2085308925284]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([2016050306191466, 2295095342791300, 3636749720161565, 3595967623310826, 6857728703671]),
+        },
+        EdwardsPoint {
+            X: FieldElement([4239269672178103, 177579767683176, 513442896140218, 3218430939732343, 5841006708975]),
+            Y: FieldElement([2917932217079485, 3054598955401739, 3722243626812891, 1951805343041722, 16794653733268]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([3162187469690850, 3325359257258926, 180902760141667, 420248165825564, 16239535957479]),
+        },
+        EdwardsPoint {
+            X: FieldElement([3817388688732557, 3938472598503641, 2555867597264637, 1537202258657071, 4403199390187]),
+            Y: FieldElement([1532146176179565, 1895384946987992, 921449072167049, 617754452709755, 13498439520156]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([211667845126743, 3636810973888484, 1827860434871095, 3408539226437860, 4189615387893]),
+        },
+        EdwardsPoint {
+            X: FieldElement([502914361126204, 472382063772243, 127550365964492, 1785486903500654, 13648403360184]),
+            Y: FieldElement([1070853849094009, 4035741119014206, 668195297862910, 2871849153763444, 3237397690923]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([1924426688904958, 674582397932876, 1251942852882890, 2778987034978949, 9607296463775]),
+        },
+    ]),
+    LookupTable([
+        EdwardsPoint {
+            X: FieldElement([2552644003602938, 1808981953191091, 903471414955824, 3300332316414464, 1357954244362]),
+            Y: FieldElement([4093972844435182, 812073331400285, 3861147012370295, 2629587357740303, 1217195464782]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([4180005177336221, 1664296142619371, 1070835317091897, 1656511767648739, 13708976636775]),
+        },
+        EdwardsPoint {
+            X: FieldElement([4299578436928765, 2888783677302874, 909750887810276, 2663282344505896, 7084774258278]),
+            Y: FieldElement([2470975061217372, 3903447976056240, 2668476449587333, 2558337168924026, 12506158281189]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([950791102635931, 4267018579936765, 3370201675219556, 839478244208872, 1682238961228]),
+        },
+        EdwardsPoint {
+            X: FieldElement([3513118369992232, 4224997856834717, 3798217989244769, 4174522911434803, 16999391652295]),
+            Y: FieldElement([914518901231956, 3672803653807562, 1352075626491695, 3478252037829570, 13143050530141]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([1897625475949439, 740150537334134, 1294467267362274, 595212337182676, 12475627244070]),
+        },
+        EdwardsPoint {
+            X: FieldElement([2452113896318130, 1070298695412204, 3082993822015636, 3996375600162200, 9968947579292]),
+            Y: FieldElement([448460006141379, 1655782110995612, 481237559007548, 2568145623470038, 15101020699352]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([356544649062691, 1050668966738856, 1224173936366256, 1855454259029289, 4718890105459]),
+        },
+        EdwardsPoint {
+            X: FieldElement([900610735667870, 2023096667120118, 74993610524671, 4391226619176654, 1347604571899]),
+            Y: FieldElement([1156549153392116, 221196837231957, 1888456415104902, 1721660237822381, 17035011624554]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([3446327575208824, 3553572585435375, 2013968438457820, 1364611104764670, 2041849509590]),
+        },
+        EdwardsPoint {
+            X: FieldElement([3065085841374167, 2049543897460182, 2453270100220920, 3103474142373135, 13699813096336]),
+            Y: FieldElement([4048505735148856, 2426781503621087, 2046046579011023, 2918124844767898, 8826604906808]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([2597197917303739, 2655667725489764, 4382799911373578, 132013978867473, 1774323163503]),
+        },
+        EdwardsPoint {
+            X: FieldElement([166072474524809, 557587098243546, 3654365178565048, 3464640857046940, 446339841453]),
+            Y: FieldElement([3237855694077917, 3290039278666626, 1098592704427344, 3758021717804214, 9718391785738]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([2721301247879755, 1987159443744799, 3595905920408260, 3555942181610642, 17069002982718]),
+        },
+        EdwardsPoint {
+            X: FieldElement([22883640347564, 3768374136904618, 1624842405194231, 2821757190233311, 11273307269256]),
+            Y: FieldElement([4358065793801179, 1892027825045622, 3655925513848193, 4433749199023401, 1052423971807]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([3085215716190846, 428038635146210, 1326949523778512, 884334742548283, 8575056105470]),
+        },
+    ]),
+    LookupTable([
+        EdwardsPoint {
+            X: FieldElement([1682148001286454, 746581871756698, 4228738515327558, 3428637489544220, 8007203524933]),
+            Y: FieldElement([3733206601671130, 2173644027490635, 2900795358053641, 2094695009019980, 13531267017990]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([3808814319496532, 431189516972702, 811168799040154, 734987181062615, 6295734364551]),
+        },
+        EdwardsPoint {
+            X: FieldElement([2631516423029084, 2874909563068253, 839476655865832, 4181027359049350, 16501099307747]),
+            Y: FieldElement([1528385258020642, 349087913019863, 2639052109561750, 2434599092698755, 8855557802430]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([4040025068347409, 2894337611972964, 4018148500920823, 4294698860948258, 3535120374173]),
+        },
+        EdwardsPoint {
+            X: FieldElement([2084367979198149, 4392293946693823, 790122863654470, 4186182912205540, 8144196387746]),
+            Y: FieldElement([2294741420195696, 791220581506107, 1024214687543158, 3144396116184946, 3465419244256]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([585826356915, 391755637837297, 4053983995419682, 1911032615441300, 6187030464094]),
+        },
+        EdwardsPoint {
+            X: FieldElement([3597276124299146, 2538978717450851, 1188092499844613, 3419315044603205, 7400876672757]),
+            Y: FieldElement([2090706627818475, 3506582062168345, 1115006147012061, 1852207350736629, 9567518496286]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([2055481868232297, 3492368724919639, 3614733580945022, 2419686896026790, 13580331024257]),
+        },
+        EdwardsPoint {
+            X: FieldElement([650818671275084, 2169226945931237, 1783034468333006, 3073657202437506, 17017559382292]),
+            Y: FieldElement([2113844533275282, 3805582618102797, 2952361734984880, 1108837884796747, 11378898757240]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([3678909854318445, 3107909720595960, 2467558027054950, 4288529067135067, 10787105033670]),
+        },
+        EdwardsPoint {
+            X: FieldElement([1361805057618048, 2191686833536909, 887030312399419, 188826581892367, 6385863056775]),
+            Y: FieldElement([2877609832958190, 3032077030040212, 2700960452820357, 4436222407599413, 4200180813530]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([1816462078162337, 1212461865322095, 3657997853518536, 3432367443455269, 16849096577098]),
+        },
+        EdwardsPoint {
+            X: FieldElement([3474433778138368, 2741048437821594, 1784366768410866, 2338187667531796, 4953061599938]),
+            Y: FieldElement([976453705729303, 3112628383890156, 4055700648633742, 2541682593914387, 11950629315248]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([2061183358162343, 826359524646067, 3039800049252090, 261766849085257, 7995476495891]),
+        },
+        EdwardsPoint {
+            X: FieldElement([853591875036708, 2488623802426713, 3543513956664158, 2239029547354359, 771418892803]),
+            Y: FieldElement([3822877303357388, 1536749161880523, 3496309098076768, 3906168905593902, 2419377107422]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([1016871225082096, 4275074904678624, 967471931523570, 498566290432001, 1198075992714]),
+        },
+    ]),
+    LookupTable([
+        EdwardsPoint {
+            X: FieldElement([2028344796655842, 1499413095947848, 2567078850170705, 1092091005034296, 6620183631246]),
+            Y: FieldElement([3585249050694722, 4048627568684021, 3950565185190315, 581243239134041, 6109877014376]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([289624623111456, 551125948065676, 1254334015936917, 2325449402670790, 3553447104510]),
+        },
+        EdwardsPoint {
+            X: FieldElement([113385659958295, 4014026025067958, 3825407144410596, 115395033026584, 4318324490560]),
+            Y: FieldElement([803655094592013, 1175566949140576, 420502405884034, 720794670822167, 1993197818361]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([3697618985003198, 3741405934833474, 4359563463641741, 3154540294145233, 5195104042052]),
+        },
+        EdwardsPoint {
+            X: FieldElement([735807793585668, 1392453623093366, 4074578252218916, 1999512469263035, 13153718493747]),
+            Y: FieldElement([64812693012909, 1990518468960133, 961782679106037, 1730736338809966, 8696908728386]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([3296988449845145, 2452004560186969, 4058749628057896, 3925274674177203, 9587081615731]),
+        },
+        EdwardsPoint {
+            X: FieldElement([2697004331104685, 2589234519809382, 3821398393795836, 4159049409107101, 3103280801379]),
+            Y: FieldElement([2700819185036333, 1063914343539332, 2275821154970251, 3999969171644286, 6056677311249]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([1558355213815405, 4330050918861445, 1492653607306122, 3464952971169143, 14884641337111]),
+        },
+        EdwardsPoint {
+            X: FieldElement([1579315614290572, 3655206373550706, 2262221134099418, 2557901244539513, 14740411188368]),
+            Y: FieldElement([2804117046388133, 218872241545916, 123212202959982, 241186837505356, 8031377648857]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([970020641616459, 849782744604218, 142890716035005, 4260622605147614, 17303848720865]),
+        },
+        EdwardsPoint {
+            X: FieldElement([3315113234267471, 1540339154453191, 374116919902122, 799554028224400, 8158190373519]),
+            Y: FieldElement([944105929736425, 4143589197540427, 2042801498348650, 1415144347769016, 1946831787944]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([32311172647523, 1537147523343048, 3297371430558577, 3141165329890853, 3875439836001]),
+        },
+        EdwardsPoint {
+            X: FieldElement([1563710617708127, 2662929758547607, 240630942032969, 3229222683294641, 1178869616204]),
+            Y: FieldElement([2570724480326784, 2917416145789420, 3111315141214362, 1140318958838170, 14231456976079]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([2503228153476331, 1626122932334007, 4395611901509185, 3380184650022764, 9705506409204]),
+        },
+        EdwardsPoint {
+            X: FieldElement([642620466406328, 1157576136416823, 3092222108385556, 4328598785218932, 16089265732061]),
+            Y: FieldElement([4501105101306265, 2011909276877263, 2208214630627227, 929485605751759, 9461066504123]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([1191202641038434, 1998015163327774, 2042596221659818, 2867111328261096, 13867748860301]),
+        },
+    ]),
+    LookupTable([
+        EdwardsPoint {
+            X: FieldElement([535730524131895, 2783769175856597, 3261934067943010, 3530632850672091, 11157873424526]),
+            Y: FieldElement([61672107617018, 2880407566514306, 3027026742379654, 2595405484132786, 6860986812336]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([1576275210036138, 934002455719706, 2907275840288421, 492924154517276, 14687880056633]),
+        },
+        EdwardsPoint {
+            X: FieldElement([2086260003494867, 435316919406983, 3834034534730457, 3705815174685159, 9675844316576]),
+            Y: FieldElement([1392346310148756, 4009312683791673, 1314226206574903, 2457106408105305, 14824416281382]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([1883565262634613, 3100460136003303, 3122774861191456, 374151756884359, 14410708970386]),
+        },
+        EdwardsPoint {
+            X: FieldElement([1322056597290181, 1946108342397069, 4184233407479664, 2397983026442974, 13621486620374]),
+            Y: FieldElement([1878171362394254, 1473976657108391, 97729877166847, 858522123701584, 10667729296891]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([4309963392108490, 317446521733664, 3369753873435079, 4241520845839654, 10037180261326]),
+        },
+        EdwardsPoint {
+            X: FieldElement([3575510469895615, 1526149991931202, 1599630762118130, 634814771302213, 16751777338306]),
+            Y: FieldElement([4450285008037692, 1314310439926036, 653094072557633, 283766304380169, 15879778628603]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([3705357005208913, 1134008125146945, 2363418261609498, 929441188429740, 16913662653316]),
+        },
+        EdwardsPoint {
+            X: FieldElement([1089113319315349, 1396485002933999, 1155219441669054, 1357825533282737, 10077543434049]),
+            Y: FieldElement([3909720253153145, 378665596390737, 818423411848991, 1601626856510327, 2182263479763]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([2799570651285431, 2738102110334581, 1197532916628828, 424548366873960, 2800174077579]),
+        },
+        EdwardsPoint {
+            X: FieldElement([2121669411118041, 1052495230838248, 3210920422095907, 3599414359858740, 12120202033047]),
+            Y: FieldElement([2114105066796860, 2778295638968017, 3343656125684010, 2867307467101192, 14462732380039]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([3606193450317217, 4156432226017583, 3171075855925331, 3170074489134550, 13059456913022]),
+        },
+        EdwardsPoint {
+            X: FieldElement([1892616377213254, 1879261914628457, 577462172005813, 3720768253755615, 12290330188423]),
+            Y: FieldElement([3884681269995291, 985936144762002, 94240075845931, 1498085874441503, 15353775197164]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([630663709221164, 1862471590826223, 1514783508271793, 2470664691872519, 9196854758303]),
+        },
+        EdwardsPoint {
+            X: FieldElement([2825084701732981, 1586228144504757, 2188301203858748, 4058612489102509, 9844606240850]),
+            Y: FieldElement([2502990204650382, 200134835318611, 193010818288395, 132183243144338, 17401922599467]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([3115391162420637, 2960846962626430, 811553340905214, 1476325031658581, 16260136145062]),
+        },
+    ]),
+    LookupTable([
+        EdwardsPoint {
+            X: FieldElement([2629559452657020, 4163536776220540, 2215435477065900, 975371867406168, 12908337922930]),
+            Y: FieldElement([250609143328870, 2842620467694898, 3447883714439852, 3222741524890092, 13455603817481]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([3570372851837492, 1476049902743260, 3276138643363152, 1435401633462387, 11332995097920]),
+        },
+        EdwardsPoint {
+            X: FieldElement([1366754298932904, 1759935090190146, 2146999582382204, 2491585144486275, 11536450124008]),
+            Y: FieldElement([684996739428836, 1553686322454200, 1174845259238396, 135469166059377, 8807307983872]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([721523684483285, 2081424755113852, 2458947512190152, 3086061000029257, 15490323003210]),
+        },
+        EdwardsPoint {
+            X: FieldElement([4312123596959978, 1506441227243846, 2668863116809808, 142113736557347, 11051368320633]),
+            Y: FieldElement([2184794340976781, 1189444423091679, 481941992172406, 1670179750706622, 1725806609096]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([589570661256119, 4092768765638905, 3931132529893077, 2517495985009831, 2453988105582]),
+        },
+        EdwardsPoint {
+            X: FieldElement([3961401202535076, 272129382481952, 1932484406676447, 973902464564911, 334169019505]),
+            Y: FieldElement([2227336457601891, 1599875196587242, 902367154716222, 3668911659670561, 12937170666031]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([2085981519508997, 1720349182469038, 1700276613417820, 2393914511503716, 926594409373]),
+        },
+        EdwardsPoint {
+            X: FieldElement([3554409276387415, 1350703869240744, 247430695100731, 1851249643604709, 15427708025822]),
+            Y: FieldElement([2695007245688335, 4373041256701099, 2621403000527550, 1232396928905151, 3143167124870]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([259612842787096, 3896073804368189, 583022797756130, 1542335942735141, 6602691900369]),
+        },
+        EdwardsPoint {
+            X: FieldElement([1375577504591144, 1755030993352185, 417580784704586, 1459640475327105, 12829391331441]),
+            Y: FieldElement([102024631243140, 748979697301843, 2918535673683911, 3354225695557000, 17378968204837]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([405646246693453, 1070270543507321, 2426444919755071, 3580071870958904, 11643760094951]),
+        },
+        EdwardsPoint {
+            X: FieldElement([665833689134330, 2288900622921289, 1862621161362130, 13851497011625, 7422018658087]),
+            Y: FieldElement([1753883077291928, 3449620341231809, 3222076317862601, 1826642383568340, 5259715384853]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([3048357081669903, 133291688256249, 26062994365649, 2486174518970603, 7779551862922]),
+        },
+        EdwardsPoint {
+            X: FieldElement([2398381470805552, 1038326406143799, 1317299348564864, 2065302472513970, 7886151002653]),
+            Y: FieldElement([2722659591105595, 1572018722135772, 3762415229917290, 3023379379527609, 1147898747032]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([486763379981364, 1461961082330167, 1653036312357840, 3081198751282855, 7337264591487]),
+        },
+    ]),
+    LookupTable([
+        EdwardsPoint {
+            X: FieldElement([3492446148081732, 3883409516699440, 3132081778085274, 2016647616792869, 1065813004153]),
+            Y: FieldElement([1396616734983028, 2985969055784765, 2932047978135948, 1647092978885444, 12976809191613]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([1933287875461247, 355222138916932, 3026105116728819, 2316725515032830, 7413345944827]),
+        },
+        EdwardsPoint {
+            X: FieldElement([4442061508554958, 1109016843783110, 1714255937110746, 880416346023803, 11066719587034]),
+            Y: FieldElement([910155033450447, 287041603003355, 698674994482024, 4357247551014372, 14300133080518]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([4348602049655239, 252831061074406, 4397995878538521, 4092147303240039, 7709885888364]),
+        },
+        EdwardsPoint {
+            X: FieldElement([1075751016136917, 465803327592658, 4221729488886880, 1567166360152434, 13929608552385]),
+            Y: FieldElement([4355829952830855, 3804158626821309, 787560984891922, 1214190678233239, 9506536894506]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([2861301371486716, 2132603698041340, 2225152814302059, 1271912976157089, 15322911693710]),
+        },
+        EdwardsPoint {
+            X: FieldElement([3668393794673626, 2169663354622835, 63564449030884, 1232490968252052, 9602098514554]),
+            Y: FieldElement([2547528560423110, 1972905513226849, 1778932819603283, 3648995448659517, 2865476198611]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([1028897907747582, 3069081539759257, 3757800308898626, 3796506273214876, 8635041111707]),
+        },
+        EdwardsPoint {
+            X: FieldElement([3556894007338557, 2063838836159808, 22934887563111, 2278638592243904, 8936019836649]),
+            Y: FieldElement([814411619179224, 1262692070087815, 2916566908057219, 336969791943343, 17331990244101]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([2670815487880223, 1872289894814672, 3257515319386172, 1640779230653778, 7217577368893]),
+        },
+        EdwardsPoint {
+            X: FieldElement([1509672848230074, 4207512920027199, 4270733935950808, 2672109682194384, 5954227448255]),
+            Y: FieldElement([50009443323444, 2533617512114368, 2659838775049955, 2843209291872899, 8104945089924]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([4166309982731186, 2772986045773719, 3257685304855234, 3004731507596829, 16984076827979]),
+        },
+        EdwardsPoint {
+            X: FieldElement([1498413143592561, 3709659875914884, 148943037527250, 3140883528909361, 10173170208900]),
+            Y: FieldElement([3835780079080111, 1052548163182663, 1619630545573415, 371085738600329, 11780725619468]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([38981204589469, 903039597232423, 2710022247479049, 914725820764384, 7880505089839]),
+        },
+        EdwardsPoint {
+            X: FieldElement([4051594141720019, 1292678488211442, 2096323386560833, 2194346541333564, 11860551004234]),
+            Y: FieldElement([4477157738982, 3522245068663357, 810152522049455, 986292887523181, 6726763354816]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([2759684462091513, 2218633659526076, 718495218547641, 4469753540072721, 8409934587635]),
+        },
+    ]),
+    LookupTable([
+        EdwardsPoint {
+            X: FieldElement([3591885478570312, 3143143517080289, 2446277616456753, 2500812472588223, 14135387358347]),
+            Y: FieldElement([1621915936686255, 1311172954504503, 3375828774378248, 2531355553001185, 7763956804694]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([1258067632611105, 2795199338986471, 3219960676055739, 2719543054234136, 4799425727752]),
+        },
+        EdwardsPoint {
+            X: FieldElement([2879365123711507, 1691978164868917, 3432670372417805, 909049027461900, 2206344112955]),
+            Y: FieldElement([2109512685764378, 1334947917828371, 226838967328515, 3000702441316224, 10077629709276]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([2509902568194830, 148917520302917, 3142351149472942, 1121223599932487, 5858886914457]),
+        },
+        EdwardsPoint {
+            X: FieldElement([4448540274506778, 1022017581532051, 647845654009418, 3704137937172568, 3294895927757]),
+            Y: FieldElement([1698250849606963, 2469090255434129, 1121675991524749, 4070026248135459, 4539649780100]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([3977013218744184, 3722396080004644, 4352217864689412, 1407297884678955, 279660435796]),
+        },
+        EdwardsPoint {
+            X: FieldElement([2320363391723329, 4107831644616926, 2267015703136323, 1537593574570555, 11084922308899]),
+            Y: FieldElement([1622148300915350, 1650045843858554, 2136608620445285, 3240223227751440, 14623566096255]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([2328210680403575, 579167315402163, 2336070262998340, 463291515386183, 9604894529789]),
+        },
+        EdwardsPoint {
+            X: FieldElement([2111442344882746, 308274454447174, 66425307490875, 2818348392316442, 14233960177485]),
+            Y: FieldElement([1514660405671721, 221429940810298, 2238452981531717, 3029437640754650, 2326298415771]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([438891018807369, 3778553768068440, 2448234098095528, 177600368834295, 9482099258551]),
+        },
+        EdwardsPoint {
+            X: FieldElement([3890954299175416, 3052527610725346, 4129088689187155, 1231549354741857, 8495460270929]),
+            Y: FieldElement([148940201529997, 3426617160360156, 2353569345379096, 2363269861292141, 13289564413670]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([1705066255053432, 3391192210996848, 4035823444629545, 3359758094848629, 7584989180926]),
+        },
+        EdwardsPoint {
+            X: FieldElement([762781576585366, 2844076981216236, 2770159705297025, 393935060952417, 1207559857941]),
+            Y: FieldElement([2389411173864792, 1412482174073592, 676276121580779, 1039383834683074, 301344796040]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([3131071562899451, 339461376139317, 1513931286458294, 9441279838739, 16751684806222]),
+        },
+        EdwardsPoint {
+            X: FieldElement([4018497183016481, 2763012223224128, 637568585875040, 90531563538302, 15444425993416]),
+            Y: FieldElement([3074944789788739, 1068312115814977, 3245783933405814, 1237816391727845, 17423500213219]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([1934827983102828, 1439860304388846, 1306037221988727, 1622936164829127, 9514935442516]),
+        },
+    ]),
+    LookupTable([
+        EdwardsPoint {
+            X: FieldElement([2081979366306507, 3762005074800584, 4008618129404681, 2879663363498211, 7589167832000]),
+            Y: FieldElement([3720355181753844, 4051802491940148, 4113139975825791, 2937804636938589, 10388232109726]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([1362342604750753, 1504123313050573, 1235490721030239, 2480418907716991, 14376698225525]),
+        },
+        EdwardsPoint {
+            X: FieldElement([1770353919150363, 1472141294640730, 1844047111925979, 4192138523789662, 15277379427486]),
+            Y: FieldElement([2032491984043992, 2741874707484591, 2219578781457083, 2758450196925702, 8910744680394]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([2907501334995424, 2730704763052461, 1832147841690810, 3710970481413138, 16780988222675]),
+        },
+        EdwardsPoint {
+            X: FieldElement([3282226900187601, 3050439867504044, 2070428246663334, 4330822251123628, 13300760158470]),
+            Y: FieldElement([4348248094108056, 2112123978038676, 3814656067389120, 3407165560823939, 12865097976520]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([1160632644632091, 4038254372203005, 1123779398141099, 4061676783870676, 17173389224711]),
+        },
+        EdwardsPoint {
+            X: FieldElement([3702477002450705, 1290955702617227, 2378143863036923, 3707923841253144, 5590481785579]),
+            Y: FieldElement([4462981797701227, 2123742119971385, 4046996731903802, 3359500796330439, 2173381494298]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([3679587825192500, 252374950570481, 1619568773776946, 3516666352384314, 11091383918322]),
+        },
+        EdwardsPoint {
+            X: FieldElement([3531101949252457, 1785828130446651, 4135111708194928, 3823038283269594, 13984569314754]),
+            Y: FieldElement([3786983342381427, 3836865948778108, 4333519487416171, 1164910233350973, 15245472909539]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([2468992302548951, 1499764037914614, 4446501131994546, 3483147227360893, 7259684149868]),
+        },
+        EdwardsPoint {
+            X: FieldElement([2651558381813902, 4131188410656285, 796336908957125, 743782435521090, 10451390447120]),
+            Y: FieldElement([2651647208876800, 4301265032297426, 2499950102157804, 975453560617594, 3458493397739]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([1566876224086667, 4380132317550985, 427380108610278, 3818424715984711, 16458468951505]),
+        },
+        EdwardsPoint {
+            X: FieldElement([506533251746950, 3045746250135532, 4148010618966281, 4297373336437917, 2005420958027]),
+            Y: FieldElement([2485377504130761, 2823838278308273, 238324787186971, 85545591868810, 4401634127197]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([2775826603168690, 417719372825980, 301471634856787, 125632259689991, 13100551268235]),
+        },
+        EdwardsPoint {
+            X: FieldElement([529756577793173, 2143134650370546, 1104533100499057, 4313968621188623, 13205106126286]),
+            Y: FieldElement([152798220521497, 2698285240788176, 2387706894338873, 4380226536457098, 13796985815956]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([1022965097865816, 2620855540323819, 3358765044495857, 856430090098513, 16060719570873]),
+        },
+    ]),
+    LookupTable([
+        EdwardsPoint {
+            X: FieldElement([2118130622205690, 99912583241724, 2979144406774842, 775526328736925, 3973746159939]),
+            Y: FieldElement([2560433859421109, 1970359689564378, 1309451371343022, 3814744929266650, 5859700985832]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([3025313278124794, 4091721899017758, 2205918618707455, 1063857208243931, 14491664972858]),
+        },
+        EdwardsPoint {
+            X: FieldElement([2545450793873451, 1587320213841133, 705567930532331, 3387463281563662, 14693457071774]),
+            Y: FieldElement([2901895085954786, 2033273064172168, 788948601906996, 3445748597229663, 3800271587144]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([3170985621812275, 4378705649698262, 953682901965593, 2919132788795916, 16708345580090]),
+        },
+        EdwardsPoint {
+            X: FieldElement([70497566101373, 2707505323526878, 94515277504532, 1805128184296600, 14806555757739]),
+            Y: FieldElement([2658869436680706, 4474741214806379, 2582457144749626, 1505496100659409, 9479036737255]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([2505944443194389, 782310401378268, 1073972027670486, 2954168191618617, 11267034620312]),
+        },
+        EdwardsPoint {
+            X: FieldElement([2297069404927566, 2274738132066, 4099358985450269, 840346426863157, 10512793484115]),
+            Y: FieldElement([3044614540603306, 3498159062312581, 2999369935910454, 1479892941240778, 16391565996872]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([3057399928550284, 1003517577762077, 1848620027104322, 4161118604315207, 2784169053845]),
+        },
+        EdwardsPoint {
+            X: FieldElement([3430494691532067, 946351397428173, 582845575155131, 351260030544283, 2879006823151]),
+            Y: FieldElement([570098238697777, 1149455138422876, 4482723761311914, 4386663708489633, 6901884546684]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([635810638313944, 668760333066117, 2215350962182345, 2295953257502430, 488703067588]),
+        },
+        EdwardsPoint {
+            X: FieldElement([1139753225385364, 733291299376639, 1956403772022842, 529981623686889, 7835717158103]),
+            Y: FieldElement([809340965733384, 3771071382876443, 113879951339668, 3660430561541354, 13210405259610]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([3641160965506037, 267709747864504, 422298594932435, 2076778936073588, 6949780939612]),
+        },
+        EdwardsPoint {
+            X: FieldElement([577869730783071, 2875881752675759, 2255030213992035, 2022308818054283, 14692157713537]),
+            Y: FieldElement([4499215936240452, 1565421918718057, 4054279373614852, 3796774062781339, 11318178358134]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([3522716756149033, 3035723185713321, 3130304046854305, 3661068644268566, 13090962873864]),
+        },
+        EdwardsPoint {
+            X: FieldElement([2916127640965917, 4055138965623532, 2204258145283062, 946191337802581, 7328363353434]),
+            Y: FieldElement([4281082058029884, 2427662935352760, 2013358276487243, 1887798384683805, 10338845288731]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([2827738648856173, 2792824407632310, 2433572895044112, 780133842866287, 5154869548637]),
+        },
+    ]),
+    LookupTable([
+        EdwardsPoint {
+            X: FieldElement([348161161098462, 3899291743415185, 1457170383232736, 3949143196616638, 14913436324944]),
+            Y: FieldElement([3770315160300760, 4126348114988313, 3552368381626419, 2361212342723303, 2903741203519]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([3962207174430743, 3096838983419605, 2035571350314088, 3099984675799651, 2974738975998]),
+        },
+        EdwardsPoint {
+            X: FieldElement([1354075214090278, 1855206747789258, 2263351823768557, 1493957434350259, 15752771015680]),
+            Y: FieldElement([3530952181120722, 3902351028531256, 888039541259062, 1545700626030256, 12955314962692]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([2101756541545921, 1124033080772605, 4364148430648543, 680442309569279, 5778227551449]),
+        },
+        EdwardsPoint {
+            X: FieldElement([1592032182602841, 4381473433617179, 1484782820719300, 1006609684486607, 12204855469553]),
+            Y: FieldElement([3141014853615130, 2453379938606524, 1057846061347852, 4343036714028980, 9588684733432]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([964746430132324, 2762922664364111, 685719179855176, 1692840372222994, 14774468801052]),
+        },
+        EdwardsPoint {
+            X: FieldElement([2873017148367729, 4148747852290454, 3737776617072234, 1079743474255828, 2529173915651]),
+            Y: FieldElement([3950037579279318, 655069460805045, 1803475101286542, 2978150267671660, 14244671970138]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([723223350871187, 2104518751305352, 4478065594273725, 1703117364969118, 5144205225925]),
+        },
+        EdwardsPoint {
+            X: FieldElement([2378842000380489, 1992788327572190, 2341270349293398, 906997674471226, 16862282519637]),
+            Y: FieldElement([1074372565247665, 1551753958887264, 4014777025730019, 3274761595386465, 15900491165355]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([622584023207030, 91210377491988, 2827663241251196, 4147923139008954, 13424491448165]),
+        },
+        EdwardsPoint {
+            X: FieldElement([3744508518322792, 2113966392712684, 1738645975906866, 881806413191804, 3188053029640]),
+            Y: FieldElement([3978718254449545, 406469195146937, 2002053140291357, 4236425716293580, 13187168252041]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([757734162590194, 1193546496111859, 47714558222651, 527697919920183, 7132326352354]),
+        },
+        EdwardsPoint {
+            X: FieldElement([78776372666207, 2738521904218576, 66827333883462, 1025307709774068, 5001228860886]),
+            Y: FieldElement([808643781412756, 4066496764505672, 1233149293742141, 760694765685552, 17535828564401]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([665452866862632, 782246349354318, 3172282065424157, 3393914159992629, 11235402179207]),
+        },
+        EdwardsPoint {
+            X: FieldElement([1238076588041081, 2948627985526839, 419132480660197, 2972051453104224, 16261249910278]),
+            Y: FieldElement([3426372470364363, 2277891798753615, 2956722509866798, 2324702526571121, 1032581791502]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([3511420785256809, 467484623952978, 1898623843524654, 1680375351290103, 5070473608164]),
+        },
+    ]),
+    LookupTable([
+        EdwardsPoint {
+            X: FieldElement([458750346094168, 4485066041679252, 662150192276858, 4189688356555528, 7110374588433]),
+            Y: FieldElement([3201808736888071, 3819494275340211, 4466328337207744, 2080683780305654, 13403685763776]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([3099811337322111, 4077388956111818, 3451980584603264, 4425474415437034, 12985738756614]),
+        },
+        EdwardsPoint {
+            X: FieldElement([2108433483176603, 280644776785002, 2891005057066032, 2279622359089363, 17485897408590]),
+            Y: FieldElement([1608866319307260, 3204043808855304, 2530375420222322, 165763839922728, 1696806718642]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([3236858298432894, 2632787458300963, 3237908622639991, 2392823751658692, 4955034297966]),
+        },
+        EdwardsPoint {
+            X: FieldElement([1479470052928004, 4137226490537219, 1493169514310046, 736666450382863, 17079152526285]),
+            Y: FieldElement([910142276090225, 1881538578318073, 3458844411193550, 182006230500743, 7065690035413]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([2226801489764511, 2566863250528161, 60780951507759, 3341588417023026, 11339236256808]),
+        },
+        EdwardsPoint {
+            X: FieldElement([737808090239717, 2242799717665521, 2030469608803278, 2513413280898068, 8643984030420]),
+            Y: FieldElement([450162175048346, 1073333181765544, 1965805220915722, 3485279080312669, 3344477931335]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([3954831480159163, 1495486848896735, 3933355728991063, 65389506270105, 10224390643030]),
+        },
+        EdwardsPoint {
+            X: FieldElement([2621813668448660, 2977786469198948, 834820670999124, 1375989515145771, 15538534047042]),
+            Y: FieldElement([2705271669954655, 2829109010033227, 959668757086229, 3585285067095840, 17208734761189]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([2517989928985942, 1108603773596054, 3880797043627794, 4300531568533565, 8817188003542]),
+        },
+        EdwardsPoint {
+            X: FieldElement([3839744182877602, 1369758633651284, 3645012425197970, 4101058187373157, 10461625150009]),
+            Y: FieldElement([4193548580087360, 1312526997211922, 1588229097059907, 2912642226446752, 9780253903585]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([1754026397621057, 4422543712413653, 2135727373664072, 4308720896997557, 7515615598684]),
+        },
+        EdwardsPoint {
+            X: FieldElement([3481837807415549, 3597754990341757, 1122441659882843, 1213889425458698, 9002793460472]),
+            Y: FieldElement([206050616579832, 3936967759358559, 573427063260726, 4092436337541638, 1679870094676]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([2559641736086123, 4462200648077, 3986577909969954, 2311420155016977, 3713679942211]),
+        },
+        EdwardsPoint {
+            X: FieldElement([2745370073154375, 1991002002496652, 1265767660284063, 1178771935866762, 15562928349203]),
+            Y: FieldElement([2789186152395108, 3191180234182712, 4381758622158027, 3306994079148123, 6862002413810]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([1733310091713428, 4001538404073195, 3995231802072474, 4236200464116440, 12112413115155]),
+        },
+    ]),
+    LookupTable([
+        EdwardsPoint {
+            X: FieldElement([4117183851753693, 880255887319958, 3508381103143486, 2474285738709577, 598243628175]),
+            Y: FieldElement([775106533161853, 1244293872889967, 3555874278327204, 2527709418784000, 12830761505598]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([728863539534421, 3198545081542293, 1596591014304900, 2074799846724427, 12109997943013]),
+        },
+        EdwardsPoint {
+            X: FieldElement([551162214187928, 3655588809316212, 49780525917242, 1475101511867140, 362374510832]),
+            Y: FieldElement([518178710004125, 2204543211011992, 3101778357233779, 3068086397124460, 10918100008631]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([1116537798077349, 4029324257771157, 2264505542151092, 1293698051187192, 2234892316206]),
+        },
+        EdwardsPoint {
+            X: FieldElement([4250166625214312, 1878909306287025, 2989357569702009, 2664984657613396, 12532949038364]),
+            Y: FieldElement([2754058683574950, 4282347718863875, 4439263261835512, 2743503892620471, 13511489787531]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([2393262831069247, 557878361606101, 800168471813638, 87071186782772, 13617503289859]),
+        },
+        EdwardsPoint {
+            X: FieldElement([2718171909584656, 2522158264132497, 3380582024908357, 882856699087712, 16461816920653]),
+            Y: FieldElement([3770823781591945, 3460289086225529, 47284786253837, 360725685037116, 10171101728560]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([4213823913610528, 4161899207067444, 3988287639740077, 3355907380094770, 10544722054691]),
+        },
+        EdwardsPoint {
+            X: FieldElement([2369404779039363, 4295593263318826, 964141249778511, 2600380985724012, 1839111724657]),
+            Y: FieldElement([234663091366991, 2444553585506041, 2345160117329737, 2338822092972802, 10712796606692]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([4124485364920774, 2048333797839055, 2683926063598767, 1197206854062177, 13322752097523]),
+        },
+        EdwardsPoint {
+            X: FieldElement([17791837989665, 4166100885852415, 1679378032017226, 1057796679103378, 2109067410907]),
+            Y: FieldElement([120951675282656, 1224006187241724, 1083860655545223, 3368226862719386, 9340816841598]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([3690397713297802, 1600138249264225, 4067609327242540, 3169445162022203, 12166830185121]),
+        },
+        EdwardsPoint {
+            X: FieldElement([830334943442384, 485743754096524, 3641038556863551, 2681029267689563, 10156558903922]),
+            Y: FieldElement([1196213453883934, 2274953104883, 4422183030196036, 2772983586048633, 3476178535977]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([134764947665274, 3863600962843215, 3248220030629371, 2758880005620135, 14085473521745]),
+        },
+        EdwardsPoint {
+            X: FieldElement([643503635355621, 1842413518638933, 767299229496802, 3887837418838004, 13427242358646]),
+            Y: FieldElement([3313077553564880, 4091672485054686, 2320689154906897, 3036785517639273, 15717474184610]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([760714521516497, 1795334242627931, 1683549632111871, 2688919252365955, 10421775092751]),
+        },
+    ]),
+    LookupTable([
+        EdwardsPoint {
+            X: FieldElement([1484627891946197, 613423499591980, 3906178576088009, 1727268716827901, 13630543297632]),
+            Y: FieldElement([4149547026143997, 4355630005779458, 2749463784869990, 3256324666512906, 17534348819085]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([2478866748772756, 2690484383767787, 1660217782800066, 2789278109839618, 3519265060329]),
+        },
+        EdwardsPoint {
+            X: FieldElement([239447378080190, 3534265059267701, 1641537521890638, 928868207791904, 2943281152262]),
+            Y: FieldElement([904767099836177, 3900200002068817, 2452612554463508, 1024934779169675, 12039150381656]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([2393552454946860, 4485624589405840, 2818290460175451, 4290174250368224, 2730851447180]),
+        },
+        EdwardsPoint {
+            X: FieldElement([3906972481871727, 2766703035826004, 3088767848010636, 42025425575802, 1852947837366]),
+            Y: FieldElement([2532381507283671, 423210148597803, 1143437978383145, 2961499344646630, 7763860156272]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([1285537907115517, 3931069002906504, 129498046711747, 1292653034552110, 3337077803436]),
+        },
+        EdwardsPoint {
+            X: FieldElement([3263991555446965, 855234341321219, 931235101962137, 1678342625381205, 17372193011881]),
+            Y: FieldElement([316081933631583, 2123114730591653, 3210884569193143, 1995979573916481, 5582202574914]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([2054553541344063, 2876315754415218, 3702344610367560, 728802696129567, 12384334962802]),
+        },
+        EdwardsPoint {
+            X: FieldElement([3619499601432269, 3548077640149539, 850548581511872, 306996440742971, 16526045007692]),
+            Y: FieldElement([2455590973409610, 973142018541238, 1962428660793282, 2497781812148885, 5142856738516]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([2764782461616089, 3772201868142708, 2687879552974383, 4410002329009380, 4363724142085]),
+        },
+        EdwardsPoint {
+            X: FieldElement([459765127444697, 934885864319970, 3020163990450445, 368378795883359, 3639923745648]),
+            Y: FieldElement([509772405133422, 1536786746916536, 296057521975555, 1630066216917776, 9386318372726]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([1207386690671918, 883314203918118, 2366722621750247, 1631399881362368, 13824390535531]),
+        },
+        EdwardsPoint {
+            X: FieldElement([2468259687709210, 606297994621938, 4043307615677859, 1390563689070765, 5959809037928]),
+            Y: FieldElement([4278878003378153, 3749773907547704, 4018800974109373, 1473195668977913, 3366570823978]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([1127151512840932, 1246688391882037, 1183208024508628, 1174452447538809, 11953810621252]),
+        },
+        EdwardsPoint {
+            X: FieldElement([2868980334578028, 1744133885220695, 622385556898463, 1510515876046828, 2327992717335]),
+            Y: FieldElement([3877321954030396, 2525077905162577, 1245289009433655, 2849149605696445, 361786646812]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([3731220225534352, 2421723398297552, 373397359580547, 4222413839003089, 954578867781]),
+        },
+    ]),
+    LookupTable([
+        EdwardsPoint {
+            X: FieldElement([1218590722397266, 1688753652291503, 93820295665831, 3928172286353391, 82769868953]),
+            Y: FieldElement([3488172151019796, 4395041758747464, 2434610310010422, 3658300339858862, 6822429642282]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([821890138808864, 2053129765836954, 397890269028674, 153935591545313, 7353136695227]),
+        },
+        EdwardsPoint {
+            X: FieldElement([620376477212974, 3154764179470821, 137442323791067, 3018954519054993, 4321421635257]),
+            Y: FieldElement([509705026741969, 2730298300760822, 4225078748043059, 3426538419844727, 6232202567354]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([2207181867673746, 1651333158551576, 1792987922483709, 3836360589794684, 1197833520677]),
+        },
+        EdwardsPoint {
+            X: FieldElement([3643839928864397, 152693841927085, 577249336363726, 2861365461300020, 13692906573028]),
+            Y: FieldElement([1592166943133898, 3497362222267474, 1267598550882820, 2433891441867190, 14134889977366]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([1832415106223204, 2375699439020107, 2402265284955046, 1046721654542550, 11062981130541]),
+        },
+        EdwardsPoint {
+            X: FieldElement([487664788012610, 3924813357506631, 445968152720390, 277989970286674, 13669646683917]),
+            Y: FieldElement([1909259412422674, 2101583884544160, 1417960175136004, 2802596847037818, 16649188617848]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([2752428754856664, 1242665029679431, 2751816175293070, 1390516247083036, 12793438172331]),
+        },
+        EdwardsPoint {
+            X: FieldElement([1952594616939999, 1677361485986206, 197319278575799, 3142169167265245, 594355766851]),
+            Y: FieldElement([4286248944088242, 2158117806208729, 1895545294914393, 416472379298994, 1381801257482]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([1922317907293616, 2204951380081239, 1795432825736762, 3929435090527268, 238404236044]),
+        },
+        EdwardsPoint {
+            X: FieldElement([1638839567675029, 121211569012930, 3606177767465428, 668442822699079, 14385392528664]),
+            Y: FieldElement([3464803851903440, 3126145524641117, 2259458827421317, 3187983702990955, 9035744310170]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([2411141348598378, 2194039314292752, 2000266160739129, 2848054756429218, 9992284277844]),
+        },
+        EdwardsPoint {
+            X: FieldElement([3525487865557225, 3803754941184713, 573621281900868, 1263796160722669, 12633744233300]),
+            Y: FieldElement([3045712414886880, 1777361606326381, 2891109692277551, 1923690203182120, 2474182565941]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([1035394067149242, 820745332622456, 363811227843765, 3274141643071762, 10690090037823]),
+        },
+        EdwardsPoint {
+            X: FieldElement([4371263842771842, 86603979891241, 1021298417104236, 564501445536408, 14605734271286]),
+            Y: FieldElement([106769208247758, 3022373543098247, 317628417540346, 2428747161115808, 14995781836525]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([4204726130409437, 1309976865045450, 3582090438088176, 1146300810461103, 13324952631074]),
+        },
+    ]),
+    LookupTable([
+        EdwardsPoint {
+            X: FieldElement([1253003698908595, 2512322357139684, 3210999008621281, 1769863618057205, 9689429037505]),
+            Y: FieldElement([1715074101536867, 2781950828356949, 2395103102304133, 1615629852179724, 16766394606031]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([2422577533208343, 3504081982650706, 2803587896269374, 1751809168264089, 16639290846310]),
+        },
+        EdwardsPoint {
+            X: FieldElement([2908892667832227, 3629448184244612, 4327020349710784, 3933922701741746, 4900516006414]),
+            Y: FieldElement([3105461695204346, 1395866121680296, 3646911562787297, 2809383507664311, 15880330336846]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([612705722962107, 434045086951484, 264401824809476, 2988634393195025, 14283751116646]),
+        },
+        EdwardsPoint {
+            X: FieldElement([2557034339114549, 3003947213951688, 4264754734168451, 3625838217626474, 596082360061]),
+            Y: FieldElement([1715526406580638, 3358520344789193, 2021902584963228, 4230463935281926, 13017381042634]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([3783229133355536, 2047230621195950, 1591143476611821, 3724875775094078, 17285551935377]),
+        },
+        EdwardsPoint {
+            X: FieldElement([3911168098474468, 1646046861376626, 1575250176767133, 3665600643376365, 13996020722810]),
+            Y: FieldElement([2827759875576384, 1390630430919618, 3900467458997414, 1251576937307236, 5889377550650]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([75990736908396, 1118476740319956, 27186453028027, 4004123295057227, 4715497499937]),
+        },
+        EdwardsPoint {
+            X: FieldElement([4109325832463417, 499575469904043, 3254958671112881, 136511651645607, 11496934123988]),
+            Y: FieldElement([1733218065571295, 3146006764339928, 4204712541470990, 4349658854279158, 3512465769913]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([2675816784680, 1616108088160187, 4501804391605916, 3774268268827055, 8838360387952]),
+        },
+        EdwardsPoint {
+            X: FieldElement([3761237653754659, 3906979960694835, 3514397850664391, 640277311471763, 3318204830409]),
+            Y: FieldElement([832115188617163, 185214155025079, 369520640250175, 95358159910792, 9872666047278]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([628686282607519, 626451606472348, 2398862508187874, 3136741759698816, 282007875214]),
+        },
+        EdwardsPoint {
+            X: FieldElement([3692857369216136, 1375498431313117, 3857046102465408, 2636406807802445, 16846126528002]),
+            Y: FieldElement([3501929434014307, 3595140747558479, 541157930400141, 364332766470344, 16143828248482]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([2092065968218864, 2213538573169793, 514373362028246, 984644556991175, 6253810886305]),
+        },
+        EdwardsPoint {
+            X: FieldElement([4436885408810595, 2955061170039815, 4215880084593393, 310397041479350, 13880485574846]),
+            Y: FieldElement([2641379804387467, 1179531563830268, 3664874557219480, 2251665574978441, 15511533173965]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([4040131606934740, 1733342039848554, 1306363007544162, 1754371472393666, 15134080125105]),
+        },
+    ]),
+    LookupTable([
+        EdwardsPoint {
+            X: FieldElement([3599982642728421, 630651737569996, 2575903968697492, 4476713509690316, 3357898116829]),
+            Y: FieldElement([2077431303402590, 2237278431027160, 3770253655455662, 1166161968237055, 11684198491780]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([4269968177010565, 1833965635683060, 1895569415396089, 444476515874586, 1678144305480]),
+        },
+        EdwardsPoint {
+            X: FieldElement([2464543372585826, 1312837979181212, 2096571163676316, 2336468393120448, 3322648241219]),
+            Y: FieldElement([3525832860676220, 366460810384085, 926211803942109, 785182349477859, 6348936509203]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([4178706248543331, 2460548289152050, 1033788242284582, 1145227234936483, 6059497573952]),
+        },
+        EdwardsPoint {
+            X: FieldElement([3208469735455732, 1289458579115841, 2407500791558258, 1946985800653426, 1522717681542]),
+            Y: FieldElement([440872683475253, 45625900693330, 3642011231906430, 436718703874036, 16173036206162]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([3690990174093332, 1027287195816865, 2127317404785561, 727293601618454, 15324319138108]),
+        },
+        EdwardsPoint {
+            X: FieldElement([4466341920482738, 3737894332006665, 2750481044141177, 2650816487654415, 12488514963742]),
+            Y: FieldElement([1813843004537493, 2491919653861821, 1217582013320402, 3405467376130973, 14596134187153]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([2478742453665276, 749815563992554, 799918822911318, 1102027528050643, 4230310838236]),
+        },
+        EdwardsPoint {
+            X: FieldElement([1157383991214535, 2002763187680751, 2523834073357296, 3995216138996657, 2705760423037]),
+            Y: FieldElement([1294934480315027, 1928453743727897, 2928449227230510, 574015239535925, 16514674293429]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([3824892540317872, 83853340221564, 1652015188369134, 3252665624897472, 2804390771746]),
+        },
+        EdwardsPoint {
+            X: FieldElement([179712565088013, 3231132919872227, 2318259495504531, 1673413501451057, 17310135319604]),
+            Y: FieldElement([3285709087887741, 4287647719912595, 1409430106965370, 3726791084787265, 8079182759877]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([2587107043906465, 900688727709243, 2335426071468858, 172251439962936, 2045833147431]),
+        },
+        EdwardsPoint {
+            X: FieldElement([4246929546620356, 1997817085221764, 2850601443132132, 4003289705925248, 1106870765300]),
+            Y: FieldElement([2205357870122870, 2748249125491399, 4407322471786368, 3083330143266672, 10552844322930]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([2822933224001875, 2771155555036298, 1286005313878398, 2125850730174776, 13822482790691]),
+        },
+        EdwardsPoint {
+            X: FieldElement([3949513753539654, 1378361808940380, 2923509656456428, 3175535257476708, 15416022532857]),
+            Y: FieldElement([3828028305345925, 3939626468305783, 3254836127942718, 3073789897806069, 10659537423741]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([4023744519294157, 1568570221877306, 2337680180544076, 3553766262218299, 7689551664647]),
+        },
+    ]),
+    LookupTable([
+        EdwardsPoint {
+            X: FieldElement([2374744440954022, 4081786417269733, 2533750650954807, 604508670807918, 1528648317267]),
+            Y: FieldElement([218534907630630, 4233152683212054, 3123283236687977, 2322584817569103, 15201326669730]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([4145902610656585, 1622378028716865, 165441804383185, 2711435450561842, 9915449181061]),
+        },
+        EdwardsPoint {
+            X: FieldElement([4494729315927787, 1670711977553886, 3208692832867317, 2836176600685814, 16843914104884]),
+            Y: FieldElement([1240161521024367, 486197017051417, 3107786200053686, 23320182386587, 2977388113244]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([4016817814344072, 3856182815408526, 134834752466518, 4427340430474509, 3893642406083]),
+        },
+        EdwardsPoint {
+            X: FieldElement([2390823167437648, 2911822416958697, 1851102210214522, 2751525197403631, 14012330522814]),
+            Y: FieldElement([1034547709818518, 3820073451683038, 349189579373576, 4479434518823640, 370220438728]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([2099307967971944, 2244018752923419, 2248387173186103, 3811534868544696, 7049984508713]),
+        },
+        EdwardsPoint {
+            X: FieldElement([4495916189382161, 756373452052949, 3625968399230475, 4004013741009942, 679440991146]),
+            Y: FieldElement([2812675151142484, 1643724701197375, 4078274250456573, 4066940705918828, 1297287365720]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([3525534129650752, 277512731275213, 4440571959970221, 253235805258244, 12951088234834]),
+        },
+        EdwardsPoint {
+            X: FieldElement([4101573998076645, 2226622612535289, 3301182628787002, 4445101845652273, 16006046485649]),
+            Y: FieldElement([638435354112121, 922079753546698, 1819551135387894, 2681338329801642, 14506621301513]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([3744473535132507, 3976398910701915, 1594704867167204, 3885231536561546, 13985267817055]),
+        },
+        EdwardsPoint {
+            X: FieldElement([2316155115471569, 3371376374095005, 2502099298972875, 89202730467787, 15636348558084]),
+            Y: FieldElement([444714384765902, 1478915704949359, 4320881983389560, 639805909967922, 6754482463048]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([2662707633535067, 3727460879485694, 981107836771267, 4072382497309104, 5648231968505]),
+        },
+        EdwardsPoint {
+            X: FieldElement([1009237699588460, 3394419533330573, 1023883409078886, 2933701500234179, 11092832018930]),
+            Y: FieldElement([489164335916059, 224544055186475, 3837598168193821, 2486332109717418, 12825242043572]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([3648338505042085, 3930247467813228, 426232943538981, 2033639869855496, 8260802275765]),
+        },
+        EdwardsPoint {
+            X: FieldElement([4498113277486900, 9478565707240, 3468246987589437, 1546741479694150, 14200797751797]),
+            Y: FieldElement([3139634761656957, 3130454178832674, 1347318237618947, 3520248656081169, 7259126345163]),
+            Z: FieldElement([1, 0, 0, 0, 0]),
+            T: FieldElement([1345922978664148, 2385503274914318, 1990365625836757, 3994275514288268, 7712109901668]),
+        },
+    ]),
+]);
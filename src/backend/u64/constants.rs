@@ -5,137 +5,116 @@ use crate::backend::u64::scalar::Scalar;
 use crate::edwards::*;
 use crate::ristretto::RistrettoPoint;
 
+/// Limbs of the numeric constants, computed by `build.rs` from the
+/// orders of the field and of the subgroup and from `d`.
+mod generated {
+    include!(concat!(env!("OUT_DIR"), "/constants.rs"));
+}
+
 /// `L` is the order of base point for Sonny, in this case it is equivalent to 2^249 + 14490550575682688738086195780655237219
-pub const L: Scalar = Scalar([1129677152307299, 1363544697812651, 714439, 0, 2199023255552]);
+pub const L: Scalar = Scalar(generated::L);
+
+/// Order of the prime-order subgroup generated by `BASEPOINT`,
+/// ie. `L`. Since it is not reduced, it is only meant to be used
+/// as a multiplier, eg. `P * BASEPOINT_ORDER` is the identity for
+/// every point `P` of the subgroup.
+pub const BASEPOINT_ORDER: Scalar = L;
+
+/// Cofactor of the curve: `#E = COFACTOR * L`, with `COFACTOR = 8`.
+pub const COFACTOR: Scalar = Scalar([8, 0, 0, 0, 0]);
 
 /// `(L - 1) / 2` used to check positiveness of a `FieldElement` on the Decaf paper.
-pub(crate) const POS_RANGE: FieldElement =
-    FieldElement([2587757230352886, 4210131976237760, 683900, 0, 8796093022208]);
+pub(crate) const POS_RANGE: FieldElement = FieldElement(generated::MINUS_ONE_HALF);
 
 /// Scalar-LFACTOR is the value that satisfies the equation: `L * LFACTOR = -1 (mod 2^52)`
 /// In this case, `LFACTOR` is the one used for the Montgomery Reduction algorithm,
 /// implemented on Scalar Arithmetics module.
-pub const LFACTOR: u64 = 1331240223835829;
+pub const LFACTOR: u64 = generated::LFACTOR;
+
+/// Montgomery modulus defined for Scalar arithmetics, `R = 2^260 % L`
+pub const R: Scalar = Scalar(generated::R);
 
 /// Montgomery modulus defined for Scalar arithmetics, `R^2 = (2^260)^2 % L`
-pub const RR: Scalar = Scalar([
-    137682194168839,
-    3209056245311277,
-    1480926248458276,
-    2533620989757837,
-    1314911199310,
-]);
+pub const RR: Scalar = Scalar(generated::RR);
+
+/// Barrett constant defined for Scalar arithmetics, `MU = floor(2^508 / L)`.
+pub const MU: Scalar = Scalar(generated::MU);
 
 /// `FIELD_L` is the order of the Prime field for Sonny, n this case it is equivalent to 2^252 + 27742317777372353535851937790883648493`
-pub const FIELD_L: FieldElement = FieldElement([
-    671914833335277,
-    3916664325105025,
-    1367801,
-    0,
-    17592186044416,
-]);
+pub const FIELD_L: FieldElement = FieldElement(generated::FIELD_L);
 
 /// Montgomery modulus defined for FieldElement arithmetics, `R^2 = (2^260)^2 % FIELD_L`
-pub const RR_FIELD: FieldElement = FieldElement([
-    2764609938444603,
-    3768881411696287,
-    1616719297148420,
-    1087343033131391,
-    10175238647962,
-]);
+pub const RR_FIELD: FieldElement = FieldElement(generated::RR_FIELD);
 
 /// `SCALAR_INVERSE_MOD_TWO = 1/2 (mod l)`. 
-pub const SCALAR_INVERSE_MOD_TWO: Scalar = Scalar([2816638389838898, 2933572162591573, 357219, 0, 1099511627776]);
+pub const SCALAR_INVERSE_MOD_TWO: Scalar = Scalar(generated::SCALAR_INVERSE_MOD_TWO);
 
 /// `INVERSE_MOD_TWO = 1/2 (mod l)`.                   
-pub const INVERSE_MOD_TWO: FieldElement = FieldElement([2587757230352887, 4210131976237760, 683900, 0, 8796093022208]);
+pub const INVERSE_MOD_TWO: FieldElement = FieldElement(generated::INVERSE_MOD_TWO);
+
+/// `-1 (mod l)`, see `FieldElement::minus_one`.
+pub(crate) const MINUS_ONE: FieldElement = FieldElement(generated::MINUS_ONE);
 
 /// `MINUS_ONE_HALF = (-1/2) (mod l)`. 
-pub const MINUS_ONE_HALF: FieldElement = FieldElement([2587757230352886, 4210131976237760, 683900, 0, 8796093022208]);
+pub const MINUS_ONE_HALF: FieldElement = FieldElement(generated::MINUS_ONE_HALF);
 
 /// FieldElement-LFACTOR is the value that satisfies the equation: `L * LFACTOR = -1 (mod 2^52)`
 /// In this case, `LFACTOR` is the one used for the Montgomery Reduction algorithm,
 /// implemented on FieldElement Arithmetics module.
-pub const LFACTOR_FIELD: u64 = 1439961107955227;
+pub const LFACTOR_FIELD: u64 = generated::LFACTOR_FIELD;
 
 /// Montgomery modulus defined for FieldElements on `inverse()` functions scope.
 /// It is used for the Montgomery Mul operation that takes place on the `Inversion
 // operation`. It's defined as: `R^2 = (2^253)^2 % L`
-pub const INV_RR: FieldElement = FieldElement([
-    2210115751650724,
-    3809421927348411,
-    2357176729341513,
-    3420097284349172,
-    7483527818736,
-]);
+pub const INV_RR: FieldElement = FieldElement(generated::INV_RR);
 
 /// Edwards `a` variable value = `-1 (mod l)` equals:
 /// `7237005577332262213973186563042994240857116359379907606001950938285454250988`
 /// where `l = Prime of the field = 2^252 + 27742317777372353535851937790883648493`
-pub static EDWARDS_A: FieldElement = FieldElement([
-    671914833335276,
-    3916664325105025,
-    1367801,
-    0,
-    17592186044416,
-]);
+pub static EDWARDS_A: FieldElement = FieldElement(generated::EDWARDS_A);
 
 /// Edwards `d` variable value = `-126296/126297 (mod l)` equals:
 /// `951605751702391019481481818669129158712512026257330939079110344917983315091`
 /// where `l = Prime of the field = 2^252 + 27742317777372353535851937790883648493`
-pub static EDWARDS_D: FieldElement = FieldElement([
-    3304133203739795,
-    2446467598308289,
-    1534112949566882,
-    2032729967918914,
-    2313225441931,
-]);
+pub static EDWARDS_D: FieldElement = FieldElement(generated::EDWARDS_D);
 
 /// Holds the value of one of both `sqrt(-1 (mod p)) values.
 /// `SQRT_MINUS_ONE = 3034649101460298094273452163494570791663566989388331537498831373842135895065`.
-pub const SQRT_MINUS_ONE: FieldElement = FieldElement([
-    3075585030474777,
-    2451921961843096,
-    1194333869305507,
-    2218299809671669,
-    7376823328646,
-]);
+pub const SQRT_MINUS_ONE: FieldElement = FieldElement(generated::SQRT_MINUS_ONE);
+
+/// Width of the windows of the addition chains, see `FieldElement::pow_chain`.
+pub(crate) const CHAIN_WINDOW: usize = generated::CHAIN_WINDOW;
+
+/// Addition chain of `(l + 3) / 8`, used to compute square roots
+/// since `l = 5 (mod 8)`.
+pub(crate) const SQRT_CHAIN: &[(u32, u8)] = &generated::SQRT_CHAIN;
+
+/// Addition chain of `MINUS_ONE_HALF = (l - 1) / 2`, for the
+/// Legendre symbol.
+pub(crate) const LEGENDRE_CHAIN: &[(u32, u8)] = &generated::LEGENDRE_CHAIN;
+
+/// `FIELD_L` as signed limbs in radix `2^62`, see `backend::u64::safegcd`.
+pub(crate) const SAFEGCD_MODULUS: [i64; 5] = generated::SAFEGCD_MODULUS;
+
+/// `1 / FIELD_L (mod 2^62)`.
+pub(crate) const SAFEGCD_MODULUS_INV62: u64 = generated::SAFEGCD_MODULUS_INV62;
 
 /// `(+)1/SQRT(a) (mod l)` equals: `4202356475871964119699734399548423449193549369991576068503119564443318355924`.
-pub static INV_SQRT_A: FieldElement = FieldElement([
-    2099929430230996,
-    1464742363261928,
-    3309265759432790,
-    2285299817698826,
-    10215362715769,
-]);
+pub static INV_SQRT_A: FieldElement = FieldElement(generated::INV_SQRT_A);
 
 /// `(-)SQRT(a) (mod l)` equals: `4202356475871964119699734399548423449193549369991576068503119564443318355924`.
-pub static MINUS_SQRT_A: FieldElement = FieldElement([
-    2099929430230996,
-    1464742363261928,
-    3309265759432790,
-    2285299817698826,
-    10215362715769,
-]);
+pub static MINUS_SQRT_A: FieldElement = FieldElement(generated::INV_SQRT_A);
 
 /// `INV_SQRT_A_MINUS_D = 482283834104289360917429750399313974390948281833312135312952165682596457149`.
-pub const INV_SQRT_A_MINUS_D: FieldElement = FieldElement([
-    550050132044477,
-    3953042081665262,
-    2971403105229349,
-    212915494370164,
-    1172367057772,
-]);
+pub const INV_SQRT_A_MINUS_D: FieldElement = FieldElement(generated::INV_SQRT_A_MINUS_D);
 
 /// `SQRT_AD_MINUS_ONE = `.
-pub const SQRT_AD_MINUS_ONE: FieldElement = FieldElement([
-    3601277882726560,
-    1817821323014817,
-    1726005090908779,
-    2111284621343800,
-    648674458156,
-]);
+pub const SQRT_AD_MINUS_ONE: FieldElement = FieldElement(generated::SQRT_AD_MINUS_ONE);
+
+/// `D_PLUS_ONE_OVER_D_MINUS_ONE = (d + 1) / (d - 1) (mod l)` equals:
+/// `496404724726571104976382680403981575962478762446371353052498691399737048741`.
+pub const D_PLUS_ONE_OVER_D_MINUS_ONE: FieldElement =
+    FieldElement(generated::D_PLUS_ONE_OVER_D_MINUS_ONE);
 
 /// 4Coset of a RistrettoPoint.
 pub(crate) const FOUR_COSET_GROUP: [EdwardsPoint; 4] = [
@@ -213,6 +192,17 @@ pub const BASEPOINT: EdwardsPoint = EdwardsPoint {
 /// Ristretto Basepoint.
 pub const RISTRETTO_BASEPOINT: RistrettoPoint = RistrettoPoint(BASEPOINT);
 
+/// Identity of the curve on Extended Coordinates: `(0, 1, 1, 0)`.
+pub const IDENTITY: EdwardsPoint = EdwardsPoint {
+    X: FieldElement([0, 0, 0, 0, 0]),
+    Y: FieldElement([1, 0, 0, 0, 0]),
+    Z: FieldElement([1, 0, 0, 0, 0]),
+    T: FieldElement([0, 0, 0, 0, 0]),
+};
+
+/// Identity of the Ristretto group.
+pub const RISTRETTO_IDENTITY: RistrettoPoint = RistrettoPoint(IDENTITY);
+
 pub(crate) const BASEPOINT_ODD_MULTIPLES_TABLE: [RistrettoPoint; 126] = [
     RistrettoPoint(EdwardsPoint {
             X: FieldElement([0,0,0,0,0]),
@@ -13,14 +13,13 @@ use std::fmt::{Debug, Display};
 use std::cmp::{Ord, Ordering, PartialOrd};
 use std::default::Default;
 
-use core::ops::{Add, Div, Mul, Neg, Sub};
+use core::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub, SubAssign};
 use core::ops::{Index, IndexMut};
 
-use subtle::{Choice, ConditionallyNegatable, ConditionallySelectable, ConstantTimeEq};
-
-use num::Integer;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
 
 use crate::backend::u64::constants;
+use crate::errors::Error;
 use crate::scalar::Ristretto255Scalar;
 use crate::traits::ops::*;
 use crate::traits::Identity;
@@ -40,11 +39,18 @@ impl Debug for FieldElement {
 }
 
 impl Display for FieldElement {
+    /// Prints the limbs of the `FieldElement`, or its canonical
+    /// value in base 10 with the alternate flag (`{:#}`).
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        if f.alternate() {
+            return write!(f, "{}", self.to_decimal_string());
+        };
         write!(f, "FieldElement({:?})", &self.0[..])
     }
 }
 
+define_hex_formatting!(FieldElement, to_bytes);
+
 impl Index<usize> for FieldElement {
     type Output = u64;
     fn index(&self, _index: usize) -> &u64 {
@@ -160,22 +166,13 @@ impl<'a> From<&'a Ristretto255Scalar> for FieldElement {
     }
 }
 
-impl Into<Ristretto255Scalar> for FieldElement {
-    /// Given a FieldElement reference get it's
-    /// Ristretto255Scalar Equivalent on it's
-    /// canonical bytes representation.
-    fn into(self) -> Ristretto255Scalar {
-        Ristretto255Scalar::from_canonical_bytes(self.to_bytes()).unwrap()
-    }
-}
-
 impl<'a> Neg for &'a FieldElement {
     type Output = FieldElement;
     /// Computes `-self (mod l)`.
     /// Compute the negated value that corresponds to the
     /// complement of the two, of the input FieldElement.
     fn neg(self) -> FieldElement {
-        &FieldElement::zero() - self
+        FieldElement::zero() - self
     }
 }
 
@@ -231,10 +228,14 @@ impl<'a, 'b> Sub<&'b FieldElement> for &'a FieldElement {
         // Conditionaly add l, if difference is negative.
         // Be aware that here `sub` tells us the most significant bit of the last limb
         // so then we know whether or not the value is greater than `l`.
-        let underflow_mask = ((sub >> 63) ^ 1).wrapping_sub(1);
+        //
+        // The mask goes through a `Choice`, otherwise the compiler
+        // turns the addition into a branch on the underflow.
+        let underflow = Choice::from((sub >> 63) as u8);
         let mut carry = 0u64;
         for i in 0..5 {
-            carry = (carry >> 52) + difference[i] + (constants::FIELD_L[i] & underflow_mask);
+            let addend = u64::conditional_select(&0, &constants::FIELD_L[i], underflow);
+            carry = (carry >> 52) + difference[i] + addend;
             difference[i] = carry & mask;
         }
         difference
@@ -251,31 +252,77 @@ impl Sub<FieldElement> for FieldElement {
 
 impl<'a, 'b> Mul<&'b FieldElement> for &'a FieldElement {
     type Output = FieldElement;
-    /// This Mul implementation returns a double precision result.
+    /// Compute `a * b (mod l)`.
     ///
-    /// The result of the standard mul is stored on a [u128; 9].
-    ///
-    /// Then, we apply the Montgomery Reduction function to perform
-    /// the modulo and the reduction to the `FieldElement` format: [u64; 5].
+    /// The interleaved Montgomery multiplication gives `a * b / R`,
+    /// and a second one by `R^2` takes the result out of the
+    /// Montgomery form.
     fn mul(self, _rhs: &'b FieldElement) -> FieldElement {
-        let prod = FieldElement::montgomery_reduce(&FieldElement::mul_internal(self, _rhs));
-        FieldElement::montgomery_reduce(&FieldElement::mul_internal(&prod, &constants::RR_FIELD))
+        mul_mod(self, _rhs)
     }
 }
 
 impl Mul<FieldElement> for FieldElement {
     type Output = FieldElement;
-    /// This Mul implementation returns a double precision result.
-    ///
-    /// The result of the standard mul is stored on a [u128; 9].
+    /// Compute `a * b (mod l)`.
     ///
-    /// Then, we apply the Montgomery Reduction function to perform
-    /// the modulo and the reduction to the `FieldElement` format: [u64; 5].
+    /// The interleaved Montgomery multiplication gives `a * b / R`,
+    /// and a second one by `R^2` takes the result out of the
+    /// Montgomery form.
     fn mul(self, _rhs: FieldElement) -> FieldElement {
         &self * &_rhs
     }
 }
 
+impl Mul<u64> for FieldElement {
+    type Output = FieldElement;
+    /// Compute `a * k (mod l)` for a small integer `k`.
+    ///
+    /// `a * k` fits on 6 limbs and its quotient by `2^252` is
+    /// at most `q < 2^64`, so as `FIELD_L = 2^252 + c`, the result
+    /// is `(a * k mod 2^252) - q * c`, plus `FIELD_L` if negative.
+    /// This is much cheaper than the two Montgomery multiplications
+    /// of `a * FieldElement::from(k)`.
+    fn mul(self, k: u64) -> FieldElement {
+        let mask = (1u64 << 52) - 1;
+        let l = &constants::FIELD_L;
+
+        let mut x = [0u64; 6];
+        let mut carry = 0u128;
+        for i in 0..5 {
+            carry += m(self[i], k);
+            x[i] = (carry as u64) & mask;
+            carry >>= 52;
+        }
+        x[5] = carry as u64;
+
+        // `FIELD_L[4] = 2^44`, so the quotient starts at the bit 44
+        // of the limb 4.
+        let q = (x[4] >> 44) | (x[5] << 8);
+        let lo = FieldElement([x[0], x[1], x[2], x[3], x[4] & ((1u64 << 44) - 1)]);
+
+        // q * c < 2^189. FIELD_L[3] = 0 so it fits on 4 limbs.
+        let mut qc = [0u64; 5];
+        let mut carry = 0u128;
+        for i in 0..3 {
+            carry += m(q, l[i]);
+            qc[i] = (carry as u64) & mask;
+            carry >>= 52;
+        }
+        qc[3] = carry as u64;
+
+        lo - FieldElement(qc)
+    }
+}
+
+impl Mul<u64> for &FieldElement {
+    type Output = FieldElement;
+    /// Compute `a * k (mod l)` for a small integer `k`.
+    fn mul(self, k: u64) -> FieldElement {
+        *self * k
+    }
+}
+
 impl<'a, 'b> Div<&'a FieldElement> for &'b FieldElement {
     type Output = FieldElement;
     /// Performs the op: `x / y (mod l)`.
@@ -283,9 +330,13 @@ impl<'a, 'b> Div<&'a FieldElement> for &'b FieldElement {
     /// Since on modular fields we don't divide, the equivalent op
     /// is: `x * (y^-1 (mod l))`, which is equivalent to the naive
     /// division but for Finite Fields.
+    ///
+    /// # Panics
+    /// If `y = 0`. Use `FieldElement::checked_div` to get an
+    /// error instead.
     fn div(self, _rhs: &'a FieldElement) -> FieldElement {
         assert!(_rhs != &FieldElement::zero(), "Cannot divide by zero.");
-        self * &_rhs.inverse()
+        self * _rhs.inverse()
     }
 }
 
@@ -301,18 +352,37 @@ impl Div<FieldElement> for FieldElement {
     }
 }
 
+define_mixed_add_variants!(LHS = FieldElement, RHS = FieldElement, Output = FieldElement);
+define_mixed_sub_variants!(LHS = FieldElement, RHS = FieldElement, Output = FieldElement);
+define_mixed_mul_variants!(LHS = FieldElement, RHS = FieldElement, Output = FieldElement);
+define_add_assign_variants!(LHS = FieldElement, RHS = FieldElement);
+define_sub_assign_variants!(LHS = FieldElement, RHS = FieldElement);
+define_mul_assign_variants!(LHS = FieldElement, RHS = FieldElement);
+
 impl<'a> Square for &'a FieldElement {
     type Output = FieldElement;
     /// Compute `a^2 (mod l)`.
     ///
-    /// This `Square` implementation returns a double precision result.
-    /// The result of the standard square is stored on a [u128; 9].
-    ///
-    /// Then, we apply the Montgomery Reduction function to perform
-    /// the modulo and the reduction to the `FieldElement` format: [u64; 5].
+    /// Same as `a * a`, see the `Mul` implementation.
     fn square(self) -> FieldElement {
-        let aa = FieldElement::montgomery_reduce(&FieldElement::square_internal(self));
-        FieldElement::montgomery_reduce(&FieldElement::mul_internal(&aa, &constants::RR_FIELD))
+        mul_mod(self, self)
+    }
+}
+
+impl Double for &FieldElement {
+    type Output = FieldElement;
+    /// Compute `2 * a (mod l)` by shifting the limbs one bit and
+    /// subtracting `l` if the result is greater or equal than it.
+    fn double(self) -> FieldElement {
+        let mut res = FieldElement::zero();
+        let mask = (1u64 << 52) - 1;
+
+        let mut carry: u64 = 0;
+        for i in 0..5 {
+            carry = (self.0[i] << 1) + (carry >> 52);
+            res[i] = carry & mask;
+        }
+        res - constants::FIELD_L
     }
 }
 
@@ -320,7 +390,16 @@ impl<'a> Half for &'a FieldElement {
     type Output = FieldElement;
     /// Give the half of the FieldElement value (mod l).
     fn half(self) -> FieldElement {
-        self * &constants::INVERSE_MOD_TWO
+        self * constants::INVERSE_MOD_TWO
+    }
+}
+
+impl Pow<u64> for &FieldElement {
+    type Output = FieldElement;
+    /// Performs the op: `a^b (mod l)` with a `u64` exponent,
+    /// see `FieldElement::pow_u64`.
+    fn pow(self, exp: u64) -> FieldElement {
+        self.pow_u64(exp)
     }
 }
 
@@ -344,8 +423,8 @@ impl<'a, 'b> Pow<&'b FieldElement> for &'a FieldElement {
                 expon = expon.half_without_mod();
                 base = base * base;
             } else {
-                expon = expon - one;
-                res = res * base;
+                expon -= one;
+                res *= base;
 
                 expon = expon.half_without_mod();
                 base = base * base;
@@ -380,7 +459,7 @@ impl<'a> ModSqrt for &'a FieldElement {
     fn mod_sqrt(self, sign: Choice) -> Option<FieldElement> {
         let zero = FieldElement::zero();
         // If the input is `0` the sqrt is directly 0.
-        if self.ct_eq(&FieldElement::zero()).unwrap_u8() == 1u8 {
+        if self.is_zero().unwrap_u8() == 1u8 {
             return Some(zero);
         }
 
@@ -400,7 +479,7 @@ impl<'a> ModSqrt for &'a FieldElement {
         let mut q = FieldElement::minus_one();
         let mut s = zero;
         while q.is_even() {
-            s = s + one;
+            s += one;
             q = q.half_without_mod();
         }
 
@@ -419,17 +498,17 @@ impl<'a> ModSqrt for &'a FieldElement {
             let mut e = FieldElement::from(2u8);
             let b;
             while i < m {
-                i = i + one;
+                i += one;
                 if t.pow(&e).ct_eq(&one).unwrap_u8() == 1u8 {
                     break;
                 }
-                e = e * two;
+                e *= two;
             }
 
             // Update values for next iter
             b = c.pow(&two.pow(&(m - i - one)));
-            x = x * b;
-            t = t * b.square();
+            x *= b;
+            t *= b.square();
             c = b.square();
             m = i;
         }
@@ -487,23 +566,46 @@ impl SqrtRatioI<&FieldElement> for FieldElement {
             // (u/v) is not QR, so we multiply by `i` and
             // return `(false, +sqrt(i*u/v))`.
             false => {
-                let mut res = (constants::SQRT_MINUS_ONE * (self / v))
-                    .mod_sqrt(Choice::from(1u8))
-                    .unwrap();
-                res.conditional_negate(!res.is_positive());
+                let res = (constants::SQRT_MINUS_ONE * (self / v)).sqrt().unwrap();
                 (Choice::from(0u8), res)
             }
             // (u/v) is QR, so we don't need to do anything and
             // we return `(true, +sqrt(u/v))`.
             true => {
-                let mut res = (self / v).mod_sqrt(Choice::from(1u8)).unwrap();
-                res.conditional_negate(!res.is_positive());
+                let res = (self / v).sqrt().unwrap();
                 (Choice::from(1u8), res)
             }
         }
     }
 }
 
+/// Compute `a * b (mod l)` with two Montgomery multiplications,
+/// see the `Mul` implementation.
+#[cfg(not(all(
+    feature = "asm",
+    target_arch = "x86_64",
+    target_feature = "bmi2",
+    target_feature = "adx"
+)))]
+#[inline(always)]
+fn mul_mod(a: &FieldElement, b: &FieldElement) -> FieldElement {
+    let prod = FieldElement::montgomery_mul(a, b);
+    FieldElement::montgomery_mul(&prod, &constants::RR_FIELD)
+}
+
+/// Compute `a * b (mod l)` with the `mulx`, `adcx` and `adox`
+/// instructions, see `backend::asm::field`.
+#[cfg(all(
+    feature = "asm",
+    target_arch = "x86_64",
+    target_feature = "bmi2",
+    target_feature = "adx"
+))]
+#[inline(always)]
+fn mul_mod(a: &FieldElement, b: &FieldElement) -> FieldElement {
+    crate::backend::asm::field::mul(a, b)
+}
+
 /// u64 * u64 = u128 inline func multiply helpe
 fn m(x: u64, y: u64) -> u128 {
     (x as u128) * (y as u128)
@@ -523,13 +625,112 @@ impl FieldElement {
 
     /// Construct -1 (mod l).
     pub const fn minus_one() -> FieldElement {
-        FieldElement([
-            671914833335276,
-            3916664325105025,
-            1367801,
-            0,
-            17592186044416,
-        ])
+        constants::MINUS_ONE
+    }
+
+    /// Construct a `FieldElement` from its limbs in radix `2^52`,
+    /// checking that they're the canonical representation of the
+    /// value.
+    ///
+    /// # Returns
+    /// - `None` if any of the limbs is greater or equal than `2^52`,
+    ///   or if the value is greater or equal than `FIELD_L`.
+    pub fn from_raw_limbs(limbs: [u64; 5]) -> Option<FieldElement> {
+        let fe = FieldElement(limbs);
+        if limbs.iter().any(|limb| limb >> 52 != 0) || fe >= constants::FIELD_L {
+            return None;
+        };
+        Some(fe)
+    }
+
+    /// Construct a `FieldElement` from its limbs in radix `2^52`
+    /// without any checks, eg. for constants.
+    ///
+    /// The arithmetic gives wrong results if any of the limbs is
+    /// greater or equal than `2^52` or the value is greater or equal
+    /// than `FIELD_L`. Use `FieldElement::reduce` for such inputs.
+    pub const fn from_raw_limbs_unchecked(limbs: [u64; 5]) -> FieldElement {
+        FieldElement(limbs)
+    }
+
+    /// Get the limbs of the `FieldElement` in radix `2^52`.
+    pub const fn as_limbs(&self) -> &[u64; 5] {
+        &self.0
+    }
+
+    /// Get the value of the `FieldElement` as four saturated LE
+    /// u64 words.
+    pub fn to_u64_words(&self) -> [u64; 4] {
+        [
+            self.0[0] | (self.0[1] << 52),
+            (self.0[1] >> 12) | (self.0[2] << 40),
+            (self.0[2] >> 24) | (self.0[3] << 28),
+            (self.0[3] >> 36) | (self.0[4] << 16),
+        ]
+    }
+
+    /// Construct a `FieldElement` from four saturated LE u64 words.
+    ///
+    /// # Returns
+    /// - `None` if the value is greater or equal than `FIELD_L`.
+    pub fn from_u64_words(words: [u64; 4]) -> Option<FieldElement> {
+        let mask = (1u64 << 52) - 1;
+        let fe = FieldElement([
+            words[0] & mask,
+            ((words[0] >> 52) | (words[1] << 12)) & mask,
+            ((words[1] >> 40) | (words[2] << 24)) & mask,
+            ((words[2] >> 28) | (words[3] << 36)) & mask,
+            words[3] >> 16,
+        ]);
+        if fe >= constants::FIELD_L {
+            return None;
+        };
+        Some(fe)
+    }
+
+    /// Bit length of `FIELD_L`, and so of the canonical `FieldElement`s.
+    pub const BITS: usize = 253;
+
+    /// Get the `FieldElement::BITS` bits of the `FieldElement`,
+    /// starting from the least significant one.
+    pub fn to_bits(&self) -> [bool; FieldElement::BITS] {
+        let words = self.to_u64_words();
+        let mut bits = [false; FieldElement::BITS];
+        for (i, bit) in bits.iter_mut().enumerate() {
+            *bit = (words[i >> 6] >> (i & 63)) & 1 == 1;
+        }
+        bits
+    }
+
+    /// Construct a `FieldElement` from its LE `bits`, the inverse of
+    /// `FieldElement::to_bits`. Fewer bits are zero-extended.
+    ///
+    /// # Returns
+    /// - `None` if there are too many bits or the value isn't canonical.
+    pub fn from_bits(bits: &[bool]) -> Option<FieldElement> {
+        if bits.len() > FieldElement::BITS {
+            return None;
+        };
+        let mut words = [0u64; 4];
+        for (i, bit) in bits.iter().enumerate() {
+            words[i >> 6] |= (*bit as u64) << (i & 63);
+        }
+        FieldElement::from_u64_words(words)
+    }
+
+    /// Compute `3 * a (mod l)` with a doubling and an addition.
+    pub fn mul_by_3(&self) -> FieldElement {
+        self.double() + self
+    }
+
+    /// Compute `5 * a (mod l)` with two doublings and an addition.
+    pub fn mul_by_5(&self) -> FieldElement {
+        self.double().double() + self
+    }
+
+    /// Compute `8 * a (mod l)` with three doublings.
+    pub fn mul_by_8(&self) -> FieldElement {
+        self.double().double().double()
     }
 
     /// Evaluate if a `FieldElement` is even or not.
@@ -592,44 +793,49 @@ impl FieldElement {
     /// encoding is canonical.
     pub fn to_bytes(self) -> [u8; 32] {
         let mut res = [0u8; 32];
+        self.write_bytes_into(&mut res);
+        res
+    }
 
-        res[0] = (self.0[0] >> 0) as u8;
-        res[1] = (self.0[0] >> 8) as u8;
-        res[2] = (self.0[0] >> 16) as u8;
-        res[3] = (self.0[0] >> 24) as u8;
-        res[4] = (self.0[0] >> 32) as u8;
-        res[5] = (self.0[0] >> 40) as u8;
+    /// Serialize this `FieldElement` into the given 32-byte buffer,
+    /// with the same encoding as `FieldElement::to_bytes`.
+    pub fn write_bytes_into(&self, out: &mut [u8; 32]) {
+        out[0] = (self.0[0] >> 0) as u8;
+        out[1] = (self.0[0] >> 8) as u8;
+        out[2] = (self.0[0] >> 16) as u8;
+        out[3] = (self.0[0] >> 24) as u8;
+        out[4] = (self.0[0] >> 32) as u8;
+        out[5] = (self.0[0] >> 40) as u8;
         // Satisfy radix 52 with the next limb value shifted according the needs
-        res[6] = ((self.0[0] >> 48) | (self.0[1] << 4)) as u8;
-        res[7] = (self.0[1] >> 4) as u8;
-        res[8] = (self.0[1] >> 12) as u8;
-        res[9] = (self.0[1] >> 20) as u8;
-        res[10] = (self.0[1] >> 28) as u8;
-        res[11] = (self.0[1] >> 36) as u8;
-        res[12] = (self.0[1] >> 44) as u8;
-        res[13] = (self.0[2] >> 0) as u8;
-        res[14] = (self.0[2] >> 8) as u8;
-        res[15] = (self.0[2] >> 16) as u8;
-        res[16] = (self.0[2] >> 24) as u8;
-        res[17] = (self.0[2] >> 32) as u8;
-        res[18] = (self.0[2] >> 40) as u8;
-        res[19] = ((self.0[2] >> 48) | (self.0[3] << 4)) as u8;
-        res[20] = (self.0[3] >> 4) as u8;
-        res[21] = (self.0[3] >> 12) as u8;
-        res[22] = (self.0[3] >> 20) as u8;
-        res[23] = (self.0[3] >> 28) as u8;
-        res[24] = (self.0[3] >> 36) as u8;
-        res[25] = (self.0[3] >> 44) as u8;
-        res[26] = (self.0[4] >> 0) as u8;
-        res[27] = (self.0[4] >> 8) as u8;
-        res[28] = (self.0[4] >> 16) as u8;
-        res[29] = (self.0[4] >> 24) as u8;
-        res[30] = (self.0[4] >> 32) as u8;
-        res[31] = (self.0[4] >> 40) as u8;
+        out[6] = ((self.0[0] >> 48) | (self.0[1] << 4)) as u8;
+        out[7] = (self.0[1] >> 4) as u8;
+        out[8] = (self.0[1] >> 12) as u8;
+        out[9] = (self.0[1] >> 20) as u8;
+        out[10] = (self.0[1] >> 28) as u8;
+        out[11] = (self.0[1] >> 36) as u8;
+        out[12] = (self.0[1] >> 44) as u8;
+        out[13] = (self.0[2] >> 0) as u8;
+        out[14] = (self.0[2] >> 8) as u8;
+        out[15] = (self.0[2] >> 16) as u8;
+        out[16] = (self.0[2] >> 24) as u8;
+        out[17] = (self.0[2] >> 32) as u8;
+        out[18] = (self.0[2] >> 40) as u8;
+        out[19] = ((self.0[2] >> 48) | (self.0[3] << 4)) as u8;
+        out[20] = (self.0[3] >> 4) as u8;
+        out[21] = (self.0[3] >> 12) as u8;
+        out[22] = (self.0[3] >> 20) as u8;
+        out[23] = (self.0[3] >> 28) as u8;
+        out[24] = (self.0[3] >> 36) as u8;
+        out[25] = (self.0[3] >> 44) as u8;
+        out[26] = (self.0[4] >> 0) as u8;
+        out[27] = (self.0[4] >> 8) as u8;
+        out[28] = (self.0[4] >> 16) as u8;
+        out[29] = (self.0[4] >> 24) as u8;
+        out[30] = (self.0[4] >> 32) as u8;
+        out[31] = (self.0[4] >> 40) as u8;
 
         // High bit should be zero.
-        //debug_assert!((res[31] & 0b1000_0000u8) == 0u8);
-        res
+        //debug_assert!((out[31] & 0b1000_0000u8) == 0u8);
     }
 
     /// Given a `k`: u64, compute `2^k` giving the resulting result
@@ -639,6 +845,7 @@ impl FieldElement {
     ///
     /// NOTE: This function implements an `assert!` statement that
     /// checks the correctness of the exponent provided as param.
+    /// Use `FieldElement::try_two_pow_k` to get an error instead.
     pub fn two_pow_k(exp: u64) -> FieldElement {
         // Check that exp has to be less than 260.
         // Note that a FieldElement can be as much
@@ -667,6 +874,16 @@ impl FieldElement {
         res
     }
 
+    /// Given a `k`: u64, compute `2^k` as a `FieldElement`.
+    ///
+    /// Returns `Error::OutOfRange` if `k >= 253`.
+    pub fn try_two_pow_k(exp: u64) -> Result<FieldElement, Error> {
+        if exp >= 253u64 {
+            return Err(Error::OutOfRange);
+        };
+        Ok(FieldElement::two_pow_k(exp))
+    }
+
     /// Returns the half of an **EVEN** `FieldElement`.
     /// 
     /// This function performs almost 4x faster than the
@@ -681,7 +898,7 @@ impl FieldElement {
         let mut res = self;
 
         for i in (0..5).rev() {
-            res[i] = res[i] | carry;
+            res[i] |= carry;
             
             carry = (res[i] & 1) << 52;
             res[i] >>= 1;
@@ -689,6 +906,30 @@ impl FieldElement {
         res
     }
 
+    /// Compute `a^exp (mod l)` for a `u64` exponent.
+    ///
+    /// Unlike `Pow<&FieldElement>`, the exponent doesn't need to be
+    /// lifted to a `FieldElement` first. The running time depends on
+    /// `exp`, so it must not be secret.
+    pub fn pow_u64(&self, exp: u64) -> FieldElement {
+        self.pow_u128(exp as u128)
+    }
+
+    /// Compute `a^exp (mod l)` for a `u128` exponent.
+    ///
+    /// The running time depends on `exp`, so it must not be secret.
+    pub fn pow_u128(&self, exp: u128) -> FieldElement {
+        let mut res = FieldElement::one();
+        // Left-to-right square and multiply over the significant bits.
+        for i in (0..(128 - exp.leading_zeros())).rev() {
+            res = res.square();
+            if (exp >> i) & 1 == 1 {
+                res *= self;
+            }
+        }
+        res
+    }
+
     /// Given a FieldElement, this function evaluates if it is a quadratic
     /// residue (mod l).
     ///
@@ -703,10 +944,36 @@ impl FieldElement {
     /// `0`  -> `Input (mod l) == 0`. Not implemented since you can't pass
     /// an input which is multiple of `FIELD_L`.
     pub fn legendre_symbol(&self) -> Choice {
-        let res = self.pow(&constants::MINUS_ONE_HALF);
+        let res = self.pow_chain(constants::LEGENDRE_CHAIN);
         res.ct_eq(&FieldElement::minus_one()) ^ Choice::from(1u8)
     }
 
+    /// Compute `a^e (mod l)` for a fixed exponent `e` given by its
+    /// addition chain, as generated by `build.rs`: each step `(s, d)`
+    /// squares `s` times and then multiplies by `a^d` unless `d = 0`.
+    ///
+    /// Unlike the generic `Pow`, the sequence of operations only
+    /// depends on the exponent, so it runs in constant time on `a`.
+    pub(crate) fn pow_chain(&self, chain: &[(u32, u8)]) -> FieldElement {
+        // The odd powers `a, a^3, ..., a^(2^CHAIN_WINDOW - 1)`.
+        let mut table = [*self; 1 << (constants::CHAIN_WINDOW - 1)];
+        let square = self.square();
+        for i in 1..table.len() {
+            table[i] = table[i - 1] * square;
+        }
+
+        let mut res = FieldElement::one();
+        for &(squarings, digit) in chain {
+            for _ in 0..squarings {
+                res = res.square();
+            }
+            if digit != 0 {
+                res *= table[(digit >> 1) as usize];
+            };
+        }
+        res
+    }
+
     /// Given a `k`: u64, compute `2^k` giving the resulting result
     /// as a `FieldElement`.
     ///
@@ -811,14 +1078,57 @@ impl FieldElement {
         let r4 = carry as u64;
 
         // result may be >= r, so attempt to subtract l
-        &FieldElement([r0, r1, r2, r3, r4]) - l
+        FieldElement([r0, r1, r2, r3, r4]) - l
     }
 
     //--------------------InverseModMontgomery tools-----------------------//
 
-    /// Compute `(a * b) / R` (mod l), where R is the Montgomery modulus 2^253
+    /// Compute `(a * b) / R` (mod l), where R is the Montgomery modulus 2^260
+    ///
+    /// The product and the reduction are interleaved limb by limb
+    /// (Coarsely Integrated Operand Scanning), so the intermediate
+    /// result always fits on 6 limbs instead of the 9 ones of
+    /// `mul_internal`. The limbs are kept on u128s and the carries
+    /// only get propagated once at the end, except for the lowest
+    /// limb, which gets shifted out on every round.
+    ///
+    /// [Source: Koç, Acar & Kaliski, "Analyzing and comparing
+    /// Montgomery multiplication algorithms"], IEEE Micro 16(3), 1996.
     pub(self) fn montgomery_mul(a: &FieldElement, b: &FieldElement) -> FieldElement {
-        FieldElement::montgomery_reduce(&FieldElement::mul_internal(a, b))
+        let mask = (1u64 << 52) - 1;
+        // FIELD_L[3] = 0 so we can skip these products.
+        let l = &constants::FIELD_L;
+        let mut t = [0u128; 5];
+
+        for i in 0..5 {
+            // t += a[i] * b
+            t[0] += m(a[i], b[0]);
+            t[1] += m(a[i], b[1]);
+            t[2] += m(a[i], b[2]);
+            t[3] += m(a[i], b[3]);
+            let t4 = t[4] + m(a[i], b[4]);
+
+            // t = (t + n * l) / 2^52, where n makes the lowest limb zero.
+            let n = (t[0] as u64).wrapping_mul(constants::LFACTOR_FIELD) & mask;
+            let carry = (t[0] + m(n, l[0])) >> 52;
+            t[0] = t[1] + m(n, l[1]) + carry;
+            t[1] = t[2] + m(n, l[2]);
+            t[2] = t[3];
+            t[3] = t4 + m(n, l[4]);
+            t[4] = 0;
+        }
+
+        let mut res = [0u64; 5];
+        let mut carry = 0u128;
+        for (limb, t_i) in res.iter_mut().zip(t.iter()) {
+            carry += t_i;
+            *limb = (carry as u64) & mask;
+            carry >>= 52;
+        }
+
+        // The result is lower than 2 * l, so a single subtraction
+        // of l leaves it on [0, l).
+        FieldElement(res) - l
     }
 
     /// Puts a FieldElement into Montgomery form, i.e. computes `a*R (mod l)`
@@ -847,6 +1157,8 @@ impl FieldElement {
     /// # Panics
     /// It is not possible to invert `0` by obvious reasons. So an
     /// the function panics when trying to invert zero.
+    /// See `FieldElement::invert_nonzero` for a version that handles
+    /// it without panicking.
     ///
     /// Special issue on Montgomery arithmetic.
     /// Montgomery inversion - Erkay Sava ̧s & Çetin Kaya Koç
@@ -864,55 +1176,41 @@ impl FieldElement {
         fn phase1(a: &FieldElement) -> (FieldElement, u64) {
             assert!(a != &FieldElement::zero());
 
-            // Declare L = 2^252 + 27742317777372353535851937790883648493
-            let p = FieldElement([
-                671914833335277,
-                3916664325105025,
-                1367801,
-                0,
-                17592186044416,
-            ]);
-            let mut u = p.clone();
-            let mut v = *a;
-            let mut r = FieldElement::zero();
-            let mut s = FieldElement::one();
-            let two = FieldElement([2, 0, 0, 0, 0]);
+            // Work on the four saturated words of the numbers, since
+            // `r` and `s` stay below `2 * FIELD_L < 2^254`.
+            let p = constants::FIELD_L.to_u64_words();
+            let mut u = p;
+            let mut v = a.to_u64_words();
+            let mut r = [0u64; 4];
+            let mut s = [1u64, 0, 0, 0];
             let mut k = 0u64;
 
-            while v > FieldElement::zero() {
-                match (u.is_even(), v.is_even(), u > v, v >= u) {
-                    // u is even
-                    (true, _, _, _) => {
-                        u = u.half_without_mod();
-                        s = s * two;
-                    }
-                    // u isn't even but v is even
-                    (false, true, _, _) => {
-                        v = v.half_without_mod();
-                        r = r * two;
-                    }
-                    // u and v aren't even and u > v
-                    (false, false, true, _) => {
-                        u = u - v;
-                        u = u.half_without_mod();
-                        r = r + s;
-                        s = s * two;
-                    }
-                    // u and v aren't even and v > u
-                    (false, false, false, true) => {
-                        v = v - u;
-                        v = v.half_without_mod();
-                        s = r + s;
-                        r = r * two;
-                    }
-                    (false, false, false, false) => panic!("Unexpected error has ocurred."),
+            while v != [0u64; 4] {
+                if u[0] & 1 == 0 {
+                    words_shr1(&mut u);
+                    words_shl1(&mut s);
+                } else if v[0] & 1 == 0 {
+                    words_shr1(&mut v);
+                    words_shl1(&mut r);
+                } else if words_cmp(&u, &v) == Ordering::Greater {
+                    words_sub_assign(&mut u, &v);
+                    words_shr1(&mut u);
+                    words_add_assign(&mut r, &s);
+                    words_shl1(&mut s);
+                } else {
+                    words_sub_assign(&mut v, &u);
+                    words_shr1(&mut v);
+                    words_add_assign(&mut s, &r);
+                    words_shl1(&mut r);
                 }
                 k += 1;
             }
-            if r > p {
-                r = r - p;
+            if words_cmp(&r, &p) != Ordering::Less {
+                words_sub_assign(&mut r, &p);
             }
-            (p - r, k)
+            let mut res = p;
+            words_sub_assign(&mut res, &r);
+            (FieldElement::from_u64_words(res).unwrap(), k)
         }
 
         let (mut r, mut z) = phase1(&self);
@@ -926,6 +1224,281 @@ impl FieldElement {
     }
 }
 
+/// Shift the saturated words of a number one bit to the right.
+fn words_shr1(a: &mut [u64; 4]) {
+    for i in 0..3 {
+        a[i] = (a[i] >> 1) | (a[i + 1] << 63);
+    }
+    a[3] >>= 1;
+}
+
+/// Shift the saturated words of a number one bit to the left,
+/// dropping the top bit.
+fn words_shl1(a: &mut [u64; 4]) {
+    for i in (1..4).rev() {
+        a[i] = (a[i] << 1) | (a[i - 1] >> 63);
+    }
+    a[0] <<= 1;
+}
+
+/// Compute `a += b`, dropping the final carry.
+fn words_add_assign(a: &mut [u64; 4], b: &[u64; 4]) {
+    let mut carry = false;
+    for (a, b) in a.iter_mut().zip(b.iter()) {
+        let (sum, c1) = a.overflowing_add(*b);
+        let (sum, c2) = sum.overflowing_add(carry as u64);
+        *a = sum;
+        carry = c1 | c2;
+    }
+}
+
+/// Compute `a -= b`, which must not underflow.
+fn words_sub_assign(a: &mut [u64; 4], b: &[u64; 4]) {
+    let mut borrow = false;
+    for (a, b) in a.iter_mut().zip(b.iter()) {
+        let (diff, b1) = a.overflowing_sub(*b);
+        let (diff, b2) = diff.overflowing_sub(borrow as u64);
+        *a = diff;
+        borrow = b1 | b2;
+    }
+    debug_assert!(!borrow);
+}
+
+/// Compare the numbers given by their saturated words, in variable time.
+fn words_cmp(a: &[u64; 4], b: &[u64; 4]) -> Ordering {
+    a.iter().rev().cmp(b.iter().rev())
+}
+
+/// The double-width product of two `FieldElement`s, as returned by
+/// `FieldElement::mul_wide` and `FieldElement::square_wide`.
+///
+/// It holds the 9 limbs of `a * b` in radix `2^52` with no carry
+/// propagation, each of them lower than `5 * 2^104`. Use
+/// `UnreducedFieldElement` to add several of them together.
+#[derive(Copy, Clone, Debug)]
+pub struct WideFieldElement([u128; 9]);
+
+impl ConstantTimeEq for WideFieldElement {
+    /// Compare the limbs, in constant time. Products of the same
+    /// value may still have different limbs, `reduce` them first
+    /// to compare the values.
+    fn ct_eq(&self, other: &WideFieldElement) -> Choice {
+        let mut res = Choice::from(1u8);
+        for (a, b) in self.0.iter().zip(other.0.iter()) {
+            res &= (*a as u64).ct_eq(&(*b as u64));
+            res &= ((a >> 64) as u64).ct_eq(&((b >> 64) as u64));
+        }
+        res
+    }
+}
+
+impl PartialEq for WideFieldElement {
+    fn eq(&self, other: &WideFieldElement) -> bool {
+        self.ct_eq(other).unwrap_u8() == 1u8
+    }
+}
+
+impl Eq for WideFieldElement {}
+
+impl WideFieldElement {
+    /// Get the limbs of the product, in radix `2^52`.
+    pub fn limbs(&self) -> &[u128; 9] {
+        &self.0
+    }
+
+    /// Reduce the product to a canonical `FieldElement`.
+    pub fn reduce(&self) -> FieldElement {
+        let prod = FieldElement::montgomery_reduce(&self.0);
+        FieldElement::montgomery_reduce(&FieldElement::mul_internal(&prod, &constants::RR_FIELD))
+    }
+}
+
+/// Maximum number of products that an `UnreducedFieldElement`
+/// can hold while a single Montgomery reduction still gives
+/// back a value lower than `2 * FIELD_L`.
+pub const MAX_UNREDUCED_TERMS: usize = 256;
+
+/// A sum of double-width `FieldElement` products that haven't been
+/// reduced yet.
+///
+/// Every product of the `FieldElement`s goes through two Montgomery
+/// reductions. Accumulating the products of a sum on the `[u128; 9]`
+/// limbs returned by `mul_internal` and reducing once at the end
+/// saves the reductions of all of the products but one.
+///
+/// It can hold up to `MAX_UNREDUCED_TERMS` products of canonical
+/// `FieldElement`s.
+#[derive(Copy, Clone, Debug)]
+pub struct UnreducedFieldElement {
+    limbs: [u128; 9],
+    terms: usize,
+}
+
+impl Default for UnreducedFieldElement {
+    fn default() -> UnreducedFieldElement {
+        UnreducedFieldElement::zero()
+    }
+}
+
+impl Add<&UnreducedFieldElement> for &UnreducedFieldElement {
+    type Output = UnreducedFieldElement;
+    /// Add the limbs of both sums without any carry propagation.
+    fn add(self, b: &UnreducedFieldElement) -> UnreducedFieldElement {
+        let mut res = *self;
+        res += b;
+        res
+    }
+}
+
+impl Add<UnreducedFieldElement> for UnreducedFieldElement {
+    type Output = UnreducedFieldElement;
+    /// Add the limbs of both sums without any carry propagation.
+    fn add(self, b: UnreducedFieldElement) -> UnreducedFieldElement {
+        &self + &b
+    }
+}
+
+impl AddAssign<&UnreducedFieldElement> for UnreducedFieldElement {
+    fn add_assign(&mut self, b: &UnreducedFieldElement) {
+        self.terms += b.terms;
+        debug_assert!(self.terms <= MAX_UNREDUCED_TERMS);
+        for (limb, b_limb) in self.limbs.iter_mut().zip(b.limbs.iter()) {
+            *limb += b_limb;
+        }
+    }
+}
+
+impl AddAssign<UnreducedFieldElement> for UnreducedFieldElement {
+    fn add_assign(&mut self, b: UnreducedFieldElement) {
+        *self += &b;
+    }
+}
+
+impl UnreducedFieldElement {
+    /// Construct an empty sum.
+    pub const fn zero() -> UnreducedFieldElement {
+        UnreducedFieldElement {
+            limbs: [0u128; 9],
+            terms: 0,
+        }
+    }
+
+    /// Add `a * b` to the sum.
+    pub fn mul_acc(&mut self, a: &FieldElement, b: &FieldElement) {
+        *self += &FieldElement::mul_unreduced(a, b);
+    }
+
+    /// Add `a^2` to the sum.
+    pub fn square_acc(&mut self, a: &FieldElement) {
+        *self += &UnreducedFieldElement::from(a.square_wide());
+    }
+
+    /// Reduce the sum to a canonical `FieldElement`.
+    pub fn reduce(&self) -> FieldElement {
+        WideFieldElement(self.limbs).reduce()
+    }
+}
+
+impl From<WideFieldElement> for UnreducedFieldElement {
+    fn from(prod: WideFieldElement) -> UnreducedFieldElement {
+        UnreducedFieldElement {
+            limbs: prod.0,
+            terms: 1,
+        }
+    }
+}
+
+impl FieldElement {
+    /// Compute the double-width product `self * other`, leaving
+    /// its reduction to `WideFieldElement::reduce`.
+    pub fn mul_wide(&self, other: &FieldElement) -> WideFieldElement {
+        WideFieldElement(FieldElement::mul_internal(self, other))
+    }
+
+    /// Compute the double-width square of `self`, leaving its
+    /// reduction to `WideFieldElement::reduce`.
+    pub fn square_wide(&self) -> WideFieldElement {
+        WideFieldElement(FieldElement::square_internal(self))
+    }
+
+    /// Compute `a * b` without reducing the result, so it can
+    /// be accumulated with other products.
+    pub fn mul_unreduced(a: &FieldElement, b: &FieldElement) -> UnreducedFieldElement {
+        UnreducedFieldElement::from(a.mul_wide(b))
+    }
+
+    /// Compute `sum(a[i] * b[i])` with a single reduction every
+    /// `MAX_UNREDUCED_TERMS` products.
+    ///
+    /// Panics if `a` and `b` have different lengths.
+    pub fn sum_of_products(a: &[FieldElement], b: &[FieldElement]) -> FieldElement {
+        assert_eq!(a.len(), b.len());
+        a.chunks(MAX_UNREDUCED_TERMS)
+            .zip(b.chunks(MAX_UNREDUCED_TERMS))
+            .map(|(a, b)| {
+                let mut acc = UnreducedFieldElement::zero();
+                for (a_i, b_i) in a.iter().zip(b.iter()) {
+                    acc.mul_acc(a_i, b_i);
+                }
+                acc.reduce()
+            })
+            .fold(FieldElement::zero(), |sum, chunk| sum + chunk)
+    }
+
+    /// Get the canonical `FieldElement` of the value
+    /// `sum(limbs[i] * 2^(52 * i))`.
+    ///
+    /// Unlike the `FieldElement` constructor, the limbs can use all
+    /// of their 64 bits and the value can be greater than `FIELD_L`.
+    pub fn reduce(limbs: [u64; 5]) -> FieldElement {
+        let mut wide = [0u128; 5];
+        for (w, limb) in wide.iter_mut().zip(limbs.iter()) {
+            *w = *limb as u128;
+        }
+        FieldElement::reduce_wide(wide)
+    }
+
+    /// Get the canonical `FieldElement` of the value
+    /// `sum(limbs[i] * 2^(52 * i))`, where the limbs can use all of
+    /// their 128 bits, eg. to reduce a sum of several products.
+    pub fn reduce_wide(limbs: [u128; 5]) -> FieldElement {
+        let mask = (1u128 << 52) - 1;
+        // Split every limb in three chunks of 52, 52 and 24 bits,
+        // so that all of the limbs fit on 54 bits.
+        let mut res = [0u128; 9];
+        for (i, limb) in limbs.iter().enumerate() {
+            res[i] += limb & mask;
+            res[i + 1] += (limb >> 52) & mask;
+            res[i + 2] += limb >> 104;
+        }
+        WideFieldElement(res).reduce()
+    }
+
+    /// Get the canonical `FieldElement` of the 512-bit value
+    /// represented by the LE u64 `words`.
+    pub fn reduce_wide_words(words: [u64; 8]) -> FieldElement {
+        let mask = (1u128 << 52) - 1;
+        let mut res = [0u128; 9];
+        let mut acc = 0u128;
+        let mut acc_bits = 0;
+        let mut i = 0;
+        for word in words.iter() {
+            acc |= (*word as u128) << acc_bits;
+            acc_bits += 64;
+            while acc_bits >= 52 && i < 8 {
+                res[i] = acc & mask;
+                acc >>= 52;
+                acc_bits -= 52;
+                i += 1;
+            }
+        }
+        // The highest limb keeps the last 96 bits. The value is
+        // still lower than `R * FIELD_L`, so it can be reduced.
+        res[8] = acc;
+        WideFieldElement(res).reduce()
+    }
+}
+
 /// Module with constants used for `FieldElement` u64 implementation
 /// testing. It also includes the tests but they remain hidden on the docs.
 #[cfg(test)]
@@ -1279,6 +1852,7 @@ pub mod tests {
         assert!(bool::from(res2));
     }
 
+    #[allow(deprecated)]
     #[test]
     fn mod_sqrt_tonelli_shanks() {
         // Test for valid Quadratic-residue values.
@@ -1303,6 +1877,7 @@ pub mod tests {
         assert!(-res == INV_SQRT_27);
     }
 
+    #[allow(deprecated)]
     #[allow(non_snake_case)]
     #[test]
     fn non_QRmod_sqrt_tonelli_shanks() {
@@ -1401,6 +1976,8 @@ pub mod tests {
 
     #[test]
     fn into_ristretto255scalar() {
+        use core::convert::TryInto;
+
         // a = `2238329342913194256032495932344128051776374960164957527413114840482143558222` = res.
         let a: Ristretto255Scalar = Ristretto255Scalar::from_canonical_bytes([
             0x4e, 0x5a, 0xb4, 0x34, 0x5d, 0x47, 0x08, 0x84, 0x59, 0x13, 0xb4, 0x64, 0x1b, 0xc2,
@@ -1415,7 +1992,8 @@ pub mod tests {
             1298816433963441,
             5441077225716,
         ])
-        .into();
+        .try_into()
+        .unwrap();
 
         for i in 0..32 {
             assert!(a[i] == res[i]);
@@ -1545,6 +2123,37 @@ pub mod tests {
         for i in 0..5 {
             assert!(res[i] == INV_MOD_C[i]);
         }
+
+        assert!(FieldElement::one().inverse() == FieldElement::one());
+        assert!(FieldElement::minus_one().inverse() == FieldElement::minus_one());
+        let mut a = A;
+        for _ in 0..32 {
+            assert!(a * a.inverse() == FieldElement::one());
+            a = a * a + B;
+        }
+    }
+
+    #[test]
+    fn words_helpers() {
+        let p = constants::FIELD_L.to_u64_words();
+        let mut a = p;
+        words_shl1(&mut a);
+        assert!(words_cmp(&a, &p) == Ordering::Greater);
+        words_shr1(&mut a);
+        assert!(a == p);
+
+        // `2 * p - p = p`.
+        let mut b = p;
+        words_add_assign(&mut b, &p);
+        words_sub_assign(&mut b, &p);
+        assert!(words_cmp(&b, &p) == Ordering::Equal);
+        // Carries and borrows cross the words.
+        let mut c = [u64::MAX, u64::MAX, 0, 0];
+        words_add_assign(&mut c, &[1, 0, 0, 0]);
+        assert!(c == [0, 0, 1, 0]);
+        words_sub_assign(&mut c, &[1, 0, 0, 0]);
+        assert!(c == [u64::MAX, u64::MAX, 0, 0]);
+        assert!(words_cmp(&c, &[0, 0, 1, 0]) == Ordering::Less);
     }
 
     #[test]
@@ -1554,4 +2163,366 @@ pub mod tests {
         // Odd number should return false.
         assert!(!B.is_even());
     }
+    #[test]
+    fn decimal_display() {
+        assert_eq!(FieldElement::zero().to_decimal_string(), "0");
+        assert_eq!(
+            format!("{:#}", FieldElement::minus_one()),
+            "7237005577332262213973186563042994240857116359379907606001950938285454250988"
+        );
+        assert_eq!(
+            format!("{}", FieldElement::one()),
+            "FieldElement([1, 0, 0, 0, 0])"
+        );
+    }
+
+    #[test]
+    fn hex_formatting() {
+        let a = FieldElement::from(0xabcdu64);
+        let zeros = "00".repeat(30);
+        assert_eq!(format!("{:x}", a), format!("cdab{}", zeros));
+        assert_eq!(format!("{:X}", a), format!("CDAB{}", zeros));
+    }
+
+    #[test]
+    fn reduce_overflowed_limbs() {
+        use num::{BigUint, One};
+
+        let p = BigUint::from_bytes_le(&constants::FIELD_L.to_bytes());
+        let from_big = |n: &BigUint| {
+            let mut bytes = [0u8; 32];
+            let n_bytes = (n % &p).to_bytes_le();
+            bytes[..n_bytes.len()].copy_from_slice(&n_bytes);
+            FieldElement::from_bytes(&bytes)
+        };
+
+        assert!(FieldElement::reduce(constants::FIELD_L.0) == FieldElement::zero());
+        assert!(FieldElement::reduce(A.0) == A);
+
+        // sum((2^64 - 1) * 2^(52 * i))
+        let max = (BigUint::one() << 64) - 1u32;
+        let mut expected = BigUint::from(0u8);
+        for i in 0..5 {
+            expected += &max << (52 * i);
+        }
+        assert!(FieldElement::reduce([u64::MAX; 5]) == from_big(&expected));
+
+        let max = (BigUint::one() << 128) - 1u32;
+        let mut expected = BigUint::from(0u8);
+        for i in 0..5 {
+            expected += &max << (52 * i);
+        }
+        assert!(FieldElement::reduce_wide([u128::MAX; 5]) == from_big(&expected));
+
+        let expected = (BigUint::one() << 512) - 1u32;
+        assert!(FieldElement::reduce_wide_words([u64::MAX; 8]) == from_big(&expected));
+        let mut words = [0u64; 8];
+        words[4] = 1;
+        assert!(FieldElement::reduce_wide_words(words) == from_big(&(BigUint::one() << 256)));
+    }
+
+    #[test]
+    fn raw_limbs() {
+        assert!(FieldElement::from_raw_limbs(*B.as_limbs()).unwrap() == B);
+        assert!(FieldElement::from_raw_limbs(*FieldElement::minus_one().as_limbs()).is_some());
+        assert!(FieldElement::from_raw_limbs(constants::FIELD_L.0).is_none());
+        assert!(FieldElement::from_raw_limbs([1u64 << 52, 0, 0, 0, 0]).is_none());
+        assert!(FieldElement::from_raw_limbs_unchecked([0, 1, 0, 0, 0]) == FieldElement::two_pow_k(52));
+    }
+
+    #[test]
+    fn u64_words() {
+        for fe in [A, B, FieldElement::zero(), FieldElement::minus_one()].iter() {
+            let words = fe.to_u64_words();
+            for (i, word) in words.iter().enumerate() {
+                assert_eq!(word.to_le_bytes()[..], fe.to_bytes()[8 * i..8 * (i + 1)]);
+            }
+            assert!(FieldElement::from_u64_words(words).unwrap() == *fe);
+        }
+        assert!(FieldElement::from_u64_words(constants::FIELD_L.to_u64_words()).is_none());
+        assert!(FieldElement::from_u64_words([u64::MAX; 4]).is_none());
+    }
+
+    #[test]
+    fn bit_decomposition() {
+        for fe in [A, B, FieldElement::zero(), FieldElement::minus_one()].iter() {
+            let bits = fe.to_bits();
+            let mut acc = FieldElement::zero();
+            for bit in bits.iter().rev() {
+                acc = acc.double() + FieldElement::from(*bit as u8);
+            }
+            assert!(acc == *fe);
+            assert!(FieldElement::from_bits(&bits).unwrap() == *fe);
+        }
+        assert!(FieldElement::from_bits(&[]).unwrap() == FieldElement::zero());
+        assert!(FieldElement::from_bits(&[false, true]).unwrap() == FieldElement::from(2u8));
+        assert!(FieldElement::from_bits(&constants::FIELD_L.to_bits()).is_none());
+        assert!(FieldElement::from_bits(&[false; FieldElement::BITS + 1]).is_none());
+    }
+
+    #[test]
+    fn slice_encoding() {
+        use crate::errors::DecodingError;
+
+        let elements = [A, B, FieldElement::minus_one()];
+        let bytes = FieldElement::slice_to_bytes(&elements);
+        assert_eq!(bytes.len(), 96);
+        assert!(FieldElement::slice_from_bytes(&bytes).unwrap() == elements);
+        assert!(FieldElement::slice_from_bytes(&[]).unwrap().is_empty());
+
+        assert!(FieldElement::slice_from_bytes(&bytes[1..]) == Err(DecodingError::InvalidLength));
+        let mut bytes = bytes;
+        bytes.extend_from_slice(&constants::FIELD_L.to_bytes());
+        assert!(
+            FieldElement::slice_from_bytes(&bytes) == Err(DecodingError::NonCanonicalFieldElement)
+        );
+    }
+
+    #[test]
+    fn fallible_ops() {
+        use crate::errors::Error;
+
+        assert!(A.try_inverse().unwrap() == A.inverse());
+        assert!(FieldElement::zero().try_inverse() == Err(Error::ZeroInverse));
+        assert!(A.checked_div(&B).unwrap() == A / B);
+        assert!(A.checked_div(&FieldElement::zero()) == Err(Error::ZeroInverse));
+        assert!(FieldElement::try_two_pow_k(252).unwrap() == FieldElement::two_pow_k(252));
+        assert!(FieldElement::try_two_pow_k(253) == Err(Error::OutOfRange));
+    }
+
+    #[test]
+    fn write_into_buffers() {
+        use crate::errors::DecodingError;
+
+        let mut out = [0xffu8; 32];
+        A.write_bytes_into(&mut out);
+        assert!(out == A.to_bytes());
+
+        let elements = [A, B];
+        let mut out = [0u8; 64];
+        FieldElement::write_slice_into(&elements, &mut out).unwrap();
+        assert!(out[..] == FieldElement::slice_to_bytes(&elements)[..]);
+        assert!(FieldElement::write_slice_into(&elements, &mut out[1..]) == Err(DecodingError::InvalidLength));
+    }
+
+    #[test]
+    fn mul_by_u64() {
+        for k in [0u64, 1, 2, 3, 121666, u64::MAX].iter() {
+            assert!(A * *k == A * FieldElement::from(*k));
+            assert!(B * *k == B * FieldElement::from(*k));
+            assert!(FieldElement::minus_one() * *k == FieldElement::minus_one() * FieldElement::from(*k));
+        }
+        assert!(&FieldElement::minus_one() * 2u64 == FieldElement::minus_one() - FieldElement::one());
+    }
+
+    #[test]
+    fn doubling() {
+        assert!(A.double() == A + A);
+        assert!(B.double() == B + B);
+        assert!(FieldElement::minus_one().double() == FieldElement::minus_one() - FieldElement::one());
+        assert!(FieldElement::zero().double() == FieldElement::zero());
+    }
+
+    #[test]
+    fn small_constant_multipliers() {
+        for a in [A, B, FieldElement::minus_one()].iter() {
+            assert!(a.mul_by_3() == a * FieldElement::from(3u8));
+            assert!(a.mul_by_5() == a * FieldElement::from(5u8));
+            assert!(a.mul_by_8() == a * FieldElement::from(8u8));
+        }
+    }
+
+    #[test]
+    fn addition_chains() {
+        // `(l + 3) / 8 = ((l + 1) / 2 + 1) / 4`.
+        let sqrt_exp = (constants::INVERSE_MOD_TWO + FieldElement::one())
+            .half_without_mod()
+            .half_without_mod();
+        for a in [A, FieldElement::from(17u8), FieldElement::minus_one()].iter() {
+            assert!(a.pow_chain(constants::SQRT_CHAIN) == a.pow(&sqrt_exp));
+            assert!(
+                a.pow_chain(constants::LEGENDRE_CHAIN) == a.pow(&constants::MINUS_ONE_HALF)
+            );
+        }
+        assert!(FieldElement::zero().pow_chain(constants::SQRT_CHAIN) == FieldElement::zero());
+    }
+
+    #[test]
+    fn invert_nonzero() {
+        assert!(A.invert_nonzero().unwrap() == INV_MOD_A);
+        assert!(bool::from(FieldElement::zero().invert_nonzero().is_none()));
+    }
+
+    #[test]
+    fn conditional_swap_and_assign() {
+        let (mut a, mut b) = (A, FieldElement::one());
+        FieldElement::conditional_swap(&mut a, &mut b, Choice::from(0u8));
+        assert!(a == A && b == FieldElement::one());
+        FieldElement::conditional_swap(&mut a, &mut b, Choice::from(1u8));
+        assert!(a == FieldElement::one() && b == A);
+
+        a.conditional_assign(&b, Choice::from(0u8));
+        assert!(a == FieldElement::one());
+        a.conditional_assign(&b, Choice::from(1u8));
+        assert!(a == A);
+    }
+
+    #[test]
+    fn pow_machine_integers() {
+        let exp = FieldElement::from(0x1234_5678_9abc_def0u64);
+        assert!(A.pow_u64(0x1234_5678_9abc_def0) == A.pow(&exp));
+        assert!(A.pow(0x1234_5678_9abc_def0u64) == A.pow(&exp));
+        assert!(A.pow_u64(0) == FieldElement::one());
+        assert!(A.pow_u64(1) == A);
+        assert!(A.pow_u128(3) == A * A * A);
+
+        let exp = FieldElement::from(u64::MAX) * FieldElement::from(1u64 << 32);
+        assert!(A.pow_u128((u64::MAX as u128) << 32) == A.pow(&exp));
+    }
+
+    #[test]
+    fn ct_sqrt() {
+        let inp = FieldElement::from(17u8);
+        assert!(bool::from(inp.is_square()));
+        let root = inp.sqrt().unwrap();
+        assert!(root.square() == inp);
+        assert!(bool::from(root.is_positive()));
+        assert!(root == SQRT1_27_NEG || root == SQRT1_27_POS);
+
+        // `2` is not a square since `l = 5 (mod 8)`.
+        let two = FieldElement::from(2u8);
+        assert!(!bool::from(two.is_square()));
+        assert!(bool::from(two.sqrt().is_none()));
+        assert!(FieldElement::minus_one().sqrt().unwrap() == constants::SQRT_MINUS_ONE
+            || -FieldElement::minus_one().sqrt().unwrap() == constants::SQRT_MINUS_ONE);
+        assert!(bool::from(A.sqrt().is_none()));
+        assert!(FieldElement::zero().sqrt().unwrap() == FieldElement::zero());
+    }
+
+    #[test]
+    fn zero_and_one_predicates() {
+        assert!(bool::from(FieldElement::zero().is_zero()));
+        assert!(bool::from(FieldElement::one().is_one()));
+        assert!(!bool::from(FieldElement::one().is_zero()));
+        assert!(!bool::from(A.is_one()));
+    }
+
+    #[test]
+    fn canonical_decoding() {
+        use crate::errors::DecodingError;
+
+        let bytes = FieldElement::minus_one().to_bytes();
+        assert!(FieldElement::from_canonical_bytes(&bytes).unwrap() == FieldElement::minus_one());
+
+        let p_bytes = constants::FIELD_L.to_bytes();
+        assert!(
+            FieldElement::from_canonical_bytes(&p_bytes)
+                == Err(DecodingError::NonCanonicalFieldElement)
+        );
+        assert!(FieldElement::from_canonical_bytes(&[0xff; 32]).is_err());
+    }
+
+    #[test]
+    fn bytes_mod_order() {
+        use num::BigUint;
+
+        let p = BigUint::from_bytes_le(&constants::FIELD_L.to_bytes());
+        let bytes: Vec<u8> = (0..100u32).map(|i| (i * 37 + 11) as u8).collect();
+        for len in [0, 31, 32, 48, 64, 100].iter() {
+            let expected = BigUint::from_bytes_le(&bytes[..*len]) % &p;
+            let mut expected_bytes = [0u8; 32];
+            let n_bytes = expected.to_bytes_le();
+            expected_bytes[..n_bytes.len()].copy_from_slice(&n_bytes);
+            let res = FieldElement::from_bytes_mod_order(&bytes[..*len]);
+            assert!(res == FieldElement::from_bytes(&expected_bytes));
+        }
+        let p_bytes = constants::FIELD_L.to_bytes();
+        assert!(FieldElement::from_bytes_mod_order(&p_bytes) == FieldElement::zero());
+    }
+
+    #[test]
+    fn hash_map_keys() {
+        use std::collections::HashMap;
+
+        let mut inverses = HashMap::new();
+        for fe in [A, B, C].iter() {
+            inverses.insert(*fe, fe.inverse());
+        }
+        // The same values computed in other ways hit the same entries.
+        let a = (A + FieldElement::one()) - FieldElement::one();
+        inverses.insert(a, a.inverse());
+        assert_eq!(inverses.len(), 3);
+        assert!(inverses[&(B * FieldElement::one())] == B.inverse());
+        assert!(!inverses.contains_key(&FieldElement::zero()));
+    }
+
+    #[test]
+    fn wide_multiplication() {
+        assert!(A.mul_wide(&B).reduce() == A_TIMES_B);
+        assert!(B.square_wide().reduce() == B_SQUARE);
+        assert!(B.square_wide() == B.mul_wide(&B));
+        assert!(A.mul_wide(&B) != B.mul_wide(&C));
+        assert_eq!(C.mul_wide(&FieldElement::one()).limbs()[0], C[0] as u128);
+    }
+
+    #[test]
+    fn lazy_reduction() {
+        let mut acc = UnreducedFieldElement::zero();
+        acc.mul_acc(&A, &B);
+        acc.mul_acc(&C, &B_SQUARE);
+        acc.square_acc(&FieldElement::minus_one());
+        assert!(acc.reduce() == A * B + C * B_SQUARE + FieldElement::one());
+        assert!(UnreducedFieldElement::zero().reduce() == FieldElement::zero());
+
+        // Worst case: the maximum number of `(p - 1)^2` products.
+        let a = vec![FieldElement::minus_one(); MAX_UNREDUCED_TERMS + 1];
+        let expected = FieldElement::from((MAX_UNREDUCED_TERMS + 1) as u64);
+        assert!(FieldElement::sum_of_products(&a, &a) == expected);
+        assert!(FieldElement::sum_of_products(&[], &[]) == FieldElement::zero());
+    }
+
+    #[test]
+    fn batch_inversion() {
+        let mut inputs: Vec<FieldElement> = (1..600u64).map(FieldElement::from).collect();
+        let expected: Vec<FieldElement> = inputs.iter().map(|a| a.inverse()).collect();
+        FieldElement::batch_invert(&mut inputs);
+        assert!(inputs == expected);
+
+        FieldElement::batch_invert(&mut []);
+        assert!(FieldElement::try_batch_invert(&mut []) == Ok(()));
+    }
+
+    #[test]
+    fn batch_inversion_of_zero() {
+        let mut inputs: Vec<FieldElement> = (1..600u64).map(FieldElement::from).collect();
+        inputs[300] = FieldElement::zero();
+        let untouched = inputs.clone();
+        assert!(FieldElement::try_batch_invert(&mut inputs) == Err(Error::ZeroInverse));
+        assert!(inputs == untouched);
+    }
+
+    #[test]
+    #[should_panic(expected = "Zero has no inverse")]
+    fn batch_inversion_of_zero_panics() {
+        FieldElement::batch_invert(&mut [FieldElement::one(), FieldElement::zero()]);
+    }
+
+    #[test]
+    fn batch_sqrt_ratio_i() {
+        // Squares and non-squares, with zeros on both sides.
+        let mut u: Vec<FieldElement> = (0..20u64).map(FieldElement::from).collect();
+        let mut v: Vec<FieldElement> = (0..20u64).rev().map(FieldElement::from).collect();
+        u.push(FieldElement::zero());
+        v.push(FieldElement::zero());
+        u.push(A);
+        v.push(FieldElement::minus_one());
+
+        let res = FieldElement::batch_sqrt_ratio_i(&u, &v);
+        for ((u, v), (is_square, root)) in u.iter().zip(v.iter()).zip(res.iter()) {
+            let (expected_square, expected_root) = u.sqrt_ratio_i(v);
+            assert_eq!(is_square.unwrap_u8(), expected_square.unwrap_u8());
+            assert!(*root == expected_root);
+        }
+        assert!(FieldElement::batch_sqrt_ratio_i(&[], &[]).is_empty());
+    }
 }
@@ -1,3 +1,6 @@
+#[cfg(feature = "precomputed_tables")]
+pub mod basepoint_table;
 pub mod constants;
 pub mod field;
+pub(crate) mod safegcd;
 pub mod scalar;
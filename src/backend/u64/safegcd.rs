@@ -0,0 +1,269 @@
+//! Constant-time inversion of `FieldElement`s with the safegcd
+//! algorithm of Bernstein and Yang.
+//!
+//! The inverse of `x` is tracked along the divsteps of `(f, g)`,
+//! starting with `f = FIELD_L`, `g = x`, until `g = 0` and `f = ±1`.
+//! The divsteps get batched 59 at a time into a transition matrix,
+//! computed on the low 64 bits only, which is then applied to the
+//! full numbers on signed limbs in radix `2^62`. `10 * 59 = 590`
+//! divsteps always suffice for 256-bit inputs, so the sequence of
+//! operations doesn't depend on `x`.
+//!
+//! Bernstein, Daniel J. and Yang, Bo-Yin (2019). Fast constant-time
+//! gcd computation and modular inversion. IACR Transactions on
+//! Cryptographic Hardware and Embedded Systems, 2019(3), 340-398.
+//!
+//! The `hddivsteps` variant, the transition matrices and the limb
+//! layout follow the `modinv64` implementation of libsecp256k1.
+
+use crate::backend::u64::constants;
+use crate::backend::u64::field::FieldElement;
+
+use subtle::{Choice, ConditionallySelectable};
+
+/// Mask of the low 62 bits of a limb.
+const M62: u64 = u64::MAX >> 2;
+
+/// Number of batches of 59 divsteps.
+const ITERATIONS: usize = 10;
+
+/// A number as five signed limbs in radix `2^62`, where all of
+/// the limbs but the top one are in `[0, 2^62)`.
+type Signed62 = [i64; 5];
+
+/// The transition matrix `[[u, v], [q, r]]` of a batch of divsteps,
+/// scaled by `2^62`.
+struct Transition {
+    u: i64,
+    v: i64,
+    q: i64,
+    r: i64,
+}
+
+/// Run 59 divsteps on the low 64 bits `f0`, `g0` of `f`, `g`,
+/// returning the updated `zeta = -(delta + 1/2)` and the transition
+/// matrix of the batch.
+fn divsteps_59(mut zeta: i64, f0: u64, g0: u64) -> (i64, Transition) {
+    // The identity matrix times `2^3`, since the 59 divsteps
+    // scale it by `2^59`.
+    let (mut u, mut v, mut q, mut r) = (8u64, 0u64, 0u64, 8u64);
+    let (mut f, mut g) = (f0, g0);
+
+    for _ in 3..62 {
+        // Masks for `zeta < 0` and for `g` being odd.
+        let mut mask1 = (zeta >> 63) as u64;
+        let mask2 = (g & 1).wrapping_neg();
+        // Conditionally negate `f`, `u`, `v`...
+        let x = (f ^ mask1).wrapping_sub(mask1);
+        let y = (u ^ mask1).wrapping_sub(mask1);
+        let z = (v ^ mask1).wrapping_sub(mask1);
+        // ...and add them to `g`, `q`, `r` if `g` is odd.
+        g = g.wrapping_add(x & mask2);
+        q = q.wrapping_add(y & mask2);
+        r = r.wrapping_add(z & mask2);
+        // If `zeta < 0` and `g` was odd, swap the roles of both rows:
+        // `zeta` becomes `-zeta - 2` instead of `zeta - 1`, and the
+        // new `g`, `q`, `r` get added to `f`, `u`, `v`.
+        mask1 &= mask2;
+        zeta = (zeta ^ mask1 as i64) - 1;
+        f = f.wrapping_add(g & mask1);
+        u = u.wrapping_add(q & mask1);
+        v = v.wrapping_add(r & mask1);
+        g >>= 1;
+        u <<= 1;
+        v <<= 1;
+    }
+
+    let t = Transition {
+        u: u as i64,
+        v: v as i64,
+        q: q as i64,
+        r: r as i64,
+    };
+    (zeta, t)
+}
+
+/// Compute `t * [d, e] / 2^62 (mod FIELD_L)`, keeping `d` and `e`
+/// in `(-2 * FIELD_L, FIELD_L)`.
+fn update_de(d: &mut Signed62, e: &mut Signed62, t: &Transition) {
+    let modulus = constants::SAFEGCD_MODULUS;
+    let (u, v, q, r) = (t.u as i128, t.v as i128, t.q as i128, t.r as i128);
+    // Add `[u, q]` times the modulus if `d` is negative, and `[v, r]`
+    // if `e` is, so the result stays on range.
+    let sd = d[4] >> 63;
+    let se = e[4] >> 63;
+    let mut md = (t.u & sd) + (t.v & se);
+    let mut me = (t.q & sd) + (t.r & se);
+
+    let mut cd = u * d[0] as i128 + v * e[0] as i128;
+    let mut ce = q * d[0] as i128 + r * e[0] as i128;
+    // Correct `md` and `me` so the low 62 bits of
+    // `t * [d, e] + modulus * [md, me]` are zero.
+    md -= (constants::SAFEGCD_MODULUS_INV62
+        .wrapping_mul(cd as u64)
+        .wrapping_add(md as u64)
+        & M62) as i64;
+    me -= (constants::SAFEGCD_MODULUS_INV62
+        .wrapping_mul(ce as u64)
+        .wrapping_add(me as u64)
+        & M62) as i64;
+    cd += modulus[0] as i128 * md as i128;
+    ce += modulus[0] as i128 * me as i128;
+    cd >>= 62;
+    ce >>= 62;
+
+    // Compute the rest of the limbs, shifted down by one.
+    for i in 1..5 {
+        cd += u * d[i] as i128 + v * e[i] as i128 + modulus[i] as i128 * md as i128;
+        ce += q * d[i] as i128 + r * e[i] as i128 + modulus[i] as i128 * me as i128;
+        d[i - 1] = (cd as u64 & M62) as i64;
+        e[i - 1] = (ce as u64 & M62) as i64;
+        cd >>= 62;
+        ce >>= 62;
+    }
+    d[4] = cd as i64;
+    e[4] = ce as i64;
+}
+
+/// Compute `t * [f, g] / 2^62`, which is exact.
+fn update_fg(f: &mut Signed62, g: &mut Signed62, t: &Transition) {
+    let (u, v, q, r) = (t.u as i128, t.v as i128, t.q as i128, t.r as i128);
+    let mut cf = u * f[0] as i128 + v * g[0] as i128;
+    let mut cg = q * f[0] as i128 + r * g[0] as i128;
+    cf >>= 62;
+    cg >>= 62;
+
+    for i in 1..5 {
+        cf += u * f[i] as i128 + v * g[i] as i128;
+        cg += q * f[i] as i128 + r * g[i] as i128;
+        f[i - 1] = (cf as u64 & M62) as i64;
+        g[i - 1] = (cg as u64 & M62) as i64;
+        cf >>= 62;
+        cg >>= 62;
+    }
+    f[4] = cf as i64;
+    g[4] = cg as i64;
+}
+
+/// Propagate the carries of `a` so all of its limbs but the top
+/// one are back in `[0, 2^62)`.
+fn propagate_carries(a: &mut Signed62) {
+    for i in 0..4 {
+        a[i + 1] += a[i] >> 62;
+        a[i] &= M62 as i64;
+    }
+}
+
+/// Get an all-ones mask if `x` is negative, or zero otherways.
+///
+/// The sign goes through a `Choice`, so the compiler can't turn the
+/// conditional operations on the mask into branches.
+fn sign_mask(x: i64) -> i64 {
+    let is_negative = Choice::from((x as u64 >> 63) as u8);
+    i64::conditional_select(&0, &-1, is_negative)
+}
+
+/// Add the modulus to `a` if it is negative.
+fn add_modulus_if_negative(a: &mut Signed62) {
+    let cond_add = sign_mask(a[4]);
+    for (limb, m) in a.iter_mut().zip(constants::SAFEGCD_MODULUS.iter()) {
+        *limb += m & cond_add;
+    }
+}
+
+/// Bring `a` from `(-2 * FIELD_L, FIELD_L)` to `[0, FIELD_L)`,
+/// negating it if `sign` is negative.
+fn normalize(a: &mut Signed62, sign: i64) {
+    add_modulus_if_negative(a);
+    let cond_negate = sign_mask(sign);
+    for limb in a.iter_mut() {
+        *limb = (*limb ^ cond_negate) - cond_negate;
+    }
+    propagate_carries(a);
+    add_modulus_if_negative(a);
+    propagate_carries(a);
+}
+
+fn to_signed62(a: &FieldElement) -> Signed62 {
+    let w = a.to_u64_words();
+    [
+        (w[0] & M62) as i64,
+        (((w[0] >> 62) | (w[1] << 2)) & M62) as i64,
+        (((w[1] >> 60) | (w[2] << 4)) & M62) as i64,
+        (((w[2] >> 58) | (w[3] << 6)) & M62) as i64,
+        (w[3] >> 56) as i64,
+    ]
+}
+
+fn from_signed62(a: &Signed62) -> FieldElement {
+    let l = [
+        a[0] as u64,
+        a[1] as u64,
+        a[2] as u64,
+        a[3] as u64,
+        a[4] as u64,
+    ];
+    let words = [
+        l[0] | (l[1] << 62),
+        (l[1] >> 2) | (l[2] << 60),
+        (l[2] >> 4) | (l[3] << 58),
+        (l[3] >> 6) | (l[4] << 56),
+    ];
+    let mask = (1u64 << 52) - 1;
+    FieldElement([
+        words[0] & mask,
+        ((words[0] >> 52) | (words[1] << 12)) & mask,
+        ((words[1] >> 40) | (words[2] << 24)) & mask,
+        ((words[2] >> 28) | (words[3] << 36)) & mask,
+        words[3] >> 16,
+    ])
+}
+
+/// Compute `a^-1 (mod l)` in constant time, or zero if `a = 0`.
+pub(crate) fn invert(a: &FieldElement) -> FieldElement {
+    let (mut d, mut e): (Signed62, Signed62) = ([0; 5], [1, 0, 0, 0, 0]);
+    let (mut f, mut g) = (constants::SAFEGCD_MODULUS, to_signed62(a));
+    // `zeta = -(delta + 1/2)`, with `delta` starting at `1/2`.
+    let mut zeta = -1i64;
+
+    for _ in 0..ITERATIONS {
+        let (next_zeta, t) = divsteps_59(zeta, f[0] as u64, g[0] as u64);
+        zeta = next_zeta;
+        update_de(&mut d, &mut e, &t);
+        update_fg(&mut f, &mut g, &t);
+    }
+    // Now `g = 0` and `f = ±gcd(l, a) = ±1`, so `d = ±1/a`.
+    normalize(&mut d, f[4]);
+    from_signed62(&d)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rand::rngs::OsRng;
+
+    #[test]
+    fn signed62_conversions() {
+        for a in [
+            FieldElement::zero(),
+            FieldElement::minus_one(),
+            FieldElement::random(&mut OsRng),
+        ]
+        .iter()
+        {
+            assert!(from_signed62(&to_signed62(a)) == *a);
+        }
+    }
+
+    #[test]
+    fn inversion() {
+        assert!(invert(&FieldElement::zero()) == FieldElement::zero());
+        assert!(invert(&FieldElement::one()) == FieldElement::one());
+        assert!(invert(&FieldElement::minus_one()) == FieldElement::minus_one());
+        for _ in 0..64 {
+            let a = FieldElement::random(&mut OsRng);
+            assert!(invert(&a) == a.inverse());
+        }
+    }
+}
@@ -7,7 +7,7 @@
 //! for the Sonny sub-group field.
 
 use core::fmt::Debug;
-use core::ops::{Add, Mul, Neg, Sub};
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 use core::ops::{Index, IndexMut};
 
 use std::cmp::{Ord, Ordering, PartialOrd};
@@ -16,16 +16,22 @@ use std::ops::Shr;
 use num::Integer;
 
 use crate::backend::u64::constants;
+use crate::errors::Error;
 use crate::traits::ops::*;
 use crate::traits::Identity;
 
-use subtle::ConstantTimeEq;
+use subtle::Choice;
 
 
 /// The `Scalar` struct represents an Scalar over the modulo
 /// `2^249 + 14490550575682688738086195780655237219` as 5 52-bit limbs
 /// represented in radix `2^52`.
+///
+/// It is `#[repr(transparent)]`, so it has the layout of a
+/// `[u64; 5]`. The limbs are not a wire format, use
+/// `Scalar::to_bytes` to exchange `Scalar`s.
 #[derive(Copy, Clone)]
+#[repr(transparent)]
 pub struct Scalar(pub [u64; 5]);
 
 impl Debug for Scalar {
@@ -34,6 +40,8 @@ impl Debug for Scalar {
     }
 }
 
+define_hex_formatting!(Scalar, to_bytes);
+
 impl Index<usize> for Scalar {
     type Output = u64;
     fn index(&self, _index: usize) -> &u64 {
@@ -78,7 +86,7 @@ impl From<i8> for Scalar {
                 return res
             },
             false => {
-                res[0] = _inp.abs() as u64;
+                res[0] = -(_inp as i16) as u64;
                 return -res
             }
         }
@@ -143,7 +151,7 @@ impl<'a> Neg for &'a Scalar {
     /// Performs the negate operation over the
     /// sub-group modulo l.
     fn neg(self) -> Scalar {
-        &Scalar::zero() - &self
+        Scalar::zero() - self
     }
 }
 
@@ -173,7 +181,7 @@ impl Shr<u8> for Scalar {
         for _ in 0.._rhs {
             let mut carry = 0u64;
             for i in (0..5).rev() {
-                res[i] = res[i] | carry;
+                res[i] |= carry;
                 
                 carry = (res[i] & 1) << 52;
                 res[i] >>= 1;
@@ -254,8 +262,7 @@ impl<'a, 'b> Mul<&'a Scalar> for &'b Scalar {
     /// Then, we apply the Montgomery Reduction function to perform
     /// the modulo and the reduction to the `Scalar` format: [u64; 5].
     fn mul(self, b: &'a Scalar) -> Scalar {
-        let ab = Scalar::montgomery_reduce(&Scalar::mul_internal(self, b));
-        Scalar::montgomery_reduce(&Scalar::mul_internal(&ab, &constants::RR))
+        mul_mod(self, b)
     }
 }
 
@@ -271,6 +278,13 @@ impl Mul<Scalar> for Scalar {
     }
 }
 
+define_mixed_add_variants!(LHS = Scalar, RHS = Scalar, Output = Scalar);
+define_mixed_sub_variants!(LHS = Scalar, RHS = Scalar, Output = Scalar);
+define_mixed_mul_variants!(LHS = Scalar, RHS = Scalar, Output = Scalar);
+define_add_assign_variants!(LHS = Scalar, RHS = Scalar);
+define_sub_assign_variants!(LHS = Scalar, RHS = Scalar);
+define_mul_assign_variants!(LHS = Scalar, RHS = Scalar);
+
 impl<'a> Square for &'a Scalar {
     type Output = Scalar;
     /// This `Square` implementation returns a double precision result.
@@ -279,8 +293,7 @@ impl<'a> Square for &'a Scalar {
     /// Then, we apply the Montgomery Reduction function to perform
     /// the modulo and the reduction to the `Scalar` format: [u64; 5].
     fn square(self) -> Scalar {
-        let aa = Scalar::montgomery_reduce(&Scalar::square_internal(self));
-        Scalar::montgomery_reduce(&Scalar::mul_internal(&aa, &constants::RR))
+        square_mod(self)
     }
 }
 
@@ -288,14 +301,15 @@ impl<'a> Half for &'a Scalar {
     type Output = Scalar;
     /// Give the half of the Scalar value (mod l).
     fn half(self) -> Scalar {
-        self * &constants::SCALAR_INVERSE_MOD_TWO
+        self * constants::SCALAR_INVERSE_MOD_TWO
     }
 }
 
 /// Performs the op: `a^b (mod l)`.
 ///
 /// Exponentiation by squaring classical algorithm
-/// implementation for `Scalar`.
+/// implementation for `Scalar`, performed over the Montgomery
+/// form of the base, see `UnpackedScalar::pow`.
 ///
 /// Schneier, Bruce (1996). Applied Cryptography: Protocols,
 /// Algorithms, and Source Code in C, Second Edition (2nd ed.).
@@ -303,26 +317,42 @@ impl<'a, 'b> Pow<&'b Scalar> for &'a Scalar {
     type Output = Scalar;
 
     fn pow(self, exp: &'b Scalar) -> Scalar {
-        let mut base = *self;
-        let mut res = Scalar::one();
-        let mut expon = *exp;
-
-        while expon > Scalar::zero() {
-            if expon.is_even() {
-                expon = expon.half_without_mod();
-                base = base.square();
-            } else {
-                expon = expon - Scalar::one();
-                res = res * base;
-
-                expon = expon.half();
-                base = base.square();
-            }
-        }
-        res
+        self.unpack().pow(exp).pack()
     }
 }
 
+/// Compute `a * b (mod l)` with Barrett's reduction, see
+/// `Scalar::barrett_reduce`.
+#[cfg(not(feature = "u32_backend"))]
+#[inline(always)]
+fn mul_mod(a: &Scalar, b: &Scalar) -> Scalar {
+    Scalar::barrett_reduce(&Scalar::mul_internal(a, b))
+}
+
+/// Compute `a * b (mod l)` with 29-bit limbs and `u64` products,
+/// see `backend::u32::scalar`.
+#[cfg(feature = "u32_backend")]
+#[inline(always)]
+fn mul_mod(a: &Scalar, b: &Scalar) -> Scalar {
+    crate::backend::u32::scalar::mul(a, b)
+}
+
+/// Compute `a^2 (mod l)` with Barrett's reduction, see
+/// `Scalar::barrett_reduce`.
+#[cfg(not(feature = "u32_backend"))]
+#[inline(always)]
+fn square_mod(a: &Scalar) -> Scalar {
+    Scalar::barrett_reduce(&Scalar::square_internal(a))
+}
+
+/// Compute `a^2 (mod l)` with 29-bit limbs and `u64` products,
+/// see `backend::u32::scalar`.
+#[cfg(feature = "u32_backend")]
+#[inline(always)]
+fn square_mod(a: &Scalar) -> Scalar {
+    crate::backend::u32::scalar::square(a)
+}
+
 /// u64 * u64 = u128 inline func multiply helper
 fn m(x: u64, y: u64) -> u128 {
     (x as u128) * (y as u128)
@@ -349,6 +379,19 @@ impl Scalar {
         self.0[0].is_even()
     }
 
+    /// Bit length of `l`, and so of the canonical `Scalar`s.
+    pub const BITS: usize = 250;
+
+    /// Iterate over the `Scalar::BITS` bits of the `Scalar`, starting
+    /// from the least significant one.
+    ///
+    /// The `Scalar` must be reduced, otherways its top bits are
+    /// ignored.
+    pub fn bits(&self) -> impl DoubleEndedIterator<Item = Choice> + ExactSizeIterator {
+        let bytes = self.to_bytes();
+        (0..Scalar::BITS).map(move |i| Choice::from((bytes[i >> 3] >> (i & 7)) & 1))
+    }
+
     /// Returns the bit representation of the given `Scalar` as
     /// an array of 256 bits represented as `u8`.
     pub fn into_bits(&self) -> [u8; 256] {
@@ -379,7 +422,7 @@ impl Scalar {
             if !k.is_even() {
                 let ki = 2i8 - k.mod_2_pow_k(2u8) as i8;
                 res[i] = ki;
-                k = k - Scalar::from(ki);
+                k -= Scalar::from(ki);
             } else {
                 res[i] = 0i8;
             };
@@ -405,7 +448,7 @@ impl Scalar {
             if !k.is_even() {
                 let ki = k.mods_2_pow_k(width);
                 res[i] = ki;
-                k = k - Scalar::from(ki);
+                k -= Scalar::from(ki);
             } else {
                 res[i] = 0i8;
             };
@@ -416,6 +459,50 @@ impl Scalar {
         res
     }
 
+    /// Number of signed digits returned by `Scalar::to_radix_2w`,
+    /// ie. `ceil(256 / w) + 1`.
+    pub const fn to_radix_2w_size_hint(w: usize) -> usize {
+        256usize.div_ceil(w) + 1
+    }
+
+    /// Recode the `Scalar` into signed digits `a_i` in
+    /// `[-2^(w-1), 2^(w-1))` such that `self = sum(a_i * 2^(w * i))`.
+    ///
+    /// Signed digits halve the tables needed by the windowed and
+    /// Pippenger-style multiplications, since `-a_i * P` is just
+    /// a negation of `a_i * P`.
+    ///
+    /// # Panics
+    /// If `w` is not in `4..=8`.
+    pub fn to_radix_2w(&self, w: usize) -> Vec<i8> {
+        assert!((4..=8).contains(&w), "The window width must be in 4..=8");
+        let bytes = self.to_bytes();
+        let bit = |i: usize| -> u64 {
+            if i >= 256 {
+                0
+            } else {
+                ((bytes[i >> 3] >> (i & 7)) & 1) as u64
+            }
+        };
+        let radix = 1u64 << w;
+        let digits_count = Scalar::to_radix_2w_size_hint(w);
+
+        let mut digits = vec![0i8; digits_count];
+        let mut carry = 0u64;
+        for (i, digit) in digits.iter_mut().enumerate().take(digits_count - 1) {
+            let mut coef = carry;
+            for j in 0..w {
+                coef += bit(w * i + j) << j;
+            }
+            // Move the digits greater or equal than 2^(w-1) to the
+            // negative side, carrying one to the next digit.
+            carry = (coef + (radix >> 1)) >> w;
+            *digit = (coef as i64 - (carry << w) as i64) as i8;
+        }
+        digits[digits_count - 1] = carry as i8;
+        digits
+    }
+
     /// Compute the result from `Scalar (mod 2^k)`.
     /// 
     /// # Panics
@@ -469,52 +556,111 @@ impl Scalar {
     }
 
     /// Reduce a 64 byte / 512 bit scalar mod l
-    pub fn from_bytes_wide(_bytes: &[u8; 64]) -> Scalar {
-        // We could provide 512 bit scalar support using Montgomery Reduction.
-        // But first we need to finnish the 256-bit implementation.
-        unimplemented!()
+    pub fn from_bytes_wide(bytes: &[u8; 64]) -> Scalar {
+        let mut words = [0u64; 8];
+        for i in 0..8 {
+            for j in 0..8 {
+                words[i] |= (bytes[(i * 8) + j] as u64) << (j * 8);
+            }
+        }
+
+        let mask = (1u64 << 52) - 1;
+        let mut lo = Scalar::zero();
+        let mut hi = Scalar::zero();
+
+        lo[0] = words[0] & mask;
+        lo[1] = ((words[0] >> 52) | (words[1] << 12)) & mask;
+        lo[2] = ((words[1] >> 40) | (words[2] << 24)) & mask;
+        lo[3] = ((words[2] >> 28) | (words[3] << 36)) & mask;
+        lo[4] = ((words[3] >> 16) | (words[4] << 48)) & mask;
+        hi[0] = (words[4] >> 4) & mask;
+        hi[1] = ((words[4] >> 56) | (words[5] << 8)) & mask;
+        hi[2] = ((words[5] >> 44) | (words[6] << 20)) & mask;
+        hi[3] = ((words[6] >> 32) | (words[7] << 32)) & mask;
+        hi[4] = words[7] >> 20;
+
+        // `lo * R / R = lo (mod l)` and `hi * R^2 / R = hi * R (mod l)`,
+        // which is exactly `hi * 2^260 + lo (mod l)`.
+        let lo = Scalar::montgomery_reduce(&Scalar::mul_internal(&lo, &constants::R));
+        let hi = Scalar::montgomery_reduce(&Scalar::mul_internal(&hi, &constants::RR));
+
+        hi + lo
+    }
+
+    /// Get the value of this `Scalar` as four saturated LE u64 words.
+    pub fn to_u64_words(&self) -> [u64; 4] {
+        [
+            self.0[0] | (self.0[1] << 52),
+            (self.0[1] >> 12) | (self.0[2] << 40),
+            (self.0[2] >> 24) | (self.0[3] << 28),
+            (self.0[3] >> 36) | (self.0[4] << 16),
+        ]
+    }
+
+    /// Construct a `Scalar` from four saturated LE u64 words.
+    ///
+    /// # Returns
+    /// - `None` if the value is greater or equal than `L`.
+    pub fn from_u64_words(words: [u64; 4]) -> Option<Scalar> {
+        let mask = (1u64 << 52) - 1;
+        let s = Scalar([
+            words[0] & mask,
+            ((words[0] >> 52) | (words[1] << 12)) & mask,
+            ((words[1] >> 40) | (words[2] << 24)) & mask,
+            ((words[2] >> 28) | (words[3] << 36)) & mask,
+            words[3] >> 16,
+        ]);
+        if s >= constants::L {
+            return None;
+        };
+        Some(s)
     }
 
     /// Pack the limbs of this `Scalar` into 32 bytes
     pub fn to_bytes(&self) -> [u8; 32] {
         let mut res = [0u8; 32];
+        self.write_bytes_into(&mut res);
+        res
+    }
 
-        res[0] = (self.0[0] >> 0) as u8;
-        res[1] = (self.0[0] >> 8) as u8;
-        res[2] = (self.0[0] >> 16) as u8;
-        res[3] = (self.0[0] >> 24) as u8;
-        res[4] = (self.0[0] >> 32) as u8;
-        res[5] = (self.0[0] >> 40) as u8;
-        res[6] = ((self.0[0] >> 48) | (self.0[1] << 4)) as u8;
-        res[7] = (self.0[1] >> 4) as u8;
-        res[8] = (self.0[1] >> 12) as u8;
-        res[9] = (self.0[1] >> 20) as u8;
-        res[10] = (self.0[1] >> 28) as u8;
-        res[11] = (self.0[1] >> 36) as u8;
-        res[12] = (self.0[1] >> 44) as u8;
-        res[13] = (self.0[2] >> 0) as u8;
-        res[14] = (self.0[2] >> 8) as u8;
-        res[15] = (self.0[2] >> 16) as u8;
-        res[16] = (self.0[2] >> 24) as u8;
-        res[17] = (self.0[2] >> 32) as u8;
-        res[18] = (self.0[2] >> 40) as u8;
-        res[19] = ((self.0[2] >> 48) | (self.0[3] << 4)) as u8;
-        res[20] = (self.0[3] >> 4) as u8;
-        res[21] = (self.0[3] >> 12) as u8;
-        res[22] = (self.0[3] >> 20) as u8;
-        res[23] = (self.0[3] >> 28) as u8;
-        res[24] = (self.0[3] >> 36) as u8;
-        res[25] = (self.0[3] >> 44) as u8;
-        res[26] = (self.0[4] >> 0) as u8;
-        res[27] = (self.0[4] >> 8) as u8;
-        res[28] = (self.0[4] >> 16) as u8;
-        res[29] = (self.0[4] >> 24) as u8;
-        res[30] = (self.0[4] >> 32) as u8;
-        res[31] = (self.0[4] >> 40) as u8;
+    /// Pack the limbs of this `Scalar` into the given 32-byte buffer,
+    /// with the same encoding as `Scalar::to_bytes`.
+    pub fn write_bytes_into(&self, out: &mut [u8; 32]) {
+        out[0] = (self.0[0] >> 0) as u8;
+        out[1] = (self.0[0] >> 8) as u8;
+        out[2] = (self.0[0] >> 16) as u8;
+        out[3] = (self.0[0] >> 24) as u8;
+        out[4] = (self.0[0] >> 32) as u8;
+        out[5] = (self.0[0] >> 40) as u8;
+        out[6] = ((self.0[0] >> 48) | (self.0[1] << 4)) as u8;
+        out[7] = (self.0[1] >> 4) as u8;
+        out[8] = (self.0[1] >> 12) as u8;
+        out[9] = (self.0[1] >> 20) as u8;
+        out[10] = (self.0[1] >> 28) as u8;
+        out[11] = (self.0[1] >> 36) as u8;
+        out[12] = (self.0[1] >> 44) as u8;
+        out[13] = (self.0[2] >> 0) as u8;
+        out[14] = (self.0[2] >> 8) as u8;
+        out[15] = (self.0[2] >> 16) as u8;
+        out[16] = (self.0[2] >> 24) as u8;
+        out[17] = (self.0[2] >> 32) as u8;
+        out[18] = (self.0[2] >> 40) as u8;
+        out[19] = ((self.0[2] >> 48) | (self.0[3] << 4)) as u8;
+        out[20] = (self.0[3] >> 4) as u8;
+        out[21] = (self.0[3] >> 12) as u8;
+        out[22] = (self.0[3] >> 20) as u8;
+        out[23] = (self.0[3] >> 28) as u8;
+        out[24] = (self.0[3] >> 36) as u8;
+        out[25] = (self.0[3] >> 44) as u8;
+        out[26] = (self.0[4] >> 0) as u8;
+        out[27] = (self.0[4] >> 8) as u8;
+        out[28] = (self.0[4] >> 16) as u8;
+        out[29] = (self.0[4] >> 24) as u8;
+        out[30] = (self.0[4] >> 32) as u8;
+        out[31] = (self.0[4] >> 40) as u8;
 
         // High bit should be zero.
-        //debug_assert!((res[31] & 0b1000_0000u8) == 0u8);
-        res
+        //debug_assert!((out[31] & 0b1000_0000u8) == 0u8);
     }
 
     /// Given a `k`: u64, compute `2^k` giving the resulting result
@@ -524,6 +670,7 @@ impl Scalar {
     ///
     /// # Panics
     /// If the input is greater than the Sub-group order.
+    /// Use `Scalar::try_two_pow_k` to get an error instead.
     pub fn two_pow_k(exp: u64) -> Scalar {
         // Check that exp has to be less than 260.
         // Note that a Scalar can be as much
@@ -553,6 +700,16 @@ impl Scalar {
         res
     }
 
+    /// Given a `k`: u64, compute `2^k` as a `Scalar`.
+    ///
+    /// Returns `Error::OutOfRange` if `k >= 250`.
+    pub fn try_two_pow_k(exp: u64) -> Result<Scalar, Error> {
+        if exp >= 250u64 {
+            return Err(Error::OutOfRange);
+        };
+        Ok(Scalar::two_pow_k(exp))
+    }
+
     /// Returns the half of an **EVEN** `Scalar`.
     /// 
     /// This function performs almost 4x faster than the
@@ -567,7 +724,7 @@ impl Scalar {
         let mut res = self;
 
         for i in (0..5).rev() {
-            res[i] = res[i] | carry;
+            res[i] |= carry;
             
             carry = (res[i] & 1) << 52;
             res[i] >>= 1;
@@ -650,23 +807,86 @@ impl Scalar {
         let r4 = carry as u64;
 
         // result may be >= r, so attempt to subtract l
-        &Scalar([r0, r1, r2, r3, r4]) - l
+        Scalar([r0, r1, r2, r3, r4]) - l
+    }
+
+    /// Compute `limbs (mod l)` with Barrett's reduction, where
+    /// `limbs` holds a product of two `Scalar`s below `2^252`, so
+    /// it's `< 2^504`.
+    ///
+    /// The quotient is estimated as
+    /// `q = ((limbs >> 246) * MU) >> 262` with `MU = floor(2^508 / l)`,
+    /// which is at most 1 unit below the real one. So
+    /// `limbs - q * l` is `< 2l` and a single conditional
+    /// subtraction of `l` finishes the reduction.
+    ///
+    /// Handbook of Applied Cryptography, Algorithm 14.42 -
+    /// Alfred J. Menezes, Paul C. van Oorschot and Scott A. Vanstone.
+    #[cfg_attr(feature = "u32_backend", allow(dead_code))]
+    pub(self) fn barrett_reduce(limbs: &[u128; 9]) -> Scalar {
+        let mask = (1u64 << 52) - 1;
+        let l = &constants::L;
+
+        // Normalize both `limbs` and `q1 * MU` to limbs of 52 bits,
+        // leaving a zero limb on top for `shift_limbs`.
+        fn carry_limbs(limbs: &[u128; 9]) -> [u64; 11] {
+            let mut res = [0u64; 11];
+            let mut carry = 0u128;
+            for i in 0..9 {
+                carry += limbs[i];
+                res[i] = (carry as u64) & ((1u64 << 52) - 1);
+                carry >>= 52;
+            }
+            res[9] = carry as u64;
+            res
+        }
+
+        // Get the 5 limbs of `x >> (52 * limbs + bits)`.
+        fn shift_limbs(x: &[u64; 11], limbs: usize, bits: u32) -> Scalar {
+            let mut res = Scalar::zero();
+            for i in 0..5 {
+                let (lo, hi) = (x[limbs + i], x[limbs + i + 1]);
+                res[i] = ((lo >> bits) | (hi << (52 - bits))) & ((1u64 << 52) - 1);
+            }
+            res
+        }
+
+        let x = carry_limbs(limbs);
+        // q1 = x >> 246
+        let q1 = shift_limbs(&x, 4, 38);
+        // q = (q1 * MU) >> 262
+        let q = shift_limbs(&carry_limbs(&Scalar::mul_internal(&q1, &constants::MU)), 5, 2);
+
+        // r = x - q * l, where only the low limbs of `q * l` are
+        // needed since the difference fits on them.
+        let mut r = Scalar::zero();
+        let mut carry = 0u128;
+        let mut borrow = 0u64;
+        for i in 0..5 {
+            for j in 0..=i {
+                carry += m(q[j], l[i - j]);
+            }
+            let ql = (carry as u64) & mask;
+            carry >>= 52;
+            borrow = x[i].wrapping_sub(ql + (borrow >> 63));
+            r[i] = borrow & mask;
+        }
+
+        // r < 2l, so subtract l if needed.
+        r - l
     }
 
     /// Compute `(a * b) / R` (mod l), where R is the Montgomery modulus 2^260
-    #[allow(dead_code)]
     pub(self) fn montgomery_mul(a: &Scalar, b: &Scalar) -> Scalar {
         Scalar::montgomery_reduce(&Scalar::mul_internal(a, b))
     }
 
     /// Puts a Scalar into Montgomery form, i.e. computes `a*R (mod l)`
-    #[allow(dead_code)]
     pub(self) fn to_montgomery(&self) -> Scalar {
         Scalar::montgomery_mul(self, &constants::RR)
     }
 
     /// Takes a Scalar out of Montgomery form, i.e. computes `a/R (mod l)`
-    #[allow(dead_code)]
     pub(self) fn from_montgomery(&self) -> Scalar {
         let mut limbs = [0u128; 9];
         for i in 0..5 {
@@ -676,6 +896,113 @@ impl Scalar {
     }
 }
 
+/// A `Scalar` in Montgomery form, ie. `a * R (mod l)` with
+/// `R = 2^260`.
+///
+/// Products of `UnpackedScalar`s only need a single Montgomery
+/// reduction, so long chains of multiplications (exponentiations,
+/// inversions...) should be done over them, packing the result back
+/// into a `Scalar` at the end.
+#[derive(Copy, Clone, Debug)]
+pub struct UnpackedScalar(Scalar);
+
+impl Scalar {
+    /// Move the `Scalar` to Montgomery form.
+    pub fn unpack(&self) -> UnpackedScalar {
+        UnpackedScalar(self.to_montgomery())
+    }
+}
+
+impl UnpackedScalar {
+    /// Take the value out of Montgomery form.
+    pub fn pack(&self) -> Scalar {
+        self.0.from_montgomery()
+    }
+
+    /// Return the `UnpackedScalar` of value `1`.
+    pub fn one() -> UnpackedScalar {
+        UnpackedScalar(constants::R)
+    }
+
+    /// Compute `self^2 (mod l)`.
+    pub fn square(&self) -> UnpackedScalar {
+        UnpackedScalar(Scalar::montgomery_reduce(&Scalar::square_internal(&self.0)))
+    }
+
+    /// Compute `self^exp (mod l)` with the left-to-right square and
+    /// multiply method.
+    ///
+    /// This is variable-time with respect to `exp`.
+    pub fn pow(&self, exp: &Scalar) -> UnpackedScalar {
+        let bits = exp.into_bits();
+        let mut res = UnpackedScalar::one();
+        for bit in bits.iter().rev() {
+            res = res.square();
+            if *bit == 1u8 {
+                res = res * self;
+            };
+        }
+        res
+    }
+
+    /// Compute `self^-1 (mod l)` as `self^(l - 2)`.
+    ///
+    /// Returns zero if `self` is zero.
+    pub fn invert(&self) -> UnpackedScalar {
+        self.pow(&(constants::L - Scalar::from(2u8)))
+    }
+}
+
+impl Mul<&UnpackedScalar> for &UnpackedScalar {
+    type Output = UnpackedScalar;
+    /// Compute `(a * R) * (b * R) / R = a * b * R (mod l)` with a
+    /// single Montgomery reduction.
+    fn mul(self, b: &UnpackedScalar) -> UnpackedScalar {
+        UnpackedScalar(Scalar::montgomery_mul(&self.0, &b.0))
+    }
+}
+
+impl Add<&UnpackedScalar> for &UnpackedScalar {
+    type Output = UnpackedScalar;
+    /// Compute `a * R + b * R = (a + b) * R (mod l)`.
+    fn add(self, b: &UnpackedScalar) -> UnpackedScalar {
+        UnpackedScalar(self.0 + b.0)
+    }
+}
+
+impl Sub<&UnpackedScalar> for &UnpackedScalar {
+    type Output = UnpackedScalar;
+    /// Compute `a * R - b * R = (a - b) * R (mod l)`.
+    fn sub(self, b: &UnpackedScalar) -> UnpackedScalar {
+        UnpackedScalar(self.0 - b.0)
+    }
+}
+
+impl Mul<UnpackedScalar> for UnpackedScalar {
+    type Output = UnpackedScalar;
+    fn mul(self, b: UnpackedScalar) -> UnpackedScalar {
+        &self * &b
+    }
+}
+
+impl Add<UnpackedScalar> for UnpackedScalar {
+    type Output = UnpackedScalar;
+    fn add(self, b: UnpackedScalar) -> UnpackedScalar {
+        &self + &b
+    }
+}
+
+impl Sub<UnpackedScalar> for UnpackedScalar {
+    type Output = UnpackedScalar;
+    fn sub(self, b: UnpackedScalar) -> UnpackedScalar {
+        &self - &b
+    }
+}
+
+define_mixed_add_variants!(LHS = UnpackedScalar, RHS = UnpackedScalar, Output = UnpackedScalar);
+define_mixed_sub_variants!(LHS = UnpackedScalar, RHS = UnpackedScalar, Output = UnpackedScalar);
+define_mixed_mul_variants!(LHS = UnpackedScalar, RHS = UnpackedScalar, Output = UnpackedScalar);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -892,6 +1219,127 @@ mod tests {
         }
     }
 
+    #[test]
+    fn barrett_reduction() {
+        use num::BigUint;
+
+        let l = BigUint::from_bytes_le(&constants::L.to_bytes());
+        let big = |k: &Scalar| BigUint::from_bytes_le(&k.to_bytes());
+
+        let minus_one = Scalar::minus_one();
+        let scalars = [
+            Scalar::zero(),
+            Scalar::one(),
+            minus_one,
+            Scalar::two_pow_k(249) - Scalar::one(),
+            X,
+            Y,
+            A,
+            B,
+        ];
+        for a in scalars.iter() {
+            for b in scalars.iter() {
+                let res = Scalar::barrett_reduce(&Scalar::mul_internal(a, b));
+                assert!(big(&res) == (big(a) * big(b)) % &l);
+            }
+            let res = Scalar::barrett_reduce(&Scalar::square_internal(a));
+            assert!(big(&res) == (big(a) * big(a)) % &l);
+        }
+    }
+
+    #[test]
+    fn unpacked_scalar() {
+        assert!(Y.unpack().pack() == Y);
+        assert!((X.unpack() * Y.unpack()).pack() == X_TIMES_Y);
+        assert!((A_POW_B.unpack() + Y.unpack()).pack() == A_POW_B + Y);
+        assert!((A_POW_B.unpack() - Y.unpack()).pack() == A_POW_B - Y);
+        assert!(Y.unpack().square().pack() == Y_SQ);
+        assert!(A.unpack().pow(&B).pack() == A_POW_B);
+
+        let inv = Y.unpack().invert();
+        assert!((inv * Y.unpack()).pack() == Scalar::one());
+        assert!(Scalar::zero().unpack().invert().pack() == Scalar::zero());
+    }
+
+    #[test]
+    fn to_radix_2w() {
+        let scalars = [
+            Scalar::zero(),
+            Scalar::one(),
+            Scalar::minus_one(),
+            Scalar::from(0xff80u64),
+            Y,
+        ];
+        for w in 4..=8 {
+            for scalar in scalars.iter() {
+                let digits = scalar.to_radix_2w(w);
+                assert_eq!(digits.len(), Scalar::to_radix_2w_size_hint(w));
+                let mut acc = Scalar::zero();
+                for digit in digits.iter().rev() {
+                    let half_radix = 1i64 << (w - 1);
+                    assert!(-half_radix <= *digit as i64 && (*digit as i64) < half_radix);
+                    acc *= Scalar::from(1u64 << w);
+                    if *digit >= 0 {
+                        acc += Scalar::from(*digit as u64);
+                    } else {
+                        acc -= Scalar::from(-(*digit as i64) as u64);
+                    }
+                }
+                assert!(acc == *scalar);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn to_radix_2w_invalid_window() {
+        let _ = Scalar::one().to_radix_2w(3);
+    }
+
+    #[test]
+    fn u64_words() {
+        for s in [Scalar::zero(), Scalar::two_pow_k(200), Scalar::minus_one()].iter() {
+            let words = s.to_u64_words();
+            for (i, word) in words.iter().enumerate() {
+                assert_eq!(word.to_le_bytes()[..], s.to_bytes()[8 * i..8 * (i + 1)]);
+            }
+            assert!(Scalar::from_u64_words(words).unwrap() == *s);
+        }
+        assert!(Scalar::from_u64_words([1, 0, 0, 0]).unwrap() == Scalar::one());
+        assert!(Scalar::from_u64_words(constants::L.to_u64_words()).is_none());
+        assert!(Scalar::from_u64_words([u64::MAX; 4]).is_none());
+    }
+
+    #[test]
+    fn try_two_pow_k() {
+        use crate::errors::Error;
+
+        assert!(Scalar::try_two_pow_k(249).unwrap() == Scalar::two_pow_k(249));
+        assert!(Scalar::try_two_pow_k(250) == Err(Error::OutOfRange));
+    }
+
+    #[test]
+    fn write_bytes_into() {
+        let mut out = [0xffu8; 32];
+        Y.write_bytes_into(&mut out);
+        assert!(out == Y.to_bytes());
+    }
+
+    #[test]
+    fn bits_iterator() {
+        for k in [Scalar::zero(), Scalar::one(), Scalar::minus_one(), Y].iter() {
+            let bits = k.bits();
+            assert_eq!(bits.len(), Scalar::BITS);
+            let mut acc = Scalar::zero();
+            for bit in bits.rev() {
+                acc = acc + acc + Scalar::from(bit.unwrap_u8());
+            }
+            assert!(acc == *k);
+        }
+        let bits: Vec<bool> = Scalar::from(6u8).bits().map(bool::from).collect();
+        assert!(bits[..4] == [false, true, true, false]);
+    }
+
     #[test]
     fn square() {
         let res = &Y.square();
@@ -947,6 +1395,11 @@ mod tests {
         use subtle::ConstantTimeEq;
         assert!(A.ct_eq(&A).unwrap_u8() == 1u8);
         assert!(A.ct_eq(&B).unwrap_u8() == 0u8);
+
+        // `==` goes through `ct_eq`.
+        for (a, b) in [(A, A), (A, B), (Scalar::zero(), Scalar::one())].iter() {
+            assert_eq!(a == b, a.ct_eq(b).unwrap_u8() == 1u8);
+        }
     }
 
 
@@ -961,6 +1414,55 @@ mod tests {
         assert!(Scalar::two_pow_k(248) == Scalar([0, 0, 0, 0, 1099511627776]));
     }
 
+    #[test]
+    fn from_i8() {
+        assert!(Scalar::from(-1i8) == Scalar::minus_one());
+        assert!(Scalar::from(127i8) == Scalar::from(127u8));
+        assert!(Scalar::from(-128i8) == -Scalar::from(128u8));
+    }
+
+    #[test]
+    fn from_unsigned_integers() {
+        assert!(Scalar::from(u8::MAX) == Scalar([255, 0, 0, 0, 0]));
+        assert!(Scalar::from(u16::MAX) == Scalar([65535, 0, 0, 0, 0]));
+        assert!(Scalar::from(u32::MAX) == Scalar([4294967295, 0, 0, 0, 0]));
+        assert!(Scalar::from(u64::MAX) == Scalar([4503599627370495, 4095, 0, 0, 0]));
+        assert!(
+            Scalar::from(u128::MAX)
+                == Scalar([4503599627370495, 4503599627370495, 16777215, 0, 0])
+        );
+
+        // All of the widths agree, and match the LE encoding.
+        let n = 0xbeefu16;
+        assert!(Scalar::from(n as u8) == Scalar::from(0xefu8));
+        assert!(Scalar::from(n) == Scalar::from(n as u32));
+        assert!(Scalar::from(n as u32) == Scalar::from(n as u64));
+        assert!(Scalar::from(n as u64) == Scalar::from(n as u128));
+        let mut bytes = [0u8; 32];
+        bytes[..16].copy_from_slice(&u128::MAX.to_le_bytes());
+        assert!(Scalar::from(u128::MAX) == Scalar::from_bytes(&bytes));
+    }
+
+    #[test]
+    fn from_bytes_wide() {
+        // `(2^512 - 1) (mod l) = 860800654313289142967123049912064039460604294864560939948250969524308554161`.
+        let max = Scalar::from_bytes_wide(&[255u8; 64]);
+        assert!(max == Scalar([3289463997084081, 1465167243984843, 2486283101098855, 1382087468455689, 2092490477728]));
+
+        // `[0, 1, ..., 63]` as LE bytes `(mod l) = 189644764440987798229679482535613343645977807596321470337693856797616860894`.
+        let mut bytes = [0u8; 64];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = i as u8;
+        }
+        let res = Scalar::from_bytes_wide(&bytes);
+        assert!(res == Scalar([2970913172975326, 1359173772016661, 3038995381608787, 3526264949762046, 461000885344]));
+
+        // Values lower than `l` are left untouched.
+        let mut bytes = [0u8; 64];
+        bytes[..32].copy_from_slice(&Y.to_bytes());
+        assert!(Scalar::from_bytes_wide(&bytes) == Y);
+    }
+
     #[test]
     fn shr() {
         // Normal case.
@@ -1052,4 +1554,12 @@ mod tests {
         assert!(&naf6_scalar[..] == &scalar.compute_window_NAF(6)[..31]);
 
     }
+
+    #[test]
+    fn zero_and_one_predicates() {
+        assert!(bool::from(Scalar::zero().is_zero()));
+        assert!(bool::from(Scalar::one().is_one()));
+        assert!(!bool::from(Scalar::minus_one().is_zero()));
+        assert!(!bool::from(Scalar::minus_one().is_one()));
+    }
 }
@@ -0,0 +1,206 @@
+//! Lane-wise arithmetic over four independent `FieldElement`s.
+//!
+//! `FieldElement4x` stores the limbs transposed: the `i`-th vector
+//! holds the `i`-th radix-`2^52` limb of the four elements, so the
+//! carry chains of the `u64` backend run on the four lanes at once.
+//!
+//! Only the additions, subtractions and negations are provided.
+//! Neither AVX2 nor NEON have 64-bit multipliers, so multiplying the
+//! radix-`2^52` limbs would mean computing the products lane by lane,
+//! which is slower than the `FieldElement` multiplication: `split`
+//! the lanes and multiply the `FieldElement`s instead.
+
+use core::ops::{Add, AddAssign, Neg, Sub, SubAssign};
+
+use super::lanes::U64x4;
+use crate::backend::serial::constants;
+use crate::backend::serial::field::FieldElement;
+
+/// Four `FieldElement`s packed to be operated lane-wise.
+#[derive(Copy, Clone)]
+pub struct FieldElement4x([U64x4; 5]);
+
+impl FieldElement4x {
+    /// Pack four `FieldElement`s.
+    pub fn new(elems: [FieldElement; 4]) -> FieldElement4x {
+        let mut limbs = [U64x4::splat(0); 5];
+        for (i, limb) in limbs.iter_mut().enumerate() {
+            *limb = U64x4::new([elems[0][i], elems[1][i], elems[2][i], elems[3][i]]);
+        }
+        FieldElement4x(limbs)
+    }
+
+    /// Pack four copies of the same `FieldElement`.
+    pub fn splat(elem: &FieldElement) -> FieldElement4x {
+        FieldElement4x::new([*elem; 4])
+    }
+
+    /// Unpack the four `FieldElement`s.
+    pub fn split(&self) -> [FieldElement; 4] {
+        let mut elems = [FieldElement::zero(); 4];
+        for i in 0..5 {
+            let lanes = self.0[i].to_lanes();
+            for (elem, lane) in elems.iter_mut().zip(lanes.iter()) {
+                elem[i] = *lane;
+            }
+        }
+        elems
+    }
+}
+
+impl From<[FieldElement; 4]> for FieldElement4x {
+    fn from(elems: [FieldElement; 4]) -> FieldElement4x {
+        FieldElement4x::new(elems)
+    }
+}
+
+impl From<FieldElement4x> for [FieldElement; 4] {
+    fn from(elems: FieldElement4x) -> [FieldElement; 4] {
+        elems.split()
+    }
+}
+
+impl Add<&FieldElement4x> for &FieldElement4x {
+    type Output = FieldElement4x;
+    /// Compute `a + b (mod p)` on each lane.
+    fn add(self, b: &FieldElement4x) -> FieldElement4x {
+        let mask = U64x4::splat((1u64 << 52) - 1);
+        let mut sum = [U64x4::splat(0); 5];
+
+        let mut carry = U64x4::splat(0);
+        for (i, limb) in sum.iter_mut().enumerate() {
+            carry = self.0[i].add(b.0[i]).add(carry.shr::<52>());
+            *limb = carry.and(mask);
+        }
+        // Subtract p if the sum is >= p.
+        FieldElement4x(sum) - FieldElement4x::splat(&constants::FIELD_L)
+    }
+}
+
+impl Sub<&FieldElement4x> for &FieldElement4x {
+    type Output = FieldElement4x;
+    /// Compute `a - b (mod p)` on each lane.
+    fn sub(self, b: &FieldElement4x) -> FieldElement4x {
+        let mask = U64x4::splat((1u64 << 52) - 1);
+        let one = U64x4::splat(1);
+        let mut difference = [U64x4::splat(0); 5];
+
+        // The top bit of `borrow` is set when the limb underflows.
+        let mut borrow = U64x4::splat(0);
+        for (i, limb) in difference.iter_mut().enumerate() {
+            borrow = self.0[i].sub(b.0[i].add(borrow.shr::<63>()));
+            *limb = borrow.and(mask);
+        }
+
+        // Conditionally add p on the lanes where the difference
+        // is negative.
+        let underflow_mask = borrow.shr::<63>().xor(one).sub(one);
+        let mut carry = U64x4::splat(0);
+        for (i, limb) in difference.iter_mut().enumerate() {
+            let p_limb = U64x4::splat(constants::FIELD_L[i]).and(underflow_mask);
+            carry = carry.shr::<52>().add(*limb).add(p_limb);
+            *limb = carry.and(mask);
+        }
+        FieldElement4x(difference)
+    }
+}
+
+impl Neg for &FieldElement4x {
+    type Output = FieldElement4x;
+    /// Compute `-a (mod p)` on each lane.
+    fn neg(self) -> FieldElement4x {
+        FieldElement4x::splat(&FieldElement::zero()) - self
+    }
+}
+
+impl Add<FieldElement4x> for FieldElement4x {
+    type Output = FieldElement4x;
+    fn add(self, b: FieldElement4x) -> FieldElement4x {
+        &self + &b
+    }
+}
+
+impl Sub<FieldElement4x> for FieldElement4x {
+    type Output = FieldElement4x;
+    fn sub(self, b: FieldElement4x) -> FieldElement4x {
+        &self - &b
+    }
+}
+
+impl Neg for FieldElement4x {
+    type Output = FieldElement4x;
+    fn neg(self) -> FieldElement4x {
+        -&self
+    }
+}
+
+define_mixed_add_variants!(LHS = FieldElement4x, RHS = FieldElement4x, Output = FieldElement4x);
+define_add_assign_variants!(LHS = FieldElement4x, RHS = FieldElement4x);
+define_mixed_sub_variants!(LHS = FieldElement4x, RHS = FieldElement4x, Output = FieldElement4x);
+define_sub_assign_variants!(LHS = FieldElement4x, RHS = FieldElement4x);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rand::rngs::OsRng;
+
+    fn random_elems() -> [FieldElement; 4] {
+        [
+            FieldElement::random(&mut OsRng),
+            FieldElement::minus_one(),
+            FieldElement::zero(),
+            FieldElement::random(&mut OsRng),
+        ]
+    }
+
+    #[test]
+    fn pack_and_split() {
+        let elems = random_elems();
+        let packed = FieldElement4x::from(elems);
+        assert!(packed.split() == elems);
+    }
+
+    #[test]
+    fn lane_wise_ops() {
+        let a = random_elems();
+        let mut b = random_elems();
+        b.reverse();
+        let (a4, b4) = (FieldElement4x::new(a), FieldElement4x::new(b));
+
+        let sum = (a4 + b4).split();
+        let difference = (a4 - b4).split();
+        let negation = (-a4).split();
+        for i in 0..4 {
+            assert!(sum[i] == a[i] + b[i]);
+            assert!(difference[i] == a[i] - b[i]);
+            assert!(negation[i] == -a[i]);
+        }
+    }
+
+    #[test]
+    fn lane_wise_ops_random() {
+        for _ in 0..256 {
+            let a: [FieldElement; 4] = core::array::from_fn(|_| FieldElement::random(&mut OsRng));
+            let b: [FieldElement; 4] = core::array::from_fn(|_| FieldElement::random(&mut OsRng));
+            let (mut a4, b4) = (FieldElement4x::new(a), FieldElement4x::new(b));
+
+            let sum = (a4 + b4).split();
+            let difference = (a4 - b4).split();
+            let negation = (-b4).split();
+            for i in 0..4 {
+                assert!(sum[i] == a[i] + b[i]);
+                assert!(difference[i] == a[i] - b[i]);
+                assert!(negation[i] == -b[i]);
+            }
+
+            // Chained operations keep the limbs reduced.
+            a4 += b4;
+            a4 -= b4 + b4;
+            let chained = a4.split();
+            for i in 0..4 {
+                assert!(chained[i] == a[i] - b[i]);
+            }
+        }
+    }
+}
@@ -0,0 +1,175 @@
+//! Four `u64` lanes with the handful of operations needed by the
+//! limb arithmetic of `FieldElement4x`.
+//!
+//! - On `x86_64` with `avx2` enabled, the lanes live on a `__m256i`.
+//! - On `aarch64` with `neon` enabled, they live on two `uint64x2_t`.
+//! - Otherwise, they are a plain `[u64; 4]`.
+//!
+//! The target features are checked at compile time, so build with
+//! `RUSTFLAGS="-C target-cpu=native"` to get the vectorized code.
+
+#[cfg(all(target_arch = "x86_64", target_feature = "avx2"))]
+mod imp {
+    use core::arch::x86_64::*;
+
+    #[derive(Copy, Clone)]
+    pub struct U64x4(__m256i);
+
+    impl U64x4 {
+        #[inline(always)]
+        pub fn new(lanes: [u64; 4]) -> U64x4 {
+            unsafe { U64x4(_mm256_loadu_si256(lanes.as_ptr() as *const __m256i)) }
+        }
+
+        #[inline(always)]
+        pub fn splat(x: u64) -> U64x4 {
+            unsafe { U64x4(_mm256_set1_epi64x(x as i64)) }
+        }
+
+        #[inline(always)]
+        pub fn to_lanes(self) -> [u64; 4] {
+            let mut lanes = [0u64; 4];
+            unsafe { _mm256_storeu_si256(lanes.as_mut_ptr() as *mut __m256i, self.0) };
+            lanes
+        }
+
+        #[inline(always)]
+        pub fn add(self, rhs: U64x4) -> U64x4 {
+            unsafe { U64x4(_mm256_add_epi64(self.0, rhs.0)) }
+        }
+
+        #[inline(always)]
+        pub fn sub(self, rhs: U64x4) -> U64x4 {
+            unsafe { U64x4(_mm256_sub_epi64(self.0, rhs.0)) }
+        }
+
+        #[inline(always)]
+        pub fn and(self, rhs: U64x4) -> U64x4 {
+            unsafe { U64x4(_mm256_and_si256(self.0, rhs.0)) }
+        }
+
+        #[inline(always)]
+        pub fn xor(self, rhs: U64x4) -> U64x4 {
+            unsafe { U64x4(_mm256_xor_si256(self.0, rhs.0)) }
+        }
+
+        #[inline(always)]
+        pub fn shr<const N: i32>(self) -> U64x4 {
+            unsafe { U64x4(_mm256_srli_epi64::<N>(self.0)) }
+        }
+    }
+}
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+mod imp {
+    use core::arch::aarch64::*;
+
+    #[derive(Copy, Clone)]
+    pub struct U64x4(uint64x2_t, uint64x2_t);
+
+    impl U64x4 {
+        #[inline(always)]
+        pub fn new(lanes: [u64; 4]) -> U64x4 {
+            unsafe { U64x4(vld1q_u64(lanes.as_ptr()), vld1q_u64(lanes[2..].as_ptr())) }
+        }
+
+        #[inline(always)]
+        pub fn splat(x: u64) -> U64x4 {
+            unsafe { U64x4(vdupq_n_u64(x), vdupq_n_u64(x)) }
+        }
+
+        #[inline(always)]
+        pub fn to_lanes(self) -> [u64; 4] {
+            let mut lanes = [0u64; 4];
+            unsafe {
+                vst1q_u64(lanes.as_mut_ptr(), self.0);
+                vst1q_u64(lanes[2..].as_mut_ptr(), self.1);
+            }
+            lanes
+        }
+
+        #[inline(always)]
+        pub fn add(self, rhs: U64x4) -> U64x4 {
+            unsafe { U64x4(vaddq_u64(self.0, rhs.0), vaddq_u64(self.1, rhs.1)) }
+        }
+
+        #[inline(always)]
+        pub fn sub(self, rhs: U64x4) -> U64x4 {
+            unsafe { U64x4(vsubq_u64(self.0, rhs.0), vsubq_u64(self.1, rhs.1)) }
+        }
+
+        #[inline(always)]
+        pub fn and(self, rhs: U64x4) -> U64x4 {
+            unsafe { U64x4(vandq_u64(self.0, rhs.0), vandq_u64(self.1, rhs.1)) }
+        }
+
+        #[inline(always)]
+        pub fn xor(self, rhs: U64x4) -> U64x4 {
+            unsafe { U64x4(veorq_u64(self.0, rhs.0), veorq_u64(self.1, rhs.1)) }
+        }
+
+        #[inline(always)]
+        pub fn shr<const N: i32>(self) -> U64x4 {
+            unsafe { U64x4(vshrq_n_u64::<N>(self.0), vshrq_n_u64::<N>(self.1)) }
+        }
+    }
+}
+
+#[cfg(not(any(
+    all(target_arch = "x86_64", target_feature = "avx2"),
+    all(target_arch = "aarch64", target_feature = "neon")
+)))]
+mod imp {
+    #[derive(Copy, Clone)]
+    pub struct U64x4([u64; 4]);
+
+    impl U64x4 {
+        #[inline(always)]
+        pub fn new(lanes: [u64; 4]) -> U64x4 {
+            U64x4(lanes)
+        }
+
+        #[inline(always)]
+        pub fn splat(x: u64) -> U64x4 {
+            U64x4([x; 4])
+        }
+
+        #[inline(always)]
+        pub fn to_lanes(self) -> [u64; 4] {
+            self.0
+        }
+
+        #[inline(always)]
+        fn map(self, rhs: U64x4, f: impl Fn(u64, u64) -> u64) -> U64x4 {
+            let (a, b) = (self.0, rhs.0);
+            U64x4([f(a[0], b[0]), f(a[1], b[1]), f(a[2], b[2]), f(a[3], b[3])])
+        }
+
+        #[inline(always)]
+        pub fn add(self, rhs: U64x4) -> U64x4 {
+            self.map(rhs, u64::wrapping_add)
+        }
+
+        #[inline(always)]
+        pub fn sub(self, rhs: U64x4) -> U64x4 {
+            self.map(rhs, u64::wrapping_sub)
+        }
+
+        #[inline(always)]
+        pub fn and(self, rhs: U64x4) -> U64x4 {
+            self.map(rhs, |a, b| a & b)
+        }
+
+        #[inline(always)]
+        pub fn xor(self, rhs: U64x4) -> U64x4 {
+            self.map(rhs, |a, b| a ^ b)
+        }
+
+        #[inline(always)]
+        pub fn shr<const N: i32>(self) -> U64x4 {
+            self.map(self, |a, _| a >> N)
+        }
+    }
+}
+
+pub(crate) use self::imp::U64x4;
@@ -0,0 +1,9 @@
+//! Vectorized implementations that operate on several independent
+//! values at once.
+//!
+//! They use AVX2 or NEON when the corresponding target feature is
+//! enabled at compile time, and portable code otherwise.
+
+mod lanes;
+
+pub mod field;
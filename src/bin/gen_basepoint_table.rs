@@ -0,0 +1,57 @@
+//! Prints the source of `src/backend/u64/basepoint_table.rs`, the
+//! precomputed `EdwardsBasepointTableRadix16` of the `BASEPOINT`:
+//!
+//! ```sh
+//! cargo run --bin gen_basepoint_table > src/backend/u64/basepoint_table.rs
+//! ```
+extern crate zerocaf;
+
+use zerocaf::constants::BASEPOINT;
+use zerocaf::edwards::{EdwardsBasepointTableRadix16, EdwardsPoint};
+use zerocaf::field::FieldElement;
+
+fn field_element(name: &str, fe: &FieldElement) -> String {
+    let limbs: Vec<String> = fe.0.iter().map(|limb| limb.to_string()).collect();
+    format!("            {}: FieldElement([{}]),\n", name, limbs.join(", "))
+}
+
+fn main() {
+    let table = EdwardsBasepointTableRadix16::create(&BASEPOINT);
+    let bytes = table.to_bytes();
+    // Skip the header: the tag, the window width and the count.
+    let points: Vec<EdwardsPoint> = bytes[9..]
+        .chunks(64)
+        .map(|chunk| {
+            let mut buf = [0u8; 64];
+            buf.copy_from_slice(chunk);
+            EdwardsPoint::from_uncompressed(&buf).unwrap()
+        })
+        .collect();
+
+    let mut src = String::new();
+    src.push_str("//! Precomputed `EdwardsBasepointTableRadix16` of the `BASEPOINT`.\n");
+    src.push_str("//!\n");
+    src.push_str("//! Generated with `cargo run --bin gen_basepoint_table`, do not edit.\n\n");
+    src.push_str("use crate::edwards::{EdwardsBasepointTableRadix16, EdwardsPoint};\n");
+    src.push_str("use crate::field::FieldElement;\n");
+    src.push_str("use crate::window::LookupTable;\n\n");
+    src.push_str("/// Table of the multiples of the `BASEPOINT` used by\n");
+    src.push_str("/// `EdwardsPoint::mul_base`, with all of the points in affine form.\n");
+    src.push_str("#[rustfmt::skip]\n");
+    src.push_str("pub static EDWARDS_BASEPOINT_TABLE: EdwardsBasepointTableRadix16 = ");
+    src.push_str("EdwardsBasepointTableRadix16([\n");
+    for table in points.chunks(8) {
+        src.push_str("    LookupTable([\n");
+        for point in table {
+            src.push_str("        EdwardsPoint {\n");
+            src.push_str(&field_element("X", &point.X));
+            src.push_str(&field_element("Y", &point.Y));
+            src.push_str(&field_element("Z", &point.Z));
+            src.push_str(&field_element("T", &point.T));
+            src.push_str("        },\n");
+        }
+        src.push_str("    ]),\n");
+    }
+    src.push_str("]);\n");
+    print!("{}", src);
+}
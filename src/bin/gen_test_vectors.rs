@@ -0,0 +1,7 @@
+//! Prints the known-answer vectors of `zerocaf::test_vectors` as JSON.
+extern crate zerocaf;
+
+fn main() {
+    let vectors = zerocaf::test_vectors::generate();
+    println!("{}", serde_json::to_string_pretty(&vectors).unwrap());
+}
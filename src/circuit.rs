@@ -0,0 +1,289 @@
+//! Decompositions of `Scalar`s for the authors of R1CS and Plonk
+//! gadgets, where the scalars of the Sonny curve live in the native
+//! field of the circuit.
+//!
+//! Every decomposition comes with its recomposition and a `check_*`
+//! function that mirrors the constraints a gadget has to enforce:
+//! each of the digits is in range, and the digits recompose to the
+//! decomposed `Scalar` as an integer. The recomposition is not
+//! reduced modulo `l`, so aliases such as the digits of `x + l`
+//! are rejected. All of the digits are little-endian.
+//!
+//! - Bits: `Scalar::BITS` booleans.
+//! - Windows: unsigned digits in `[0, 2^w)`.
+//! - Signed digits: digits in `[-2^(w-1), 2^(w-1)]` as returned by
+//!   `Scalar::to_radix_2w`, the ones used by the windowed
+//!   multiplications with a `LookupTable`.
+//!
+//! # Examples
+//! ```rust
+//! use zerocaf::circuit::*;
+//! use zerocaf::scalar::Scalar;
+//!
+//! let k = Scalar::from(1234567u64);
+//! let bits = to_bits(&k);
+//! assert!(check_bits(&k, &bits));
+//!
+//! let windows = to_windows(&k, 3);
+//! assert!(check_windows(&k, &windows, 3));
+//!
+//! let digits = to_signed_digits(&k, 4);
+//! assert!(check_signed_digits(&k, &digits, 4));
+//! ```
+
+use crate::scalar::Scalar;
+
+use num::bigint::{BigInt, Sign};
+
+/// Fold the LE `digits` in radix `2^w` into a `Scalar`:
+/// `sum(d_i * 2^(w * i)) (mod l)`.
+fn recompose<T>(digits: &[T], w: usize) -> Scalar
+where
+    T: Copy + Into<Scalar>,
+{
+    let radix = Scalar::from(1u16 << w);
+    digits
+        .iter()
+        .rev()
+        .fold(Scalar::zero(), |acc, digit| acc * radix + (*digit).into())
+}
+
+/// Check that the LE `digits` in radix `2^w` recompose to the
+/// integer value of `scalar`, with no reduction modulo `l`.
+fn recomposes_to<T>(digits: &[T], w: usize, scalar: &Scalar) -> bool
+where
+    T: Copy + Into<i64>,
+{
+    let value = digits
+        .iter()
+        .rev()
+        .fold(BigInt::from(0), |acc, digit| (acc << w) + (*digit).into());
+    value == BigInt::from_bytes_le(Sign::Plus, &scalar.to_bytes())
+}
+
+/// Number of unsigned windows of `w` bits needed to hold
+/// `Scalar::BITS` bits.
+pub const fn windows_count(w: usize) -> usize {
+    Scalar::BITS.div_ceil(w)
+}
+
+/// Get the `Scalar::BITS` bits of the `Scalar`.
+pub fn to_bits(scalar: &Scalar) -> Vec<bool> {
+    scalar.bits().map(|bit| bit.unwrap_u8() == 1).collect()
+}
+
+/// Compute `sum(b_i * 2^i) (mod l)`.
+pub fn from_bits(bits: &[bool]) -> Scalar {
+    let digits: Vec<u8> = bits.iter().map(|bit| *bit as u8).collect();
+    recompose(&digits, 1)
+}
+
+/// Check that `bits` is the bit decomposition of `scalar`: there
+/// are `Scalar::BITS` of them and they recompose to `scalar` as an
+/// integer.
+pub fn check_bits(scalar: &Scalar, bits: &[bool]) -> bool {
+    let digits: Vec<u8> = bits.iter().map(|bit| *bit as u8).collect();
+    bits.len() == Scalar::BITS && recomposes_to(&digits, 1, scalar)
+}
+
+/// Decompose the `Scalar` into `windows_count(w)` unsigned digits
+/// in `[0, 2^w)`.
+///
+/// # Panics
+/// If `w` is not in `1..=8`.
+pub fn to_windows(scalar: &Scalar, w: usize) -> Vec<u8> {
+    assert!((1..=8).contains(&w), "The window width must be in 1..=8");
+    let bits = to_bits(scalar);
+    bits.chunks(w)
+        .map(|window| {
+            window
+                .iter()
+                .rev()
+                .fold(0u8, |acc, bit| (acc << 1) | *bit as u8)
+        })
+        .collect()
+}
+
+/// Compute `sum(d_i * 2^(w * i)) (mod l)` for the unsigned
+/// digits `windows`.
+///
+/// # Panics
+/// If `w` is not in `1..=8`.
+pub fn from_windows(windows: &[u8], w: usize) -> Scalar {
+    assert!((1..=8).contains(&w), "The window width must be in 1..=8");
+    recompose(windows, w)
+}
+
+/// Check that `windows` is the `w`-bit windowed decomposition of
+/// `scalar`: there are `windows_count(w)` digits, all of them in
+/// `[0, 2^w)`, and they recompose to `scalar` as an integer.
+///
+/// # Panics
+/// If `w` is not in `1..=8`.
+pub fn check_windows(scalar: &Scalar, windows: &[u8], w: usize) -> bool {
+    assert!((1..=8).contains(&w), "The window width must be in 1..=8");
+    windows.len() == windows_count(w)
+        && windows.iter().all(|digit| (*digit as u16) < (1u16 << w))
+        && recomposes_to(windows, w, scalar)
+}
+
+/// Decompose the `Scalar` into the signed digits of
+/// `Scalar::to_radix_2w`.
+///
+/// # Panics
+/// If `w` is not in `4..=8`.
+pub fn to_signed_digits(scalar: &Scalar, w: usize) -> Vec<i8> {
+    scalar.to_radix_2w(w)
+}
+
+/// Compute `sum(a_i * 2^(w * i)) (mod l)` for the signed `digits`.
+///
+/// # Panics
+/// If `w` is not in `4..=8`.
+pub fn from_signed_digits(digits: &[i8], w: usize) -> Scalar {
+    assert!((4..=8).contains(&w), "The window width must be in 4..=8");
+    recompose(digits, w)
+}
+
+/// Check that `digits` is the signed decomposition of `scalar`:
+/// there are `Scalar::to_radix_2w_size_hint(w)` digits, all of them
+/// in `[-2^(w-1), 2^(w-1)]`, and they recompose to `scalar` as an
+/// integer.
+///
+/// # Panics
+/// If `w` is not in `4..=8`.
+pub fn check_signed_digits(scalar: &Scalar, digits: &[i8], w: usize) -> bool {
+    assert!((4..=8).contains(&w), "The window width must be in 4..=8");
+    let bound = 1i16 << (w - 1);
+    digits.len() == Scalar::to_radix_2w_size_hint(w)
+        && digits.iter().all(|digit| (*digit as i16).abs() <= bound)
+        && recomposes_to(digits, w, scalar)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use num::BigUint;
+    use rand::rngs::OsRng;
+
+    /// Get `x + l` as an integer, which recomposes to `x (mod l)`.
+    fn alias(x: u8) -> BigUint {
+        let l = BigUint::from_bytes_le(&Scalar::minus_one().to_bytes()) + 1u8;
+        l + x
+    }
+
+    /// Get the `count` LE digits of `value` in radix `2^w`.
+    fn digits_of(value: &BigUint, w: usize, count: usize) -> Vec<u8> {
+        let mask = BigUint::from((1u16 << w) - 1);
+        (0..count)
+            .map(|i| {
+                let digit = (value >> (w * i)) & &mask;
+                digit.to_bytes_le()[0]
+            })
+            .collect()
+    }
+
+    /// Recode the digits of `value` into `[-2^(w-1), 2^(w-1))`.
+    fn signed_digits_of(value: &BigUint, w: usize, count: usize) -> Vec<i8> {
+        let mut carry = 0i16;
+        digits_of(value, w, count)
+            .iter()
+            .map(|digit| {
+                let mut digit = *digit as i16 + carry;
+                carry = (digit >= 1 << (w - 1)) as i16;
+                digit -= carry << w;
+                digit as i8
+            })
+            .collect()
+    }
+
+    fn scalars() -> Vec<Scalar> {
+        vec![
+            Scalar::zero(),
+            Scalar::one(),
+            Scalar::minus_one(),
+            Scalar::random(&mut OsRng),
+        ]
+    }
+
+    #[test]
+    fn bits() {
+        for k in scalars().iter() {
+            let bits = to_bits(k);
+            assert_eq!(bits.len(), Scalar::BITS);
+            assert!(check_bits(k, &bits));
+
+            let mut flipped = bits.clone();
+            flipped[7] = !flipped[7];
+            assert!(!check_bits(k, &flipped));
+            // Extra zero bits recompose to the same value but aren't
+            // a valid decomposition.
+            let mut longer = bits.clone();
+            longer.push(false);
+            assert!(from_bits(&longer) == *k && !check_bits(k, &longer));
+        }
+
+        // The bits of `l` and `x + l` recompose to `0` and `x` modulo `l`.
+        for x in [0u8, 1, 5].iter() {
+            let bits: Vec<bool> = digits_of(&alias(*x), 1, Scalar::BITS)
+                .iter()
+                .map(|bit| *bit == 1)
+                .collect();
+            let k = Scalar::from(*x);
+            assert!(from_bits(&bits) == k && !check_bits(&k, &bits));
+        }
+    }
+
+    #[test]
+    fn windows() {
+        for k in scalars().iter() {
+            for w in 1..=8 {
+                let windows = to_windows(k, w);
+                assert_eq!(windows.len(), windows_count(w));
+                assert!(check_windows(k, &windows, w));
+            }
+        }
+
+        // `2^3` overflows a 3-bit window, even if `8 + 0 * 2^3`
+        // recomposes to the same value as `0 + 1 * 2^3`.
+        let k = Scalar::from(8u8);
+        let mut windows = vec![0u8; windows_count(3)];
+        windows[0] = 8;
+        assert!(from_windows(&windows, 3) == k && !check_windows(&k, &windows, 3));
+
+        for x in [0u8, 1, 5].iter() {
+            let k = Scalar::from(*x);
+            for w in 1..=8 {
+                let windows = digits_of(&alias(*x), w, windows_count(w));
+                assert!(from_windows(&windows, w) == k && !check_windows(&k, &windows, w));
+            }
+        }
+    }
+
+    #[test]
+    fn signed_digits() {
+        for k in scalars().iter() {
+            for w in 4..=8 {
+                let digits = to_signed_digits(k, w);
+                assert!(check_signed_digits(k, &digits, w));
+                assert!(!check_signed_digits(&(k + Scalar::one()), &digits, w));
+            }
+        }
+
+        let k = Scalar::from(100u8);
+        let mut digits = vec![0i8; Scalar::to_radix_2w_size_hint(4)];
+        digits[0] = 100;
+        assert!(from_signed_digits(&digits, 4) == k && !check_signed_digits(&k, &digits, 4));
+
+        for x in [0u8, 1, 5].iter() {
+            let k = Scalar::from(*x);
+            for w in 4..=8 {
+                let count = Scalar::to_radix_2w_size_hint(w);
+                let digits = signed_digits_of(&alias(*x), w, count);
+                assert!(from_signed_digits(&digits, w) == k);
+                assert!(!check_signed_digits(&k, &digits, w));
+            }
+        }
+    }
+}
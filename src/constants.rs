@@ -1,10 +1,12 @@
 //! Contains the curve-constants needed by different algorithm implementations.
 
 use crate::edwards::CompressedEdwardsY;
+use crate::montgomery::MontgomeryPoint;
 use crate::ristretto::CompressedRistretto;
 
-#[cfg(feature = "u64_backend")]
-pub use crate::backend::u64::constants::*;
+#[cfg(feature = "precomputed_tables")]
+pub use crate::backend::serial::basepoint_table::EDWARDS_BASEPOINT_TABLE;
+pub use crate::backend::serial::constants::*;
 
 /// Holds the value of the Curve basepoint, which has been constructed
 /// from taking `y-coodrinate = 3/5 (mod l)`.
@@ -15,7 +17,22 @@ pub const BASEPOINT_COMPRESSED: CompressedEdwardsY = CompressedEdwardsY([
     153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 9,
 ]);
 
+/// Identity of the curve on compressed format: `y = 1` with the
+/// x-sign bit set to `0`.
+pub const IDENTITY_COMPRESSED: CompressedEdwardsY = CompressedEdwardsY([
+    1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+]);
+
 /// Ristretto Basepoint on compressed format.
 pub const RISTRETTO_BASEPOINT_COMPRESSED: CompressedRistretto = CompressedRistretto([
     2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
 ]);
+
+/// Ristretto Identity on compressed format.
+pub const RISTRETTO_IDENTITY_COMPRESSED: CompressedRistretto = CompressedRistretto([0u8; 32]);
+
+/// Basepoint of the Montgomery form of the curve: `u = 4`, which
+/// corresponds to the `u-coordinate` of the Edwards `BASEPOINT`.
+pub const MONTGOMERY_BASEPOINT: MontgomeryPoint = MontgomeryPoint([
+    4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+]);
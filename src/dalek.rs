@@ -0,0 +1,311 @@
+//! Conversions between the types of this library and the ones of
+//! [curve25519-dalek](https://github.com/dalek-cryptography/curve25519-dalek).
+//!
+//! The base field of the Sonny curve is the scalar field of
+//! Ristretto255, so the coordinates and the compressed encodings
+//! of the Sonny points can be moved into `Ristretto255Scalar`s
+//! (and back) to be used inside circuits defined over Ristretto255.
+//!
+//! The Sonny `Scalar`s can also be moved into `Ristretto255Scalar`s,
+//! since the order of the Sonny subgroup is smaller than the one of
+//! Ristretto255. The opposite conversion only succeeds for the
+//! values under the Sonny order.
+//!
+//! Every conversion checks that the value is canonical on both
+//! sides and returns a `ConversionError` otherways, so the
+//! encodings never get silently reduced.
+//!
+//! # Examples
+//! ```rust
+//! use zerocaf::dalek::*;
+//! use zerocaf::constants::RISTRETTO_BASEPOINT;
+//! use zerocaf::field::FieldElement;
+//! use zerocaf::ristretto::CompressedRistretto;
+//! use zerocaf::scalar::Ristretto255Scalar;
+//!
+//! use core::convert::TryFrom;
+//!
+//! let compressed = RISTRETTO_BASEPOINT.compress();
+//! let s = Ristretto255Scalar::try_from(&compressed).unwrap();
+//! assert!(CompressedRistretto::try_from(s).unwrap() == compressed);
+//!
+//! let elems = [FieldElement::one(), FieldElement::minus_one()];
+//! let scalars = to_ristretto255_scalars(&elems).unwrap();
+//! assert!(from_ristretto255_scalars(&scalars).unwrap() == elems);
+//! ```
+
+use crate::errors::Error;
+use crate::field::FieldElement;
+use crate::ristretto::CompressedRistretto;
+use crate::scalar::{Ristretto255Scalar, Scalar};
+
+use core::convert::TryFrom;
+use core::fmt;
+
+/// Errors that can occur when converting values from or into
+/// `curve25519-dalek` types.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ConversionError {
+    /// The `FieldElement` is not reduced modulo `p`.
+    NonCanonicalFieldElement,
+    /// The `Ristretto255Scalar` is not reduced modulo `l`.
+    NonCanonicalScalar,
+    /// The bytes are not the canonical encoding of a
+    /// `CompressedRistretto` point.
+    NonCanonicalEncoding,
+    /// The Sonny `Scalar` is not reduced modulo its `l`.
+    NonCanonicalSonnyScalar,
+    /// The `Ristretto255Scalar` is not smaller than the order of
+    /// the Sonny subgroup.
+    ScalarOutOfRange,
+}
+
+impl fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ConversionError::NonCanonicalFieldElement => {
+                write!(f, "FieldElement is not reduced modulo p")
+            }
+            ConversionError::NonCanonicalScalar => {
+                write!(f, "Ristretto255Scalar is not reduced modulo l")
+            }
+            ConversionError::NonCanonicalEncoding => {
+                write!(f, "bytes are not a canonical CompressedRistretto encoding")
+            }
+            ConversionError::NonCanonicalSonnyScalar => {
+                write!(f, "Scalar is not reduced modulo l")
+            }
+            ConversionError::ScalarOutOfRange => {
+                write!(f, "Ristretto255Scalar is not smaller than the Sonny subgroup order")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConversionError {}
+
+impl From<ConversionError> for Error {
+    fn from(err: ConversionError) -> Error {
+        match err {
+            ConversionError::ScalarOutOfRange => Error::OutOfRange,
+            _ => Error::NonCanonicalEncoding,
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a FieldElement> for Ristretto255Scalar {
+    type Error = ConversionError;
+
+    /// Get the `Ristretto255Scalar` with the same value as the
+    /// given `FieldElement`.
+    ///
+    /// The `FieldElement`s returned by the arithmetic are always
+    /// reduced, so this only fails for the ones built from
+    /// unreduced limbs.
+    fn try_from(fe: &'a FieldElement) -> Result<Ristretto255Scalar, ConversionError> {
+        Ristretto255Scalar::from_canonical_bytes(fe.to_bytes())
+            .ok_or(ConversionError::NonCanonicalFieldElement)
+    }
+}
+
+impl TryFrom<FieldElement> for Ristretto255Scalar {
+    type Error = ConversionError;
+
+    /// Get the `Ristretto255Scalar` with the same value as the
+    /// given `FieldElement`, see `TryFrom<&FieldElement>`.
+    fn try_from(fe: FieldElement) -> Result<Ristretto255Scalar, ConversionError> {
+        Ristretto255Scalar::try_from(&fe)
+    }
+}
+
+impl TryFrom<Ristretto255Scalar> for FieldElement {
+    type Error = ConversionError;
+
+    /// Get the `FieldElement` with the same value as the given
+    /// `Ristretto255Scalar`.
+    ///
+    /// Scalars built with `Ristretto255Scalar::from_bits` can
+    /// hold values over `l`, and they get rejected here.
+    fn try_from(scalar: Ristretto255Scalar) -> Result<FieldElement, ConversionError> {
+        if !scalar.is_canonical() {
+            return Err(ConversionError::NonCanonicalScalar);
+        };
+        Ok(FieldElement::from(&scalar))
+    }
+}
+
+impl<'a> TryFrom<&'a CompressedRistretto> for Ristretto255Scalar {
+    type Error = ConversionError;
+
+    /// Get the encoded `s` value of the `CompressedRistretto` point
+    /// as a `Ristretto255Scalar`.
+    ///
+    /// This does not check that the encoding corresponds to a point,
+    /// just that it is a canonical field element.
+    fn try_from(point: &'a CompressedRistretto) -> Result<Ristretto255Scalar, ConversionError> {
+        Ristretto255Scalar::from_canonical_bytes(point.as_bytes())
+            .ok_or(ConversionError::NonCanonicalEncoding)
+    }
+}
+
+impl TryFrom<Ristretto255Scalar> for CompressedRistretto {
+    type Error = ConversionError;
+
+    /// Use the value of the `Ristretto255Scalar` as the `s` value
+    /// of a `CompressedRistretto` point.
+    ///
+    /// The result still needs to be decompressed to know if it is
+    /// a valid point.
+    fn try_from(scalar: Ristretto255Scalar) -> Result<CompressedRistretto, ConversionError> {
+        if !scalar.is_canonical() {
+            return Err(ConversionError::NonCanonicalScalar);
+        };
+        Ok(CompressedRistretto(scalar.to_bytes()))
+    }
+}
+
+impl<'a> TryFrom<&'a Scalar> for Ristretto255Scalar {
+    type Error = ConversionError;
+
+    /// Get the `Ristretto255Scalar` with the same value as the
+    /// given `Scalar`.
+    ///
+    /// Every reduced `Scalar` fits, so this only fails if the
+    /// `Scalar` is not reduced modulo `l`.
+    fn try_from(scalar: &'a Scalar) -> Result<Ristretto255Scalar, ConversionError> {
+        let bytes = scalar.to_bytes();
+        Scalar::from_canonical_bytes(&bytes)
+            .map_err(|_| ConversionError::NonCanonicalSonnyScalar)?;
+        // The Sonny order is smaller than the Ristretto255 one.
+        Ok(Ristretto255Scalar::from_bits(bytes))
+    }
+}
+
+impl TryFrom<Ristretto255Scalar> for Scalar {
+    type Error = ConversionError;
+
+    /// Get the Sonny `Scalar` with the same value as the given
+    /// `Ristretto255Scalar`.
+    ///
+    /// The value is never reduced modulo the Sonny order, so the
+    /// `Ristretto255Scalar`s greater or equal than it get rejected.
+    fn try_from(scalar: Ristretto255Scalar) -> Result<Scalar, ConversionError> {
+        if !scalar.is_canonical() {
+            return Err(ConversionError::NonCanonicalScalar);
+        };
+        Scalar::from_canonical_bytes(&scalar.to_bytes())
+            .map_err(|_| ConversionError::ScalarOutOfRange)
+    }
+}
+
+/// Convert a slice of `FieldElement`s into `Ristretto255Scalar`s.
+///
+/// Fails on the first non-canonical `FieldElement` found.
+pub fn to_ristretto255_scalars(
+    elems: &[FieldElement],
+) -> Result<Vec<Ristretto255Scalar>, ConversionError> {
+    elems.iter().map(Ristretto255Scalar::try_from).collect()
+}
+
+/// Convert a slice of `Ristretto255Scalar`s into `FieldElement`s.
+///
+/// Fails on the first non-canonical `Ristretto255Scalar` found.
+pub fn from_ristretto255_scalars(
+    scalars: &[Ristretto255Scalar],
+) -> Result<Vec<FieldElement>, ConversionError> {
+    scalars.iter().map(|s| FieldElement::try_from(*s)).collect()
+}
+
+/// Convert a slice of `CompressedRistretto` points into the
+/// `Ristretto255Scalar`s of their encodings.
+///
+/// Fails on the first non-canonical encoding found.
+pub fn compressed_to_ristretto255_scalars(
+    points: &[CompressedRistretto],
+) -> Result<Vec<Ristretto255Scalar>, ConversionError> {
+    points.iter().map(Ristretto255Scalar::try_from).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants;
+
+    use core::convert::TryInto;
+    use crate::ristretto::RistrettoPoint;
+
+    use rand::rngs::OsRng;
+
+    #[test]
+    fn field_element_roundtrip() {
+        let a = FieldElement::minus_one();
+        let s = Ristretto255Scalar::try_from(&a).unwrap();
+        assert!(s == -Ristretto255Scalar::one());
+        assert!(FieldElement::try_from(s).unwrap() == a);
+
+        // `p` itself is not reduced.
+        let p = constants::FIELD_L;
+        assert!(
+            Ristretto255Scalar::try_from(&p) == Err(ConversionError::NonCanonicalFieldElement)
+        );
+
+        let unreduced = Ristretto255Scalar::from_bits([0xff; 32]);
+        assert!(FieldElement::try_from(unreduced) == Err(ConversionError::NonCanonicalScalar));
+
+        // By value, and through `TryInto`.
+        let s: Result<Ristretto255Scalar, _> = a.try_into();
+        assert!(s.unwrap() == -Ristretto255Scalar::one());
+        let err = Ristretto255Scalar::try_from(p).unwrap_err();
+        assert!(Error::from(err) == Error::NonCanonicalEncoding);
+    }
+
+    #[test]
+    fn compressed_roundtrip() {
+        let point = constants::RISTRETTO_BASEPOINT * Scalar::random(&mut OsRng);
+        let compressed = point.compress();
+        let s = Ristretto255Scalar::try_from(&compressed).unwrap();
+        let back = CompressedRistretto::try_from(s).unwrap();
+        assert!(back.decompress().unwrap() == point);
+
+        assert!(
+            Ristretto255Scalar::try_from(&CompressedRistretto([0xff; 32]))
+                == Err(ConversionError::NonCanonicalEncoding)
+        );
+    }
+
+    #[test]
+    fn batch_conversions() {
+        let points: Vec<CompressedRistretto> = (0..4)
+            .map(|_| RistrettoPoint::new_random_point(&mut OsRng).compress())
+            .collect();
+        let scalars = compressed_to_ristretto255_scalars(&points).unwrap();
+        let elems = from_ristretto255_scalars(&scalars).unwrap();
+        assert!(to_ristretto255_scalars(&elems).unwrap() == scalars);
+
+        let mut elems = elems;
+        elems[2] = FieldElement([u64::MAX; 5]);
+        assert!(to_ristretto255_scalars(&elems).is_err());
+    }
+
+    #[test]
+    fn scalar_roundtrip() {
+        let k = Scalar::random(&mut OsRng);
+        let s = Ristretto255Scalar::try_from(&k).unwrap();
+        assert!(s.to_bytes() == k.to_bytes());
+        assert!(Scalar::try_from(s).unwrap() == k);
+
+        let minus_one = Ristretto255Scalar::try_from(&Scalar::minus_one()).unwrap();
+        assert!(Scalar::try_from(minus_one).unwrap() == Scalar::minus_one());
+
+        // Values in `[l_sonny, l_ristretto)` don't fit.
+        let l = Ristretto255Scalar::from_bits(constants::L.to_bytes());
+        assert!(Scalar::try_from(l) == Err(ConversionError::ScalarOutOfRange));
+        let minus_one = -Ristretto255Scalar::one();
+        assert!(Scalar::try_from(minus_one) == Err(ConversionError::ScalarOutOfRange));
+
+        let unreduced = Ristretto255Scalar::from_bits([0xff; 32]);
+        assert!(Scalar::try_from(unreduced) == Err(ConversionError::NonCanonicalScalar));
+        let unreduced = Ristretto255Scalar::try_from(&constants::L);
+        assert!(unreduced == Err(ConversionError::NonCanonicalSonnyScalar));
+    }
+}
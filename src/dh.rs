@@ -0,0 +1,277 @@
+//! Implementation of the Diffie-Hellman key exchange over the
+//! Montgomery form of the Sonny curve.
+//!
+//! The API follows the shape of `x25519-dalek`:
+//!
+//! - `EphemeralSecret`: single-use secret that gets consumed
+//!   by the key exchange.
+//! - `StaticSecret`: reusable secret for long-term keys.
+//! - `PublicKey`: the `u-coordinate` of `secret * B` where `B`
+//!   is `constants::MONTGOMERY_BASEPOINT`.
+//! - `SharedSecret`: the result of the key exchange.
+//!
+//! All of the scalar multiplications are performed using the
+//! x-only Montgomery ladder.
+//!
+//! The shared secret is `8 * secret * their_public`, so it never
+//! depends on `secret mod 8`: a peer sending a point of small order
+//! can't learn the low bits of a reused `StaticSecret`. Such a peer
+//! forces the shared secret to the identity (all-zero bytes), which
+//! `SharedSecret::was_contributory` reports.
+//!
+//! # Examples
+//! ```rust
+//! use zerocaf::dh::*;
+//!
+//! use rand::rngs::OsRng;
+//!
+//! let alice_secret = EphemeralSecret::new(&mut OsRng);
+//! let alice_public = PublicKey::from(&alice_secret);
+//!
+//! let bob_secret = StaticSecret::new(&mut OsRng);
+//! let bob_public = PublicKey::from(&bob_secret);
+//!
+//! let alice_shared = alice_secret.diffie_hellman(&bob_public);
+//! let bob_shared = bob_secret.diffie_hellman(&alice_public);
+//!
+//! assert!(alice_shared.as_bytes() == bob_shared.as_bytes());
+//! ```
+
+use crate::constants;
+use crate::montgomery::MontgomeryPoint;
+use crate::scalar::Scalar;
+
+use rand::{CryptoRng, RngCore};
+use subtle::ConstantTimeEq;
+use zeroize::Zeroize;
+
+/// Compute `8 * k * u` where `u` is the `u-coordinate` of a point
+/// of the Montgomery curve, using the Montgomery ladder.
+///
+/// Clearing the cofactor keeps the result independent of `k mod 8`
+/// and maps every point of small order to the identity (`u = 0`).
+pub fn diffie_hellman(k: &Scalar, u: &MontgomeryPoint) -> MontgomeryPoint {
+    u.mul_clear_cofactor(k)
+}
+
+/// A `PublicKey` is the `u-coordinate` of `secret * B`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct PublicKey(pub(crate) MontgomeryPoint);
+
+impl PublicKey {
+    /// Get the `u-coordinate` of the `PublicKey` as LE bytes.
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0 .0
+    }
+
+    /// Get the `u-coordinate` of the `PublicKey` as LE bytes.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.0 .0
+    }
+}
+
+impl From<[u8; 32]> for PublicKey {
+    /// Build a `PublicKey` from the LE bytes of its `u-coordinate`.
+    fn from(bytes: [u8; 32]) -> PublicKey {
+        PublicKey(MontgomeryPoint(bytes))
+    }
+}
+
+impl<'a> From<&'a EphemeralSecret> for PublicKey {
+    /// Compute the `PublicKey` of the given `EphemeralSecret`.
+    fn from(secret: &'a EphemeralSecret) -> PublicKey {
+        PublicKey(constants::MONTGOMERY_BASEPOINT * secret.0)
+    }
+}
+
+impl<'a> From<&'a StaticSecret> for PublicKey {
+    /// Compute the `PublicKey` of the given `StaticSecret`.
+    fn from(secret: &'a StaticSecret) -> PublicKey {
+        PublicKey(constants::MONTGOMERY_BASEPOINT * secret.0)
+    }
+}
+
+/// A single-use secret for the key exchange.
+///
+/// The inner `Scalar` is zeroized when the secret is dropped.
+///
+/// `EphemeralSecret::diffie_hellman` consumes `self`, so the same
+/// secret can't be used on more than one key exchange.
+pub struct EphemeralSecret(pub(crate) Scalar);
+
+impl EphemeralSecret {
+    /// Generate a new random `EphemeralSecret`.
+    ///
+    /// By `rng` we mean any Rng that implements: `RngCore` + `CryptoRng`.
+    pub fn new<T>(rng: &mut T) -> EphemeralSecret
+    where
+        T: RngCore + CryptoRng,
+    {
+        EphemeralSecret(Scalar::random(rng))
+    }
+
+    /// Perform the key exchange with the given `PublicKey`.
+    pub fn diffie_hellman(self, their_public: &PublicKey) -> SharedSecret {
+        SharedSecret(diffie_hellman(&self.0, &their_public.0))
+    }
+}
+
+define_redacted_debug!(EphemeralSecret);
+
+impl Drop for EphemeralSecret {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+/// A reusable secret for the key exchange, intended for long-term keys.
+#[derive(Clone)]
+pub struct StaticSecret(pub(crate) Scalar);
+
+impl StaticSecret {
+    /// Generate a new random `StaticSecret`.
+    ///
+    /// By `rng` we mean any Rng that implements: `RngCore` + `CryptoRng`.
+    pub fn new<T>(rng: &mut T) -> StaticSecret
+    where
+        T: RngCore + CryptoRng,
+    {
+        StaticSecret(Scalar::random(rng))
+    }
+
+    /// Perform the key exchange with the given `PublicKey`.
+    pub fn diffie_hellman(&self, their_public: &PublicKey) -> SharedSecret {
+        SharedSecret(diffie_hellman(&self.0, &their_public.0))
+    }
+
+    /// Get the `StaticSecret` as LE bytes.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.0.to_bytes()
+    }
+}
+
+impl From<Scalar> for StaticSecret {
+    /// Build a `StaticSecret` from a `Scalar`.
+    fn from(scalar: Scalar) -> StaticSecret {
+        StaticSecret(scalar)
+    }
+}
+
+define_redacted_debug!(StaticSecret);
+
+impl Drop for StaticSecret {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+/// The result of a Diffie-Hellman key exchange.
+///
+/// It should be hashed before being used as a symmetric key, and
+/// rejected when it isn't contributory.
+pub struct SharedSecret(pub(crate) MontgomeryPoint);
+
+impl SharedSecret {
+    /// Get the `u-coordinate` of the `SharedSecret` as LE bytes.
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0 .0
+    }
+
+    /// Check whether the key exchange was contributory, ie. whether
+    /// the `SharedSecret` isn't the all-zero output that any `PublicKey`
+    /// of small order forces regardless of our secret.
+    ///
+    /// This check is constant time.
+    pub fn was_contributory(&self) -> bool {
+        !bool::from(self.0 .0.ct_eq(&[0u8; 32]))
+    }
+}
+
+define_redacted_debug!(SharedSecret);
+
+impl Drop for SharedSecret {
+    fn drop(&mut self) {
+        self.0 .0.zeroize();
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use crate::constants;
+    use crate::edwards::{CompressedEdwardsY, EdwardsPoint};
+    use crate::field::FieldElement;
+    use crate::traits::ops::Double;
+    use crate::traits::Identity;
+
+    use rand::rngs::OsRng;
+
+    #[test]
+    fn key_agreement() {
+        let alice = StaticSecret::new(&mut OsRng);
+        let bob = EphemeralSecret::new(&mut OsRng);
+        let alice_pk = PublicKey::from(&alice);
+        let bob_pk = PublicKey::from(&bob);
+
+        let alice_shared = alice.diffie_hellman(&bob_pk);
+        let bob_shared = bob.diffie_hellman(&alice_pk);
+        assert!(alice_shared.as_bytes() == bob_shared.as_bytes());
+        assert!(alice_shared.was_contributory());
+    }
+
+    /// Get the points of small order, from the torsion components
+    /// of a few points of the curve.
+    fn torsion_points() -> Vec<EdwardsPoint> {
+        let mut points = Vec::new();
+        for y in 2u64..64 {
+            let bytes = FieldElement::from(y).to_bytes();
+            if let Some(P) = CompressedEdwardsY::from_slice(&bytes).decompress() {
+                points.push(P * constants::L);
+            }
+        }
+        assert!(points.iter().all(|T| T.is_small_order()));
+        // Check that some of them have order 8.
+        assert!(points
+            .iter()
+            .any(|T| T.double().double() != EdwardsPoint::identity()));
+        points
+    }
+
+    #[test]
+    fn low_order_points_are_not_contributory() {
+        let secret = StaticSecret::new(&mut OsRng);
+        let mut keys: Vec<PublicKey> = torsion_points()
+            .iter()
+            .map(|T| PublicKey(T.to_montgomery()))
+            .collect();
+        keys.push(PublicKey::from([0u8; 32]));
+
+        for pk in keys {
+            let shared = secret.diffie_hellman(&pk);
+            assert!(shared.as_bytes() == &[0u8; 32]);
+            assert!(!shared.was_contributory());
+        }
+    }
+
+    #[test]
+    fn torsion_components_are_ignored() {
+        let secret = StaticSecret::new(&mut OsRng);
+        let Q = constants::BASEPOINT * Scalar::random(&mut OsRng);
+        let shared = secret.diffie_hellman(&PublicKey(Q.to_montgomery()));
+        assert!(shared.was_contributory());
+
+        for T in torsion_points() {
+            let pk = PublicKey((Q + T).to_montgomery());
+            assert!(secret.diffie_hellman(&pk).as_bytes() == shared.as_bytes());
+        }
+    }
+
+    #[test]
+    fn public_key_matches_edwards() {
+        let k = Scalar::from(987654321u64);
+        let pk = PublicKey::from(&StaticSecret::from(k));
+        let expected = (constants::BASEPOINT * k).to_montgomery();
+        assert!(pk.as_bytes() == expected.as_bytes());
+    }
+}
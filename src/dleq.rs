@@ -0,0 +1,342 @@
+#![allow(non_snake_case)]
+//! Implementation of Chaum-Pedersen proofs of discrete-log
+//! equality over the Ristretto-encoded prime-order group of the
+//! Sonny curve.
+//!
+//! Given the generators `G`, `H` and the points `A = x * G`,
+//! `B = x * H`, the prover shows that `log_G(A) == log_H(B)`
+//! without revealing `x`:
+//!
+//! - `R1 = k * G` and `R2 = k * H` for a nonce `k` derived from
+//!   `x` and the whole statement `G || H || A || B` (see the `nonce`
+//!   module).
+//! - `c = H("zerocaf-dleq" || G || H || A || B || R1 || R2) (mod l)`.
+//! - `s = k + c * x (mod l)`.
+//!
+//! The proof is `(R1, R2, s)`, and the verifier accepts if
+//! `s * G == R1 + c * A` and `s * H == R2 + c * B`.
+//!
+//! The proof carries the commitments instead of the challenge,
+//! so many proofs can be checked at once with `verify_batch`.
+//!
+//! # Examples
+//! ```rust
+//! use zerocaf::constants::RISTRETTO_BASEPOINT;
+//! use zerocaf::dleq::*;
+//! use zerocaf::ristretto::RistrettoPoint;
+//! use zerocaf::scalar::Scalar;
+//!
+//! use rand::rngs::OsRng;
+//!
+//! let x = Scalar::random(&mut OsRng);
+//! let H = RistrettoPoint::new_random_point(&mut OsRng);
+//! let statement = DleqStatement::new(&x, &RISTRETTO_BASEPOINT, &H);
+//!
+//! let proof = prove(&statement, &x);
+//! assert!(verify(&statement, &proof));
+//!
+//! // Proofs can be sent as 96 bytes.
+//! assert!(DleqProof::from_bytes(&proof.to_bytes()).unwrap() == proof);
+//! ```
+
+use crate::edwards::vartime_multiscalar_mul;
+use crate::nonce::{derive_hedged_nonce, derive_nonce};
+use crate::ristretto::{CompressedRistretto, RistrettoPoint};
+use crate::scalar::Scalar;
+use crate::traits::{Identity, MultiscalarMul};
+
+use rand::{thread_rng, CryptoRng, RngCore};
+use sha2::{Digest, Sha512};
+
+/// Domain separator used on the challenge and nonce derivations.
+pub(crate) const DLEQ_DOMAIN: &[u8] = b"zerocaf-dleq";
+
+/// The public statement `log_G(A) == log_H(B)`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DleqStatement {
+    pub G: RistrettoPoint,
+    pub H: RistrettoPoint,
+    pub A: RistrettoPoint,
+    pub B: RistrettoPoint,
+}
+
+impl DleqStatement {
+    /// Build the statement `(G, H, x * G, x * H)` of the
+    /// secret `x`.
+    pub fn new(x: &Scalar, G: &RistrettoPoint, H: &RistrettoPoint) -> DleqStatement {
+        DleqStatement {
+            G: *G,
+            H: *H,
+            A: RistrettoPoint::multiscalar_mul(&[*x], &[*G]),
+            B: RistrettoPoint::multiscalar_mul(&[*x], &[*H]),
+        }
+    }
+
+    /// Encode the whole statement `G || H || A || B`.
+    fn statement_bytes(&self) -> [u8; 128] {
+        let mut bytes = [0u8; 128];
+        bytes[..32].copy_from_slice(&self.G.compress().as_bytes());
+        bytes[32..64].copy_from_slice(&self.H.compress().as_bytes());
+        bytes[64..96].copy_from_slice(&self.A.compress().as_bytes());
+        bytes[96..].copy_from_slice(&self.B.compress().as_bytes());
+        bytes
+    }
+}
+
+/// A DLEQ proof `(R1, R2, s)`, where `R1`, `R2` are the nonce
+/// commitments and `s` the response to the challenge.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DleqProof {
+    pub R1: CompressedRistretto,
+    pub R2: CompressedRistretto,
+    pub s: Scalar,
+}
+
+impl DleqProof {
+    /// Encode the `DleqProof` as 96 bytes: `R1 || R2 || s`.
+    pub fn to_bytes(&self) -> [u8; 96] {
+        let mut bytes = [0u8; 96];
+        bytes[..32].copy_from_slice(&self.R1.as_bytes());
+        bytes[32..64].copy_from_slice(&self.R2.as_bytes());
+        bytes[64..].copy_from_slice(&self.s.to_bytes());
+        bytes
+    }
+
+    /// Decode a `DleqProof` from 96 bytes: `R1 || R2 || s`.
+    ///
+    /// # Returns
+    /// - `None` if `s` is not the canonical encoding of a `Scalar`.
+    /// - `Some(DleqProof)` otherways.
+    pub fn from_bytes(bytes: &[u8; 96]) -> Option<DleqProof> {
+        let R1 = CompressedRistretto::copy_from_slice(&bytes[..32]);
+        let R2 = CompressedRistretto::copy_from_slice(&bytes[32..64]);
+        let mut s_bytes = [0u8; 32];
+        s_bytes.copy_from_slice(&bytes[64..]);
+        let s = Scalar::from_canonical_bytes(&s_bytes).ok()?;
+
+        Some(DleqProof { R1, R2, s })
+    }
+}
+
+/// Compute the challenge
+/// `c = H("zerocaf-dleq" || G || H || A || B || R1 || R2) (mod l)`.
+pub(crate) fn challenge(
+    statement: &DleqStatement,
+    R1: &CompressedRistretto,
+    R2: &CompressedRistretto,
+) -> Scalar {
+    let hasher = Sha512::new()
+        .chain(DLEQ_DOMAIN)
+        .chain(&statement.statement_bytes()[..])
+        .chain(R1.as_bytes())
+        .chain(R2.as_bytes());
+    Scalar::from_hash(hasher)
+}
+
+/// Compute the `DleqProof` with the given nonce.
+fn prove_with_nonce(statement: &DleqStatement, x: &Scalar, k: Scalar) -> DleqProof {
+    let R1 = RistrettoPoint::multiscalar_mul(&[k], &[statement.G]).compress();
+    let R2 = RistrettoPoint::multiscalar_mul(&[k], &[statement.H]).compress();
+
+    let c = challenge(statement, &R1, &R2);
+    DleqProof {
+        R1,
+        R2,
+        s: k + c * *x,
+    }
+}
+
+/// Prove that `log_G(A) == log_H(B) == x` for the given
+/// `statement`.
+///
+/// The nonce is derived deterministically from `x` and the whole
+/// statement, the same input the challenge hashes, so proving a
+/// statement with a wrong `A` or `B` can't reuse the nonce of
+/// another proof under a different challenge.
+pub fn prove(statement: &DleqStatement, x: &Scalar) -> DleqProof {
+    let k = derive_nonce(DLEQ_DOMAIN, x, &statement.statement_bytes(), None);
+    prove_with_nonce(statement, x, k)
+}
+
+/// Prove that `log_G(A) == log_H(B) == x` for the given
+/// `statement`, mixing extra randomness taken from `rng` into
+/// the nonce derivation.
+///
+/// By `rng` we mean any Rng that implements: `RngCore` + `CryptoRng`.
+pub fn prove_with_rng<T>(statement: &DleqStatement, x: &Scalar, rng: &mut T) -> DleqProof
+where
+    T: RngCore + CryptoRng,
+{
+    let k = derive_hedged_nonce(DLEQ_DOMAIN, x, &statement.statement_bytes(), rng);
+    prove_with_nonce(statement, x, k)
+}
+
+/// Verify a `DleqProof` for the given `statement` by checking
+/// that `s * G == R1 + c * A` and `s * H == R2 + c * B`.
+///
+/// # Returns
+/// - `true` if the proof is valid.
+/// - `false` if the proof is invalid or `R1`, `R2` can't be
+///   decompressed.
+pub fn verify(statement: &DleqStatement, proof: &DleqProof) -> bool {
+    let (R1, R2) = match (proof.R1.decompress(), proof.R2.decompress()) {
+        (Some(R1), Some(R2)) => (R1, R2),
+        _ => return false,
+    };
+
+    let c = challenge(statement, &proof.R1, &proof.R2);
+    statement.G * proof.s == R1 + statement.A * c && statement.H * proof.s == R2 + statement.B * c
+}
+
+/// Verify a batch of `DleqProof`s, where `proofs[i]` is checked
+/// against `statements[i]`.
+///
+/// For random weights `z_i`, `w_i` the verifier checks that:
+/// `sum z_i * (s_i * G_i - R1_i - c_i * A_i) + w_i * (s_i * H_i - R2_i - c_i * B_i) == 0`
+/// with a single multiscalar multiplication, which is much
+/// faster than verifying every `DleqProof` separately.
+///
+/// The weights are sampled from the thread-local rng.
+///
+/// # Returns
+/// - `true` if all of the proofs are valid.
+/// - `false` if any proof is invalid, any commitment can't be
+///   decompressed or the input lengths don't match.
+pub fn verify_batch(statements: &[DleqStatement], proofs: &[DleqProof]) -> bool {
+    if statements.len() != proofs.len() {
+        return false;
+    };
+
+    let mut rng = thread_rng();
+    let n = proofs.len();
+    let mut scalars = Vec::with_capacity(6 * n);
+    let mut points = Vec::with_capacity(6 * n);
+
+    for (statement, proof) in statements.iter().zip(proofs) {
+        let (R1, R2) = match (proof.R1.decompress(), proof.R2.decompress()) {
+            (Some(R1), Some(R2)) => (R1, R2),
+            _ => return false,
+        };
+        let z = Scalar::random(&mut rng);
+        let w = Scalar::random(&mut rng);
+        let c = challenge(statement, &proof.R1, &proof.R2);
+
+        scalars.extend_from_slice(&[z * proof.s, -z, -(z * c)]);
+        points.extend_from_slice(&[statement.G, R1, statement.A]);
+        scalars.extend_from_slice(&[w * proof.s, -w, -(w * c)]);
+        points.extend_from_slice(&[statement.H, R2, statement.B]);
+    }
+
+    vartime_multiscalar_mul(&scalars, &points) == RistrettoPoint::identity()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants;
+
+    use rand::rngs::OsRng;
+
+    fn random_statement() -> (Scalar, DleqStatement) {
+        let x = Scalar::random(&mut OsRng);
+        let H = RistrettoPoint::new_random_point(&mut OsRng);
+        (x, DleqStatement::new(&x, &constants::RISTRETTO_BASEPOINT, &H))
+    }
+
+    #[test]
+    fn prove_and_verify() {
+        let (x, statement) = random_statement();
+
+        let proof = prove(&statement, &x);
+        assert!(verify(&statement, &proof));
+        assert!(prove(&statement, &x) == proof);
+
+        let hedged = prove_with_rng(&statement, &x, &mut OsRng);
+        assert!(hedged != proof);
+        assert!(verify(&statement, &hedged));
+    }
+
+    #[test]
+    fn reject_invalid_proofs() {
+        let (x, statement) = random_statement();
+        let proof = prove(&statement, &x);
+
+        // Different discrete logs.
+        let unequal = DleqStatement {
+            B: statement.B + statement.H,
+            ..statement
+        };
+        assert!(!verify(&unequal, &prove(&unequal, &x)));
+        // Swapped generators.
+        let swapped = DleqStatement {
+            G: statement.H,
+            H: statement.G,
+            A: statement.B,
+            B: statement.A,
+        };
+        assert!(!verify(&swapped, &proof));
+        // Tampered response.
+        let tampered = DleqProof {
+            s: proof.s + Scalar::one(),
+            ..proof
+        };
+        assert!(!verify(&statement, &tampered));
+    }
+
+    #[test]
+    fn nonces_bind_the_whole_statement() {
+        let (x, statement) = random_statement();
+        let proof = prove(&statement, &x);
+
+        // A caller asking for a proof of a statement with the same
+        // generators but a different `A` gets a different nonce, so
+        // the two responses can't be combined into `x`.
+        let other = DleqStatement {
+            A: statement.A + statement.G,
+            ..statement
+        };
+        let other_proof = prove(&other, &x);
+        assert!(other_proof.R1 != proof.R1 && other_proof.R2 != proof.R2);
+        let c = challenge(&statement, &proof.R1, &proof.R2);
+        let c_other = challenge(&other, &other_proof.R1, &other_proof.R2);
+        let extracted = (proof.s - other_proof.s) * (c - c_other).unpack().invert().pack();
+        assert!(extracted != x);
+
+        let other = DleqStatement {
+            B: statement.B + statement.H,
+            ..statement
+        };
+        assert!(prove(&other, &x).R1 != proof.R1);
+    }
+
+    #[test]
+    fn batch_verification() {
+        let (secrets, statements): (Vec<Scalar>, Vec<DleqStatement>) =
+            (0..8).map(|_| random_statement()).unzip();
+        let mut proofs: Vec<DleqProof> = statements
+            .iter()
+            .zip(secrets.iter())
+            .map(|(statement, x)| prove(statement, x))
+            .collect();
+
+        assert!(verify_batch(&statements, &proofs));
+        assert!(verify_batch(&[], &[]));
+        // Length mismatch.
+        assert!(!verify_batch(&statements[1..], &proofs));
+        // A single invalid proof invalidates the batch.
+        proofs[5].s += Scalar::one();
+        assert!(!verify_batch(&statements, &proofs));
+    }
+
+    #[test]
+    fn proof_encoding() {
+        let (x, statement) = random_statement();
+        let proof = prove(&statement, &x);
+        assert!(DleqProof::from_bytes(&proof.to_bytes()).unwrap() == proof);
+
+        // Non-canonical `s` gets rejected.
+        let mut bytes = proof.to_bytes();
+        bytes[95] = 0xff;
+        assert!(DleqProof::from_bytes(&bytes).is_none());
+    }
+}
@@ -71,20 +71,29 @@
 //! ```
 
 use crate::constants;
+use crate::errors::DecodingError;
 use crate::field::FieldElement;
 use crate::montgomery::MontgomeryPoint;
 use crate::scalar::Scalar;
-use crate::traits::{ops::*, Identity, ValidityCheck};
+use crate::traits::{
+    ops::*, Identity, MultiscalarMul, ValidityCheck, VartimeMultiscalarMul,
+    VartimePrecomputedMultiscalarMul,
+};
 use crate::ristretto::RistrettoPoint;
+use crate::window::{self, LookupTable};
 
-use rand::{CryptoRng, Rng};
-use subtle::{Choice, ConstantTimeEq};
+use rand::{CryptoRng, Rng, RngCore};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 
 use std::default::Default;
 use std::fmt::Debug;
 
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
 use core::ops::{Index, IndexMut};
-use std::ops::{Add, Mul, Neg, Sub};
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 
 // ------------- Common Point fn declarations ------------- //
 
@@ -108,7 +117,7 @@ where
     let mut n = *scalar;
     let mut Q = T::identity();
 
-    while n != Scalar::zero() {
+    while n.is_zero().unwrap_u8() == 0u8 {
         if !n.is_even() {
             Q = &Q + &N;
         };
@@ -119,6 +128,29 @@ where
     Q
 }
 
+/// Scalar multiplication with scalar blinding as a countermeasure
+/// against power and EM side channels.
+///
+/// The scalar gets split as `k = (k - r) + r` for a fresh random
+/// `r` on every call, so the scalars being multiplied are uniformly
+/// distributed and uncorrelated between calls. Both shares go
+/// through the constant-time `MultiscalarMul`, since leaking them
+/// would leak `k` as well.
+///
+/// The result is `k * P` only when `P` has prime order `l`,
+/// since the split holds modulo `l`. Use it over `RistrettoPoint`s
+/// or over `EdwardsPoint`s of the prime-order subgroup.
+///
+/// By `rng` we mean any Rng that implements: `RngCore` + `CryptoRng`.
+pub fn blinded_mul<T, R>(point: &T, scalar: &Scalar, rng: &mut R) -> T
+where
+    T: MultiscalarMul<Point = T>,
+    R: RngCore + CryptoRng,
+{
+    let r = Scalar::random(rng);
+    T::multiscalar_mul(&[*scalar - r, r], [point, point].iter().copied())
+}
+
 pub fn ltr_bin_mul<'a, 'b, T>(point: &'a T, scalar: &'b Scalar) -> T
 where
     for<'c> &'c T: Add<Output = T> + Double<Output = T>,
@@ -170,6 +202,136 @@ pub fn window_naf_mul(scalar: &Scalar, window_width: u8) -> RistrettoPoint {
     Q
 }
 
+/// Compute `sum(scalars[i] * points[i])` in variable time, with a
+/// single chain of doublings shared by all of the terms (Straus'
+/// method with a window of 1 bit).
+///
+/// The additions performed depend on the bits of the scalars, so it
+/// must only be used with public scalars, eg. when verifying. Use
+/// `MultiscalarMul` when any of them is secret.
+///
+/// Panics if `scalars` and `points` have different lengths.
+#[cfg(not(feature = "parallel"))]
+pub fn vartime_multiscalar_mul<T>(scalars: &[Scalar], points: &[T]) -> T
+where
+    for<'c> &'c T: Add<Output = T> + Double<Output = T>,
+    T: Identity,
+{
+    assert_eq!(scalars.len(), points.len());
+    straus_mul(scalars, points)
+}
+
+/// Compute `sum(scalars[i] * points[i])` in variable time, with a
+/// single chain of doublings shared by all of the terms (Straus'
+/// method with a window of 1 bit).
+///
+/// The additions performed depend on the bits of the scalars, so it
+/// must only be used with public scalars, eg. when verifying. Use
+/// `MultiscalarMul` when any of them is secret.
+///
+/// The terms are split into chunks of `MSM_CHUNK` terms whose
+/// partial sums get computed on the rayon thread pool.
+///
+/// Panics if `scalars` and `points` have different lengths.
+#[cfg(feature = "parallel")]
+pub fn vartime_multiscalar_mul<T>(scalars: &[Scalar], points: &[T]) -> T
+where
+    for<'c> &'c T: Add<Output = T> + Double<Output = T>,
+    T: Identity + Send + Sync,
+{
+    use rayon::prelude::*;
+
+    assert_eq!(scalars.len(), points.len());
+    if scalars.len() <= MSM_CHUNK {
+        return straus_mul(scalars, points);
+    };
+    scalars
+        .par_chunks(MSM_CHUNK)
+        .zip(points.par_chunks(MSM_CHUNK))
+        .map(|(scalars, points)| straus_mul(scalars, points))
+        .reduce(T::identity, |a, b| &a + &b)
+}
+
+/// Number of terms of each of the partial sums computed in
+/// parallel by `vartime_multiscalar_mul`.
+#[cfg(feature = "parallel")]
+const MSM_CHUNK: usize = 64;
+
+/// Sequential, variable-time Straus' method over terms of the same
+/// length.
+fn straus_mul<T>(scalars: &[Scalar], points: &[T]) -> T
+where
+    for<'c> &'c T: Add<Output = T> + Double<Output = T>,
+    T: Identity,
+{
+    let scalars_bits: Vec<[u8; 256]> = scalars.iter().map(|k| k.into_bits()).collect();
+
+    let mut Q = T::identity();
+    for i in (0..256).rev() {
+        Q = Q.double();
+        for (bits, point) in scalars_bits.iter().zip(points.iter()) {
+            if bits[i] == 1u8 {
+                Q = &Q + point;
+            };
+        }
+    }
+    Q
+}
+
+/// Window width of the signed digits used by the constant-time
+/// `MultiscalarMul` implementation.
+const MSM_CT_WINDOW: usize = 4;
+
+impl MultiscalarMul for EdwardsPoint {
+    type Point = EdwardsPoint;
+
+    /// Straus' method over signed radix-16 digits: each point gets a
+    /// table of `[P, 2P, ..., 8P]`, and every lookup scans the whole
+    /// table, so neither the doublings and additions performed nor
+    /// the memory accesses depend on the scalars.
+    fn multiscalar_mul<I, J>(scalars: I, points: J) -> EdwardsPoint
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Scalar>,
+        J: IntoIterator,
+        J::Item: Borrow<EdwardsPoint>,
+    {
+        let digits: Vec<Vec<i8>> = scalars
+            .into_iter()
+            .map(|k| k.borrow().to_radix_2w(MSM_CT_WINDOW))
+            .collect();
+        let tables: Vec<LookupTable<EdwardsPoint, 8>> = points
+            .into_iter()
+            .map(|point| LookupTable::from_multiples_of(point.borrow()))
+            .collect();
+        assert_eq!(digits.len(), tables.len());
+
+        let mut Q = EdwardsPoint::identity();
+        for i in (0..Scalar::to_radix_2w_size_hint(MSM_CT_WINDOW)).rev() {
+            Q = Q.double_n(MSM_CT_WINDOW as u32);
+            for (digits, table) in digits.iter().zip(tables.iter()) {
+                Q += table.select(digits[i]);
+            }
+        }
+        Q
+    }
+}
+
+impl VartimeMultiscalarMul for EdwardsPoint {
+    type Point = EdwardsPoint;
+
+    fn optional_multiscalar_mul<I, J>(scalars: I, points: J) -> Option<EdwardsPoint>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Scalar>,
+        J: IntoIterator<Item = Option<EdwardsPoint>>,
+    {
+        let scalars: Vec<Scalar> = scalars.into_iter().map(|k| *k.borrow()).collect();
+        let points: Vec<EdwardsPoint> = points.into_iter().collect::<Option<_>>()?;
+        Some(vartime_multiscalar_mul(&scalars, &points))
+    }
+}
+
 /// Multiply by the cofactor: return (8 P).
 pub fn mul_by_cofactor<'a, T>(point: &'a T) -> T
 where
@@ -207,7 +369,11 @@ pub(self) fn find_xx(y: &FieldElement) -> FieldElement {
 
 /// The first 255 bits of a `CompressedEdwardsY` represent the
 /// (y)-coordinate.  The high bit of the 32nd byte gives the sign of (x).
+///
+/// It is `#[repr(transparent)]`, so it has the layout of a
+/// `[u8; 32]` and can cross FFI boundaries as such.
 #[derive(Copy, Clone)]
+#[repr(transparent)]
 pub struct CompressedEdwardsY(pub [u8; 32]);
 
 impl ConstantTimeEq for CompressedEdwardsY {
@@ -224,6 +390,19 @@ impl PartialEq for CompressedEdwardsY {
 
 impl Eq for CompressedEdwardsY {}
 
+impl Hash for CompressedEdwardsY {
+    /// Hash the bytes of the encoding, consistently with `PartialEq`.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+define_hex_formatting!(CompressedEdwardsY, to_bytes);
+define_hex_parsing!(CompressedEdwardsY, |bytes: &[u8; 32]| {
+    let point = CompressedEdwardsY(*bytes);
+    point.decompress_strict().map(|_| point)
+});
+
 impl Index<usize> for CompressedEdwardsY {
     type Output = u8;
     fn index(&self, _index: usize) -> &u8 {
@@ -306,6 +485,11 @@ impl CompressedEdwardsY {
         self.0
     }
 
+    /// Copy the bytes of the `CompressedEdwardsY` into the given buffer.
+    pub fn write_bytes_into(&self, out: &mut [u8; 32]) {
+        out.copy_from_slice(&self.0);
+    }
+
     /// Attempt to decompress to an `EdwardsPoint`.
     ///
     /// Returns `None` if the input is not the Y-coordinate of a
@@ -324,6 +508,80 @@ impl CompressedEdwardsY {
         // Otherways, return `None`.
         EdwardsPoint::new_from_y_coord(&FieldElement::from_bytes(&y.to_bytes()), sign)
     }
+
+    /// Decompress the point and compute `[2^k] * P` on it, which is
+    /// much cheaper than a scalar multiplication by `2^k`.
+    ///
+    /// Returns `None` if the input is not the Y-coordinate of a
+    /// curve point.
+    pub fn mul_by_pow_2(&self, k: u32) -> Option<EdwardsPoint> {
+        self.decompress().map(|point| point.double_n(k))
+    }
+
+    /// Attempt to decompress to an `EdwardsPoint`, checking that
+    /// the bytes are the canonical encoding of the point.
+    ///
+    /// Unlike `CompressedEdwardsY::decompress`, the unused high bits
+    /// of the Y-coordinate are not masked, and encodings of `x = 0`
+    /// with the sign bit set get rejected.
+    pub fn decompress_strict(&self) -> Result<EdwardsPoint, DecodingError> {
+        let mut y = self.0;
+        y[31] &= 0b0111_1111;
+        FieldElement::from_canonical_bytes(&y)
+            .map_err(|_| DecodingError::NonCanonicalPoint)?;
+
+        let point = self.decompress().ok_or(DecodingError::InvalidPoint)?;
+        if point.compress() != *self {
+            return Err(DecodingError::NonCanonicalPoint);
+        };
+        Ok(point)
+    }
+
+    /// Decompress all of the `points` at once, sharing a single
+    /// field inversion across the batch.
+    ///
+    /// Returns `None` if any of the inputs is not the Y-coordinate
+    /// of a curve point.
+    // The sign bit of the encoding follows `mod_sqrt`.
+    #[allow(deprecated)]
+    pub fn batch_decompress(points: &[CompressedEdwardsY]) -> Option<Vec<EdwardsPoint>> {
+        let one = FieldElement::one();
+        let coords: Vec<(FieldElement, Choice)> = points
+            .iter()
+            .map(|point| {
+                let sign = Choice::from(point[31] >> 7);
+                let mut y = point.to_bytes();
+                y[31] &= 0b0000_1111;
+                (FieldElement::from_bytes(&y), sign)
+            })
+            .collect();
+
+        // xx = (y^2 - 1) / (d*y^2 - a)
+        let mut denominators: Vec<FieldElement> = coords
+            .iter()
+            .map(|(y, _)| (constants::EDWARDS_D * y.square()) - constants::EDWARDS_A)
+            .collect();
+        // A zero would break the batch inversion.
+        if denominators.iter().any(|den| bool::from(den.is_zero())) {
+            return None;
+        };
+        FieldElement::batch_invert(&mut denominators);
+
+        coords
+            .iter()
+            .zip(denominators.iter())
+            .map(|((y, sign), inv)| {
+                let xx = (y.square() - one) * inv;
+                xx.mod_sqrt(*sign).map(|x| {
+                    EdwardsPoint::from(ProjectivePoint {
+                        X: x,
+                        Y: *y,
+                        Z: one,
+                    })
+                })
+            })
+            .collect()
+    }
 }
 
 /// An `EdwardsPoint` represents a point on the Sonny Curve which is expressed
@@ -371,6 +629,34 @@ impl PartialEq for EdwardsPoint {
 
 impl Eq for EdwardsPoint {}
 
+impl ConditionallySelectable for EdwardsPoint {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        EdwardsPoint {
+            X: FieldElement::conditional_select(&a.X, &b.X, choice),
+            Y: FieldElement::conditional_select(&a.Y, &b.Y, choice),
+            Z: FieldElement::conditional_select(&a.Z, &b.Z, choice),
+            T: FieldElement::conditional_select(&a.T, &b.T, choice),
+        }
+    }
+
+    /// Overwrite `self` with `other` if `choice == 1`, coordinate by
+    /// coordinate.
+    fn conditional_assign(&mut self, other: &Self, choice: Choice) {
+        self.X.conditional_assign(&other.X, choice);
+        self.Y.conditional_assign(&other.Y, choice);
+        self.Z.conditional_assign(&other.Z, choice);
+        self.T.conditional_assign(&other.T, choice);
+    }
+
+    /// Swap `a` and `b` if `choice == 1`, coordinate by coordinate.
+    fn conditional_swap(a: &mut Self, b: &mut Self, choice: Choice) {
+        FieldElement::conditional_swap(&mut a.X, &mut b.X, choice);
+        FieldElement::conditional_swap(&mut a.Y, &mut b.Y, choice);
+        FieldElement::conditional_swap(&mut a.Z, &mut b.Z, choice);
+        FieldElement::conditional_swap(&mut a.T, &mut b.T, choice);
+    }
+}
+
 impl Default for EdwardsPoint {
     /// Returns the default EdwardsPoint Extended Coordinates: (0, 1, 1, 0).
     fn default() -> EdwardsPoint {
@@ -576,6 +862,25 @@ impl Mul<Scalar> for EdwardsPoint {
     }
 }
 
+define_mixed_add_variants!(LHS = EdwardsPoint, RHS = EdwardsPoint, Output = EdwardsPoint);
+define_mixed_sub_variants!(LHS = EdwardsPoint, RHS = EdwardsPoint, Output = EdwardsPoint);
+define_mixed_mul_variants!(LHS = EdwardsPoint, RHS = Scalar, Output = EdwardsPoint);
+define_add_assign_variants!(LHS = EdwardsPoint, RHS = EdwardsPoint);
+define_sub_assign_variants!(LHS = EdwardsPoint, RHS = EdwardsPoint);
+define_mul_assign_variants!(LHS = EdwardsPoint, RHS = Scalar);
+
+impl Sum for EdwardsPoint {
+    fn sum<I: Iterator<Item = EdwardsPoint>>(iter: I) -> EdwardsPoint {
+        iter.fold(EdwardsPoint::identity(), |acc, point| acc + point)
+    }
+}
+
+impl<'a> Sum<&'a EdwardsPoint> for EdwardsPoint {
+    fn sum<I: Iterator<Item = &'a EdwardsPoint>>(iter: I) -> EdwardsPoint {
+        iter.fold(EdwardsPoint::identity(), |acc, point| acc + point)
+    }
+}
+
 impl<'a> Double for &'a EdwardsPoint {
     type Output = EdwardsPoint;
     /// Performs the point doubling operation
@@ -592,10 +897,152 @@ impl<'a> Double for &'a EdwardsPoint {
 }
 
 impl EdwardsPoint {
+    /// Returns `constants::BASEPOINT`, the generator of the
+    /// prime-order subgroup.
+    pub fn generator() -> EdwardsPoint {
+        constants::BASEPOINT
+    }
+
+    /// Get `slice[index]` in constant time, for tables that must be
+    /// read at a secret index, see `window::ct_select_from`.
+    ///
+    /// # Returns
+    /// - `None` if `index` is out of the bounds of the slice.
+    /// - `Some(slice[index])` otherways.
+    ///
+    /// # Panics
+    /// If the slice is empty.
+    pub fn ct_select_from(slice: &[EdwardsPoint], index: usize) -> CtOption<EdwardsPoint> {
+        window::ct_select_from(slice, index)
+    }
+
+    /// Compute `[8] * self` with three doublings.
+    pub fn mul_by_cofactor(&self) -> EdwardsPoint {
+        self.double_n(3)
+    }
+
+    /// Compute `[2^k] * self` by doubling `k` times.
+    ///
+    /// The doublings run on projective coordinates, which are
+    /// cheaper than the extended ones, and `T` only gets recomputed
+    /// at the end.
+    pub fn double_n(&self, k: u32) -> EdwardsPoint {
+        if k == 0 {
+            return *self;
+        }
+        EdwardsPoint::from(ProjectivePoint::from(*self).double_n(k))
+    }
+
+    /// Compute `[k] * self` for a small `k` with a left-to-right
+    /// double-and-add over its 64 bits, skipping the leading zeros,
+    /// so there's no need to go through the `Scalar` machinery.
+    ///
+    /// `k` leaks through the timing, so it's meant for public
+    /// constants like indices or cofactor multiples.
+    pub fn mul_small(&self, k: u64) -> EdwardsPoint {
+        let mut Q = EdwardsPoint::identity();
+        for i in (0..64 - k.leading_zeros()).rev() {
+            Q = Q.double();
+            if (k >> i) & 1 == 1 {
+                Q += self;
+            };
+        }
+        Q
+    }
+
+    /// Compute `scalar * B` in constant time, where `B` is the
+    /// `BASEPOINT`, with the static `EDWARDS_BASEPOINT_TABLE`.
+    #[cfg(feature = "precomputed_tables")]
+    pub fn mul_base(scalar: &Scalar) -> EdwardsPoint {
+        constants::EDWARDS_BASEPOINT_TABLE.mul(scalar)
+    }
+
+    /// Compute `scalar * B` in constant time, where `B` is the
+    /// `BASEPOINT`, computing the multiples of `B` on the fly.
+    #[cfg(not(feature = "precomputed_tables"))]
+    pub fn mul_base(scalar: &Scalar) -> EdwardsPoint {
+        EdwardsPoint::multiscalar_mul(&[*scalar], &[constants::BASEPOINT])
+    }
+
+    /// Compute `scalar * self` in variable time, with a width-5
+    /// sliding window over a table of the odd multiples
+    /// `[P, 3P, ..., 15P]`.
+    ///
+    /// Both the scalar and the point leak through the timing, so it
+    /// must only be used with public inputs, eg. when verifying.
+    pub fn vartime_mul(&self, scalar: &Scalar) -> EdwardsPoint {
+        let naf = non_adjacent_form(scalar, VARTIME_MUL_NAF_WIDTH);
+        let top = match naf.iter().rposition(|digit| *digit != 0) {
+            Some(top) => top,
+            None => return EdwardsPoint::identity(),
+        };
+        let table = odd_multiples(self, 1 << (VARTIME_MUL_NAF_WIDTH - 2));
+
+        let mut Q = EdwardsPoint::identity();
+        for i in (0..=top).rev() {
+            Q = Q.double();
+            add_naf_digit(&mut Q, &table, naf[i]);
+        }
+        Q
+    }
+
+    /// Check whether the point is on the small-order torsion
+    /// subgroup, ie. whether `[8] * self` is the identity.
+    ///
+    /// This check is not constant time.
+    pub fn is_small_order(&self) -> bool {
+        self.mul_by_cofactor() == EdwardsPoint::identity()
+    }
+
+    /// Check whether the point has no small-order component, ie.
+    /// whether it lives on the prime-order subgroup generated by
+    /// `constants::BASEPOINT`, by checking that `[l] * self` is
+    /// the identity.
+    ///
+    /// This check is not constant time.
+    pub fn is_torsion_free(&self) -> bool {
+        self * constants::BASEPOINT_ORDER == EdwardsPoint::identity()
+    }
+
+    /// Check whether the point lives on the prime-order subgroup,
+    /// ie. whether `[l] * self` is the identity, in constant time.
+    ///
+    /// Unlike `EdwardsPoint::is_torsion_free`, every bit of `l`
+    /// costs one doubling and one addition of either `self` or the
+    /// identity, so neither the control flow nor the result path
+    /// depend on the point. Use it on attacker-controlled points
+    /// whose validity must not leak.
+    pub fn is_in_prime_order_subgroup(&self) -> Choice {
+        let identity = EdwardsPoint::identity();
+        let mut Q = identity;
+        for bit in constants::BASEPOINT_ORDER.bits().rev() {
+            Q = Q.double();
+            Q += EdwardsPoint::conditional_select(&identity, self, bit);
+        }
+        Q.ct_eq(&identity)
+    }
+
+    /// Check whether the point has order exactly `l`, ie. whether it
+    /// is a generator of the prime-order subgroup: it is torsion free
+    /// and it is not the identity.
+    ///
+    /// This check is not constant time.
+    pub fn has_prime_order(&self) -> bool {
+        *self != EdwardsPoint::identity() && self.is_torsion_free()
+    }
+
     /// Convert this `EdwardsPoint` on the Edwards model to the
     /// corresponding `MontgomeryPoint` on the Montgomery model.
+    ///
+    /// The birational map is `u = (1 + y) / (1 - y) = (Z + Y) / (Z - Y)`.
+    /// The identity gets mapped to `u = 0`.
     pub fn to_montgomery(&self) -> MontgomeryPoint {
-        unimplemented!()
+        let U = self.Z + self.Y;
+        let W = self.Z - self.Y;
+
+        // The identity, `W = 0`, gets mapped to `u = 0`.
+        let u = U * W.invert_nonzero().unwrap_or(FieldElement::zero());
+        MontgomeryPoint(u.to_bytes())
     }
 
     /// Prints the 4Coset where the input `EdwardsPoint`
@@ -603,13 +1050,15 @@ impl EdwardsPoint {
     pub fn coset4(&self) -> [EdwardsPoint; 4] {
         [
             *self,
-            self + &constants::FOUR_COSET_GROUP[0],
-            self + &constants::FOUR_COSET_GROUP[1],
-            self + &constants::FOUR_COSET_GROUP[2],
+            self + constants::FOUR_COSET_GROUP[0],
+            self + constants::FOUR_COSET_GROUP[1],
+            self + constants::FOUR_COSET_GROUP[2],
         ]
     }
 
     /// Compress this point to `CompressedEdwardsY` format.
+    // The sign bit of the encoding follows `mod_sqrt`.
+    #[allow(deprecated)]
     pub fn compress(&self) -> CompressedEdwardsY {
         // Get the Affine point coordinates and compress
         // the point using them.
@@ -628,6 +1077,50 @@ impl EdwardsPoint {
         CompressedEdwardsY::from_slice(&compr)
     }
 
+    /// Compress all of the `points`, on the rayon thread pool if
+    /// the `parallel` feature is on.
+    pub fn compress_batch(points: &[EdwardsPoint]) -> Vec<CompressedEdwardsY> {
+        #[cfg(feature = "parallel")]
+        {
+            use rayon::prelude::*;
+            points.par_iter().map(|point| point.compress()).collect()
+        }
+        #[cfg(not(feature = "parallel"))]
+        points.iter().map(|point| point.compress()).collect()
+    }
+
+    /// Convert the point to affine coordinates.
+    pub fn to_affine(&self) -> AffinePoint {
+        AffinePoint::from(*self)
+    }
+
+    /// Encode the point in the uncompressed `x || y` format, see
+    /// `AffinePoint::to_uncompressed`.
+    pub fn to_uncompressed(&self) -> [u8; 64] {
+        self.to_affine().to_uncompressed()
+    }
+
+    /// Decode a point from the uncompressed `x || y` format, see
+    /// `AffinePoint::from_uncompressed`.
+    pub fn from_uncompressed(bytes: &[u8; 64]) -> Result<EdwardsPoint, DecodingError> {
+        AffinePoint::from_uncompressed(bytes).map(EdwardsPoint::from)
+    }
+
+    /// Convert all of the `points` to affine coordinates, sharing a
+    /// single field inversion across the batch.
+    pub fn batch_normalize(points: &[EdwardsPoint]) -> Vec<AffinePoint> {
+        let mut zinvs: Vec<FieldElement> = points.iter().map(|point| point.Z).collect();
+        FieldElement::batch_invert(&mut zinvs);
+        points
+            .iter()
+            .zip(zinvs.iter())
+            .map(|(point, zinv)| AffinePoint {
+                X: point.X * zinv,
+                Y: point.Y * zinv,
+            })
+            .collect()
+    }
+
     /// This function tries to build a Point over the Sonny Curve from
     /// a `Y` coordinate and a Choice that determines the sign of the `X`
     /// coordinate that the user wants to use.
@@ -663,6 +1156,63 @@ impl EdwardsPoint {
     }
 }
 
+#[cfg(feature = "group")]
+impl group::Group for EdwardsPoint {
+    type Scalar = Scalar;
+
+    /// Sample random `y-coordinates` until one of them
+    /// decompresses into a point that is not the identity.
+    fn random(mut rng: impl RngCore) -> EdwardsPoint {
+        loop {
+            let mut bytes = [0u8; 32];
+            rng.fill_bytes(&mut bytes);
+            if let Some(point) = CompressedEdwardsY(bytes).decompress() {
+                if point != <EdwardsPoint as Identity>::identity() {
+                    return point;
+                };
+            };
+        }
+    }
+
+    fn identity() -> EdwardsPoint {
+        <EdwardsPoint as Identity>::identity()
+    }
+
+    /// Returns `constants::BASEPOINT`, the generator of
+    /// the prime-order subgroup.
+    fn generator() -> EdwardsPoint {
+        constants::BASEPOINT
+    }
+
+    fn is_identity(&self) -> Choice {
+        self.ct_eq(&<EdwardsPoint as Identity>::identity())
+    }
+
+    fn double(&self) -> EdwardsPoint {
+        Double::double(self)
+    }
+}
+
+#[cfg(feature = "group")]
+impl group::GroupEncoding for EdwardsPoint {
+    type Repr = [u8; 32];
+
+    fn from_bytes(bytes: &[u8; 32]) -> CtOption<EdwardsPoint> {
+        match CompressedEdwardsY(*bytes).decompress() {
+            Some(point) => CtOption::new(point, Choice::from(1u8)),
+            None => CtOption::new(<EdwardsPoint as Identity>::identity(), Choice::from(0u8)),
+        }
+    }
+
+    fn from_bytes_unchecked(bytes: &[u8; 32]) -> CtOption<EdwardsPoint> {
+        <EdwardsPoint as group::GroupEncoding>::from_bytes(bytes)
+    }
+
+    fn to_bytes(&self) -> [u8; 32] {
+        self.compress().to_bytes()
+    }
+}
+
 /// A `ProjectivePoint` represents a point on the Sonny Curve expressed
 /// over the Twisted Edwards Projective Coordinates eg. (X:Y:Z).
 ///  
@@ -931,7 +1481,7 @@ impl<'a> Double for &'a ProjectivePoint {
         let E = constants::EDWARDS_A * C;
         let F = E + D;
         let H = self.Z.square();
-        let J = F - (FieldElement::from(2u8) * H);
+        let J = F - (H * 2u64);
 
         ProjectivePoint {
             X: ((B - C) - D) * J,
@@ -942,6 +1492,15 @@ impl<'a> Double for &'a ProjectivePoint {
 }
 
 impl ProjectivePoint {
+    /// Compute `[2^k] * self` by doubling `k` times.
+    pub fn double_n(&self, k: u32) -> ProjectivePoint {
+        let mut P = *self;
+        for _ in 0..k {
+            P = P.double();
+        }
+        P
+    }
+
     /// This function tries to build a Point over the Sonny Curve from
     /// a `Y` coordinate and a Choice that determines the sign of the `X`
     /// coordinate that the user wants to use.
@@ -959,6 +1518,8 @@ impl ProjectivePoint {
     /// # Returns
     /// `Some(ProjectivePoint)` if there exists a result for the `mod_sqrt`
     /// and `None` if the resulting `x^2` isn't a QR modulo `FIELD_L`.
+    // The sign bit of the encoding follows `mod_sqrt`.
+    #[allow(deprecated)]
     pub fn new_from_y_coord(y: &FieldElement, sign: Choice) -> Option<ProjectivePoint> {
         let one = FieldElement::one();
         let x: FieldElement;
@@ -1055,6 +1616,49 @@ impl PartialEq for AffinePoint {
 
 impl Eq for AffinePoint {}
 
+impl AffinePoint {
+    /// Compute `[2^k] * self` with `k` projective doublings,
+    /// which is much cheaper than a scalar multiplication by `2^k`.
+    pub fn mul_by_pow_2(&self, k: u32) -> EdwardsPoint {
+        let P = ProjectivePoint {
+            X: self.X,
+            Y: self.Y,
+            Z: FieldElement::one(),
+        };
+        EdwardsPoint::from(P.double_n(k))
+    }
+
+    /// Encode the point as the 64 bytes `x || y`, with both
+    /// coordinates given as their canonical LE encodings.
+    ///
+    /// Unlike the compressed format, decoding it back doesn't need
+    /// any square root.
+    pub fn to_uncompressed(&self) -> [u8; 64] {
+        let mut bytes = [0u8; 64];
+        bytes[..32].copy_from_slice(&self.X.to_bytes());
+        bytes[32..].copy_from_slice(&self.Y.to_bytes());
+        bytes
+    }
+
+    /// Decode a point from its uncompressed `x || y` encoding,
+    /// checking that both coordinates are canonical and that the
+    /// point lies on the curve.
+    pub fn from_uncompressed(bytes: &[u8; 64]) -> Result<AffinePoint, DecodingError> {
+        let mut x = [0u8; 32];
+        let mut y = [0u8; 32];
+        x.copy_from_slice(&bytes[..32]);
+        y.copy_from_slice(&bytes[32..]);
+        let point = AffinePoint {
+            X: FieldElement::from_canonical_bytes(&x)?,
+            Y: FieldElement::from_canonical_bytes(&y)?,
+        };
+        if !bool::from(point.is_valid()) {
+            return Err(DecodingError::InvalidPoint);
+        };
+        Ok(point)
+    }
+}
+
 impl ValidityCheck for AffinePoint {
     /// Verifies if the curve equation holds given the
     /// (X, Y) coordinates of a point in Affine Coordinates.
@@ -1083,7 +1687,7 @@ impl From<EdwardsPoint> for AffinePoint {
     /// Huseyin Hisil, Kenneth Koon-Ho Wong, Gary Carter,
     /// and Ed Dawson.
     fn from(point: EdwardsPoint) -> AffinePoint {
-        let Zinv = point.Z.inverse();
+        let Zinv = point.Z.invert_nonzero().unwrap();
         AffinePoint {
             X: point.X * Zinv,
             Y: point.Y * Zinv,
@@ -1101,7 +1705,7 @@ impl From<ProjectivePoint> for AffinePoint {
     /// Huseyin Hisil, Kenneth Koon-Ho Wong, Gary Carter,
     /// and Ed Dawson.
     fn from(point: ProjectivePoint) -> AffinePoint {
-        let Zinv = point.Z.inverse();
+        let Zinv = point.Z.invert_nonzero().unwrap();
         AffinePoint {
             X: point.X * Zinv,
             Y: point.Y * Zinv,
@@ -1132,6 +1736,413 @@ impl Neg for AffinePoint {
     }
 }
 
+// ---------------- Basepoint Tables ---------------- //
+
+/// Length of the header of the serialized tables: a 4-byte tag,
+/// the window width and the number of tables or points as a LE u32.
+const TABLE_HEADER_LEN: usize = 9;
+
+/// Encode the header of a serialized table.
+fn table_header(tag: &[u8; 4], width: usize, count: usize) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(TABLE_HEADER_LEN);
+    bytes.extend_from_slice(tag);
+    bytes.push(width as u8);
+    bytes.extend_from_slice(&(count as u32).to_le_bytes());
+    bytes
+}
+
+/// Check the header of a serialized table and return the number of
+/// tables or points that it announces together with the encoded
+/// points.
+fn read_table_header<'a>(
+    bytes: &'a [u8],
+    tag: &[u8; 4],
+    width: usize,
+) -> Result<(usize, &'a [u8]), DecodingError> {
+    if bytes.len() < TABLE_HEADER_LEN {
+        return Err(DecodingError::InvalidLength);
+    };
+    if bytes[..4] != tag[..] || bytes[4] as usize != width {
+        return Err(DecodingError::MalformedTable);
+    };
+    let mut count = [0u8; 4];
+    count.copy_from_slice(&bytes[5..TABLE_HEADER_LEN]);
+    Ok((u32::from_le_bytes(count) as usize, &bytes[TABLE_HEADER_LEN..]))
+}
+
+/// Append the uncompressed encodings of the `points` to `bytes`,
+/// normalizing all of them with a single inversion.
+fn write_table_points(bytes: &mut Vec<u8>, points: &[EdwardsPoint]) {
+    for point in EdwardsPoint::batch_normalize(points) {
+        bytes.extend_from_slice(&point.to_uncompressed());
+    }
+}
+
+/// Decode the points of a serialized table, checking that there are
+/// exactly `count` of them.
+fn read_table_points(bytes: &[u8], count: usize) -> Result<Vec<EdwardsPoint>, DecodingError> {
+    // `count` comes from the header, so the product may overflow
+    // on 32-bit targets.
+    count
+        .checked_mul(64)
+        .filter(|len| *len == bytes.len())
+        .ok_or(DecodingError::InvalidLength)?;
+    bytes
+        .chunks(64)
+        .map(|chunk| {
+            let mut buf = [0u8; 64];
+            buf.copy_from_slice(chunk);
+            EdwardsPoint::from_uncompressed(&buf)
+        })
+        .collect()
+}
+
+/// Number of tables of multiples held by the basepoint tables of
+/// window width `w`: one for every pair of signed digits.
+const fn tables_count(w: usize) -> usize {
+    Scalar::to_radix_2w_size_hint(w).div_ceil(2)
+}
+
+macro_rules! define_basepoint_table {
+    ($name:ident, window = $w:expr, entries = $entries:expr, $doc:expr) => {
+        #[doc = $doc]
+        ///
+        /// The scalar is recoded with signed digits `a_i` in radix
+        /// `2^w`, and the table `j` holds the multiples
+        /// `[1..2^(w-1)] * 2^(2wj) * B`. So the odd digits are added
+        /// first, then the sum gets multiplied by `2^w` and finally
+        /// the even digits are added. All of the lookups are
+        /// constant-time.
+        #[derive(Clone)]
+        pub struct $name(pub(crate) [LookupTable<EdwardsPoint, $entries>; tables_count($w)]);
+
+        impl $name {
+            /// Window width of the table, in bits.
+            pub const WINDOW: usize = $w;
+
+            /// Precompute the multiples of `basepoint` used by the table.
+            pub fn create(basepoint: &EdwardsPoint) -> $name {
+                let empty = LookupTable([EdwardsPoint::identity(); $entries]);
+                let mut tables = [empty; tables_count($w)];
+                let mut base = *basepoint;
+                for table in tables.iter_mut() {
+                    *table = LookupTable::from_multiples_of(&base);
+                    base = base.double_n(2 * $w);
+                }
+                $name(tables)
+            }
+
+            /// Encode the table in a stable format, so it can be built
+            /// once and loaded back with `from_bytes`: the tag
+            /// `"zcbt"`, the window width as a byte and the number of
+            /// tables as a LE u32, followed by the uncompressed
+            /// encodings of all of the points (see
+            /// `AffinePoint::to_uncompressed`).
+            pub fn to_bytes(&self) -> Vec<u8> {
+                let mut bytes = table_header(b"zcbt", $w, self.0.len());
+                let points: Vec<EdwardsPoint> =
+                    self.0.iter().flat_map(|table| table.0.iter().copied()).collect();
+                write_table_points(&mut bytes, &points);
+                bytes
+            }
+
+            /// Decode a table encoded with `to_bytes`.
+            ///
+            /// Only the encodings get checked, not that the points
+            /// are the right multiples of the basepoint, so the bytes
+            /// must come from a trusted source.
+            pub fn from_bytes(bytes: &[u8]) -> Result<$name, DecodingError> {
+                let (count, points) = read_table_header(bytes, b"zcbt", $w)?;
+                if count != tables_count($w) {
+                    return Err(DecodingError::MalformedTable);
+                };
+                let points = read_table_points(points, count * $entries)?;
+                let empty = LookupTable([EdwardsPoint::identity(); $entries]);
+                let mut tables = [empty; tables_count($w)];
+                for (table, chunk) in tables.iter_mut().zip(points.chunks($entries)) {
+                    table.0.copy_from_slice(chunk);
+                }
+                Ok($name(tables))
+            }
+
+            /// Get the basepoint of the table.
+            pub fn basepoint(&self) -> EdwardsPoint {
+                *self.0[0].point()
+            }
+
+            /// Compute `scalar * B`, where `B` is the basepoint of
+            /// the table.
+            pub fn mul(&self, scalar: &Scalar) -> EdwardsPoint {
+                let digits = scalar.to_radix_2w($w);
+
+                let mut P = EdwardsPoint::identity();
+                for i in (1..digits.len()).step_by(2) {
+                    P = &P + &self.0[i / 2].select(digits[i]);
+                }
+                P = P.double_n($w);
+                for i in (0..digits.len()).step_by(2) {
+                    P = &P + &self.0[i / 2].select(digits[i]);
+                }
+                P
+            }
+        }
+
+        impl<'a, 'b> Mul<&'b Scalar> for &'a $name {
+            type Output = EdwardsPoint;
+            /// Compute `scalar * B` with the precomputed table.
+            fn mul(self, scalar: &'b Scalar) -> EdwardsPoint {
+                $name::mul(self, scalar)
+            }
+        }
+
+        impl<'a, 'b> Mul<&'a $name> for &'b Scalar {
+            type Output = EdwardsPoint;
+            /// Compute `scalar * B` with the precomputed table.
+            fn mul(self, table: &'a $name) -> EdwardsPoint {
+                table.mul(self)
+            }
+        }
+
+        impl Debug for $name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                write!(f, "{}({:?})", stringify!($name), self.basepoint())
+            }
+        }
+    };
+}
+
+define_basepoint_table!(
+    EdwardsBasepointTableRadix16,
+    window = 4,
+    entries = 8,
+    "A precomputed table of 33 x 8 multiples of a basepoint (~42 KB)."
+);
+define_basepoint_table!(
+    EdwardsBasepointTableRadix32,
+    window = 5,
+    entries = 16,
+    "A precomputed table of 27 x 16 multiples of a basepoint (~69 KB)."
+);
+define_basepoint_table!(
+    EdwardsBasepointTableRadix64,
+    window = 6,
+    entries = 32,
+    "A precomputed table of 22 x 32 multiples of a basepoint (~113 KB)."
+);
+define_basepoint_table!(
+    EdwardsBasepointTableRadix128,
+    window = 7,
+    entries = 64,
+    "A precomputed table of 19 x 64 multiples of a basepoint (~195 KB)."
+);
+define_basepoint_table!(
+    EdwardsBasepointTableRadix256,
+    window = 8,
+    entries = 128,
+    "A precomputed table of 17 x 128 multiples of a basepoint (~348 KB)."
+);
+
+/// The default precomputed basepoint table.
+///
+/// The bigger radixes need less additions per multiplication, at
+/// the cost of a bigger table and of longer constant-time scans on
+/// every lookup, so the fastest one depends on the target:
+///
+/// | Table                           | Size    | Additions |
+/// |---------------------------------|---------|-----------|
+/// | `EdwardsBasepointTableRadix16`  | ~42 KB  | 65        |
+/// | `EdwardsBasepointTableRadix32`  | ~69 KB  | 53        |
+/// | `EdwardsBasepointTableRadix64`  | ~113 KB | 44        |
+/// | `EdwardsBasepointTableRadix128` | ~195 KB | 38        |
+/// | `EdwardsBasepointTableRadix256` | ~348 KB | 33        |
+pub type EdwardsBasepointTable = EdwardsBasepointTableRadix16;
+
+// ---------------- Precomputed Multiscalar Mul ---------------- //
+
+/// Window width of the NAFs of the scalars of the static points of
+/// a `VartimeEdwardsPrecomputation`.
+const STATIC_NAF_WIDTH: usize = 8;
+
+/// Window width of the NAFs of the scalars of the dynamic points of
+/// a `VartimeEdwardsPrecomputation`.
+const DYNAMIC_NAF_WIDTH: usize = 5;
+
+/// Window width of the NAF used by `EdwardsPoint::vartime_mul`.
+const VARTIME_MUL_NAF_WIDTH: usize = 5;
+
+/// Compute the width-`w` NAF of a `Scalar`: the digits are either
+/// zero or odd and in `(-2^(w-1), 2^(w-1))`, and any `w` consecutive
+/// digits hold at most one non-zero value.
+///
+/// `w` must be in `2..=8`.
+fn non_adjacent_form(scalar: &Scalar, w: usize) -> [i8; 257] {
+    let bytes = scalar.to_bytes();
+    let mut words = [0u64; 5];
+    for (i, word) in words.iter_mut().take(4).enumerate() {
+        let mut buf = [0u8; 8];
+        buf.copy_from_slice(&bytes[8 * i..8 * i + 8]);
+        *word = u64::from_le_bytes(buf);
+    }
+
+    let width = 1u64 << w;
+    let window_mask = width - 1;
+
+    let mut naf = [0i8; 257];
+    let mut pos = 0;
+    let mut carry = 0;
+    while pos < 257 {
+        let (idx, bit) = (pos / 64, pos % 64);
+        let bit_buf = if bit < 64 - w {
+            words[idx] >> bit
+        } else {
+            (words[idx] >> bit) | (words[1 + idx] << (64 - bit))
+        };
+        let window = carry + (bit_buf & window_mask);
+        if window & 1 == 0 {
+            pos += 1;
+            continue;
+        };
+        if window < width / 2 {
+            carry = 0;
+            naf[pos] = window as i8;
+        } else {
+            carry = 1;
+            naf[pos] = (window as i16 - width as i16) as i8;
+        };
+        pos += w;
+    }
+    naf
+}
+
+/// Compute the table of odd multiples `[P, 3P, 5P, ..., (2n - 1)P]`.
+fn odd_multiples(point: &EdwardsPoint, n: usize) -> Vec<EdwardsPoint> {
+    let P2 = point.double();
+    let mut table = Vec::with_capacity(n);
+    table.push(*point);
+    for i in 1..n {
+        table.push(table[i - 1] + P2);
+    }
+    table
+}
+
+/// Add `digit * P` to `Q` given the table of odd multiples of `P`.
+fn add_naf_digit(Q: &mut EdwardsPoint, table: &[EdwardsPoint], digit: i8) {
+    match digit.cmp(&0) {
+        Ordering::Greater => *Q += table[(digit / 2) as usize],
+        Ordering::Less => *Q -= table[(-digit / 2) as usize],
+        Ordering::Equal => (),
+    }
+}
+
+/// Precomputed tables of odd multiples of a set of static points
+/// for the `VartimePrecomputedMultiscalarMul` trait.
+///
+/// The scalars are recoded in width-8 NAF for the static points and
+/// in width-5 NAF for the dynamic ones, and all of the terms share
+/// a single chain of doublings.
+#[derive(Clone)]
+pub struct VartimeEdwardsPrecomputation(Vec<Vec<EdwardsPoint>>);
+
+impl VartimeEdwardsPrecomputation {
+    /// Encode the precomputation in a stable format, so it can be
+    /// built once and loaded back with `from_bytes`: the tag `"zcmp"`,
+    /// the NAF width as a byte and the number of static points as a
+    /// LE u32, followed by the uncompressed encodings of the odd
+    /// multiples of every point (see `AffinePoint::to_uncompressed`).
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = table_header(b"zcmp", STATIC_NAF_WIDTH, self.0.len());
+        let points: Vec<EdwardsPoint> = self.0.iter().flatten().copied().collect();
+        write_table_points(&mut bytes, &points);
+        bytes
+    }
+
+    /// Decode a precomputation encoded with `to_bytes`.
+    ///
+    /// Only the encodings get checked, not that the points are the
+    /// right multiples of the static points, so the bytes must come
+    /// from a trusted source.
+    pub fn from_bytes(bytes: &[u8]) -> Result<VartimeEdwardsPrecomputation, DecodingError> {
+        let entries = 1 << (STATIC_NAF_WIDTH - 2);
+        let (count, points) = read_table_header(bytes, b"zcmp", STATIC_NAF_WIDTH)?;
+        let count = count
+            .checked_mul(entries)
+            .ok_or(DecodingError::InvalidLength)?;
+        let points = read_table_points(points, count)?;
+        Ok(VartimeEdwardsPrecomputation(
+            points.chunks(entries).map(|chunk| chunk.to_vec()).collect(),
+        ))
+    }
+}
+
+impl VartimePrecomputedMultiscalarMul for VartimeEdwardsPrecomputation {
+    type Point = EdwardsPoint;
+
+    fn new<I>(static_points: I) -> VartimeEdwardsPrecomputation
+    where
+        I: IntoIterator,
+        I::Item: Borrow<EdwardsPoint>,
+    {
+        VartimeEdwardsPrecomputation(
+            static_points
+                .into_iter()
+                .map(|point| odd_multiples(point.borrow(), 1 << (STATIC_NAF_WIDTH - 2)))
+                .collect(),
+        )
+    }
+
+    fn optional_mixed_multiscalar_mul<I, J, K>(
+        &self,
+        static_scalars: I,
+        dynamic_scalars: J,
+        dynamic_points: K,
+    ) -> Option<EdwardsPoint>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Scalar>,
+        J: IntoIterator,
+        J::Item: Borrow<Scalar>,
+        K: IntoIterator<Item = Option<EdwardsPoint>>,
+    {
+        let static_nafs: Vec<[i8; 257]> = static_scalars
+            .into_iter()
+            .map(|k| non_adjacent_form(k.borrow(), STATIC_NAF_WIDTH))
+            .collect();
+        let dynamic_nafs: Vec<[i8; 257]> = dynamic_scalars
+            .into_iter()
+            .map(|k| non_adjacent_form(k.borrow(), DYNAMIC_NAF_WIDTH))
+            .collect();
+        let dynamic_tables: Vec<Vec<EdwardsPoint>> = dynamic_points
+            .into_iter()
+            .map(|point| point.map(|P| odd_multiples(&P, 1 << (DYNAMIC_NAF_WIDTH - 2))))
+            .collect::<Option<_>>()?;
+        assert_eq!(static_nafs.len(), self.0.len());
+        assert_eq!(dynamic_nafs.len(), dynamic_tables.len());
+
+        // Skip the doublings of the identity.
+        let top = static_nafs
+            .iter()
+            .chain(dynamic_nafs.iter())
+            .filter_map(|naf| naf.iter().rposition(|digit| *digit != 0))
+            .max();
+        let top = match top {
+            Some(top) => top,
+            None => return Some(EdwardsPoint::identity()),
+        };
+
+        let mut Q = EdwardsPoint::identity();
+        for i in (0..=top).rev() {
+            Q = Q.double();
+            for (naf, table) in dynamic_nafs.iter().zip(dynamic_tables.iter()) {
+                add_naf_digit(&mut Q, table, naf[i]);
+            }
+            for (naf, table) in static_nafs.iter().zip(self.0.iter()) {
+                add_naf_digit(&mut Q, table, naf[i]);
+            }
+        }
+        Some(Q)
+    }
+}
+
 #[allow(dead_code)]
 #[cfg(test)]
 /// Module used for tesing `EdwardsPoint` operations and implementations.
@@ -1561,6 +2572,236 @@ pub mod tests {
         assert!(compr2 == P2_EXTENDED.compress());
     }
 
+    #[test]
+    fn generator_and_identity_constants() {
+        assert!(EdwardsPoint::generator() == constants::BASEPOINT);
+        assert!(constants::BASEPOINT.compress() == constants::BASEPOINT_COMPRESSED);
+        assert!(constants::IDENTITY == EdwardsPoint::identity());
+        assert!(constants::IDENTITY.compress() == constants::IDENTITY_COMPRESSED);
+        assert!(constants::IDENTITY_COMPRESSED == CompressedEdwardsY::identity());
+    }
+
+    #[test]
+    fn order_helpers() {
+        let B = constants::BASEPOINT;
+        assert!(B.has_prime_order());
+        assert!(B.is_torsion_free());
+        assert!(!B.is_small_order());
+        assert!(B.mul_by_cofactor() == B * constants::COFACTOR);
+
+        let id = EdwardsPoint::identity();
+        assert!(id.is_small_order() && id.is_torsion_free());
+        assert!(!id.has_prime_order());
+
+        // `(0, -1)` has order 2.
+        let T2 = EdwardsPoint {
+            X: FieldElement::zero(),
+            Y: FieldElement::minus_one(),
+            Z: FieldElement::one(),
+            T: FieldElement::zero(),
+        };
+        assert!(T2.is_small_order());
+        assert!(!T2.is_torsion_free());
+        let P = B + T2;
+        assert!(!P.is_small_order() && !P.has_prime_order());
+        assert!(P.mul_by_cofactor().has_prime_order());
+    }
+
+    #[test]
+    fn double_n() {
+        let mut rng = rand::thread_rng();
+        let P = constants::BASEPOINT * Scalar::random(&mut rng);
+        assert!(P.double_n(0) == P);
+        assert!(P.double_n(1) == P.double());
+        assert!(P.double_n(5) == P * Scalar::from(32u8));
+        assert!(P.double_n(3) == P.double().double().double());
+        assert!(EdwardsPoint::identity().double_n(7) == EdwardsPoint::identity());
+        // `T` must be consistent for the later additions.
+        assert!(P.double_n(4) + P == P * Scalar::from(17u8));
+    }
+
+    #[test]
+    fn mul_small() {
+        let mut rng = rand::thread_rng();
+        let P = constants::BASEPOINT * Scalar::random(&mut rng);
+        assert!(P.mul_small(0) == EdwardsPoint::identity());
+        assert!(P.mul_small(1) == P);
+        assert!(P.mul_small(8) == P.mul_by_cofactor());
+        for k in [3u64, 1000, 0xdead_beef, u64::MAX].iter() {
+            assert!(P.mul_small(*k) == P * Scalar::from(*k));
+        }
+    }
+
+    #[test]
+    fn ct_select_from() {
+        let points: Vec<EdwardsPoint> =
+            (1..=8u64).map(|k| constants::BASEPOINT.mul_small(k)).collect();
+        for (k, point) in points.iter().enumerate() {
+            assert!(EdwardsPoint::ct_select_from(&points, k).unwrap() == *point);
+        }
+        assert!(bool::from(EdwardsPoint::ct_select_from(&points, 8).is_none()));
+    }
+
+    #[test]
+    fn precomputed_mixed_multiscalar_mul() {
+        let mut rng = rand::thread_rng();
+        let static_points: Vec<EdwardsPoint> = (0..5)
+            .map(|_| constants::BASEPOINT * Scalar::random(&mut rng))
+            .collect();
+        let dynamic_points = [static_points[0], constants::BASEPOINT, -static_points[3]];
+        let static_scalars: Vec<Scalar> = (0..5).map(|_| Scalar::random(&mut rng)).collect();
+        let dynamic_scalars = [Scalar::minus_one(), Scalar::random(&mut rng), Scalar::zero()];
+
+        let expected = EdwardsPoint::multiscalar_mul(
+            static_scalars.iter().chain(dynamic_scalars.iter()),
+            static_points.iter().chain(dynamic_points.iter()),
+        );
+        let precomp = VartimeEdwardsPrecomputation::new(&static_points);
+        let sum =
+            precomp.vartime_mixed_multiscalar_mul(&static_scalars, &dynamic_scalars, &dynamic_points);
+        assert!(sum == expected);
+
+        // Without static points, only the dynamic terms are left.
+        let empty = VartimeEdwardsPrecomputation::new(core::iter::empty::<EdwardsPoint>());
+        let sum = empty.vartime_mixed_multiscalar_mul(
+            core::iter::empty::<Scalar>(),
+            &dynamic_scalars,
+            &dynamic_points,
+        );
+        assert!(sum == EdwardsPoint::vartime_multiscalar_mul(&dynamic_scalars, &dynamic_points));
+    }
+
+    #[cfg(feature = "precomputed_tables")]
+    #[test]
+    fn static_basepoint_table() {
+        let table = EdwardsBasepointTableRadix16::create(&constants::BASEPOINT);
+        for (expected, table) in table.0.iter().zip(constants::EDWARDS_BASEPOINT_TABLE.0.iter()) {
+            for (P, Q) in expected.0.iter().zip(table.0.iter()) {
+                assert!(P == Q);
+                assert!(Q.T * Q.Z == Q.X * Q.Y);
+            }
+        }
+    }
+
+    #[test]
+    fn mul_base() {
+        let k = Scalar::random(&mut rand::thread_rng());
+        assert!(EdwardsPoint::mul_base(&k) == constants::BASEPOINT * k);
+        assert!(EdwardsPoint::mul_base(&Scalar::zero()) == EdwardsPoint::identity());
+    }
+
+    #[test]
+    fn table_serialization() {
+        let table = EdwardsBasepointTableRadix32::create(&constants::BASEPOINT);
+        let bytes = table.to_bytes();
+        assert!(bytes.len() == 9 + 27 * 16 * 64);
+        let decoded = EdwardsBasepointTableRadix32::from_bytes(&bytes).unwrap();
+        let k = Scalar::random(&mut rand::thread_rng());
+        assert!(decoded.mul(&k) == table.mul(&k));
+        assert!(decoded.to_bytes() == bytes);
+
+        // Tables of other window widths get rejected.
+        assert!(
+            EdwardsBasepointTableRadix16::from_bytes(&bytes).unwrap_err()
+                == DecodingError::MalformedTable
+        );
+        assert!(
+            EdwardsBasepointTableRadix32::from_bytes(&bytes[..bytes.len() - 1]).unwrap_err()
+                == DecodingError::InvalidLength
+        );
+        let mut corrupted = bytes.clone();
+        corrupted[9 + 64 * 5] ^= 1;
+        assert!(EdwardsBasepointTableRadix32::from_bytes(&corrupted).is_err());
+
+        let points = [constants::BASEPOINT, -constants::BASEPOINT.double()];
+        let precomp = VartimeEdwardsPrecomputation::new(points);
+        let decoded = VartimeEdwardsPrecomputation::from_bytes(&precomp.to_bytes()).unwrap();
+        let scalars = [k, Scalar::from(77u8)];
+        assert!(
+            decoded.vartime_multiscalar_mul(scalars) == precomp.vartime_multiscalar_mul(scalars)
+        );
+        assert!(VartimeEdwardsPrecomputation::from_bytes(&bytes).is_err());
+
+        // Huge counts on the header get rejected without overflowing.
+        let mut huge = precomp.to_bytes();
+        huge[5..9].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert!(matches!(
+            VartimeEdwardsPrecomputation::from_bytes(&huge),
+            Err(DecodingError::InvalidLength)
+        ));
+    }
+
+    #[test]
+    fn vartime_mul() {
+        let mut rng = rand::thread_rng();
+        let P = constants::BASEPOINT * Scalar::random(&mut rng);
+        for _ in 0..8 {
+            let k = Scalar::random(&mut rng);
+            assert!(P.vartime_mul(&k) == P * k);
+        }
+        assert!(P.vartime_mul(&Scalar::zero()) == EdwardsPoint::identity());
+        assert!(P.vartime_mul(&Scalar::one()) == P);
+        assert!(P.vartime_mul(&-Scalar::one()) == -P);
+        let k = Scalar::random(&mut rng);
+        assert!(EdwardsPoint::identity().vartime_mul(&k) == EdwardsPoint::identity());
+    }
+
+    #[test]
+    fn mul_by_pow_2_on_affine_and_compressed() {
+        let mut rng = rand::thread_rng();
+        let P = constants::BASEPOINT * Scalar::random(&mut rng);
+        for k in [0u32, 2, 3, 10].iter() {
+            let expected = P * Scalar::two_pow_k(*k as u64);
+            assert!(P.to_affine().mul_by_pow_2(*k) == expected);
+            assert!(P.compress().mul_by_pow_2(*k).unwrap() == expected);
+        }
+        // `T` must be consistent for the later additions.
+        assert!(P.to_affine().mul_by_pow_2(2) + P == P * Scalar::from(5u8));
+
+        let not_a_point = CompressedEdwardsY::from_slice(&[
+            250, 144, 188, 47, 13, 101, 118, 114, 201, 185, 169, 115, 255, 111, 40, 25, 69, 105,
+            170, 255, 113, 65, 120, 126, 170, 192, 48, 109, 112, 20, 221, 149,
+        ]);
+        assert!(not_a_point.mul_by_pow_2(3).is_none());
+    }
+
+    #[test]
+    fn conditional_select_and_swap() {
+        let mut rng = rand::thread_rng();
+        let P = constants::BASEPOINT * Scalar::random(&mut rng);
+        let Q = constants::BASEPOINT * Scalar::random(&mut rng);
+        assert!(EdwardsPoint::conditional_select(&P, &Q, Choice::from(0u8)) == P);
+        assert!(EdwardsPoint::conditional_select(&P, &Q, Choice::from(1u8)) == Q);
+
+        let (mut A, mut B) = (P, Q);
+        EdwardsPoint::conditional_swap(&mut A, &mut B, Choice::from(0u8));
+        assert!(A == P && B == Q);
+        EdwardsPoint::conditional_swap(&mut A, &mut B, Choice::from(1u8));
+        assert!(A == Q && B == P);
+
+        A.conditional_assign(&B, Choice::from(1u8));
+        assert!(A == P);
+    }
+
+    #[test]
+    fn ct_subgroup_check() {
+        let mut rng = rand::thread_rng();
+        let P = constants::BASEPOINT * Scalar::random(&mut rng);
+        assert!(bool::from(P.is_in_prime_order_subgroup()));
+        assert!(bool::from(EdwardsPoint::identity().is_in_prime_order_subgroup()));
+
+        let T2 = EdwardsPoint {
+            X: FieldElement::zero(),
+            Y: FieldElement::minus_one(),
+            Z: FieldElement::one(),
+            T: FieldElement::zero(),
+        };
+        for Q in [T2, P + T2].iter() {
+            assert!(!bool::from(Q.is_in_prime_order_subgroup()));
+            assert!(bool::from(Q.is_in_prime_order_subgroup()) == Q.is_torsion_free());
+        }
+    }
+
     #[test]
     fn point_decompression() {
         assert!(P1_COMPRESSED.decompress().unwrap() == P1_EXTENDED);
@@ -1616,6 +2857,155 @@ pub mod tests {
         assert!(P1_EXTENDED * Scalar::minus_one() == binary_naf_mul(&P1_EXTENDED, &Scalar::minus_one()));
     }
 
+    #[test]
+    fn straus_multiscalar_mul() {
+        let scalars = [Scalar::two_pow_k(215), Scalar::minus_one(), Scalar::from(1234567u64)];
+        let points = [P1_EXTENDED, constants::BASEPOINT, P1_EXTENDED.double()];
+        let expected = P1_EXTENDED * scalars[0] + constants::BASEPOINT * scalars[1] + P1_EXTENDED.double() * scalars[2];
+
+        assert!(vartime_multiscalar_mul(&scalars, &points) == expected);
+        assert!(vartime_multiscalar_mul::<EdwardsPoint>(&[], &[]) == EdwardsPoint::identity());
+    }
+
+    #[test]
+    fn uncompressed_encoding() {
+        let P = constants::BASEPOINT * Scalar::from(7u64);
+        let bytes = P.to_uncompressed();
+        assert!(EdwardsPoint::from_uncompressed(&bytes).unwrap() == P);
+        let id = EdwardsPoint::identity();
+        assert!(EdwardsPoint::from_uncompressed(&id.to_uncompressed()).unwrap() == id);
+
+        // Off-curve points get rejected.
+        let mut off_curve = bytes;
+        off_curve[0] ^= 1;
+        assert!(
+            EdwardsPoint::from_uncompressed(&off_curve).unwrap_err() == DecodingError::InvalidPoint
+        );
+
+        // As well as non-canonical coordinates.
+        let mut non_canonical = bytes;
+        non_canonical[32..].copy_from_slice(&constants::FIELD_L.to_bytes());
+        assert!(
+            EdwardsPoint::from_uncompressed(&non_canonical).unwrap_err()
+                == DecodingError::NonCanonicalFieldElement
+        );
+    }
+
+    #[test]
+    fn batch_normalization() {
+        let points: Vec<EdwardsPoint> = (1..10u64)
+            .map(|k| constants::BASEPOINT * Scalar::from(k))
+            .chain(core::iter::once(EdwardsPoint::identity()))
+            .collect();
+        let affine = EdwardsPoint::batch_normalize(&points);
+        for (point, aff) in points.iter().zip(affine) {
+            assert!(point.to_affine() == aff);
+            assert!(EdwardsPoint::from(aff) == *point);
+        }
+        assert!(EdwardsPoint::batch_normalize(&[]).is_empty());
+    }
+
+    #[test]
+    fn batch_decompression() {
+        let points: Vec<EdwardsPoint> = (1..10u64)
+            .map(|k| constants::BASEPOINT * Scalar::from(k))
+            .collect();
+        let compressed = EdwardsPoint::compress_batch(&points);
+        let decompressed = CompressedEdwardsY::batch_decompress(&compressed).unwrap();
+        for (point, compr) in decompressed.iter().zip(compressed.iter()) {
+            assert!(*point == compr.decompress().unwrap());
+        }
+        assert!(decompressed == points);
+        assert!(CompressedEdwardsY::batch_decompress(&[]).unwrap().is_empty());
+
+        // A single invalid encoding invalidates the batch.
+        let mut invalid = compressed;
+        invalid.push(CompressedEdwardsY([0xff; 32]));
+        assert!(invalid[9].decompress().is_none());
+        assert!(CompressedEdwardsY::batch_decompress(&invalid).is_none());
+    }
+
+    #[test]
+    fn basepoint_tables() {
+        let scalars = [Scalar::zero(), Scalar::one(), Scalar::minus_one(), Scalar::from(1234567u64)];
+        let expected: Vec<EdwardsPoint> = scalars.iter().map(|k| constants::BASEPOINT * k).collect();
+
+        let table = EdwardsBasepointTable::create(&constants::BASEPOINT);
+        assert!(table.basepoint() == constants::BASEPOINT);
+        for (k, point) in scalars.iter().zip(expected.iter()) {
+            assert!(&table * k == *point);
+            assert!(k * &table == *point);
+        }
+
+        let table = EdwardsBasepointTableRadix32::create(&constants::BASEPOINT);
+        assert!(table.mul(&scalars[2]) == expected[2]);
+        let table = EdwardsBasepointTableRadix64::create(&constants::BASEPOINT);
+        assert!(table.mul(&scalars[2]) == expected[2]);
+        let table = EdwardsBasepointTableRadix128::create(&constants::BASEPOINT);
+        assert!(table.mul(&scalars[2]) == expected[2]);
+        let table = EdwardsBasepointTableRadix256::create(&constants::BASEPOINT);
+        assert!(table.mul(&scalars[3]) == expected[3]);
+    }
+
+    #[test]
+    fn multiscalar_mul_traits() {
+        let scalars = [Scalar::zero(), Scalar::one(), Scalar::minus_one(), Scalar::from(1234u64)];
+        let points: Vec<EdwardsPoint> = (1..5u64)
+            .map(|k| constants::BASEPOINT * Scalar::from(k))
+            .collect();
+        let expected: EdwardsPoint = scalars.iter().zip(points.iter()).map(|(k, P)| P * k).sum();
+
+        assert!(EdwardsPoint::multiscalar_mul(&scalars, &points) == expected);
+        assert!(EdwardsPoint::multiscalar_mul(scalars.iter(), points.iter()) == expected);
+        assert!(EdwardsPoint::vartime_multiscalar_mul(&scalars, &points) == expected);
+        let mut maybe_points: Vec<Option<EdwardsPoint>> = points.iter().map(|P| Some(*P)).collect();
+        let sum = EdwardsPoint::optional_multiscalar_mul(&scalars, maybe_points.clone());
+        assert!(sum == Some(expected));
+        maybe_points[2] = None;
+        assert!(EdwardsPoint::optional_multiscalar_mul(&scalars, maybe_points).is_none());
+    }
+
+    #[test]
+    fn naf_recoding() {
+        let two = Scalar::from(2u8);
+        let scalars = [
+            Scalar::zero(),
+            Scalar::one(),
+            Scalar::minus_one(),
+            Scalar::from(0xdead_beefu64),
+        ];
+        for k in scalars.iter() {
+            for w in [2, 5, 8].iter() {
+                let naf = non_adjacent_form(k, *w);
+                let mut acc = Scalar::zero();
+                for digit in naf.iter().rev() {
+                    let abs = Scalar::from(digit.unsigned_abs());
+                    acc = acc * two + if *digit < 0 { -abs } else { abs };
+                    assert!(*digit == 0 || (digit % 2 != 0 && digit.unsigned_abs() < 1 << (w - 1)));
+                }
+                assert!(acc == *k);
+                for window in naf.windows(*w) {
+                    assert!(window.iter().filter(|digit| **digit != 0).count() <= 1);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn multiscalar_mul_many_terms() {
+        // More terms than a single chunk of the parallel version.
+        let scalars: Vec<Scalar> = (1..100u64).map(Scalar::from).collect();
+        let points: Vec<EdwardsPoint> = scalars.iter().map(|k| constants::BASEPOINT * k).collect();
+        // sum(i^2) for i in 1..100.
+        let expected = constants::BASEPOINT * Scalar::from(328350u64);
+        assert!(vartime_multiscalar_mul(&scalars, &points) == expected);
+
+        let compressed = EdwardsPoint::compress_batch(&points);
+        for (point, compr) in points.iter().zip(compressed.iter()) {
+            assert!(point.compress() == *compr);
+        }
+    }
+
 /*
     #[test]
     fn aaaaa() {
@@ -1633,4 +3023,74 @@ pub mod tests {
         assert!(double_and_add(&constants::RISTRETTO_BASEPOINT, &scalar) == window_naf_mul(&scalar, 5u8));
 
     }*/
+
+    #[cfg(feature = "group")]
+    #[test]
+    fn group_traits() {
+        use group::{Group, GroupEncoding};
+
+        let mut rng = rand::thread_rng();
+        let P = <EdwardsPoint as Group>::random(&mut rng);
+        let k = Scalar::random(&mut rng);
+
+        assert!(bool::from(<EdwardsPoint as Group>::identity().is_identity()));
+        assert!(!bool::from(P.is_identity()));
+        assert!(<EdwardsPoint as Group>::double(&P) == P + P);
+        assert!([P, P, P].iter().sum::<EdwardsPoint>() == P * Scalar::from(3u8));
+
+        let mut Q = P;
+        Q *= &k;
+        Q += P;
+        assert!(Q == P * k + P);
+
+        let bytes = <EdwardsPoint as GroupEncoding>::to_bytes(&Q);
+        assert!(<EdwardsPoint as GroupEncoding>::from_bytes(&bytes).unwrap() == Q);
+    }
+    #[test]
+    fn strict_decompression() {
+        let P = constants::BASEPOINT * Scalar::from(1234u64);
+        let compressed = P.compress();
+        assert!(compressed.decompress_strict().unwrap() == P);
+
+        // `decompress` masks the high bits of the Y-coordinate,
+        // but `decompress_strict` rejects them.
+        let mut masked = compressed;
+        masked[31] |= 0b0110_0000;
+        assert!(masked.decompress().unwrap() == P);
+        assert!(masked.decompress_strict() == Err(DecodingError::NonCanonicalPoint));
+
+        // `x = 0` with the sign bit set.
+        let mut identity = EdwardsPoint::identity().compress();
+        identity[31] |= 0b1000_0000;
+        assert!(identity.decompress_strict() == Err(DecodingError::NonCanonicalPoint));
+    }
+
+    #[test]
+    fn compressed_hex_parsing() {
+        use core::convert::TryFrom;
+
+        let P = P1_COMPRESSED;
+        assert!(CompressedEdwardsY::from_hex(&format!("{:x}", P)).unwrap() == P);
+        assert!(CompressedEdwardsY::try_from(format!("{:X}", P).as_str()).unwrap() == P);
+
+        let mut not_on_curve = [0u8; 32];
+        not_on_curve.copy_from_slice(&[
+            250, 144, 188, 47, 13, 101, 118, 114, 201, 185, 169, 115, 255, 111, 40, 25, 69, 105,
+            170, 255, 113, 65, 120, 126, 170, 192, 48, 109, 112, 20, 221, 149,
+        ]);
+        let hex = format!("{:x}", CompressedEdwardsY(not_on_curve));
+        assert!(CompressedEdwardsY::from_hex(&hex).is_err());
+
+        let mut identity = EdwardsPoint::identity().compress();
+        identity[31] |= 0b1000_0000;
+        let hex = format!("{:x}", identity);
+        assert!(CompressedEdwardsY::from_hex(&hex) == Err(DecodingError::NonCanonicalPoint));
+        assert!(CompressedEdwardsY::from_hex(&hex[1..]) == Err(DecodingError::InvalidLength));
+    }
+
+    #[test]
+    fn transparent_layout() {
+        assert_eq!(std::mem::size_of::<CompressedEdwardsY>(), 32);
+        assert_eq!(std::mem::align_of::<CompressedEdwardsY>(), 1);
+    }
 }
@@ -0,0 +1,129 @@
+//! Errors returned by the strict decoding functions of the
+//! library.
+//!
+//! Every `from_bytes`-style entry point that may silently reduce or
+//! mask its input has a strict counterpart that returns a
+//! `DecodingError` instead, so that decoding and encoding back a
+//! value always gives the same bytes:
+//!
+//! - `FieldElement::from_canonical_bytes`.
+//! - `Scalar::from_canonical_bytes`.
+//! - `CompressedEdwardsY::decompress_strict`.
+//! - `CompressedRistretto::decompress_strict`.
+//! - `FieldElement::slice_from_bytes`.
+//! - `AffinePoint::from_uncompressed`.
+//! - `from_bytes` of the basepoint tables and of the precomputations
+//!   of `VartimePrecomputedMultiscalarMul`.
+//! - `from_hex` (and `TryFrom<&str>`) of `FieldElement`, `Scalar`,
+//!   `CompressedEdwardsY` and `CompressedRistretto`.
+//!
+//! On top of that, `Error` gathers every failure of the library,
+//! and the operations that panic on invalid inputs have fallible
+//! counterparts that return it instead:
+//!
+//! - `FieldElement::try_inverse`, `FieldElement::try_batch_invert` and
+//!   `FieldElement::checked_div`.
+//! - `FieldElement::try_two_pow_k` and `Scalar::try_two_pow_k`.
+
+use core::fmt;
+
+/// Errors that can occur when decoding values from bytes.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DecodingError {
+    /// The bytes encode a value greater or equal than `p`.
+    NonCanonicalFieldElement,
+    /// The bytes encode a value greater or equal than `l`.
+    NonCanonicalScalar,
+    /// The bytes are not the canonical encoding of the point
+    /// they decode to.
+    NonCanonicalPoint,
+    /// The bytes don't encode any point of the curve.
+    InvalidPoint,
+    /// The length of the input isn't a multiple of the length of
+    /// the encoded values.
+    InvalidLength,
+    /// The bytes are not a serialized table of the expected type.
+    MalformedTable,
+    /// The string contains characters that are not hex digits.
+    InvalidHex,
+}
+
+impl fmt::Display for DecodingError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DecodingError::NonCanonicalFieldElement => {
+                write!(f, "non-canonical FieldElement encoding")
+            }
+            DecodingError::NonCanonicalScalar => write!(f, "non-canonical Scalar encoding"),
+            DecodingError::NonCanonicalPoint => write!(f, "non-canonical point encoding"),
+            DecodingError::InvalidPoint => write!(f, "the bytes don't encode a valid point"),
+            DecodingError::InvalidLength => write!(f, "invalid length of the encoded values"),
+            DecodingError::MalformedTable => write!(f, "malformed precomputed table"),
+            DecodingError::InvalidHex => write!(f, "invalid hex digit"),
+        }
+    }
+}
+
+impl std::error::Error for DecodingError {}
+
+/// Crate-wide error type, returned by the fallible counterparts of
+/// the operations that would otherways panic.
+///
+/// Every `DecodingError` converts into an `Error`, so `?` can be
+/// used across both.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The bytes are not the canonical encoding of the value
+    /// they decode to.
+    NonCanonicalEncoding,
+    /// The bytes or coordinates don't correspond to any point
+    /// of the curve.
+    NotOnCurve,
+    /// Tried to invert or divide by zero.
+    ZeroInverse,
+    /// The length of the input isn't the expected one.
+    InvalidLength,
+    /// An exponent or index is out of the supported range.
+    OutOfRange,
+    /// A result of `self_test` doesn't match its known answer.
+    SelfTestFailed,
+    /// A value revealed by a participant of a multi-party protocol
+    /// doesn't match the commitment it made to it.
+    CommitmentMismatch,
+    /// The indices of the participants of a multi-party protocol
+    /// contain zero or repeated indices.
+    InvalidIndices,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::NonCanonicalEncoding => write!(f, "non-canonical encoding"),
+            Error::NotOnCurve => write!(f, "the point is not on the curve"),
+            Error::ZeroInverse => write!(f, "zero has no inverse"),
+            Error::InvalidLength => write!(f, "invalid length of the input"),
+            Error::OutOfRange => write!(f, "the input is out of the supported range"),
+            Error::SelfTestFailed => write!(f, "the known-answer self-test failed"),
+            Error::CommitmentMismatch => {
+                write!(f, "the revealed value doesn't match its commitment")
+            }
+            Error::InvalidIndices => write!(f, "the participant indices are zero or repeated"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<DecodingError> for Error {
+    fn from(err: DecodingError) -> Error {
+        match err {
+            DecodingError::NonCanonicalFieldElement
+            | DecodingError::NonCanonicalScalar
+            | DecodingError::NonCanonicalPoint
+            | DecodingError::MalformedTable
+            | DecodingError::InvalidHex => Error::NonCanonicalEncoding,
+            DecodingError::InvalidPoint => Error::NotOnCurve,
+            DecodingError::InvalidLength => Error::InvalidLength,
+        }
+    }
+}
@@ -41,9 +41,9 @@
 //! res = a.square(); // Performs a^2 (mod l).
 //! res = -&a; // Performs Negation over the modulo l.
 //! res = a.pow(&b); // Performs Modular exponentiation.
-//! res = a.mod_sqrt(Choice::from(1u8)).unwrap(); //Performs
+//! res = a.square().sqrt().unwrap(); //Performs
 //! // modular sqrt.
-//! // Returs `None` if the input is not a QR on the field.
+//! // Returns `None` if the input is not a QR on the field.
 //! // Returns Some(result) if everything is correct.
 //!
 //! // Division has been also implemented. Remember that when we write
@@ -71,26 +71,37 @@
 //! for both, `&FieldElement` and `FieldElement`.
 
 use core::cmp::PartialEq;
+use core::convert::TryInto;
+use core::hash::{Hash, Hasher};
 
-use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+use subtle::{Choice, ConditionallyNegatable, ConditionallySelectable, ConstantTimeEq, CtOption};
 
 use rand::{CryptoRng, Rng};
 
-use curve25519_dalek::scalar::Scalar;
+use num::BigUint;
 
 use crate::backend;
+use crate::errors::{DecodingError, Error};
+use crate::traits::ops::Square;
+use crate::window;
+#[cfg(feature = "ff")]
+use bitvec::{array::BitArray, order::Lsb0};
+#[cfg(feature = "ff")]
+use rand::RngCore;
 
-#[cfg(feature = "u64_backend")]
-pub use backend::u64::field::*;
+pub use backend::serial::field::*;
 /// A `FieldElement` represents an element of the field
 /// `2^252 + 27742317777372353535851937790883648493`
 ///
 /// The `FieldElement` type is an alias for one of the platform-specific
 /// implementations.
-#[cfg(feature = "u64_backend")]
-pub type FieldElement = backend::u64::field::FieldElement;
+pub type FieldElement = backend::serial::field::FieldElement;
+
+pub use backend::vector::field::FieldElement4x;
 
 impl PartialEq for FieldElement {
+    /// Compare the 32-byte encodings in constant time, see
+    /// `ConstantTimeEq::ct_eq` to get the result as a `Choice`.
     fn eq(&self, other: &FieldElement) -> bool {
         self.ct_eq(other).unwrap_u8() == 1u8
     }
@@ -105,6 +116,14 @@ impl ConstantTimeEq for FieldElement {
     }
 }
 
+impl Hash for FieldElement {
+    /// Hash the canonical encoding of the `FieldElement`, so that
+    /// equal values always get the same hash.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.to_bytes().hash(state);
+    }
+}
+
 impl ConditionallySelectable for FieldElement {
     fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
         FieldElement([
@@ -115,11 +134,20 @@ impl ConditionallySelectable for FieldElement {
             u64::conditional_select(&a.0[4], &b.0[4], choice),
         ])
     }
-}
 
-impl Into<Scalar> for &FieldElement {
-    fn into(self) -> Scalar {
-        Scalar::from_bytes_mod_order(self.to_bytes())
+    /// Overwrite `self` with `other` if `choice == 1`, limb by limb.
+    fn conditional_assign(&mut self, other: &Self, choice: Choice) {
+        for i in 0..5 {
+            self.0[i].conditional_assign(&other.0[i], choice);
+        }
+    }
+
+    /// Swap `a` and `b` if `choice == 1`, limb by limb, so ladders can
+    /// swap their working variables under a secret bit.
+    fn conditional_swap(a: &mut Self, b: &mut Self, choice: Choice) {
+        for i in 0..5 {
+            u64::conditional_swap(&mut a.0[i], &mut b.0[i], choice);
+        }
     }
 }
 
@@ -138,4 +166,501 @@ impl FieldElement {
         bytes[31] &= 0b0000_0111;
         FieldElement::from_bytes(&bytes)
     }
+
+    /// Get `slice[index]` in constant time, for tables that must be
+    /// read at a secret index, see `window::ct_select_from`.
+    ///
+    /// # Returns
+    /// - `None` if `index` is out of the bounds of the slice.
+    /// - `Some(slice[index])` otherways.
+    ///
+    /// # Panics
+    /// If the slice is empty.
+    pub fn ct_select_from(slice: &[FieldElement], index: usize) -> CtOption<FieldElement> {
+        window::ct_select_from(slice, index)
+    }
+
+    /// Check whether the `FieldElement` is zero, in constant time.
+    pub fn is_zero(&self) -> Choice {
+        self.ct_eq(&FieldElement::zero())
+    }
+
+    /// Check whether the `FieldElement` is one, in constant time.
+    pub fn is_one(&self) -> Choice {
+        self.ct_eq(&FieldElement::one())
+    }
+
+    /// Compute `a^-1 (mod l)`, returning `None` instead of
+    /// panicking if `a = 0`.
+    ///
+    /// Uses the safegcd algorithm of Bernstein and Yang, so unlike
+    /// `FieldElement::inverse` it runs in constant time, and the
+    /// caller doesn't need to branch on `a = 0`.
+    pub fn invert_nonzero(&self) -> CtOption<FieldElement> {
+        let is_zero = self.is_zero();
+        CtOption::new(backend::serial::safegcd::invert(self), !is_zero)
+    }
+
+    /// Compute `a^-1 (mod l)`.
+    ///
+    /// Unlike `FieldElement::inverse`, it returns
+    /// `Error::ZeroInverse` instead of panicking if `a = 0`.
+    pub fn try_inverse(&self) -> Result<FieldElement, Error> {
+        Option::from(self.invert_nonzero()).ok_or(Error::ZeroInverse)
+    }
+
+    /// Compute `a / b (mod l)`.
+    ///
+    /// Unlike the `Div` implementations, it returns
+    /// `Error::ZeroInverse` instead of panicking if `b = 0`.
+    pub fn checked_div(&self, b: &FieldElement) -> Result<FieldElement, Error> {
+        Ok(self * b.try_inverse()?)
+    }
+
+    /// Check whether the `FieldElement` is a square on the field,
+    /// zero included, in constant time.
+    pub fn is_square(&self) -> Choice {
+        self.legendre_symbol()
+    }
+
+    /// Compute the non-negative square root of the `FieldElement`,
+    /// returning `None` if it is not a square on the field.
+    ///
+    /// Since `l = 5 (mod 8)`, `x = a^((l + 3) / 8)` satisfies
+    /// `x^2 = a` or `x^2 = -a`, and the second case gets fixed by
+    /// multiplying `x` by `sqrt(-1)`, so there's no branching on
+    /// the value of `a`.
+    ///
+    /// Replaces `ModSqrt::mod_sqrt`, whose sign `Choice` doesn't
+    /// correspond to `is_positive`.
+    pub fn sqrt(&self) -> CtOption<FieldElement> {
+        let mut root = self.pow_chain(backend::serial::constants::SQRT_CHAIN);
+        let flipped = root.square().ct_eq(&-self);
+        root = FieldElement::conditional_select(
+            &root,
+            &(root * backend::serial::constants::SQRT_MINUS_ONE),
+            flipped,
+        );
+        root.conditional_negate(!root.is_positive());
+        let is_root = root.square().ct_eq(self);
+        CtOption::new(root, is_root)
+    }
+
+    /// Get the canonical value of the `FieldElement` as a base 10
+    /// string, as printed by Sage or Python.
+    pub fn to_decimal_string(&self) -> String {
+        BigUint::from_bytes_le(&self.to_bytes()).to_str_radix(10)
+    }
+
+    /// Decode a `FieldElement` from its 32 LE bytes, checking that
+    /// they are the canonical encoding of the value.
+    ///
+    /// Unlike `FieldElement::from_bytes`, values greater or equal
+    /// than `p` get rejected instead of being kept unreduced.
+    pub fn from_canonical_bytes(bytes: &[u8; 32]) -> Result<FieldElement, DecodingError> {
+        let fe = FieldElement::from_bytes(bytes);
+        if fe >= backend::serial::constants::FIELD_L {
+            return Err(DecodingError::NonCanonicalFieldElement);
+        };
+        Ok(fe)
+    }
+
+    /// Reduce the value of the LE `bytes`, of any length, modulo `p`.
+    ///
+    /// It allows to map the 48 or 64-byte outputs of hash functions
+    /// into the field at once. An empty slice gives zero.
+    pub fn from_bytes_mod_order(bytes: &[u8]) -> FieldElement {
+        // Fold the 32-byte chunks starting from the most significant
+        // one: `acc = acc * 2^256 + chunk (mod p)`.
+        let mut acc = FieldElement::zero();
+        for chunk in bytes.chunks(32).rev() {
+            let mut wide = [0u8; 64];
+            wide[..chunk.len()].copy_from_slice(chunk);
+            wide[32..].copy_from_slice(&acc.to_bytes());
+            let mut words = [0u64; 8];
+            for (word, word_bytes) in words.iter_mut().zip(wide.chunks(8)) {
+                *word = u64::from_le_bytes(word_bytes.try_into().unwrap());
+            }
+            acc = FieldElement::reduce_wide_words(words);
+        }
+        acc
+    }
+
+    /// Concatenate the 32-byte encodings of all of the `elements`.
+    pub fn slice_to_bytes(elements: &[FieldElement]) -> Vec<u8> {
+        let mut bytes = vec![0u8; elements.len() * 32];
+        // The length always matches.
+        let _ = FieldElement::write_slice_into(elements, &mut bytes);
+        bytes
+    }
+
+    /// Write the concatenated 32-byte encodings of all of the
+    /// `elements` into `out`, without allocating.
+    ///
+    /// Fails if the length of `out` isn't `32 * elements.len()`.
+    pub fn write_slice_into(
+        elements: &[FieldElement],
+        out: &mut [u8],
+    ) -> Result<(), DecodingError> {
+        if out.len() != elements.len() * 32 {
+            return Err(DecodingError::InvalidLength);
+        };
+        for (element, chunk) in elements.iter().zip(out.chunks_exact_mut(32)) {
+            let chunk: &mut [u8; 32] = chunk.try_into().unwrap();
+            element.write_bytes_into(chunk);
+        }
+        Ok(())
+    }
+
+    /// Decode the concatenated 32-byte encodings of several
+    /// `FieldElement`s, as returned by `FieldElement::slice_to_bytes`.
+    ///
+    /// Fails if the length of `bytes` isn't a multiple of 32 or if
+    /// any of the encodings isn't canonical.
+    pub fn slice_from_bytes(bytes: &[u8]) -> Result<Vec<FieldElement>, DecodingError> {
+        let chunks = bytes.chunks_exact(32);
+        if !chunks.remainder().is_empty() {
+            return Err(DecodingError::InvalidLength);
+        };
+        chunks
+            .map(|chunk| {
+                let mut encoding = [0u8; 32];
+                encoding.copy_from_slice(chunk);
+                FieldElement::from_canonical_bytes(&encoding)
+            })
+            .collect()
+    }
+
+    /// Invert every element of `inputs` in place with Montgomery's
+    /// trick, which trades all of the inversions but one for
+    /// three multiplications each.
+    ///
+    /// With the `parallel` feature, the slice is split into chunks
+    /// of `BATCH_INVERT_CHUNK` elements that get inverted on the
+    /// rayon thread pool.
+    ///
+    /// # Panics
+    /// If any of the inputs is zero. See
+    /// `FieldElement::try_batch_invert` for a version that returns
+    /// an error instead.
+    pub fn batch_invert(inputs: &mut [FieldElement]) {
+        FieldElement::try_batch_invert(inputs).expect("Zero has no inverse")
+    }
+
+    /// Invert every element of `inputs` in place, see
+    /// `FieldElement::batch_invert`.
+    ///
+    /// Unlike `FieldElement::batch_invert`, it returns
+    /// `Error::ZeroInverse` instead of panicking if any of the inputs
+    /// is zero, leaving all of them untouched.
+    pub fn try_batch_invert(inputs: &mut [FieldElement]) -> Result<(), Error> {
+        let any_zero = inputs
+            .iter()
+            .fold(Choice::from(0u8), |acc, input| acc | input.is_zero());
+        if bool::from(any_zero) {
+            return Err(Error::ZeroInverse);
+        };
+
+        #[cfg(feature = "parallel")]
+        {
+            use rayon::prelude::*;
+            inputs
+                .par_chunks_mut(BATCH_INVERT_CHUNK)
+                .for_each(batch_invert_chunk);
+        }
+        #[cfg(not(feature = "parallel"))]
+        batch_invert_chunk(inputs);
+        Ok(())
+    }
+
+    /// Compute `u[i].sqrt_ratio_i(&v[i])` for all of the pairs,
+    /// sharing a single field inversion across the batch through
+    /// `FieldElement::batch_invert`.
+    ///
+    /// The results are the ones of `SqrtRatioI::sqrt_ratio_i`, but
+    /// every pair takes the same sequence of operations, zeros
+    /// included.
+    ///
+    /// # Panics
+    /// If `u` and `v` have different lengths.
+    pub fn batch_sqrt_ratio_i(
+        u: &[FieldElement],
+        v: &[FieldElement],
+    ) -> Vec<(Choice, FieldElement)> {
+        assert_eq!(u.len(), v.len(), "There must be as many numerators as denominators");
+        let zero = FieldElement::zero();
+        // Zeros would break the batch inversion, so they get swapped
+        // by one and their results get fixed below.
+        let mut inverses: Vec<FieldElement> = v
+            .iter()
+            .map(|v| FieldElement::conditional_select(v, &FieldElement::one(), v.is_zero()))
+            .collect();
+        FieldElement::batch_invert(&mut inverses);
+
+        u.iter()
+            .zip(v.iter())
+            .zip(inverses.iter())
+            .map(|((u, v), inv)| {
+                let ratio = u * inv;
+                let root = ratio.sqrt();
+                // `sqrt(-1)` is not a square, so `sqrt(-1) * u / v`
+                // is one if `u / v` isn't.
+                let twisted_root = (backend::serial::constants::SQRT_MINUS_ONE * ratio).sqrt();
+                let is_square = root.is_some();
+                let mut res = FieldElement::conditional_select(
+                    &twisted_root.unwrap_or(zero),
+                    &root.unwrap_or(zero),
+                    is_square,
+                );
+
+                let v_is_zero = v.is_zero() & !u.is_zero();
+                res.conditional_assign(&zero, v_is_zero);
+                (is_square & !v_is_zero, res)
+            })
+            .collect()
+    }
+}
+
+/// Number of elements inverted by each task of
+/// `FieldElement::batch_invert` when the `parallel` feature is on.
+#[cfg(feature = "parallel")]
+const BATCH_INVERT_CHUNK: usize = 256;
+
+/// Montgomery's trick over a single chunk of non-zero elements.
+fn batch_invert_chunk(inputs: &mut [FieldElement]) {
+    if inputs.is_empty() {
+        return;
+    };
+    // scratch[i] = inputs[0] * ... * inputs[i - 1]
+    let mut scratch = Vec::with_capacity(inputs.len());
+    let mut acc = FieldElement::one();
+    for input in inputs.iter() {
+        scratch.push(acc);
+        acc *= input;
+    }
+
+    // None of the inputs is zero, so neither is their product.
+    acc = acc.invert_nonzero().unwrap();
+    for (input, prefix) in inputs.iter_mut().rev().zip(scratch.into_iter().rev()) {
+        let inv = acc * prefix;
+        acc *= *input;
+        *input = inv;
+    }
+}
+
+/// `2^((p - 1) / 4) (mod p)`, a primitive `2^S`-th root of unity
+/// of the field where `S = 2`.
+#[cfg(feature = "ff")]
+const ROOT_OF_UNITY: FieldElement = FieldElement([
+    2099929430230996,
+    1464742363261928,
+    3309265759432790,
+    2285299817698826,
+    10215362715769,
+]);
+
+define_hex_parsing!(FieldElement, FieldElement::from_canonical_bytes);
+
+#[cfg(feature = "ff")]
+impl From<FieldElement> for [u8; 32] {
+    fn from(value: FieldElement) -> [u8; 32] {
+        value.to_bytes()
+    }
+}
+
+#[cfg(feature = "ff")]
+impl<'a> From<&'a FieldElement> for [u8; 32] {
+    fn from(value: &'a FieldElement) -> [u8; 32] {
+        value.to_bytes()
+    }
+}
+
+#[cfg(feature = "ff")]
+impl ff::Field for FieldElement {
+    /// Sample a `FieldElement` uniformly by rejection sampling
+    /// over 253-bit values.
+    fn random(mut rng: impl RngCore) -> FieldElement {
+        loop {
+            let mut bytes = [0u8; 32];
+            rng.fill_bytes(&mut bytes);
+            bytes[31] &= 0b0001_1111;
+            if let Some(fe) = <FieldElement as ff::PrimeField>::from_repr(bytes) {
+                return fe;
+            };
+        }
+    }
+
+    fn zero() -> FieldElement {
+        FieldElement::zero()
+    }
+
+    fn one() -> FieldElement {
+        FieldElement::one()
+    }
+
+    fn is_zero(&self) -> bool {
+        FieldElement::is_zero(self).into()
+    }
+
+    fn square(&self) -> FieldElement {
+        Square::square(self)
+    }
+
+    fn double(&self) -> FieldElement {
+        crate::traits::ops::Double::double(self)
+    }
+
+    /// Returns `None` if `self` is zero.
+    fn invert(&self) -> CtOption<FieldElement> {
+        self.invert_nonzero()
+    }
+
+    /// Returns `None` if `self` is not a QR on the field.
+    fn sqrt(&self) -> CtOption<FieldElement> {
+        FieldElement::sqrt(self)
+    }
+}
+
+#[cfg(feature = "ff")]
+impl ff::PrimeField for FieldElement {
+    type Repr = [u8; 32];
+    type ReprBits = [u64; 4];
+
+    /// Returns `None` if the bytes are not the canonical
+    /// encoding of a `FieldElement`.
+    fn from_repr(repr: [u8; 32]) -> Option<FieldElement> {
+        FieldElement::from_canonical_bytes(&repr).ok()
+    }
+
+    fn to_repr(&self) -> [u8; 32] {
+        self.to_bytes()
+    }
+
+    fn to_le_bits(&self) -> BitArray<Lsb0, [u64; 4]> {
+        BitArray::new(self.to_u64_words())
+    }
+
+    fn is_odd(&self) -> bool {
+        self.to_bytes()[0] & 1 == 1
+    }
+
+    fn char_le_bits() -> BitArray<Lsb0, [u64; 4]> {
+        BitArray::new(backend::serial::constants::FIELD_L.to_u64_words())
+    }
+
+    const NUM_BITS: u32 = 253;
+
+    const CAPACITY: u32 = 252;
+
+    fn multiplicative_generator() -> FieldElement {
+        FieldElement::from(2u8)
+    }
+
+    const S: u32 = 2;
+
+    fn root_of_unity() -> FieldElement {
+        ROOT_OF_UNITY
+    }
+}
+
+#[cfg(feature = "num")]
+impl num::traits::Zero for FieldElement {
+    fn zero() -> FieldElement {
+        FieldElement::zero()
+    }
+
+    fn is_zero(&self) -> bool {
+        FieldElement::is_zero(self).into()
+    }
+}
+
+#[cfg(feature = "num")]
+impl num::traits::One for FieldElement {
+    fn one() -> FieldElement {
+        FieldElement::one()
+    }
+}
+
+#[cfg(feature = "num")]
+impl num::traits::Inv for &FieldElement {
+    type Output = FieldElement;
+    /// Computes `a^-1 (mod l)` in constant time, see
+    /// `FieldElement::invert_nonzero`.
+    ///
+    /// # Panics
+    /// If `a = 0`.
+    fn inv(self) -> FieldElement {
+        Option::from(self.invert_nonzero()).expect("Zero has no inverse")
+    }
+}
+
+#[cfg(feature = "num")]
+impl num::traits::Inv for FieldElement {
+    type Output = FieldElement;
+    /// See the implementation for `&FieldElement`.
+    fn inv(self) -> FieldElement {
+        num::traits::Inv::inv(&self)
+    }
+}
+
+#[cfg(feature = "num")]
+impl num::traits::Pow<u64> for &FieldElement {
+    type Output = FieldElement;
+    /// Computes `a^b (mod l)`, see `FieldElement::pow_u64`.
+    fn pow(self, exp: u64) -> FieldElement {
+        self.pow_u64(exp)
+    }
+}
+
+#[cfg(feature = "num")]
+impl num::traits::Pow<u64> for FieldElement {
+    type Output = FieldElement;
+    /// Computes `a^b (mod l)`, see `FieldElement::pow_u64`.
+    fn pow(self, exp: u64) -> FieldElement {
+        self.pow_u64(exp)
+    }
+}
+
+#[cfg(all(test, feature = "ff"))]
+mod ff_tests {
+    use super::*;
+    use ff::{Field, PrimeField};
+
+    use rand::rngs::OsRng;
+
+    #[test]
+    fn root_of_unity() {
+        let root = <FieldElement as PrimeField>::root_of_unity();
+        let minus_one = -FieldElement::one();
+        assert!(<FieldElement as Field>::square(&root) == minus_one);
+        assert!(root.pow_vartime([4u64]) == FieldElement::one());
+
+        // `g^((p - 1) / 4)` must be the root of unity.
+        let t = [
+            0x960498c6973d74fbu64,
+            0x0537be77a8bde735,
+            0x0,
+            0x400000000000000,
+        ];
+        assert!(FieldElement::multiplicative_generator().pow_vartime(t) == root);
+    }
+
+    #[test]
+    fn repr_round_trip() {
+        let a = <FieldElement as Field>::random(&mut OsRng);
+        assert!(FieldElement::from_repr(a.to_repr()).unwrap() == a);
+        assert!(FieldElement::from_repr(backend::serial::constants::FIELD_L.to_bytes()).is_none());
+        assert!(FieldElement::from_repr([0xff; 32]).is_none());
+    }
+
+    #[test]
+    fn invert_and_sqrt() {
+        assert!(bool::from(<FieldElement as Field>::invert(&FieldElement::zero()).is_none()));
+        let a = <FieldElement as Field>::random(&mut OsRng);
+        assert!(a * a.invert().unwrap() == FieldElement::one());
+
+        let sq = <FieldElement as Field>::square(&a);
+        let root = <FieldElement as Field>::sqrt(&sq).unwrap();
+        assert!(root == a || root == -a);
+    }
 }
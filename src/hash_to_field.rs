@@ -0,0 +1,217 @@
+//! Hashing of arbitrary messages to `FieldElement`s, following
+//! [RFC 9380](https://www.rfc-editor.org/rfc/rfc9380).
+//!
+//! `expand_message_xmd` stretches a message into any number of
+//! uniform bytes with a Merkle-Damgard hash function, and
+//! `hash_to_field` reduces chunks of `HASH_TO_FIELD_CHUNK` of them
+//! into `FieldElement`s, using `Sha512`.
+//!
+//! Every call takes a domain separation tag (DST), so two protocols
+//! hashing the same message never derive the same values. The
+//! `*_DST` constants are the tags used by the library itself, and
+//! protocols built on top of it should pick their own, eg.
+//! `b"my-protocol-v1"`.
+//!
+//! # Examples
+//! ```rust
+//! use zerocaf::hash_to_field::hash_to_field;
+//!
+//! let elems = hash_to_field(b"message", b"my-protocol-v1", 2);
+//! assert_eq!(elems.len(), 2);
+//! assert!(elems[0] != elems[1]);
+//! ```
+
+use crate::field::FieldElement;
+
+use digest::generic_array::typenum::Unsigned;
+use digest::{BlockInput, Digest};
+use sha2::Sha512;
+
+/// Number of uniform bytes reduced into each `FieldElement` by
+/// `hash_to_field`, ie. `ceil((ceil(log2(p)) + k) / 8)` with the
+/// security level `k = 128`.
+pub const HASH_TO_FIELD_CHUNK: usize = 48;
+
+/// Maximum number of `FieldElement`s a single `hash_to_field` call
+/// can output: `expand_message_xmd` with `Sha512` is limited to
+/// `255 * 64` uniform bytes.
+pub const HASH_TO_FIELD_MAX_COUNT: usize = 255 * 64 / HASH_TO_FIELD_CHUNK;
+
+/// Default DST of `hash_to_field`.
+pub const HASH_TO_FIELD_DST: &[u8] = b"zerocaf-v1-field-XMD:SHA-512";
+
+/// Default DST of `Scalar::hash_to_scalar`.
+pub const HASH_TO_SCALAR_DST: &[u8] = b"zerocaf-v1-scalar-XMD:SHA-512";
+
+/// Default DST of `RistrettoPoint::hash_to_group`.
+pub const HASH_TO_GROUP_DST: &[u8] = b"zerocaf-v1-ristretto-XMD:SHA-512";
+
+/// Prefix used to hash the DSTs longer than 255 bytes.
+const OVERSIZE_DST_PREFIX: &[u8] = b"H2C-OVERSIZE-DST-";
+
+/// Expand `msg` into `len_in_bytes` uniform bytes with the
+/// `expand_message_xmd` function of RFC 9380, section 5.3.1,
+/// where the hash function `D` is eg. `Sha256` or `Sha512`.
+///
+/// DSTs longer than 255 bytes get hashed first, as described on
+/// section 5.3.3.
+///
+/// # Panics
+/// If `len_in_bytes` is greater than `65535` or than `255` times
+/// the output size of `D`.
+pub fn expand_message_xmd<D>(msg: &[u8], dst: &[u8], len_in_bytes: usize) -> Vec<u8>
+where
+    D: Digest + BlockInput,
+{
+    let b_in_bytes = D::output_size();
+    let r_in_bytes = D::BlockSize::to_usize();
+    let ell = len_in_bytes.div_ceil(b_in_bytes);
+    assert!(
+        ell <= 255 && len_in_bytes <= 65535,
+        "expand_message_xmd can't output that many bytes"
+    );
+
+    let hashed_dst;
+    let dst = if dst.len() > 255 {
+        hashed_dst = D::new().chain(OVERSIZE_DST_PREFIX).chain(dst).result();
+        hashed_dst.as_slice()
+    } else {
+        dst
+    };
+    let dst_len = [dst.len() as u8];
+
+    let b_0 = D::new()
+        .chain(vec![0u8; r_in_bytes])
+        .chain(msg)
+        .chain((len_in_bytes as u16).to_be_bytes())
+        .chain([0u8])
+        .chain(dst)
+        .chain(dst_len)
+        .result();
+
+    let mut uniform_bytes = Vec::with_capacity(ell * b_in_bytes);
+    let mut b_i = D::new()
+        .chain(b_0.as_slice())
+        .chain([1u8])
+        .chain(dst)
+        .chain(dst_len)
+        .result();
+    uniform_bytes.extend_from_slice(b_i.as_slice());
+    for i in 2..=ell {
+        let xored: Vec<u8> = b_0.iter().zip(b_i.iter()).map(|(a, b)| a ^ b).collect();
+        b_i = D::new()
+            .chain(xored)
+            .chain([i as u8])
+            .chain(dst)
+            .chain(dst_len)
+            .result();
+        uniform_bytes.extend_from_slice(b_i.as_slice());
+    }
+    uniform_bytes.truncate(len_in_bytes);
+    uniform_bytes
+}
+
+/// Hash `msg` into `count` `FieldElement`s with the `hash_to_field`
+/// function of RFC 9380, section 5.2, using `expand_message_xmd`
+/// with `Sha512`.
+///
+/// Each element comes from `HASH_TO_FIELD_CHUNK` uniform bytes,
+/// read as a big-endian integer and reduced modulo `p`, so the
+/// bias of the result is negligible.
+///
+/// # Panics
+/// If `count` is greater than `HASH_TO_FIELD_MAX_COUNT` (`340`), the
+/// output limit of `expand_message_xmd`.
+pub fn hash_to_field(msg: &[u8], dst: &[u8], count: usize) -> Vec<FieldElement> {
+    let uniform_bytes = expand_message_xmd::<Sha512>(msg, dst, count * HASH_TO_FIELD_CHUNK);
+    uniform_bytes
+        .chunks(HASH_TO_FIELD_CHUNK)
+        .map(|chunk| {
+            // OS2IP reads the bytes as a big-endian integer.
+            let mut words = [0u64; 8];
+            for (i, byte) in chunk.iter().rev().enumerate() {
+                words[i / 8] |= (*byte as u64) << (8 * (i % 8));
+            }
+            FieldElement::reduce_wide_words(words)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants;
+
+    use num::BigUint;
+    use sha2::Sha256;
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{:02x}", b)).collect()
+    }
+
+    #[test]
+    fn expand_message_xmd_sha256_vectors() {
+        // RFC 9380, appendix K.1.
+        let dst = b"QUUX-V01-CS02-with-expander-SHA256-128";
+        assert_eq!(
+            hex(&expand_message_xmd::<Sha256>(b"", dst, 0x20)),
+            "68a985b87eb6b46952128911f2a4412bbc302a9d759667f87f7a21d803f07235"
+        );
+        assert_eq!(
+            hex(&expand_message_xmd::<Sha256>(b"abc", dst, 0x20)),
+            "d8ccab23b5985ccea865c6c97b6e5b8350e794e603b4b97902f53a8a0d605615"
+        );
+    }
+
+    #[test]
+    fn expand_message_xmd_sha512_vectors() {
+        // RFC 9380, appendix K.3.
+        let dst = b"QUUX-V01-CS02-with-expander-SHA512-256";
+        assert_eq!(
+            hex(&expand_message_xmd::<Sha512>(b"", dst, 0x20)),
+            "6b9a7312411d92f921c6f68ca0b6380730a1a4d982c507211a90964c394179ba"
+        );
+        let long = expand_message_xmd::<Sha512>(b"abc", dst, 0x80);
+        assert_eq!(long.len(), 0x80);
+        assert!(long[..0x20] != long[0x20..0x40]);
+    }
+
+    #[test]
+    fn oversized_dst() {
+        let dst = [0x42u8; 256];
+        let hashed = Sha512::new().chain(OVERSIZE_DST_PREFIX).chain(&dst[..]).result();
+        assert!(
+            expand_message_xmd::<Sha512>(b"msg", &dst, 64)
+                == expand_message_xmd::<Sha512>(b"msg", hashed.as_slice(), 64)
+        );
+    }
+
+    #[test]
+    fn hash_to_field_reduction() {
+        let msg = b"hash to field";
+        let dst = b"zerocaf-test";
+        let elems = hash_to_field(msg, dst, 3);
+        let bytes = expand_message_xmd::<Sha512>(msg, dst, 3 * HASH_TO_FIELD_CHUNK);
+        let p = BigUint::from_bytes_le(&constants::FIELD_L.to_bytes());
+        for (elem, chunk) in elems.iter().zip(bytes.chunks(HASH_TO_FIELD_CHUNK)) {
+            let expected = BigUint::from_bytes_be(chunk) % &p;
+            assert!(BigUint::from_bytes_le(&elem.to_bytes()) == expected);
+        }
+
+        // The DST separates the outputs.
+        assert!(hash_to_field(msg, b"zerocaf-other", 3) != elems);
+        assert!(hash_to_field(msg, dst, 0).is_empty());
+    }
+
+    #[test]
+    fn hash_to_field_max_count() {
+        let elems = hash_to_field(b"m", b"zerocaf-test", HASH_TO_FIELD_MAX_COUNT);
+        assert_eq!(elems.len(), HASH_TO_FIELD_MAX_COUNT);
+    }
+
+    #[test]
+    #[should_panic(expected = "expand_message_xmd can't output that many bytes")]
+    fn hash_to_field_over_max_count() {
+        hash_to_field(b"m", b"zerocaf-test", HASH_TO_FIELD_MAX_COUNT + 1);
+    }
+}
@@ -0,0 +1,172 @@
+#![allow(non_snake_case)]
+//! JavaScript bindings of the Ristretto group operations and of the
+//! Schnorr signatures, built with `wasm-bindgen`.
+//!
+//! Every value goes in and out as a `Uint8Array` with its canonical
+//! encoding: 32 LE bytes for the scalars and secret keys, the 32
+//! bytes of the `CompressedRistretto` encoding for the points and
+//! public keys, and the 64 bytes of `Signature::to_bytes` for the
+//! signatures. The functions return `undefined` when any of their
+//! inputs is not a valid encoding.
+//!
+//! ```js
+//! import * as zerocaf from "zerocaf";
+//!
+//! const sk = zerocaf.generateSecretKey();
+//! const pk = zerocaf.publicKey(sk);
+//! const msg = new TextEncoder().encode("Sonny on the browser");
+//! console.assert(zerocaf.verify(pk, msg, zerocaf.sign(sk, msg)));
+//! ```
+
+use crate::constants;
+use crate::ristretto::{CompressedRistretto, RistrettoPoint};
+use crate::scalar::Scalar;
+use crate::signatures::{self, Signature};
+use crate::traits::MultiscalarMul;
+
+use rand::rngs::OsRng;
+use wasm_bindgen::prelude::*;
+
+/// Decode a canonical `Scalar` from 32 bytes.
+fn decode_scalar(bytes: &[u8]) -> Option<Scalar> {
+    if bytes.len() != 32 {
+        return None;
+    };
+    let mut buf = [0u8; 32];
+    buf.copy_from_slice(bytes);
+    Scalar::from_canonical_bytes(&buf).ok()
+}
+
+/// Decode a `RistrettoPoint` from its 32-byte compressed encoding.
+fn decode_point(bytes: &[u8]) -> Option<RistrettoPoint> {
+    if bytes.len() != 32 {
+        return None;
+    };
+    CompressedRistretto::copy_from_slice(bytes).decompress_strict().ok()
+}
+
+/// Encode a `RistrettoPoint` on its 32-byte compressed encoding.
+fn encode_point(point: &RistrettoPoint) -> Vec<u8> {
+    point.compress().as_bytes().to_vec()
+}
+
+/// Sample a random `Scalar` to be used as a secret key.
+#[wasm_bindgen(js_name = generateSecretKey)]
+pub fn generate_secret_key() -> Vec<u8> {
+    Scalar::random(&mut OsRng).to_bytes().to_vec()
+}
+
+/// Compute the public key `sk * B` of the secret key `sk`.
+#[wasm_bindgen(js_name = publicKey)]
+pub fn public_key(sk: &[u8]) -> Option<Vec<u8>> {
+    scalar_mul_base(sk)
+}
+
+/// Compute `scalar * B` in constant time, where `B` is the
+/// Ristretto basepoint.
+#[wasm_bindgen(js_name = scalarMulBase)]
+pub fn scalar_mul_base(scalar: &[u8]) -> Option<Vec<u8>> {
+    let scalar = decode_scalar(scalar)?;
+    Some(encode_point(&RistrettoPoint::mul_base(&scalar)))
+}
+
+/// Compute `scalar * point` in constant time.
+#[wasm_bindgen(js_name = scalarMul)]
+pub fn scalar_mul(point: &[u8], scalar: &[u8]) -> Option<Vec<u8>> {
+    let point = decode_point(point)?;
+    let scalar = decode_scalar(scalar)?;
+    Some(encode_point(&RistrettoPoint::multiscalar_mul(&[scalar], &[point])))
+}
+
+/// Compute `a + b`.
+#[wasm_bindgen(js_name = pointAdd)]
+pub fn point_add(a: &[u8], b: &[u8]) -> Option<Vec<u8>> {
+    Some(encode_point(&(decode_point(a)? + decode_point(b)?)))
+}
+
+/// Compute `a - b`.
+#[wasm_bindgen(js_name = pointSub)]
+pub fn point_sub(a: &[u8], b: &[u8]) -> Option<Vec<u8>> {
+    Some(encode_point(&(decode_point(a)? - decode_point(b)?)))
+}
+
+/// Compute `-a`.
+#[wasm_bindgen(js_name = pointNeg)]
+pub fn point_neg(a: &[u8]) -> Option<Vec<u8>> {
+    Some(encode_point(&-decode_point(a)?))
+}
+
+/// Sign `msg` with the secret key `sk`, see `signatures::sign`.
+#[wasm_bindgen]
+pub fn sign(sk: &[u8], msg: &[u8]) -> Option<Vec<u8>> {
+    let sk = decode_scalar(sk)?;
+    Some(signatures::sign(msg, &sk).to_bytes().to_vec())
+}
+
+/// Verify the signature `sig` over `msg` against the public key
+/// `pk`, see `signatures::verify`.
+///
+/// Invalid encodings of `pk` or `sig` make it return `false`.
+#[wasm_bindgen]
+pub fn verify(pk: &[u8], msg: &[u8], sig: &[u8]) -> bool {
+    let pk = match decode_point(pk) {
+        Some(pk) => pk,
+        None => return false,
+    };
+    if sig.len() != 64 {
+        return false;
+    };
+    let mut buf = [0u8; 64];
+    buf.copy_from_slice(sig);
+    match Signature::from_bytes(&buf) {
+        Some(sig) => signatures::verify(msg, &sig, &pk),
+        None => false,
+    }
+}
+
+/// Get the encoding of the Ristretto basepoint.
+#[wasm_bindgen]
+pub fn basepoint() -> Vec<u8> {
+    encode_point(&constants::RISTRETTO_BASEPOINT)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn point_operations() {
+        let a = generate_secret_key();
+        let b = generate_secret_key();
+        let A = scalar_mul_base(&a).unwrap();
+        let B = scalar_mul(&basepoint(), &b).unwrap();
+
+        let sum = point_add(&A, &B).unwrap();
+        assert!(point_sub(&sum, &B).unwrap() == A);
+        assert!(point_add(&A, &point_neg(&A).unwrap()).unwrap() == vec![0u8; 32]);
+
+        let a_scalar = decode_scalar(&a).unwrap();
+        assert!(A == encode_point(&(constants::RISTRETTO_BASEPOINT * a_scalar)));
+    }
+
+    #[test]
+    fn sign_and_verify() {
+        let sk = generate_secret_key();
+        let pk = public_key(&sk).unwrap();
+        let msg = b"Sonny on the browser";
+
+        let sig = sign(&sk, msg).unwrap();
+        assert!(verify(&pk, msg, &sig));
+        assert!(!verify(&pk, b"Sonny on the server", &sig));
+        assert!(!verify(&pk, msg, &sig[..63]));
+    }
+
+    #[test]
+    fn reject_invalid_encodings() {
+        assert!(scalar_mul_base(&[0xff; 32]).is_none());
+        assert!(scalar_mul_base(&[1u8; 31]).is_none());
+        assert!(point_neg(&[0xff; 32]).is_none());
+        assert!(sign(&[0xff; 32], b"").is_none());
+        assert!(!verify(&[0xff; 32], b"", &[0u8; 64]));
+    }
+}
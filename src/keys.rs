@@ -0,0 +1,370 @@
+//! Key types for the protocols built over the Ristretto-encoded
+//! prime-order group of the Sonny curve.
+//!
+//! - `SecretKey`: wraps a `Scalar` and wipes it from memory
+//!   when it gets dropped.
+//! - `PublicKey`: wraps the `RistrettoPoint` `sk * B`.
+//! - `Keypair`: holds both halves.
+//!
+//! # Examples
+//! ```rust
+//! use zerocaf::keys::*;
+//!
+//! use rand::rngs::OsRng;
+//!
+//! let keypair = Keypair::generate(&mut OsRng);
+//! let msg = b"Sonny loves Schnorr";
+//!
+//! let sig = keypair.sign(msg);
+//! assert!(keypair.public.verify(msg, &sig));
+//!
+//! // Keys can be sent and stored as bytes.
+//! let pk = PublicKey::from_bytes(&keypair.public.to_bytes()).unwrap();
+//! assert!(pk == keypair.public);
+//!
+//! // Blinded keys still match each other, but can't be linked
+//! // to the original ones without the blinding factor.
+//! let (blinded, _) = keypair.rerandomize(&mut OsRng);
+//! assert!(blinded.public.verify(msg, &blinded.sign(msg)));
+//! ```
+
+use crate::ristretto::{CompressedRistretto, RistrettoPoint};
+use crate::scalar::Scalar;
+use crate::signatures::{self, Signature};
+use crate::traits::MultiscalarMul;
+
+use rand::{CryptoRng, RngCore};
+use zeroize::Zeroize;
+
+/// A secret key: a `Scalar` chosen uniformly at random.
+///
+/// The inner `Scalar` is zeroized when the `SecretKey` is dropped,
+/// and it is hidden from the `Debug` output unless the
+/// `reveal_secrets` feature is enabled.
+#[derive(Clone)]
+pub struct SecretKey(pub(crate) Scalar);
+
+define_redacted_debug!(SecretKey);
+
+impl Drop for SecretKey {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl Zeroize for SecretKey {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl SecretKey {
+    /// Generate a new random `SecretKey`.
+    ///
+    /// By `rng` we mean any Rng that implements: `RngCore` + `CryptoRng`.
+    pub fn generate<T>(rng: &mut T) -> SecretKey
+    where
+        T: RngCore + CryptoRng,
+    {
+        SecretKey(Scalar::random(rng))
+    }
+
+    /// Get a reference to the inner `Scalar` of the `SecretKey`.
+    pub fn as_scalar(&self) -> &Scalar {
+        &self.0
+    }
+
+    /// Encode the `SecretKey` as the 32 LE bytes of its `Scalar`.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.0.to_bytes()
+    }
+
+    /// Decode a `SecretKey` from the 32 LE bytes of its `Scalar`.
+    ///
+    /// # Returns
+    /// - `None` if the bytes are not the canonical encoding of a `Scalar`.
+    /// - `Some(SecretKey)` otherways.
+    pub fn from_bytes(bytes: &[u8; 32]) -> Option<SecretKey> {
+        Scalar::from_canonical_bytes(bytes).ok().map(SecretKey)
+    }
+
+    /// Blind the `SecretKey` by multiplying it by `blinding`.
+    ///
+    /// The result matches the `PublicKey` blinded with the same
+    /// factor.
+    ///
+    /// # Panics
+    /// If `blinding` is zero.
+    pub fn blind(&self, blinding: &Scalar) -> SecretKey {
+        assert_nonzero_blinding(blinding);
+        SecretKey(self.0 * blinding)
+    }
+}
+
+/// Check that a blinding factor doesn't map the keys to
+/// the identity.
+fn assert_nonzero_blinding(blinding: &Scalar) {
+    assert!(
+        !bool::from(blinding.is_zero()),
+        "the blinding factor can't be zero"
+    );
+}
+
+impl From<Scalar> for SecretKey {
+    /// Build a `SecretKey` from a `Scalar`.
+    fn from(scalar: Scalar) -> SecretKey {
+        SecretKey(scalar)
+    }
+}
+
+/// A public key: the `RistrettoPoint` `sk * B`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct PublicKey(pub(crate) RistrettoPoint);
+
+impl PublicKey {
+    /// Get a reference to the inner `RistrettoPoint` of the `PublicKey`.
+    pub fn as_point(&self) -> &RistrettoPoint {
+        &self.0
+    }
+
+    /// Encode the `PublicKey` as its `CompressedRistretto` bytes.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.0.compress().as_bytes()
+    }
+
+    /// Decode a `PublicKey` from its `CompressedRistretto` bytes.
+    ///
+    /// # Returns
+    /// - `None` if the bytes are not a valid Ristretto encoding.
+    /// - `Some(PublicKey)` otherways.
+    pub fn from_bytes(bytes: &[u8; 32]) -> Option<PublicKey> {
+        CompressedRistretto(*bytes).decompress().map(PublicKey)
+    }
+
+    /// Decode a `PublicKey` from the representative produced by
+    /// `Keypair::generate_with_representative`.
+    ///
+    /// See `RistrettoPoint::from_representative`.
+    pub fn from_representative(bytes: &[u8; 32]) -> PublicKey {
+        PublicKey(RistrettoPoint::from_representative(bytes))
+    }
+
+    /// Blind the `PublicKey` by multiplying it by `blinding`.
+    ///
+    /// Without knowing `blinding`, the result can't be linked to
+    /// the original `PublicKey`.
+    ///
+    /// # Panics
+    /// If `blinding` is zero.
+    pub fn blind(&self, blinding: &Scalar) -> PublicKey {
+        assert_nonzero_blinding(blinding);
+        PublicKey(RistrettoPoint::multiscalar_mul(&[*blinding], &[self.0]))
+    }
+
+    /// Verify a Schnorr `Signature` over the given message.
+    ///
+    /// See `signatures::verify`.
+    pub fn verify(&self, msg: &[u8], sig: &Signature) -> bool {
+        signatures::verify(msg, sig, &self.0)
+    }
+}
+
+impl<'a> From<&'a SecretKey> for PublicKey {
+    /// Compute the `PublicKey` of the given `SecretKey`.
+    fn from(sk: &'a SecretKey) -> PublicKey {
+        PublicKey(RistrettoPoint::mul_base(&sk.0))
+    }
+}
+
+/// A `SecretKey` together with its `PublicKey`.
+#[derive(Clone, Debug)]
+pub struct Keypair {
+    pub secret: SecretKey,
+    pub public: PublicKey,
+}
+
+impl Keypair {
+    /// Generate a new random `Keypair`.
+    ///
+    /// By `rng` we mean any Rng that implements: `RngCore` + `CryptoRng`.
+    pub fn generate<T>(rng: &mut T) -> Keypair
+    where
+        T: RngCore + CryptoRng,
+    {
+        let secret = SecretKey::generate(rng);
+        let public = PublicKey::from(&secret);
+        Keypair { secret, public }
+    }
+
+    /// Generate a new random `Keypair` whose `PublicKey` can be sent
+    /// as a representative: 32 bytes indistinguishable from uniformly
+    /// random ones, which `PublicKey::from_representative` decodes.
+    ///
+    /// New keys are sampled until `RistrettoPoint::to_representative`
+    /// succeeds, which takes two attempts on average.
+    ///
+    /// By `rng` we mean any Rng that implements: `RngCore` + `CryptoRng`.
+    pub fn generate_with_representative<T>(rng: &mut T) -> (Keypair, [u8; 32])
+    where
+        T: RngCore + CryptoRng,
+    {
+        loop {
+            let keypair = Keypair::generate(rng);
+            if let Some(repr) = keypair.public.0.to_representative(rng) {
+                return (keypair, repr);
+            };
+        }
+    }
+
+    /// Encode the `Keypair` as 64 bytes: `sk || pk`.
+    pub fn to_bytes(&self) -> [u8; 64] {
+        let mut bytes = [0u8; 64];
+        bytes[..32].copy_from_slice(&self.secret.to_bytes());
+        bytes[32..].copy_from_slice(&self.public.to_bytes());
+        bytes
+    }
+
+    /// Decode a `Keypair` from 64 bytes: `sk || pk`.
+    ///
+    /// # Returns
+    /// - `None` if any of the halves is invalid or if the
+    ///   `PublicKey` doesn't correspond to the `SecretKey`.
+    /// - `Some(Keypair)` otherways.
+    pub fn from_bytes(bytes: &[u8; 64]) -> Option<Keypair> {
+        let mut sk_bytes = [0u8; 32];
+        sk_bytes.copy_from_slice(&bytes[..32]);
+        let mut pk_bytes = [0u8; 32];
+        pk_bytes.copy_from_slice(&bytes[32..]);
+
+        let secret = SecretKey::from_bytes(&sk_bytes);
+        sk_bytes.zeroize();
+        let secret = secret?;
+        let public = PublicKey::from_bytes(&pk_bytes)?;
+        if PublicKey::from(&secret) != public {
+            return None;
+        };
+        Some(Keypair { secret, public })
+    }
+
+    /// Blind both halves of the `Keypair` by `blinding`.
+    ///
+    /// # Panics
+    /// If `blinding` is zero.
+    pub fn blind(&self, blinding: &Scalar) -> Keypair {
+        Keypair {
+            secret: self.secret.blind(blinding),
+            public: self.public.blind(blinding),
+        }
+    }
+
+    /// Blind the `Keypair` with a random factor, returning the new
+    /// `Keypair` together with the blinding factor used.
+    ///
+    /// By `rng` we mean any Rng that implements: `RngCore` + `CryptoRng`.
+    pub fn rerandomize<T>(&self, rng: &mut T) -> (Keypair, Scalar)
+    where
+        T: RngCore + CryptoRng,
+    {
+        let mut blinding = Scalar::random(rng);
+        while bool::from(blinding.is_zero()) {
+            blinding = Scalar::random(rng);
+        }
+        (self.blind(&blinding), blinding)
+    }
+
+    /// Sign the given message with the `SecretKey` of the `Keypair`.
+    ///
+    /// See `signatures::sign`.
+    pub fn sign(&self, msg: &[u8]) -> Signature {
+        signatures::sign(msg, &self.secret.0)
+    }
+}
+
+impl From<SecretKey> for Keypair {
+    /// Build the `Keypair` of the given `SecretKey`.
+    fn from(secret: SecretKey) -> Keypair {
+        let public = PublicKey::from(&secret);
+        Keypair { secret, public }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rand::rngs::OsRng;
+
+    #[test]
+    fn keypair_encoding() {
+        let keypair = Keypair::generate(&mut OsRng);
+        let decoded = Keypair::from_bytes(&keypair.to_bytes()).unwrap();
+        assert!(decoded.secret.as_scalar() == keypair.secret.as_scalar());
+        assert!(decoded.public == keypair.public);
+
+        // Mismatched halves get rejected.
+        let other = Keypair::generate(&mut OsRng);
+        let mut bytes = keypair.to_bytes();
+        bytes[32..].copy_from_slice(&other.public.to_bytes());
+        assert!(Keypair::from_bytes(&bytes).is_none());
+
+        // Non-canonical secret keys get rejected.
+        assert!(SecretKey::from_bytes(&[0xff; 32]).is_none());
+    }
+
+    #[test]
+    fn representatives() {
+        let (keypair, repr) = Keypair::generate_with_representative(&mut OsRng);
+        assert!(PublicKey::from_representative(&repr) == keypair.public);
+    }
+
+    #[cfg(not(feature = "reveal_secrets"))]
+    #[test]
+    fn secret_key_debug_is_redacted() {
+        let keypair = Keypair::from(SecretKey::from(Scalar::one()));
+        let debug = format!("{:?}", keypair);
+        assert!(debug.contains("SecretKey(<redacted>)"));
+        assert!(!debug.contains(&format!("{:?}", Scalar::one())));
+    }
+
+    #[test]
+    fn secret_key_zeroize() {
+        let mut sk = SecretKey::generate(&mut OsRng);
+        sk.zeroize();
+        assert!(*sk.as_scalar() == Scalar::zero());
+    }
+
+    #[test]
+    fn blinding() {
+        let keypair = Keypair::generate(&mut OsRng);
+        let msg = b"Sonny loves privacy";
+
+        let (blinded, r) = keypair.rerandomize(&mut OsRng);
+        assert!(blinded.public != keypair.public);
+        assert!(PublicKey::from(&blinded.secret) == blinded.public);
+        assert!(keypair.public.blind(&r) == blinded.public);
+        assert!(blinded.public.verify(msg, &blinded.sign(msg)));
+        assert!(!keypair.public.verify(msg, &blinded.sign(msg)));
+
+        // Blinding twice composes the factors.
+        let r2 = Scalar::random(&mut OsRng);
+        assert!(blinded.public.blind(&r2) == keypair.public.blind(&(r * r2)));
+        assert!(keypair.blind(&Scalar::one()).public == keypair.public);
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_blinding() {
+        let keypair = Keypair::generate(&mut OsRng);
+        keypair.public.blind(&Scalar::zero());
+    }
+
+    #[test]
+    fn sign_and_verify() {
+        let keypair = Keypair::generate(&mut OsRng);
+        let msg = b"Sonny loves Schnorr";
+
+        let sig = keypair.sign(msg);
+        assert!(keypair.public.verify(msg, &sig));
+        assert!(!keypair.public.verify(b"Sonny hates Schnorr", &sig));
+    }
+}
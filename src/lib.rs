@@ -61,7 +61,14 @@
 //! Currently, `Zerocaf` has implemented the u64 backend.
 //! By default, the `u64` backend is the one which is used to perform all of
 //! the operations.
-//! Additionly, for future works, we would like to implement a `u32` backend aswell. <br>
+//! The `u32_backend` feature builds on top of it, multiplying and
+//! squaring the `Scalar`s with `u32` limbs and `u64` products. The
+//! `FieldElement`s always use the `u64` backend. <br>
+//!
+//! The backend gets selected by its cargo feature, and the rest of the
+//! crate only sees it through `backend::serial`, so the `FieldElement`
+//! and `Scalar` types are always the ones of the selected backend.
+//! The `asm` feature builds on top of `u64_backend`.
 //!
 //! To select a backend type, the following method can be used:
 //! ```sh
@@ -162,12 +169,61 @@ extern crate subtle;
 // Used for Ristretto255Scalar trait.
 extern crate curve25519_dalek;
 extern crate num;
+// Used for hashing into `Scalar`s.
+extern crate digest;
+// Used as the hash function of the signature schemes.
+extern crate sha2;
+// Used to wipe secret keys from memory.
+extern crate zeroize;
+// Used to serialize the field elements, scalars and points.
+#[cfg(feature = "serde")]
+extern crate serde;
+// Used to run the batch operations in parallel.
+#[cfg(feature = "parallel")]
+extern crate rayon;
+// Used for the Fiat-Shamir transcripts.
+#[cfg(feature = "merlin")]
+extern crate merlin;
+// Used for the JavaScript bindings.
+#[cfg(feature = "wasm-bindgen")]
+extern crate wasm_bindgen;
+
+#[macro_use]
+mod macros;
 
+#[cfg(feature = "ark")]
+pub mod ark;
 pub mod backend;
+pub mod circuit;
 pub mod constants;
+pub mod dalek;
+pub mod dh;
+pub mod dleq;
 pub mod edwards;
+pub mod errors;
 pub mod field;
+pub mod hash_to_field;
+#[cfg(feature = "wasm-bindgen")]
+pub mod js;
+pub mod keys;
 pub mod montgomery;
+pub mod musig;
+pub mod nonce;
 pub mod ristretto;
 pub mod scalar;
+pub mod self_test;
+#[cfg(feature = "serde")]
+mod serialize;
+pub mod sigma;
+pub mod signatures;
+#[cfg(feature = "test_vectors")]
+pub mod test_vectors;
+pub mod threshold;
 pub mod traits;
+#[cfg(feature = "merlin")]
+pub mod transcript;
+pub mod vrf;
+pub mod window;
+
+pub use crate::errors::Error;
+pub use crate::self_test::self_test;
@@ -0,0 +1,206 @@
+//! Internal macros used to derive the operator variants that
+//! can be built from the borrowed implementations.
+//!
+//! The idea is taken from the `macros` module of
+//! [curve25519-dalek](https://github.com/dalek-cryptography/curve25519-dalek/blob/master/src/macros.rs).
+
+/// Define the `Add` variants that mix owned and borrowed operands
+/// from the `&LHS + &RHS` implementation.
+macro_rules! define_mixed_add_variants {
+    (LHS = $lhs:ty, RHS = $rhs:ty, Output = $out:ty) => {
+        impl<'b> Add<&'b $rhs> for $lhs {
+            type Output = $out;
+            fn add(self, rhs: &'b $rhs) -> $out {
+                &self + rhs
+            }
+        }
+
+        impl<'a> Add<$rhs> for &'a $lhs {
+            type Output = $out;
+            fn add(self, rhs: $rhs) -> $out {
+                self + &rhs
+            }
+        }
+    };
+}
+
+/// Define the `AddAssign` variants from the `&LHS + &RHS` implementation.
+macro_rules! define_add_assign_variants {
+    (LHS = $lhs:ty, RHS = $rhs:ty) => {
+        impl<'b> AddAssign<&'b $rhs> for $lhs {
+            fn add_assign(&mut self, rhs: &'b $rhs) {
+                *self = &*self + rhs;
+            }
+        }
+
+        impl AddAssign<$rhs> for $lhs {
+            fn add_assign(&mut self, rhs: $rhs) {
+                *self = &*self + &rhs;
+            }
+        }
+    };
+}
+
+/// Define the `Sub` variants that mix owned and borrowed operands
+/// from the `&LHS - &RHS` implementation.
+macro_rules! define_mixed_sub_variants {
+    (LHS = $lhs:ty, RHS = $rhs:ty, Output = $out:ty) => {
+        impl<'b> Sub<&'b $rhs> for $lhs {
+            type Output = $out;
+            fn sub(self, rhs: &'b $rhs) -> $out {
+                &self - rhs
+            }
+        }
+
+        impl<'a> Sub<$rhs> for &'a $lhs {
+            type Output = $out;
+            fn sub(self, rhs: $rhs) -> $out {
+                self - &rhs
+            }
+        }
+    };
+}
+
+/// Define the `SubAssign` variants from the `&LHS - &RHS` implementation.
+macro_rules! define_sub_assign_variants {
+    (LHS = $lhs:ty, RHS = $rhs:ty) => {
+        impl<'b> SubAssign<&'b $rhs> for $lhs {
+            fn sub_assign(&mut self, rhs: &'b $rhs) {
+                *self = &*self - rhs;
+            }
+        }
+
+        impl SubAssign<$rhs> for $lhs {
+            fn sub_assign(&mut self, rhs: $rhs) {
+                *self = &*self - &rhs;
+            }
+        }
+    };
+}
+
+/// Define the `Mul` variants that mix owned and borrowed operands
+/// from the `&LHS * &RHS` implementation.
+macro_rules! define_mixed_mul_variants {
+    (LHS = $lhs:ty, RHS = $rhs:ty, Output = $out:ty) => {
+        impl<'b> Mul<&'b $rhs> for $lhs {
+            type Output = $out;
+            fn mul(self, rhs: &'b $rhs) -> $out {
+                &self * rhs
+            }
+        }
+
+        impl<'a> Mul<$rhs> for &'a $lhs {
+            type Output = $out;
+            fn mul(self, rhs: $rhs) -> $out {
+                self * &rhs
+            }
+        }
+    };
+}
+
+/// Define the `MulAssign` variants from the `&LHS * &RHS` implementation.
+macro_rules! define_mul_assign_variants {
+    (LHS = $lhs:ty, RHS = $rhs:ty) => {
+        impl<'b> MulAssign<&'b $rhs> for $lhs {
+            fn mul_assign(&mut self, rhs: &'b $rhs) {
+                *self = &*self * rhs;
+            }
+        }
+
+        impl MulAssign<$rhs> for $lhs {
+            fn mul_assign(&mut self, rhs: $rhs) {
+                *self = &*self * &rhs;
+            }
+        }
+    };
+}
+
+/// Define a `Debug` implementation for a secret-bearing newtype
+/// that hides its contents, unless the `reveal_secrets` feature is
+/// enabled.
+macro_rules! define_redacted_debug {
+    ($name:ident) => {
+        impl ::core::fmt::Debug for $name {
+            #[cfg(not(feature = "reveal_secrets"))]
+            fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                write!(f, concat!(stringify!($name), "(<redacted>)"))
+            }
+
+            #[cfg(feature = "reveal_secrets")]
+            fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                write!(f, concat!(stringify!($name), "({:?})"), &self.0)
+            }
+        }
+    };
+}
+
+/// Define the `LowerHex` and `UpperHex` implementations of a type
+/// over the 32 bytes returned by the given method, printed in
+/// encoding order.
+macro_rules! define_hex_formatting {
+    ($name:ty, $bytes:ident) => {
+        impl ::core::fmt::LowerHex for $name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                for byte in self.$bytes().iter() {
+                    write!(f, "{:02x}", byte)?;
+                }
+                Ok(())
+            }
+        }
+
+        impl ::core::fmt::UpperHex for $name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                for byte in self.$bytes().iter() {
+                    write!(f, "{:02X}", byte)?;
+                }
+                Ok(())
+            }
+        }
+    };
+}
+
+/// Define `from_hex` and `TryFrom<&str>` on a type, decoding the
+/// format of `define_hex_formatting`: 64 hex digits (in either case)
+/// in encoding order. The bytes are then checked with the given
+/// strict decoding function, so only canonical encodings are accepted.
+macro_rules! define_hex_parsing {
+    ($name:ty, $decode:expr) => {
+        impl $name {
+            /// Parse the 64 hex digits of a canonical encoding, as
+            /// printed by the `LowerHex` and `UpperHex` formatting.
+            pub fn from_hex(hex: &str) -> Result<$name, crate::errors::DecodingError> {
+                let bytes = crate::macros::decode_hex(hex)?;
+                $decode(&bytes)
+            }
+        }
+
+        impl<'a> ::core::convert::TryFrom<&'a str> for $name {
+            type Error = crate::errors::DecodingError;
+
+            fn try_from(hex: &'a str) -> Result<$name, crate::errors::DecodingError> {
+                <$name>::from_hex(hex)
+            }
+        }
+    };
+}
+
+/// Decode exactly 32 bytes from 64 hex digits.
+pub(crate) fn decode_hex(hex: &str) -> Result<[u8; 32], crate::errors::DecodingError> {
+    use crate::errors::DecodingError;
+
+    let hex = hex.as_bytes();
+    if hex.len() != 64 {
+        return Err(DecodingError::InvalidLength);
+    };
+    let digit = |c: u8| match c {
+        b'0'..=b'9' => Ok(c - b'0'),
+        b'a'..=b'f' => Ok(c - b'a' + 10),
+        b'A'..=b'F' => Ok(c - b'A' + 10),
+        _ => Err(DecodingError::InvalidHex),
+    };
+    let mut bytes = [0u8; 32];
+    for (byte, pair) in bytes.iter_mut().zip(hex.chunks(2)) {
+        *byte = (digit(pair[0])? << 4) | digit(pair[1])?;
+    }
+    Ok(bytes)
+}
@@ -3,16 +3,34 @@
 //!
 //! A `MontgomeryPoint` is represented as the `u-coordinate`
 //! of itself in LE bytes-format.
+//!
+//! The Montgomery form of the Sonny curve is:
+//! `-505188 * v^2 = u^3 + 505186 * u^2 + u`.
+//!
+//! Scalar multiplication is performed with the x-only
+//! Montgomery ladder, so only the `u-coordinate` is needed.
 
 use crate::edwards::EdwardsPoint;
 use crate::field::FieldElement;
+use crate::scalar::Scalar;
+use crate::traits::ops::*;
+
+use core::hash::{Hash, Hasher};
+use core::ops::Mul;
 
-use subtle::Choice;
-use subtle::ConstantTimeEq;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+
+/// `(A - 2) / 4 = 126296` where `A = 505186` is the Montgomery
+/// `A` coefficient of the curve.
+const A24: u32 = 126296;
 
 /// Holds the u-coordinate of a point on the Montgomery form of
 /// Doppio-curve or its twist.
+///
+/// It is `#[repr(transparent)]`, so it has the layout of a
+/// `[u8; 32]` and can cross FFI boundaries as such.
 #[derive(Copy, Clone, Debug)]
+#[repr(transparent)]
 pub struct MontgomeryPoint(pub [u8; 32]);
 
 /// Equality of `MontgomeryPoint`s is defined mod p.
@@ -25,6 +43,24 @@ impl ConstantTimeEq for MontgomeryPoint {
     }
 }
 
+impl ConditionallySelectable for MontgomeryPoint {
+    /// Select between the encodings of `a` and `b`, byte by byte.
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        let mut bytes = [0u8; 32];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = u8::conditional_select(&a.0[i], &b.0[i], choice);
+        }
+        MontgomeryPoint(bytes)
+    }
+
+    /// Swap `a` and `b` if `choice == 1`, byte by byte.
+    fn conditional_swap(a: &mut Self, b: &mut Self, choice: Choice) {
+        for (a_i, b_i) in a.0.iter_mut().zip(b.0.iter_mut()) {
+            u8::conditional_swap(a_i, b_i, choice);
+        }
+    }
+}
+
 impl Default for MontgomeryPoint {
     fn default() -> MontgomeryPoint {
         MontgomeryPoint([0u8; 32])
@@ -39,6 +75,13 @@ impl PartialEq for MontgomeryPoint {
 
 impl Eq for MontgomeryPoint {}
 
+impl Hash for MontgomeryPoint {
+    /// Hash the bytes of the encoding, consistently with `PartialEq`.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
 impl MontgomeryPoint {
     /// View this `MontgomeryPoint` as an array of bytes.
     pub fn as_bytes<'a>(&'a self) -> &'a [u8; 32] {
@@ -55,4 +98,146 @@ impl MontgomeryPoint {
     pub fn to_edwards(&self, _sign: u8) -> Option<EdwardsPoint> {
         unimplemented!()
     }
+
+    /// Compute `8 * scalar * self` with a single Montgomery ladder
+    /// over the bits of the integer `8 * scalar`.
+    ///
+    /// The result doesn't depend on `scalar mod 8`, and every point
+    /// of small order gets mapped to the identity (`u = 0`), so the
+    /// low-order component of `self` can't leak any bit of `scalar`.
+    pub fn mul_clear_cofactor(&self, scalar: &Scalar) -> MontgomeryPoint {
+        let mut bits = [0u8; 259];
+        bits[3..].copy_from_slice(&scalar.into_bits());
+        ladder(self, &bits)
+    }
+}
+
+/// Run the x-only Montgomery ladder over the LE `bits` of the
+/// scalar, with constant-time swaps.
+///
+/// Montgomery, P. L. (1987). Speeding the Pollard and elliptic
+/// curve methods of factorization. Mathematics of Computation.
+/// See also: RFC 7748, Section 5.
+fn ladder(point: &MontgomeryPoint, bits: &[u8]) -> MontgomeryPoint {
+    let a24 = FieldElement::from(A24);
+    let x1 = FieldElement::from_bytes(&point.0);
+
+    let (mut x2, mut z2) = (FieldElement::one(), FieldElement::zero());
+    let (mut x3, mut z3) = (x1, FieldElement::one());
+    let mut swap = Choice::from(0u8);
+
+    for bit in bits.iter().rev() {
+        let bit = Choice::from(*bit);
+        swap ^= bit;
+        FieldElement::conditional_swap(&mut x2, &mut x3, swap);
+        FieldElement::conditional_swap(&mut z2, &mut z3, swap);
+        swap = bit;
+
+        let a = x2 + z2;
+        let aa = a.square();
+        let b = x2 - z2;
+        let bb = b.square();
+        let e = aa - bb;
+        let c = x3 + z3;
+        let d = x3 - z3;
+        let da = d * a;
+        let cb = c * b;
+
+        x3 = (da + cb).square();
+        z3 = x1 * (da - cb).square();
+        x2 = aa * bb;
+        z2 = e * (aa + (a24 * e));
+    }
+    FieldElement::conditional_swap(&mut x2, &mut x3, swap);
+    FieldElement::conditional_swap(&mut z2, &mut z3, swap);
+
+    // The point at infinity, `z2 = 0`, is encoded as `u = 0`.
+    // The constant-time inversion keeps `z2` from leaking the scalar.
+    let u = x2 * z2.invert_nonzero().unwrap_or(FieldElement::zero());
+
+    MontgomeryPoint(u.to_bytes())
+}
+
+impl<'b> Mul<&'b Scalar> for &MontgomeryPoint {
+    type Output = MontgomeryPoint;
+    /// Scalar multiplication: compute `Scalar * self` using the
+    /// x-only Montgomery ladder with constant-time swaps.
+    fn mul(self, scalar: &'b Scalar) -> MontgomeryPoint {
+        ladder(self, &scalar.into_bits())
+    }
+}
+
+impl Mul<Scalar> for MontgomeryPoint {
+    type Output = MontgomeryPoint;
+    /// Scalar multiplication: compute `Scalar * self` using the
+    /// x-only Montgomery ladder with constant-time swaps.
+    fn mul(self, scalar: Scalar) -> MontgomeryPoint {
+        (&self).mul(&scalar)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants;
+
+    #[test]
+    fn basepoint_to_montgomery() {
+        assert!(constants::BASEPOINT.to_montgomery() == constants::MONTGOMERY_BASEPOINT);
+    }
+
+    #[test]
+    fn ladder_vs_edwards_mul() {
+        let scalars = [
+            Scalar::one(),
+            Scalar::from(8u8),
+            Scalar::from(1122334455u64),
+            Scalar::two_pow_k(215),
+            Scalar::minus_one(),
+        ];
+
+        for k in &scalars {
+            let expected = (constants::BASEPOINT * *k).to_montgomery();
+            assert!(&constants::MONTGOMERY_BASEPOINT * k == expected);
+        }
+    }
+
+    #[test]
+    fn conditional_select_and_swap() {
+        let p = constants::MONTGOMERY_BASEPOINT;
+        let q = p * Scalar::from(3u8);
+        assert!(MontgomeryPoint::conditional_select(&p, &q, Choice::from(0u8)) == p);
+        assert!(MontgomeryPoint::conditional_select(&p, &q, Choice::from(1u8)) == q);
+
+        let (mut a, mut b) = (p, q);
+        MontgomeryPoint::conditional_swap(&mut a, &mut b, Choice::from(1u8));
+        assert!(a == q && b == p);
+        MontgomeryPoint::conditional_swap(&mut a, &mut b, Choice::from(0u8));
+        assert!(a == q && b == p);
+    }
+
+    #[test]
+    fn ladder_identity() {
+        let res = constants::MONTGOMERY_BASEPOINT * constants::L;
+        assert!(res == MontgomeryPoint::default());
+        let res = constants::MONTGOMERY_BASEPOINT * Scalar::zero();
+        assert!(res == MontgomeryPoint::default());
+    }
+
+    #[test]
+    fn mul_clear_cofactor() {
+        let k = Scalar::from(1122334455u64);
+        let u = constants::MONTGOMERY_BASEPOINT * Scalar::from(3u8);
+        assert!(u.mul_clear_cofactor(&k) == u * (k * Scalar::from(8u8)));
+        assert!(u.mul_clear_cofactor(&Scalar::minus_one()) == u * -Scalar::from(8u8));
+        // The point `(0, 0)` has order 2.
+        let t = MontgomeryPoint::default();
+        assert!(t.mul_clear_cofactor(&k) == MontgomeryPoint::default());
+    }
+
+    #[test]
+    fn transparent_layout() {
+        assert_eq!(std::mem::size_of::<MontgomeryPoint>(), 32);
+        assert_eq!(std::mem::align_of::<MontgomeryPoint>(), 1);
+    }
 }
@@ -0,0 +1,310 @@
+#![allow(non_snake_case)]
+//! MuSig-style key aggregation for the Schnorr signatures of the
+//! `signatures` module.
+//!
+//! Given the public keys `PK_1, ..., PK_n` of the signers:
+//!
+//! - `L = H("zerocaf-musig-keys" || n || PK_1 || ... || PK_n)`.
+//! - `a_i = H("zerocaf-musig-coeff" || L || PK_i) (mod l)`.
+//! - The aggregate public key is `X = sum a_i * PK_i`.
+//!
+//! The coefficients `a_i` stop any signer from choosing its key as
+//! a function of the others' ones to cancel them out (rogue-key
+//! attacks).
+//!
+//! To sign a message `m`, the signers run three rounds:
+//!
+//! 1. Every signer samples a fresh random nonce `k_i`, computes
+//!    `R_i = k_i * B` and shares only the commitment
+//!    `t_i = H("zerocaf-musig-nonce" || R_i)`.
+//! 2. Once it holds the commitments of all of the others, every
+//!    signer reveals its `R_i`. `aggregate_nonces` checks every `R_i`
+//!    against its `t_i` before computing `R = sum R_i`.
+//! 3. With the challenge `c` of the `signatures` module computed
+//!    over `(R, X, m)`, each signer produces the partial signature
+//!    `s_i = k_i + c * a_i * sk_i (mod l)`. The signature
+//!    `(R, sum s_i)` verifies with `signatures::verify` against `X`.
+//!
+//! The commitment round is what keeps a signer from choosing its
+//! `R_i` after seeing the honest ones. Without it, an attacker that
+//! opens many signing sessions concurrently can combine them into a
+//! forgery (Wagner's generalized birthday attack, or the ROS attack
+//! of Benhamouda et al.), so no `R_i` may be revealed before all of
+//! the commitments of its session have been received, and sessions
+//! that skip the commitment round must never run concurrently.
+//!
+//! The nonces must never be reused nor derived deterministically,
+//! otherwise the partial signatures leak the secret keys.
+//!
+//! # Examples
+//! ```rust
+//! use zerocaf::musig::*;
+//! use zerocaf::signatures::{keygen, verify};
+//!
+//! use rand::rngs::OsRng;
+//!
+//! let (sk1, pk1) = keygen(&mut OsRng);
+//! let (sk2, pk2) = keygen(&mut OsRng);
+//! let pks = [pk1, pk2];
+//! let msg = b"Sonny loves multisigs";
+//!
+//! // Round 1: exchange the nonce commitments.
+//! let (k1, R1) = generate_nonce(&mut OsRng);
+//! let (k2, R2) = generate_nonce(&mut OsRng);
+//! let commitments = [commit_nonce(&R1), commit_nonce(&R2)];
+//!
+//! // Round 2: reveal the nonces and check them.
+//! let R = aggregate_nonces(&commitments, &[R1, R2]).unwrap();
+//!
+//! // Round 3: exchange the partial signatures.
+//!
+//! let s1 = partial_sign(msg, &sk1, &k1, &R, &pks);
+//! let s2 = partial_sign(msg, &sk2, &k2, &R, &pks);
+//! assert!(verify_partial_signature(msg, &s1, &R1, &pk1, &R, &pks));
+//!
+//! let sig = aggregate_partial_signatures(&R, &[s1, s2]);
+//! assert!(verify(msg, &sig, &aggregate_public_keys(&pks)));
+//! ```
+
+use crate::constants;
+use crate::errors::Error;
+use crate::ristretto::RistrettoPoint;
+use crate::scalar::Scalar;
+use crate::signatures::{challenge, Signature, DEFAULT_CONTEXT};
+
+use rand::{CryptoRng, RngCore};
+use sha2::{Digest, Sha512};
+
+/// Domain separator used on the hash of the set of public keys.
+pub(crate) const KEYS_DOMAIN: &[u8] = b"zerocaf-musig-keys";
+
+/// Domain separator used on the key aggregation coefficients.
+pub(crate) const COEFF_DOMAIN: &[u8] = b"zerocaf-musig-coeff";
+
+/// Domain separator used on the nonce commitments.
+pub(crate) const NONCE_DOMAIN: &[u8] = b"zerocaf-musig-nonce";
+
+/// Compute `L = H("zerocaf-musig-keys" || n || PK_1 || ... || PK_n)`.
+fn hash_keys(pks: &[RistrettoPoint]) -> [u8; 64] {
+    let mut hasher = Sha512::new()
+        .chain(KEYS_DOMAIN)
+        .chain((pks.len() as u64).to_le_bytes());
+    for pk in pks {
+        hasher = hasher.chain(pk.compress().as_bytes());
+    }
+
+    let mut L = [0u8; 64];
+    L.copy_from_slice(hasher.result().as_slice());
+    L
+}
+
+/// Compute the coefficient `a = H("zerocaf-musig-coeff" || L || PK) (mod l)`.
+fn coefficient(L: &[u8; 64], pk: &RistrettoPoint) -> Scalar {
+    let hasher = Sha512::new()
+        .chain(COEFF_DOMAIN)
+        .chain(&L[..])
+        .chain(pk.compress().as_bytes());
+    Scalar::from_hash(hasher)
+}
+
+/// Compute the key aggregation coefficient of `pk` within the
+/// set of public keys `pks`.
+///
+/// The order of `pks` matters, so all of the signers must agree
+/// on it.
+pub fn key_agg_coefficient(pks: &[RistrettoPoint], pk: &RistrettoPoint) -> Scalar {
+    coefficient(&hash_keys(pks), pk)
+}
+
+/// Compute the aggregate public key `X = sum a_i * PK_i` of the
+/// set of public keys `pks`.
+pub fn aggregate_public_keys(pks: &[RistrettoPoint]) -> RistrettoPoint {
+    let L = hash_keys(pks);
+    pks.iter().map(|pk| pk * coefficient(&L, pk)).sum()
+}
+
+/// Generate a fresh random nonce and it's commitment: `(k, k * B)`.
+///
+/// By `rng` we mean any Rng that implements: `RngCore` + `CryptoRng`.
+pub fn generate_nonce<T>(rng: &mut T) -> (Scalar, RistrettoPoint)
+where
+    T: RngCore + CryptoRng,
+{
+    let k = Scalar::random(rng);
+    (k, RistrettoPoint::mul_base(&k))
+}
+
+/// Compute the commitment `t_i = H("zerocaf-musig-nonce" || R_i)`
+/// to the nonce commitment `R_i`, shared on the first round.
+pub fn commit_nonce(R_i: &RistrettoPoint) -> [u8; 64] {
+    let hasher = Sha512::new()
+        .chain(NONCE_DOMAIN)
+        .chain(R_i.compress().as_bytes());
+
+    let mut t_i = [0u8; 64];
+    t_i.copy_from_slice(hasher.result().as_slice());
+    t_i
+}
+
+/// Compute the aggregate nonce commitment `R = sum R_i` of the
+/// revealed `nonces`, checking each of them against the commitment
+/// of the same index, see `commit_nonce`.
+///
+/// # Returns
+/// - `Error::InvalidLength` if there's not one commitment per nonce.
+/// - `Error::CommitmentMismatch` if any nonce doesn't match its
+///   commitment, in which case the session must be aborted.
+pub fn aggregate_nonces(
+    commitments: &[[u8; 64]],
+    nonces: &[RistrettoPoint],
+) -> Result<RistrettoPoint, Error> {
+    if commitments.len() != nonces.len() {
+        return Err(Error::InvalidLength);
+    };
+    if commitments
+        .iter()
+        .zip(nonces.iter())
+        .any(|(t_i, R_i)| t_i[..] != commit_nonce(R_i)[..])
+    {
+        return Err(Error::CommitmentMismatch);
+    };
+    Ok(nonces.iter().sum())
+}
+
+/// Compute the partial signature `s_i = k_i + c * a_i * sk_i (mod l)`
+/// of the signer with the secret key `sk` and the nonce `k`.
+///
+/// `R` is the aggregate nonce commitment returned by
+/// `aggregate_nonces` and `pks` the set of public keys of all of
+/// the signers.
+pub fn partial_sign(
+    msg: &[u8],
+    sk: &Scalar,
+    k: &Scalar,
+    R: &RistrettoPoint,
+    pks: &[RistrettoPoint],
+) -> Scalar {
+    let pk = RistrettoPoint::mul_base(sk);
+    let a = key_agg_coefficient(pks, &pk);
+    let c = challenge(DEFAULT_CONTEXT, &R.compress(), &aggregate_public_keys(pks).compress(), msg);
+    k + c * a * sk
+}
+
+/// Verify the partial signature `s_i` of the signer with the public
+/// key `pk` and the nonce commitment `R_i` by checking that
+/// `s_i * B == R_i + c * a_i * PK_i`.
+///
+/// It allows to find which signer misbehaved when the aggregate
+/// signature is not valid.
+pub fn verify_partial_signature(
+    msg: &[u8],
+    s_i: &Scalar,
+    R_i: &RistrettoPoint,
+    pk: &RistrettoPoint,
+    R: &RistrettoPoint,
+    pks: &[RistrettoPoint],
+) -> bool {
+    let a = key_agg_coefficient(pks, pk);
+    let c = challenge(DEFAULT_CONTEXT, &R.compress(), &aggregate_public_keys(pks).compress(), msg);
+    constants::RISTRETTO_BASEPOINT * s_i == R_i + pk * (c * a)
+}
+
+/// Aggregate the partial signatures of all of the signers into
+/// the `Signature` `(R, sum s_i)`.
+pub fn aggregate_partial_signatures(R: &RistrettoPoint, partials: &[Scalar]) -> Signature {
+    Signature {
+        R: R.compress(),
+        s: partials.iter().fold(Scalar::zero(), |acc, s_i| acc + s_i),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signatures::{keygen, verify};
+
+    use rand::rngs::OsRng;
+
+    fn sign_with(keys: &[(Scalar, RistrettoPoint)], msg: &[u8]) -> Signature {
+        let pks: Vec<RistrettoPoint> = keys.iter().map(|(_, pk)| *pk).collect();
+        let nonces: Vec<(Scalar, RistrettoPoint)> =
+            keys.iter().map(|_| generate_nonce(&mut OsRng)).collect();
+        let commitments: Vec<[u8; 64]> = nonces.iter().map(|(_, R_i)| commit_nonce(R_i)).collect();
+        let revealed: Vec<RistrettoPoint> = nonces.iter().map(|(_, R_i)| *R_i).collect();
+        let R = aggregate_nonces(&commitments, &revealed).unwrap();
+
+        let partials: Vec<Scalar> = keys
+            .iter()
+            .zip(nonces.iter())
+            .map(|((sk, _), (k, _))| partial_sign(msg, sk, k, &R, &pks))
+            .collect();
+        aggregate_partial_signatures(&R, &partials)
+    }
+
+    #[test]
+    fn aggregate_signature_verifies() {
+        let keys: Vec<(Scalar, RistrettoPoint)> = (0..5).map(|_| keygen(&mut OsRng)).collect();
+        let pks: Vec<RistrettoPoint> = keys.iter().map(|(_, pk)| *pk).collect();
+        let msg = b"Sonny loves multisigs";
+
+        let sig = sign_with(&keys, msg);
+        let X = aggregate_public_keys(&pks);
+        assert!(verify(msg, &sig, &X));
+        assert!(!verify(b"Sonny hates multisigs", &sig, &X));
+        // The aggregate key is not the plain sum of the keys.
+        assert!(X != pks.iter().sum());
+    }
+
+    #[test]
+    fn aggregation_depends_on_the_key_set() {
+        let keys: Vec<(Scalar, RistrettoPoint)> = (0..3).map(|_| keygen(&mut OsRng)).collect();
+        let pks: Vec<RistrettoPoint> = keys.iter().map(|(_, pk)| *pk).collect();
+
+        let a = key_agg_coefficient(&pks, &pks[0]);
+        assert!(a != key_agg_coefficient(&pks[..2], &pks[0]));
+        assert!(a != key_agg_coefficient(&pks, &pks[1]));
+
+        let reordered = [pks[1], pks[0], pks[2]];
+        assert!(aggregate_public_keys(&pks) != aggregate_public_keys(&reordered));
+        // A single signer still gets a coefficient.
+        let single = aggregate_public_keys(&pks[..1]);
+        assert!(single == pks[0] * key_agg_coefficient(&pks[..1], &pks[0]));
+    }
+
+    #[test]
+    fn partial_signatures() {
+        let keys: Vec<(Scalar, RistrettoPoint)> = (0..3).map(|_| keygen(&mut OsRng)).collect();
+        let pks: Vec<RistrettoPoint> = keys.iter().map(|(_, pk)| *pk).collect();
+        let msg = b"Sonny loves multisigs";
+
+        let (k, R_i) = generate_nonce(&mut OsRng);
+        let (_, R_other) = generate_nonce(&mut OsRng);
+        let commitments = [commit_nonce(&R_i), commit_nonce(&R_other)];
+        let R = aggregate_nonces(&commitments, &[R_i, R_other]).unwrap();
+        let s_i = partial_sign(msg, &keys[0].0, &k, &R, &pks);
+
+        assert!(verify_partial_signature(msg, &s_i, &R_i, &pks[0], &R, &pks));
+        assert!(!verify_partial_signature(msg, &s_i, &R_i, &pks[1], &R, &pks));
+        let tampered = s_i + Scalar::one();
+        assert!(!verify_partial_signature(msg, &tampered, &R_i, &pks[0], &R, &pks));
+    }
+
+    #[test]
+    fn nonces_must_match_their_commitments() {
+        let (_, R1) = generate_nonce(&mut OsRng);
+        let (_, R2) = generate_nonce(&mut OsRng);
+        let commitments = [commit_nonce(&R1), commit_nonce(&R2)];
+        assert!(aggregate_nonces(&commitments, &[R1, R2]) == Ok(R1 + R2));
+
+        // A signer can't swap its nonce after seeing the others.
+        let (_, R_rogue) = generate_nonce(&mut OsRng);
+        let res = aggregate_nonces(&commitments, &[R1, R_rogue]);
+        assert!(res == Err(Error::CommitmentMismatch));
+        let res = aggregate_nonces(&commitments, &[R2, R1]);
+        assert!(res == Err(Error::CommitmentMismatch));
+        // Every nonce needs its commitment.
+        let res = aggregate_nonces(&commitments[..1], &[R1, R2]);
+        assert!(res == Err(Error::InvalidLength));
+        assert!(commit_nonce(&R1) != commit_nonce(&R2));
+    }
+}
@@ -0,0 +1,99 @@
+//! Deterministic nonce derivation for the signing APIs.
+//!
+//! Nonces are derived as:
+//! `k = H("zerocaf-nonce" || domain || sk || aux || m) (mod l)`
+//! where `H` is `Sha512` and `aux` is an optional 32-byte
+//! random value.
+//!
+//! Without `aux`, signing the same message with the same key
+//! always produces the same nonce, so the signatures don't depend
+//! on the quality of the rng at signing time. Providing fresh
+//! randomness as `aux` (hedged signing) still keeps the nonce
+//! secret if the rng is broken, while adding protection against
+//! fault attacks on deterministic signing.
+
+use crate::scalar::Scalar;
+
+use rand::{CryptoRng, RngCore};
+use sha2::{Digest, Sha512};
+
+/// Domain separator used on the nonce derivation.
+pub(crate) const NONCE_DOMAIN: &[u8] = b"zerocaf-nonce";
+
+/// Derive a nonce from the secret key and the message.
+///
+/// `domain` separates the nonces of the different protocols, so
+/// the same key and message never share a nonce across them.
+///
+/// # Panics
+/// If `domain` is longer than 255 bytes, since its length gets
+/// encoded on a single byte.
+pub fn derive_nonce(domain: &[u8], sk: &Scalar, msg: &[u8], aux: Option<&[u8; 32]>) -> Scalar {
+    assert!(
+        domain.len() <= 255,
+        "the domain can't be longer than 255 bytes"
+    );
+    let mut hasher = Sha512::new()
+        .chain(NONCE_DOMAIN)
+        .chain([domain.len() as u8])
+        .chain(domain)
+        .chain(sk.to_bytes());
+    match aux {
+        Some(aux) => hasher = hasher.chain([0x01]).chain(aux),
+        None => hasher = hasher.chain([0x00]),
+    };
+    Scalar::from_hash(hasher.chain(msg))
+}
+
+/// Derive a nonce from the secret key and the message, mixing in
+/// 32 bytes of extra randomness taken from the given `rng`.
+///
+/// By `rng` we mean any Rng that implements: `RngCore` + `CryptoRng`.
+///
+/// # Panics
+/// If `domain` is longer than 255 bytes.
+pub fn derive_hedged_nonce<T>(domain: &[u8], sk: &Scalar, msg: &[u8], rng: &mut T) -> Scalar
+where
+    T: RngCore + CryptoRng,
+{
+    let mut aux = [0u8; 32];
+    rng.fill_bytes(&mut aux);
+    derive_nonce(domain, sk, msg, Some(&aux))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rand::rngs::OsRng;
+
+    #[test]
+    fn deterministic_nonces() {
+        let sk = Scalar::random(&mut OsRng);
+        let msg = b"Sonny's nonce";
+
+        let k = derive_nonce(b"test", &sk, msg, None);
+        assert!(k == derive_nonce(b"test", &sk, msg, None));
+        // Every input changes the nonce.
+        assert!(k != derive_nonce(b"other", &sk, msg, None));
+        assert!(k != derive_nonce(b"test", &(sk + Scalar::one()), msg, None));
+        assert!(k != derive_nonce(b"test", &sk, b"Other nonce", None));
+        assert!(k != derive_nonce(b"test", &sk, msg, Some(&[0u8; 32])));
+    }
+
+    #[test]
+    fn hedged_nonces() {
+        let sk = Scalar::random(&mut OsRng);
+        let msg = b"Sonny's nonce";
+
+        let k1 = derive_hedged_nonce(b"test", &sk, msg, &mut OsRng);
+        let k2 = derive_hedged_nonce(b"test", &sk, msg, &mut OsRng);
+        assert!(k1 != k2);
+    }
+
+    #[test]
+    #[should_panic(expected = "the domain can't be longer than 255 bytes")]
+    fn long_domains_are_rejected() {
+        derive_nonce(&[0u8; 256], &Scalar::one(), b"", None);
+    }
+}
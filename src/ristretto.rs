@@ -25,25 +25,48 @@
 //! 
 //! Reference: https://tools.ietf.org/html/draft-hdevalence-cfrg-ristretto-00
 use crate::constants;
-use crate::edwards::{double_and_add, EdwardsPoint};
+use crate::edwards::{blinded_mul, double_and_add, EdwardsPoint, VartimeEdwardsPrecomputation};
+use crate::errors::DecodingError;
 use crate::field::FieldElement;
+use crate::hash_to_field::expand_message_xmd;
 use crate::scalar::Scalar;
 use crate::traits::ops::*;
-use crate::traits::{Identity, ValidityCheck};
+use crate::traits::{
+    Identity, MultiscalarMul, ValidityCheck, VartimeMultiscalarMul,
+    VartimePrecomputedMultiscalarMul,
+};
 
-use core::ops::{Add, Sub, Index, Mul, Neg};
+use core::borrow::Borrow;
+use core::hash::{Hash, Hasher};
+use core::iter::Sum;
+use core::ops::{Add, AddAssign, Index, Mul, MulAssign, Neg, Sub, SubAssign};
 
 use std::fmt::Debug;
 
-use rand::{CryptoRng, Rng};
+use digest::generic_array::typenum::U64;
+use digest::Digest;
+use rand::{CryptoRng, Rng, RngCore};
+use sha2::Sha512;
 use subtle::{Choice, ConditionallyNegatable, ConditionallySelectable, ConstantTimeEq};
+#[cfg(feature = "group")]
+use subtle::CtOption;
 
 /// Ristretto Point expressed in wire format.
 /// Since the Ristretto bytes encoding is canonical,
 /// two points are equal if their encodin form is equal.
+///
+/// It is `#[repr(transparent)]`, so it has the layout of a
+/// `[u8; 32]` and can cross FFI boundaries as such.
 #[derive(Debug, Clone, Copy)]
+#[repr(transparent)]
 pub struct CompressedRistretto(pub [u8; 32]);
 
+define_hex_formatting!(CompressedRistretto, as_bytes);
+define_hex_parsing!(CompressedRistretto, |bytes: &[u8; 32]| {
+    let point = CompressedRistretto(*bytes);
+    point.decompress_strict().map(|_| point)
+});
+
 impl Index<usize> for CompressedRistretto {
     type Output = u8;
     fn index(&self, _index: usize) -> &u8 {
@@ -65,6 +88,13 @@ impl PartialEq for CompressedRistretto {
 
 impl Eq for CompressedRistretto {}
 
+impl Hash for CompressedRistretto {
+    /// Hash the bytes of the encoding, consistently with `PartialEq`.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
 impl Identity for CompressedRistretto {
     /// Returns the Identity point on `CompressedRistretto`
     /// format.
@@ -79,6 +109,12 @@ impl CompressedRistretto {
         self.0
     }
 
+    /// Copy the bytes of the `CompressedRistretto` point into the
+    /// given buffer.
+    pub fn write_bytes_into(&self, out: &mut [u8; 32]) {
+        out.copy_from_slice(&self.0);
+    }
+
     pub fn copy_from_slice(bytes: &[u8]) -> CompressedRistretto {
         let mut inp = [0u8; 32];
         inp.copy_from_slice(bytes);
@@ -94,8 +130,16 @@ impl CompressedRistretto {
     /// - If the decompression/decoding succeeds -> `Some(RistrettoPoint)`.
     /// - If the decompression/decoding fails -> `None`.
     pub fn decompress(&self) -> Option<RistrettoPoint> {
-        // Step 1: Check that the byte-string is a valid FieldElement.
+        let s = self.decode_s()?;
+        let (u1, u2, v) = CompressedRistretto::decompression_ratio(&s);
+        // I = 1/sqrt(v*u2²), returns `None` if the sqrt does not exist.
+        let (ok, I) = (v * u2.square()).inv_sqrt();
+        CompressedRistretto::decompress_with_inv_sqrt(&s, (&u1, &u2, &v), ok, &I)
+    }
 
+    /// Step 1 of the decompression: check that the byte-string is a
+    /// valid and positive `FieldElement` `s`.
+    fn decode_s(&self) -> Option<FieldElement> {
         // As Ristretto paper says: "If the implementation's field element
         // encoding function produces canonical outputs, one way to check
         // that s_bytes is a canonical encoding (in step 1) is to decode
@@ -112,19 +156,31 @@ impl CompressedRistretto {
         if s_is_positive.unwrap_u8() == 0u8 || s_correct_enc.unwrap_u8() == 0u8 {
             return None;
         };
-        // Step 2: Attempt to decompress the CompressedRistretto.
+        Some(s)
+    }
+
+    /// Get the `(u1, u2, v)` of the decompression of `s`, which
+    /// needs the inverse square root of `v * u2²`.
+    fn decompression_ratio(s: &FieldElement) -> (FieldElement, FieldElement, FieldElement) {
         let one = FieldElement::one();
 
         // u1 = 1 + as² with a = -1.
         let u1 = one - s.square();
         // u2 = 1 - as² with a = -1.
         let u2 = one + s.square();
-        let u2_sq = u2.square();
 
         // v = a*d*u1² - u2²
-        let v = -(constants::EDWARDS_D * u1.square()) - u2_sq;
-        // I = 1/sqrt(v*u2²), returns `None` if the sqrt does not exist.
-        let (ok, I) = (v * u2_sq).inv_sqrt();
+        let v = -(constants::EDWARDS_D * u1.square()) - u2.square();
+        (u1, u2, v)
+    }
+
+    /// Step 2 of the decompression, given `(ok, I) = inv_sqrt(v * u2²)`.
+    fn decompress_with_inv_sqrt(
+        s: &FieldElement,
+        (u1, u2, v): (&FieldElement, &FieldElement, &FieldElement),
+        ok: Choice,
+        I: &FieldElement,
+    ) -> Option<RistrettoPoint> {
         if ok.unwrap_u8() == 0 {
             return None;
         };
@@ -134,24 +190,67 @@ impl CompressedRistretto {
         let Dy = I * Dx * v;
 
         // Compute ABS(2*s*Dx) and negate if it is negative.
-        let mut x = (s + s) * Dx;
+        let mut x = s.double() * Dx;
         let x_is_pos = x.is_positive();
         x.conditional_negate(!x_is_pos);
         // Compute Y and T coordinates.
         let y = u1 * Dy;
         let t = x * y;
 
-        if t.is_positive().unwrap_u8() == 0u8 || y == FieldElement::zero() {
+        if t.is_positive().unwrap_u8() == 0u8 || y.is_zero().unwrap_u8() == 1u8 {
             return None;
         };
 
         Some(RistrettoPoint(EdwardsPoint {
             X: x,
             Y: y,
-            Z: one,
+            Z: FieldElement::one(),
             T: t,
         }))
     }
+
+    /// Decompress all of the `points` at once, sharing a single
+    /// field inversion across the batch through
+    /// `FieldElement::batch_sqrt_ratio_i`.
+    ///
+    /// Returns `None` if any of the inputs doesn't decode to a point.
+    pub fn batch_decompress(points: &[CompressedRistretto]) -> Option<Vec<RistrettoPoint>> {
+        let s: Vec<FieldElement> = points
+            .iter()
+            .map(|point| point.decode_s())
+            .collect::<Option<_>>()?;
+        let ratios: Vec<_> = s.iter().map(CompressedRistretto::decompression_ratio).collect();
+
+        let ones = vec![FieldElement::one(); points.len()];
+        let denominators: Vec<FieldElement> =
+            ratios.iter().map(|(_, u2, v)| *v * u2.square()).collect();
+        let inv_sqrts = FieldElement::batch_sqrt_ratio_i(&ones, &denominators);
+
+        s.iter()
+            .zip(ratios.iter())
+            .zip(inv_sqrts.iter())
+            .map(|((s, (u1, u2, v)), (ok, I))| {
+                CompressedRistretto::decompress_with_inv_sqrt(s, (u1, u2, v), *ok, I)
+            })
+            .collect()
+    }
+
+    /// Attempt to decompress a `CompressedRistretto` point, reporting
+    /// why the decoding failed.
+    ///
+    /// # Returns
+    /// - `Err(DecodingError::NonCanonicalPoint)` if the bytes are not a
+    ///   canonical and positive `FieldElement`.
+    /// - `Err(DecodingError::InvalidPoint)` if they don't encode a point.
+    /// - `Ok(RistrettoPoint)` otherways.
+    pub fn decompress_strict(&self) -> Result<RistrettoPoint, DecodingError> {
+        let s = FieldElement::from_canonical_bytes(&self.as_bytes())
+            .map_err(|_| DecodingError::NonCanonicalPoint)?;
+        if s.is_positive().unwrap_u8() == 0u8 {
+            return Err(DecodingError::NonCanonicalPoint);
+        };
+        self.decompress().ok_or(DecodingError::InvalidPoint)
+    }
 }
 
 #[derive(Clone, Copy)]
@@ -289,7 +388,7 @@ impl<'a, 'b> Sub<&'a RistrettoPoint> for &'b RistrettoPoint {
     /// [Source: 2008 Hisil–Wong–Carter–Dawson],
     /// (http://eprint.iacr.org/2008/522), Section 3.1.
     fn sub(self, other: &'a RistrettoPoint) -> RistrettoPoint {
-        self + &-other
+        self + -other
     }
 }
 
@@ -391,7 +490,127 @@ impl Mul<RistrettoPoint> for Scalar {
     }
 }
 
+define_mixed_add_variants!(LHS = RistrettoPoint, RHS = RistrettoPoint, Output = RistrettoPoint);
+define_mixed_sub_variants!(LHS = RistrettoPoint, RHS = RistrettoPoint, Output = RistrettoPoint);
+define_mixed_mul_variants!(LHS = RistrettoPoint, RHS = Scalar, Output = RistrettoPoint);
+define_add_assign_variants!(LHS = RistrettoPoint, RHS = RistrettoPoint);
+define_sub_assign_variants!(LHS = RistrettoPoint, RHS = RistrettoPoint);
+define_mul_assign_variants!(LHS = RistrettoPoint, RHS = Scalar);
+
+impl Sum for RistrettoPoint {
+    fn sum<I: Iterator<Item = RistrettoPoint>>(iter: I) -> RistrettoPoint {
+        iter.fold(RistrettoPoint::identity(), |acc, point| acc + point)
+    }
+}
+
+impl<'a> Sum<&'a RistrettoPoint> for RistrettoPoint {
+    fn sum<I: Iterator<Item = &'a RistrettoPoint>>(iter: I) -> RistrettoPoint {
+        iter.fold(RistrettoPoint::identity(), |acc, point| acc + point)
+    }
+}
+
+impl MultiscalarMul for RistrettoPoint {
+    type Point = RistrettoPoint;
+
+    fn multiscalar_mul<I, J>(scalars: I, points: J) -> RistrettoPoint
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Scalar>,
+        J: IntoIterator,
+        J::Item: Borrow<RistrettoPoint>,
+    {
+        RistrettoPoint(EdwardsPoint::multiscalar_mul(
+            scalars,
+            points.into_iter().map(|point| point.borrow().0),
+        ))
+    }
+}
+
+impl VartimeMultiscalarMul for RistrettoPoint {
+    type Point = RistrettoPoint;
+
+    fn optional_multiscalar_mul<I, J>(scalars: I, points: J) -> Option<RistrettoPoint>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Scalar>,
+        J: IntoIterator<Item = Option<RistrettoPoint>>,
+    {
+        EdwardsPoint::optional_multiscalar_mul(
+            scalars,
+            points.into_iter().map(|point| point.map(|point| point.0)),
+        )
+        .map(RistrettoPoint)
+    }
+}
+
+/// Precomputed tables of a set of static `RistrettoPoint`s, see
+/// `VartimeEdwardsPrecomputation`.
+#[derive(Clone)]
+pub struct VartimeRistrettoPrecomputation(VartimeEdwardsPrecomputation);
+
+impl VartimeRistrettoPrecomputation {
+    /// Encode the precomputation, see
+    /// `VartimeEdwardsPrecomputation::to_bytes`.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.0.to_bytes()
+    }
+
+    /// Decode a precomputation encoded with `to_bytes`, see
+    /// `VartimeEdwardsPrecomputation::from_bytes`.
+    pub fn from_bytes(bytes: &[u8]) -> Result<VartimeRistrettoPrecomputation, DecodingError> {
+        VartimeEdwardsPrecomputation::from_bytes(bytes).map(VartimeRistrettoPrecomputation)
+    }
+}
+
+impl VartimePrecomputedMultiscalarMul for VartimeRistrettoPrecomputation {
+    type Point = RistrettoPoint;
+
+    fn new<I>(static_points: I) -> VartimeRistrettoPrecomputation
+    where
+        I: IntoIterator,
+        I::Item: Borrow<RistrettoPoint>,
+    {
+        VartimeRistrettoPrecomputation(VartimeEdwardsPrecomputation::new(
+            static_points.into_iter().map(|point| point.borrow().0),
+        ))
+    }
+
+    fn optional_mixed_multiscalar_mul<I, J, K>(
+        &self,
+        static_scalars: I,
+        dynamic_scalars: J,
+        dynamic_points: K,
+    ) -> Option<RistrettoPoint>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Scalar>,
+        J: IntoIterator,
+        J::Item: Borrow<Scalar>,
+        K: IntoIterator<Item = Option<RistrettoPoint>>,
+    {
+        self.0
+            .optional_mixed_multiscalar_mul(
+                static_scalars,
+                dynamic_scalars,
+                dynamic_points.into_iter().map(|point| point.map(|point| point.0)),
+            )
+            .map(RistrettoPoint)
+    }
+}
+
 impl RistrettoPoint {
+    /// Returns `constants::RISTRETTO_BASEPOINT`, the generator
+    /// of the group.
+    pub fn generator() -> RistrettoPoint {
+        constants::RISTRETTO_BASEPOINT
+    }
+
+    /// Compute `scalar * B` in constant time, where `B` is the
+    /// `RISTRETTO_BASEPOINT`, see `EdwardsPoint::mul_base`.
+    pub fn mul_base(scalar: &Scalar) -> RistrettoPoint {
+        RistrettoPoint(EdwardsPoint::mul_base(scalar))
+    }
+
     /// Encode a Ristretto point represented by the point `(X:Y:Z:T)`
     /// in extended coordinates.
     #[allow(non_snake_case)]
@@ -424,13 +643,51 @@ impl RistrettoPoint {
         CompressedRistretto(s.to_bytes())
     }
 
+    /// Compress all of the `points`, on the rayon thread pool if
+    /// the `parallel` feature is on.
+    pub fn compress_batch(points: &[RistrettoPoint]) -> Vec<CompressedRistretto> {
+        #[cfg(feature = "parallel")]
+        {
+            use rayon::prelude::*;
+            points.par_iter().map(|point| point.compress()).collect()
+        }
+        #[cfg(not(feature = "parallel"))]
+        points.iter().map(|point| point.compress()).collect()
+    }
+
     /// Computes the Ristretto Elligator map.
     /// This gets a `RistrettoPoint` from a given
     /// `FieldElement´.
     pub fn elligator_ristretto_flavor(r_0: &FieldElement) -> RistrettoPoint {
+        let (r, N_s, D) = RistrettoPoint::elligator_ratio(r_0);
+        // s = sqrt(Ns/D)
+        let (Ns_D_is_sq, s) = N_s.sqrt_ratio_i(&D);
+        RistrettoPoint::elligator_with_sqrt(r_0, &r, &D, Ns_D_is_sq, s)
+    }
+
+    /// Computes the Ristretto Elligator map of all of the `r_0s` at
+    /// once, sharing a single field inversion across the batch
+    /// through `FieldElement::batch_sqrt_ratio_i`.
+    pub fn batch_elligator_ristretto_flavor(r_0s: &[FieldElement]) -> Vec<RistrettoPoint> {
+        let ratios: Vec<_> = r_0s.iter().map(RistrettoPoint::elligator_ratio).collect();
+        let N_s: Vec<FieldElement> = ratios.iter().map(|(_, N_s, _)| *N_s).collect();
+        let D: Vec<FieldElement> = ratios.iter().map(|(_, _, D)| *D).collect();
+        let sqrts = FieldElement::batch_sqrt_ratio_i(&N_s, &D);
+
+        r_0s.iter()
+            .zip(ratios.iter())
+            .zip(sqrts)
+            .map(|((r_0, (r, _, D)), (Ns_D_is_sq, s))| {
+                RistrettoPoint::elligator_with_sqrt(r_0, r, D, Ns_D_is_sq, s)
+            })
+            .collect()
+    }
+
+    /// Get the `(r, Ns, D)` of the Elligator map of `r_0`, which
+    /// needs `sqrt(Ns/D)`.
+    fn elligator_ratio(r_0: &FieldElement) -> (FieldElement, FieldElement, FieldElement) {
         let d = constants::EDWARDS_D;
         let one = FieldElement::one();
-        let mut c = -one;
         // 1 - d^2
         let one_minus_d_sq = one - d.square();
 
@@ -439,22 +696,35 @@ impl RistrettoPoint {
         // Ns = a(r+1)*(a+d)*(a-d)
         let N_s = (r + one) * one_minus_d_sq;
         // D = (d*r -a)*(a*r -d)
-        let D = (c - (d * r)) * (r + d);
-        // s = sqrt(Ns/D)
-        let (Ns_D_is_sq, mut s) = N_s.sqrt_ratio_i(&D);
+        let D = (-one - (d * r)) * (r + d);
+        (r, N_s, D)
+    }
+
+    /// Finish the Elligator map of `r_0`, given
+    /// `(Ns_D_is_sq, s) = sqrt_ratio_i(Ns, D)`.
+    fn elligator_with_sqrt(
+        r_0: &FieldElement,
+        r: &FieldElement,
+        D: &FieldElement,
+        Ns_D_is_sq: Choice,
+        mut s: FieldElement,
+    ) -> RistrettoPoint {
+        let d = constants::EDWARDS_D;
+        let one = FieldElement::one();
+        let mut c = -one;
 
         //s' = -ABS(s*r0)
-        let mut s_prim = &s * r_0;
+        let mut s_prim = s * r_0;
         s_prim.conditional_negate(s_prim.is_positive());
 
         s.conditional_assign(&s_prim, !Ns_D_is_sq);
-        c.conditional_assign(&r, !Ns_D_is_sq);
+        c.conditional_assign(r, !Ns_D_is_sq);
         // Nt = c(r-1)*(d-1)^2 - D
-        let N_t = ((c * (r - one)) * (d - one).square()) - D;
+        let N_t = ((c * (*r - one)) * (d - one).square()) - D;
         let s_square = s.square();
 
         // Get the `CompletePoint` coordinates.
-        let W0 = (s + s) * D;
+        let W0 = s.double() * D;
         let W1 = N_t * constants::SQRT_AD_MINUS_ONE;
         let W2 = one - s_square;
         let W3 = one + s_square;
@@ -470,6 +740,103 @@ impl RistrettoPoint {
         })
     }
 
+    /// Computes the inverse of the Ristretto Elligator map: the
+    /// non-negative `FieldElement`s `r_0` such that
+    /// `RistrettoPoint::elligator_ristretto_flavor(r_0) == self`.
+    ///
+    /// Every point of `self + E[4]` gives two candidates, one
+    /// for each sign of its Jacobi quartic coordinate `s`, so a point
+    /// has at most 8 preimages. They are returned on that fixed order,
+    /// with `None` on the slots that don't have one. The identity
+    /// has no preimages.
+    pub fn elligator_ristretto_flavor_inverse(&self) -> [Option<FieldElement>; 8] {
+        let (X, Y, Z) = (self.0.X, self.0.Y, self.0.Z);
+        let iX = constants::SQRT_MINUS_ONE * X;
+        let iY = constants::SQRT_MINUS_ONE * Y;
+        // The points of `self + E[4]`: adding `(0, -1)` maps `(x, y)`
+        // to `(-x, -y)` and adding `(-i, 0)` maps it to `(-iy, -ix)`.
+        let coset = [(X, Y), (-X, -Y), (-iY, -iX), (iY, iX)];
+
+        let mut preimages = [None; 8];
+        for (i, (X, Y)) in coset.iter().enumerate() {
+            // `s^2 = (1 - y) / (1 + y)`.
+            let (s_is_sq, s) = (Z - Y).sqrt_ratio_i(&(Z + Y));
+            if s_is_sq.unwrap_u8() == 0u8 || X.is_zero().unwrap_u8() == 1u8 {
+                continue;
+            };
+
+            for (j, s) in [s, -s].iter().enumerate() {
+                // `t = Nt / D = 2 * s / (x * sqrt(a*d - 1))`.
+                let t = (s.double() * Z) / (X * constants::SQRT_AD_MINUS_ONE);
+                let a = (t + FieldElement::one()) * constants::D_PLUS_ONE_OVER_D_MINUS_ONE;
+                let s_square = s.square();
+                // The sign of `s` tells which branch of the map was taken,
+                // and so whether `r = (s^2 + a) / (s^2 - a)` or its inverse.
+                let (num, den) = if s.is_positive().unwrap_u8() == 1u8 {
+                    (s_square + a, s_square - a)
+                } else {
+                    (s_square - a, s_square + a)
+                };
+                if den.is_zero().unwrap_u8() == 1u8 {
+                    continue;
+                };
+                // `r_0 = sqrt(r / i) = sqrt(-i * r)`.
+                let (r_0_is_sq, r_0) = (-constants::SQRT_MINUS_ONE * num).sqrt_ratio_i(&den);
+                if r_0_is_sq.unwrap_u8() == 1u8 {
+                    preimages[2 * i + j] = Some(r_0);
+                };
+            }
+        }
+        preimages
+    }
+
+    /// Encode the point as a representative: 32 bytes that are
+    /// indistinguishable from uniformly random ones, which
+    /// `RistrettoPoint::from_representative` maps back to the point.
+    ///
+    /// A random slot of `elligator_ristretto_flavor_inverse` is
+    /// taken, so the representatives of uniformly random points are
+    /// uniformly distributed. The preimage gets a random sign, and
+    /// the 4 bits over `2^252` are filled with randomness.
+    ///
+    /// # Returns
+    /// - `None` if the chosen slot has no preimage, which happens
+    ///   for about half of the calls. Callers should then retry with
+    ///   a new point, eg. with `keys::Keypair::generate_with_representative`,
+    ///   since retrying with the same one biases the output.
+    /// - `Some(representative)` otherways.
+    ///
+    /// By `rng` we mean any Rng that implements: `RngCore` + `CryptoRng`.
+    pub fn to_representative<T>(&self, rng: &mut T) -> Option<[u8; 32]>
+    where
+        T: RngCore + CryptoRng,
+    {
+        let mut rand = [0u8; 2];
+        rng.fill_bytes(&mut rand);
+
+        let mut r_0 = self.elligator_ristretto_flavor_inverse()[(rand[0] & 7) as usize]?;
+        r_0.conditional_negate(Choice::from((rand[0] >> 3) & 1));
+        let mut bytes = r_0.to_bytes();
+        // The preimages over `2^252` can't be padded, but they are
+        // only a negligible fraction of the field.
+        if bytes[31] >> 4 != 0 {
+            return None;
+        };
+        bytes[31] |= rand[1] & 0xf0;
+        Some(bytes)
+    }
+
+    /// Decode a representative produced by
+    /// `RistrettoPoint::to_representative`, ignoring its 4 highest
+    /// bits and applying the Ristretto Elligator map.
+    ///
+    /// Every 32-byte array maps to a valid point.
+    pub fn from_representative(bytes: &[u8; 32]) -> RistrettoPoint {
+        let mut r_0_bytes = *bytes;
+        r_0_bytes[31] &= 0x0f;
+        RistrettoPoint::elligator_ristretto_flavor(&FieldElement::from_bytes(&r_0_bytes))
+    }
+
     /// Debugging function used to get the 4coset where a point
     /// lives.
     pub(self) fn coset4(&self) -> [EdwardsPoint; 4] {
@@ -506,6 +873,50 @@ impl RistrettoPoint {
         R_1 + R_2
     }
 
+    /// Compute `scalar * self` blinding the scalar with fresh
+    /// randomness taken from `rng` on every call.
+    ///
+    /// This is slower than the `Mul` implementations, and it's
+    /// intended for secret scalars on targets exposed to power
+    /// or EM side-channel analysis.
+    ///
+    /// By `rng` we mean any Rng that implements: `RngCore` + `CryptoRng`.
+    pub fn mul_blinded<T>(&self, scalar: &Scalar, rng: &mut T) -> RistrettoPoint
+    where
+        T: RngCore + CryptoRng,
+    {
+        blinded_mul(self, scalar, rng)
+    }
+
+    /// Hash the given hasher state into a `RistrettoPoint` by feeding
+    /// its 512-bit output to `RistrettoPoint::from_uniform_bytes`.
+    ///
+    /// The hash function used must produce a 512-bit (64 bytes)
+    /// output, eg. `Sha512`.
+    ///
+    /// The hasher state should already contain a domain separator,
+    /// otherwise use `RistrettoPoint::hash_to_group`.
+    pub fn from_hash<D>(hash: D) -> RistrettoPoint
+    where
+        D: Digest<OutputSize = U64>,
+    {
+        let mut output = [0u8; 64];
+        output.copy_from_slice(hash.result().as_slice());
+        RistrettoPoint::from_uniform_bytes(&output)
+    }
+
+    /// Hash `msg` into a `RistrettoPoint` by feeding 64 bytes of
+    /// `expand_message_xmd` output with `Sha512` to
+    /// `RistrettoPoint::from_uniform_bytes`.
+    ///
+    /// `dst` is the domain separation tag of the caller, see
+    /// `hash_to_field::HASH_TO_GROUP_DST` for the library default.
+    pub fn hash_to_group(msg: &[u8], dst: &[u8]) -> RistrettoPoint {
+        let mut output = [0u8; 64];
+        output.copy_from_slice(&expand_message_xmd::<Sha512>(msg, dst, 64));
+        RistrettoPoint::from_uniform_bytes(&output)
+    }
+
     /// Generate a random `RistrettoPoint` from a 64-byte array generated
     /// with user-provided rng.
     ///
@@ -523,6 +934,65 @@ impl RistrettoPoint {
     }
 }
 
+#[cfg(feature = "group")]
+impl group::Group for RistrettoPoint {
+    type Scalar = Scalar;
+
+    /// Map 64 random bytes into the group with
+    /// `RistrettoPoint::from_uniform_bytes`, retrying on the identity.
+    fn random(mut rng: impl RngCore) -> RistrettoPoint {
+        loop {
+            let mut bytes = [0u8; 64];
+            rng.fill_bytes(&mut bytes);
+            let point = RistrettoPoint::from_uniform_bytes(&bytes);
+            if point != <RistrettoPoint as Identity>::identity() {
+                return point;
+            };
+        }
+    }
+
+    fn identity() -> RistrettoPoint {
+        <RistrettoPoint as Identity>::identity()
+    }
+
+    /// Returns `constants::RISTRETTO_BASEPOINT`.
+    fn generator() -> RistrettoPoint {
+        constants::RISTRETTO_BASEPOINT
+    }
+
+    fn is_identity(&self) -> Choice {
+        self.ct_eq(&<RistrettoPoint as Identity>::identity())
+    }
+
+    fn double(&self) -> RistrettoPoint {
+        Double::double(self)
+    }
+}
+
+#[cfg(feature = "group")]
+impl group::GroupEncoding for RistrettoPoint {
+    type Repr = [u8; 32];
+
+    fn from_bytes(bytes: &[u8; 32]) -> CtOption<RistrettoPoint> {
+        match CompressedRistretto(*bytes).decompress() {
+            Some(point) => CtOption::new(point, Choice::from(1u8)),
+            None => CtOption::new(<RistrettoPoint as Identity>::identity(), Choice::from(0u8)),
+        }
+    }
+
+    fn from_bytes_unchecked(bytes: &[u8; 32]) -> CtOption<RistrettoPoint> {
+        <RistrettoPoint as group::GroupEncoding>::from_bytes(bytes)
+    }
+
+    fn to_bytes(&self) -> [u8; 32] {
+        self.compress().as_bytes()
+    }
+}
+
+/// The Ristretto group has prime order `l`.
+#[cfg(feature = "group")]
+impl group::prime::PrimeGroup for RistrettoPoint {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -530,6 +1000,17 @@ mod tests {
     #[cfg(feature = "rand")]
     use rand::rngs::OsRng;
 
+    #[test]
+    fn mul_blinded() {
+        let mut rng = rand::thread_rng();
+        let scalars = [Scalar::zero(), Scalar::one(), Scalar::minus_one(), Scalar::random(&mut rng)];
+        let P = RistrettoPoint::new_random_point(&mut rng);
+
+        for k in &scalars {
+            assert!(P.mul_blinded(k, &mut rng) == P * k);
+        }
+    }
+
     #[test]
     fn basepoint_compr_decompr() {
         let compress = RistrettoPoint(constants::BASEPOINT).compress();
@@ -538,6 +1019,17 @@ mod tests {
         assert!(decompress == RistrettoPoint(constants::BASEPOINT));
     }
 
+    #[test]
+    fn generator_and_identity_constants() {
+        let B = RistrettoPoint::generator();
+        assert!(B == constants::RISTRETTO_BASEPOINT);
+        assert!(B.compress() == constants::RISTRETTO_BASEPOINT_COMPRESSED);
+        assert!(constants::RISTRETTO_IDENTITY == RistrettoPoint::identity());
+        assert!(
+            constants::RISTRETTO_IDENTITY.compress() == constants::RISTRETTO_IDENTITY_COMPRESSED
+        );
+    }
+
     #[test]
     fn valid_encoding_test_vectors() {
         // The following are the byte encodings of small multiples
@@ -574,7 +1066,7 @@ mod tests {
                 hex::encode(P.compress().as_bytes()),
                 encodings_of_small_multiples[i],
             );
-            P = P + B;
+            P += B;
         }
     }
 
@@ -648,7 +1140,7 @@ mod tests {
         let basep = constants::RISTRETTO_BASEPOINT;
         for _i in 0..16 {
             assert!(P.is_valid().unwrap_u8() == 1u8);
-            P = P + basep;
+            P += basep;
         }
 
         // This point has order `8L` is a valid `EdwardsPoint`
@@ -718,4 +1210,223 @@ mod tests {
         assert!(point_from_ellig == expected_point);
         assert!(point_from_ellig.compress() == expected_point.compress())
     }
+
+    #[test]
+    fn elligator_inverse() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..16 {
+            let r_0 = FieldElement::random(&mut rng);
+            let P = RistrettoPoint::elligator_ristretto_flavor(&r_0);
+            let preimages = P.elligator_ristretto_flavor_inverse();
+
+            let mut abs_r_0 = r_0;
+            abs_r_0.conditional_negate(!r_0.is_positive());
+            assert!(preimages.contains(&Some(abs_r_0)));
+            for pre in preimages.iter().flatten() {
+                assert!(RistrettoPoint::elligator_ristretto_flavor(pre) == P);
+            }
+        }
+
+        let id = RistrettoPoint::identity();
+        assert!(id.elligator_ristretto_flavor_inverse().iter().all(|pre| pre.is_none()));
+    }
+
+    #[test]
+    fn batch_elligator() {
+        let mut rng = rand::thread_rng();
+        let mut r_0s: Vec<FieldElement> = (0..16).map(|_| FieldElement::random(&mut rng)).collect();
+        r_0s.push(FieldElement::zero());
+        let points = RistrettoPoint::batch_elligator_ristretto_flavor(&r_0s);
+        for (r_0, P) in r_0s.iter().zip(points.iter()) {
+            assert!(*P == RistrettoPoint::elligator_ristretto_flavor(r_0));
+        }
+        assert!(RistrettoPoint::batch_elligator_ristretto_flavor(&[]).is_empty());
+    }
+
+    #[test]
+    fn batch_decompression() {
+        let mut rng = rand::thread_rng();
+        let mut points: Vec<RistrettoPoint> =
+            (0..16).map(|_| RistrettoPoint::new_random_point(&mut rng)).collect();
+        points.push(RistrettoPoint::identity());
+        let compressed = RistrettoPoint::compress_batch(&points);
+        let decompressed = CompressedRistretto::batch_decompress(&compressed).unwrap();
+        for (P, Q) in points.iter().zip(decompressed.iter()) {
+            assert!(P == Q);
+        }
+        assert!(CompressedRistretto::batch_decompress(&[]).unwrap().is_empty());
+
+        // A single invalid encoding invalidates the batch.
+        let mut invalid = compressed.clone();
+        invalid[3] = CompressedRistretto((-FieldElement::one()).to_bytes());
+        assert!(CompressedRistretto::batch_decompress(&invalid).is_none());
+    }
+
+    #[test]
+    fn representatives() {
+        let mut rng = rand::thread_rng();
+        let mut found = 0;
+        for _ in 0..32 {
+            let P = RistrettoPoint::new_random_point(&mut rng);
+            if let Some(repr) = P.to_representative(&mut rng) {
+                assert!(RistrettoPoint::from_representative(&repr) == P);
+                found += 1;
+            };
+        }
+        // Each call succeeds with probability close to 1/2.
+        assert!(found > 0);
+    }
+
+    #[cfg(feature = "group")]
+    #[test]
+    fn group_traits() {
+        use group::{Group, GroupEncoding};
+
+        let mut rng = rand::thread_rng();
+        let P = <RistrettoPoint as Group>::random(&mut rng);
+        let k = Scalar::random(&mut rng);
+
+        assert!(bool::from(<RistrettoPoint as Group>::identity().is_identity()));
+        assert!(!bool::from(P.is_identity()));
+        assert!(<RistrettoPoint as Group>::double(&P) == P + P);
+        assert!([P, P, P].iter().sum::<RistrettoPoint>() == P * Scalar::from(3u8));
+
+        let mut Q = P;
+        Q *= &k;
+        Q += P;
+        assert!(Q == P * k + P);
+
+        let bytes = <RistrettoPoint as GroupEncoding>::to_bytes(&Q);
+        assert!(<RistrettoPoint as GroupEncoding>::from_bytes(&bytes).unwrap() == Q);
+    }
+    #[test]
+    fn strict_decompression() {
+        let P = constants::RISTRETTO_BASEPOINT * Scalar::from(1234u64);
+        assert!(P.compress().decompress_strict().unwrap() == P);
+
+        let non_canonical = CompressedRistretto([0xff; 32]);
+        assert!(non_canonical.decompress_strict() == Err(DecodingError::NonCanonicalPoint));
+
+        // `s = 1` is canonical but it is not the encoding of any point.
+        let mut one = [0u8; 32];
+        one[0] = 1;
+        assert!(CompressedRistretto(one).decompress_strict() == Err(DecodingError::InvalidPoint));
+    }
+
+    #[test]
+    fn compressed_hash_set_keys() {
+        use std::collections::HashSet;
+
+        let B = constants::RISTRETTO_BASEPOINT;
+        let points = [B, B + B, B.double(), B * Scalar::from(2u64)];
+        let set: HashSet<CompressedRistretto> = points.iter().map(|P| P.compress()).collect();
+        assert_eq!(set.len(), 2);
+        assert!(set.contains(&(B + B).compress()));
+    }
+
+    #[test]
+    fn multiscalar_mul_traits() {
+        let mut rng = rand::thread_rng();
+        let scalars: Vec<Scalar> = (0..5).map(|_| Scalar::random(&mut rng)).collect();
+        let points: Vec<RistrettoPoint> = (0..5)
+            .map(|_| RistrettoPoint::new_random_point(&mut rng))
+            .collect();
+        let expected: RistrettoPoint = scalars.iter().zip(points.iter()).map(|(k, P)| P * k).sum();
+
+        assert!(RistrettoPoint::multiscalar_mul(&scalars, &points) == expected);
+        assert!(RistrettoPoint::vartime_multiscalar_mul(&scalars, &points) == expected);
+        assert!(
+            RistrettoPoint::optional_multiscalar_mul(&scalars, points.iter().map(|P| Some(*P)))
+                == Some(expected)
+        );
+
+        // A missing point makes the whole sum missing.
+        let compressed = [
+            constants::RISTRETTO_BASEPOINT.compress(),
+            CompressedRistretto([0xff; 32]),
+        ];
+        let maybe_points = compressed.iter().map(|P| P.decompress());
+        assert!(RistrettoPoint::optional_multiscalar_mul(&scalars[..2], maybe_points).is_none());
+
+        let empty: [RistrettoPoint; 0] = [];
+        let sum = RistrettoPoint::multiscalar_mul(&[] as &[Scalar], &empty);
+        assert!(sum == RistrettoPoint::identity());
+    }
+
+    #[test]
+    fn hash_to_group() {
+        use crate::hash_to_field::HASH_TO_GROUP_DST;
+
+        let msg = b"Sonny's point";
+        let P = RistrettoPoint::hash_to_group(msg, HASH_TO_GROUP_DST);
+        assert!(P == RistrettoPoint::hash_to_group(msg, HASH_TO_GROUP_DST));
+        assert!(P != RistrettoPoint::hash_to_group(msg, b"other-protocol"));
+        assert!(P != RistrettoPoint::identity());
+    }
+
+    #[test]
+    fn mul_base() {
+        let k = Scalar::random(&mut rand::thread_rng());
+        assert!(RistrettoPoint::mul_base(&k) == constants::RISTRETTO_BASEPOINT * k);
+    }
+
+    #[test]
+    fn precomputed_multiscalar_mul() {
+        let mut rng = rand::thread_rng();
+        let static_points: Vec<RistrettoPoint> = (0..4)
+            .map(|_| RistrettoPoint::new_random_point(&mut rng))
+            .collect();
+        let dynamic_points: Vec<RistrettoPoint> = (0..3)
+            .map(|_| RistrettoPoint::new_random_point(&mut rng))
+            .collect();
+        let mut static_scalars: Vec<Scalar> = (0..4).map(|_| Scalar::random(&mut rng)).collect();
+        static_scalars[1] = Scalar::minus_one();
+        let dynamic_scalars = [Scalar::zero(), Scalar::one(), Scalar::random(&mut rng)];
+
+        let precomp = VartimeRistrettoPrecomputation::new(&static_points);
+        let static_sum = RistrettoPoint::vartime_multiscalar_mul(&static_scalars, &static_points);
+        let dynamic_sum =
+            RistrettoPoint::vartime_multiscalar_mul(&dynamic_scalars, &dynamic_points);
+
+        assert!(precomp.vartime_multiscalar_mul(&static_scalars) == static_sum);
+        let sum =
+            precomp.vartime_mixed_multiscalar_mul(&static_scalars, &dynamic_scalars, &dynamic_points);
+        assert!(sum == static_sum + dynamic_sum);
+
+        let maybe_points = [Some(dynamic_points[0]), None, Some(dynamic_points[2])];
+        let sum = precomp.optional_mixed_multiscalar_mul(
+            &static_scalars,
+            &dynamic_scalars,
+            maybe_points.iter().copied(),
+        );
+        assert!(sum.is_none());
+
+        let zeros = [Scalar::zero(); 4];
+        assert!(precomp.vartime_multiscalar_mul(zeros) == RistrettoPoint::identity());
+
+        let decoded = VartimeRistrettoPrecomputation::from_bytes(&precomp.to_bytes()).unwrap();
+        assert!(decoded.vartime_multiscalar_mul(&static_scalars) == static_sum);
+    }
+
+    #[test]
+    fn compressed_hex_parsing() {
+        use core::convert::TryFrom;
+
+        let P = (constants::RISTRETTO_BASEPOINT * Scalar::from(7u8)).compress();
+        assert!(CompressedRistretto::from_hex(&format!("{:x}", P)).unwrap() == P);
+        assert!(CompressedRistretto::try_from(format!("{:X}", P).as_str()).unwrap() == P);
+
+        // A negative `s` is never a canonical encoding.
+        let minus_one = format!("{:x}", FieldElement::minus_one());
+        let res = CompressedRistretto::from_hex(&minus_one);
+        assert!(res.unwrap_err() == DecodingError::NonCanonicalPoint);
+        let bad = format!("{}zz", &minus_one[..62]);
+        assert!(CompressedRistretto::from_hex(&bad).unwrap_err() == DecodingError::InvalidHex);
+    }
+
+    #[test]
+    fn transparent_layout() {
+        assert_eq!(std::mem::size_of::<CompressedRistretto>(), 32);
+        assert_eq!(std::mem::align_of::<CompressedRistretto>(), 1);
+    }
 }
@@ -55,27 +55,50 @@
 //! `PartialOrd`, `Ord`, `PartialEq` and `Eq` are also implemented for
 //! `Scalar` type.
 //!
-//! All `std::core::ops traits -> (Add, Sub, Mul)` are implemented
-//! for both, `&Scalar` and `Scalar`.
+//! All `std::core::ops traits -> (Add, Sub, Mul, Neg)` are implemented
+//! for both, `&Scalar` and `Scalar`, as well as their `Assign` forms.
+//! The results are always reduced modulo `l`: products get reduced
+//! with Barrett's algorithm right after the multiplication.
+//!
+//! Long chains of multiplications are better performed over
+//! `UnpackedScalar`s, which keep the values in Montgomery form so
+//! that each product only needs a single reduction.
 
 use crate::backend;
+use crate::errors::DecodingError;
+use crate::hash_to_field::expand_message_xmd;
+#[cfg(feature = "ff")]
+use crate::traits::ops::{Pow, Square};
+
+use core::hash::{Hash, Hasher};
 
 use subtle::Choice;
-use subtle::ConstantTimeEq;
+use subtle::{ConditionallySelectable, ConstantTimeEq};
+#[cfg(feature = "ff")]
+use subtle::CtOption;
+
+#[cfg(feature = "ff")]
+use bitvec::{array::BitArray, order::Lsb0};
+
+use rand::{CryptoRng, RngCore};
+
+use digest::generic_array::typenum::U64;
+use digest::Digest;
+use sha2::Sha512;
 
-use rand::{CryptoRng, Rng};
+use zeroize::Zeroize;
 
-#[cfg(feature = "u64_backend")]
-pub use backend::u64::scalar::*;
+pub use backend::serial::scalar::*;
 /// A `Scalar` represents an element of the field generated by
 /// the prime of the sub-group: `2^249 - 15145038707218910765482344729778085401`.
 ///
 /// This is a type alias for one of the Scalar types in the `backend`
 /// module.
-#[cfg(feature = "u64_backend")]
-pub type Scalar = backend::u64::scalar::Scalar;
+pub type Scalar = backend::serial::scalar::Scalar;
 
 impl PartialEq for Scalar {
+    /// Compare the 32-byte encodings in constant time, see
+    /// `ConstantTimeEq::ct_eq` to get the result as a `Choice`.
     fn eq(&self, other: &Scalar) -> bool {
         self.ct_eq(other).unwrap_u8() == 1u8
     }
@@ -92,22 +115,458 @@ impl ConstantTimeEq for Scalar {
 
 impl Eq for Scalar {}
 
+impl Hash for Scalar {
+    /// Hash the canonical encoding of the `Scalar`, so that equal
+    /// values always get the same hash whatever their internal
+    /// representation.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.to_bytes().hash(state);
+    }
+}
+
+impl Default for Scalar {
+    /// Returns the default value for a Scalar = Zero.
+    fn default() -> Scalar {
+        Scalar::zero()
+    }
+}
+
+impl ConditionallySelectable for Scalar {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Scalar([
+            u64::conditional_select(&a.0[0], &b.0[0], choice),
+            u64::conditional_select(&a.0[1], &b.0[1], choice),
+            u64::conditional_select(&a.0[2], &b.0[2], choice),
+            u64::conditional_select(&a.0[3], &b.0[3], choice),
+            u64::conditional_select(&a.0[4], &b.0[4], choice),
+        ])
+    }
+}
+
+impl Zeroize for Scalar {
+    /// Overwrite the limbs of the `Scalar` with zeros.
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
 impl Scalar {
     /// Generate a valid Scalar choosen uniformly using user-
     /// provided rng.
     ///
-    /// By `rng` we mean any Rng that implements: `Rng` + `CryptoRng`.
-    pub fn random<T>(rand: &mut T) -> Scalar
+    /// By `rng` we mean any Rng that implements: `RngCore` + `CryptoRng`.
+    ///
+    /// 64 random bytes are sampled and reduced modulo `L` with
+    /// `Scalar::from_bytes_wide`, so the bias of the result is
+    /// negligible (`~2^-262`).
+    pub fn random<R>(rng: &mut R) -> Scalar
     where
-        T: Rng + CryptoRng,
+        R: RngCore + CryptoRng,
     {
-        let mut bytes = [0u8; 32];
-        rand.fill_bytes(&mut bytes);
-        // Ensure that the value is lower than `L`.
-        bytes[31] &= 0b0000_0001;
-        Scalar::from_bytes(&bytes)
+        let mut bytes = [0u8; 64];
+        rng.fill_bytes(&mut bytes);
+        Scalar::from_bytes_wide(&bytes)
+    }
+
+    /// Check whether the `Scalar` is zero, in constant time.
+    pub fn is_zero(&self) -> Choice {
+        self.ct_eq(&Scalar::zero())
+    }
+
+    /// Check whether the `Scalar` is one, in constant time.
+    pub fn is_one(&self) -> Choice {
+        self.ct_eq(&Scalar::one())
+    }
+
+    /// Get the `Scalar` that corresponds to the output of the given
+    /// hasher state reduced modulo `L`.
+    ///
+    /// The hash function used must produce a 512-bit (64 bytes)
+    /// output, eg. `Sha512`, so the resulting value is uniformly
+    /// distributed through `Scalar::from_bytes_wide`.
+    ///
+    /// The hasher state should already contain a domain separator,
+    /// otherwise use `Scalar::hash_to_scalar`.
+    pub fn from_hash<D>(hash: D) -> Scalar
+    where
+        D: Digest<OutputSize = U64>,
+    {
+        let mut output = [0u8; 64];
+        output.copy_from_slice(hash.result().as_slice());
+        Scalar::from_bytes_wide(&output)
+    }
+
+    /// Hash `msg` into a uniformly distributed `Scalar`, reducing
+    /// 64 bytes of `expand_message_xmd` output with `Sha512` modulo `L`.
+    ///
+    /// `dst` is the domain separation tag of the caller, see
+    /// `hash_to_field::HASH_TO_SCALAR_DST` for the library default.
+    pub fn hash_to_scalar(msg: &[u8], dst: &[u8]) -> Scalar {
+        let mut output = [0u8; 64];
+        output.copy_from_slice(&expand_message_xmd::<Sha512>(msg, dst, 64));
+        Scalar::from_bytes_wide(&output)
+    }
+
+    /// Reduce the value of the LE `bytes`, of any length, modulo `L`.
+    ///
+    /// It allows to map the 48 or 64-byte outputs of hash functions
+    /// into scalars at once. An empty slice gives zero.
+    pub fn from_bytes_mod_order(bytes: &[u8]) -> Scalar {
+        // Fold the 32-byte chunks starting from the most significant
+        // one: `acc = acc * 2^256 + chunk (mod L)`.
+        let mut acc = Scalar::zero();
+        for chunk in bytes.chunks(32).rev() {
+            let mut wide = [0u8; 64];
+            wide[..chunk.len()].copy_from_slice(chunk);
+            wide[32..].copy_from_slice(&acc.to_bytes());
+            acc = Scalar::from_bytes_wide(&wide);
+        }
+        acc
+    }
+
+    /// Decode a `Scalar` from its 32 LE bytes, checking that they
+    /// are the canonical encoding of the value.
+    ///
+    /// Unlike `Scalar::from_bytes`, values greater or equal than `l`
+    /// return an error instead of panicking.
+    pub fn from_canonical_bytes(bytes: &[u8; 32]) -> Result<Scalar, DecodingError> {
+        // Reduce the value and check that it encodes back to the
+        // same bytes.
+        let mut wide = [0u8; 64];
+        wide[..32].copy_from_slice(bytes);
+        let scalar = Scalar::from_bytes_wide(&wide);
+        if scalar.to_bytes() != *bytes {
+            return Err(DecodingError::NonCanonicalScalar);
+        };
+        Ok(scalar)
+    }
+}
+
+/// `(L + 1) / 4`, used to compute square roots since `L = 3 (mod 4)`.
+#[cfg(feature = "ff")]
+const SQRT_EXP: Scalar = Scalar([3660119008604697, 3718585894981034, 178609, 0, 549755813888]);
+
+define_hex_parsing!(Scalar, Scalar::from_canonical_bytes);
+
+#[cfg(feature = "ff")]
+impl From<Scalar> for [u8; 32] {
+    fn from(value: Scalar) -> [u8; 32] {
+        value.to_bytes()
+    }
+}
+
+#[cfg(feature = "ff")]
+impl<'a> From<&'a Scalar> for [u8; 32] {
+    fn from(value: &'a Scalar) -> [u8; 32] {
+        value.to_bytes()
+    }
+}
+
+#[cfg(feature = "ff")]
+impl ff::Field for Scalar {
+    fn random(mut rng: impl RngCore) -> Scalar {
+        let mut bytes = [0u8; 64];
+        rng.fill_bytes(&mut bytes);
+        Scalar::from_bytes_wide(&bytes)
+    }
+
+    fn zero() -> Scalar {
+        Scalar::zero()
+    }
+
+    fn one() -> Scalar {
+        Scalar::one()
+    }
+
+    fn is_zero(&self) -> bool {
+        Scalar::is_zero(self).into()
+    }
+
+    fn square(&self) -> Scalar {
+        Square::square(self)
+    }
+
+    fn double(&self) -> Scalar {
+        self + self
+    }
+
+    /// Computes `self^(L - 2)`. Returns `None` if `self` is zero.
+    fn invert(&self) -> CtOption<Scalar> {
+        let is_zero = self.ct_eq(&Scalar::zero());
+        CtOption::new(self.pow(&-Scalar::from(2u8)), !is_zero)
+    }
+
+    /// Computes `self^((L + 1) / 4)`. Returns `None` if `self`
+    /// is not a QR modulo `L`.
+    fn sqrt(&self) -> CtOption<Scalar> {
+        let root = self.pow(&SQRT_EXP);
+        CtOption::new(root, Square::square(&root).ct_eq(self))
+    }
+}
+
+#[cfg(feature = "ff")]
+impl ff::PrimeField for Scalar {
+    type Repr = [u8; 32];
+    type ReprBits = [u64; 4];
+
+    /// Returns `None` if the bytes are not the canonical
+    /// encoding of a `Scalar`.
+    fn from_repr(repr: [u8; 32]) -> Option<Scalar> {
+        Scalar::from_canonical_bytes(&repr).ok()
+    }
+
+    fn to_repr(&self) -> [u8; 32] {
+        self.to_bytes()
+    }
+
+    fn to_le_bits(&self) -> BitArray<Lsb0, [u64; 4]> {
+        BitArray::new(self.to_u64_words())
+    }
+
+    fn is_odd(&self) -> bool {
+        !self.is_even()
+    }
+
+    fn char_le_bits() -> BitArray<Lsb0, [u64; 4]> {
+        BitArray::new(backend::serial::constants::L.to_u64_words())
+    }
+
+    const NUM_BITS: u32 = 250;
+
+    const CAPACITY: u32 = 249;
+
+    fn multiplicative_generator() -> Scalar {
+        Scalar::from(2u8)
+    }
+
+    const S: u32 = 1;
+
+    /// Since `S = 1`, the root of unity is `-1`.
+    fn root_of_unity() -> Scalar {
+        Scalar::minus_one()
+    }
+}
+
+#[cfg(feature = "num")]
+impl num::traits::Zero for Scalar {
+    fn zero() -> Scalar {
+        Scalar::zero()
+    }
+
+    fn is_zero(&self) -> bool {
+        Scalar::is_zero(self).into()
+    }
+}
+
+#[cfg(feature = "num")]
+impl num::traits::One for Scalar {
+    fn one() -> Scalar {
+        Scalar::one()
+    }
+}
+
+#[cfg(feature = "num")]
+impl num::traits::Inv for &Scalar {
+    type Output = Scalar;
+    /// Computes `a^-1 (mod l)` as `a^(l - 2)`, in constant time.
+    ///
+    /// # Panics
+    /// If `a = 0`.
+    fn inv(self) -> Scalar {
+        assert!(!bool::from(self.is_zero()), "Zero has no inverse");
+        self.unpack().invert().pack()
+    }
+}
+
+#[cfg(feature = "num")]
+impl num::traits::Inv for Scalar {
+    type Output = Scalar;
+    /// See the implementation for `&Scalar`.
+    fn inv(self) -> Scalar {
+        num::traits::Inv::inv(&self)
+    }
+}
+
+#[cfg(feature = "num")]
+impl num::traits::Pow<u64> for &Scalar {
+    type Output = Scalar;
+    /// Computes `a^b (mod l)`.
+    fn pow(self, exp: u64) -> Scalar {
+        self.unpack().pow(&Scalar::from(exp)).pack()
+    }
+}
+
+#[cfg(feature = "num")]
+impl num::traits::Pow<u64> for Scalar {
+    type Output = Scalar;
+    /// Computes `a^b (mod l)`.
+    fn pow(self, exp: u64) -> Scalar {
+        num::traits::Pow::pow(&self, exp)
     }
 }
 
 /// This is a type alias for the Scalar type in the `curve25519-dalek` lib.
 pub type Ristretto255Scalar = curve25519_dalek::scalar::Scalar;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "ff")]
+    use ff::{Field, PrimeField};
+    #[cfg(feature = "ff")]
+    use rand::rngs::OsRng;
+
+    use sha2::Sha512;
+
+    #[test]
+    fn from_hash() {
+        let msg = b"To really appreciate architecture, you may even need to commit a murder";
+        let mut hasher = Sha512::default();
+        hasher.input(msg);
+        let res = Scalar::from_hash(hasher);
+
+        // `Sha512(msg) (mod l) = 723058401677325162477932340748717627376731841942353198216542135981592050683`.
+        let expected = Scalar([
+            1454997076212731,
+            2346132782979546,
+            3382165010992436,
+            2555541188518970,
+            1757657609540,
+        ]);
+        assert!(res == expected);
+        assert!(Scalar::from_hash(Sha512::new().chain(msg)) == expected);
+    }
+
+    #[test]
+    fn hash_to_scalar() {
+        use crate::hash_to_field::HASH_TO_SCALAR_DST;
+
+        let msg = b"Sonny's scalar";
+        let a = Scalar::hash_to_scalar(msg, HASH_TO_SCALAR_DST);
+        assert!(a == Scalar::hash_to_scalar(msg, HASH_TO_SCALAR_DST));
+        assert!(a != Scalar::hash_to_scalar(msg, b"other-protocol"));
+        assert!(a != Scalar::hash_to_scalar(b"Other scalar", HASH_TO_SCALAR_DST));
+    }
+
+    #[cfg(feature = "ff")]
+    #[test]
+    fn ff_root_of_unity() {
+        // `g^((L - 1) / 2)` must be the root of unity.
+        let t = [0xb55a01b7baafe431u64, 0x057363a6c117eac9, 0x0, 0x100000000000000];
+        assert!(Scalar::multiplicative_generator().pow_vartime(t) == Scalar::root_of_unity());
+    }
+
+    #[cfg(feature = "ff")]
+    #[test]
+    fn ff_repr_round_trip() {
+        let a = <Scalar as Field>::random(&mut OsRng);
+        assert!(Scalar::from_repr(a.to_repr()).unwrap() == a);
+        assert!(Scalar::from_repr(backend::serial::constants::L.to_bytes()).is_none());
+        assert!(Scalar::from_repr([0xff; 32]).is_none());
+    }
+
+    #[cfg(feature = "ff")]
+    #[test]
+    fn ff_invert_and_sqrt() {
+        assert!(bool::from(<Scalar as Field>::invert(&Scalar::zero()).is_none()));
+        let a = <Scalar as Field>::random(&mut OsRng);
+        assert!(a * a.invert().unwrap() == Scalar::one());
+
+        let sq = <Scalar as Field>::square(&a);
+        let root = <Scalar as Field>::sqrt(&sq).unwrap();
+        assert!(root == a || root == -a);
+        // `-1` is not a QR since `L = 3 (mod 4)`.
+        assert!(bool::from(Scalar::minus_one().sqrt().is_none()));
+    }
+
+    #[cfg(feature = "num")]
+    #[test]
+    fn num_traits() {
+        use crate::field::FieldElement;
+        use num::traits::{Inv, One, Pow, Zero};
+
+        // Numeric-generic code only sees the `num` traits.
+        fn check<T>(two: T)
+        where
+            T: Copy + PartialEq + Zero + One + Inv<Output = T> + Pow<u64, Output = T>,
+        {
+            assert!(T::zero().is_zero() && !T::one().is_zero());
+            assert!(two * two.inv() == T::one());
+            assert!(Pow::pow(two, 3u64) == two * two * two);
+            assert!(Pow::pow(two, 0u64) == T::one());
+        }
+        check(Scalar::from(2u8));
+        check(FieldElement::from(2u8));
+    }
+
+    #[cfg(feature = "num")]
+    #[test]
+    #[should_panic]
+    fn num_inv_zero() {
+        let _ = num::traits::Inv::inv(Scalar::zero());
+    }
+    #[test]
+    fn canonical_decoding() {
+        let bytes = Scalar::minus_one().to_bytes();
+        assert!(Scalar::from_canonical_bytes(&bytes).unwrap() == Scalar::minus_one());
+
+        let l_bytes = backend::serial::constants::L.to_bytes();
+        assert!(Scalar::from_canonical_bytes(&l_bytes) == Err(DecodingError::NonCanonicalScalar));
+        assert!(Scalar::from_canonical_bytes(&[0xff; 32]).is_err());
+    }
+
+    #[test]
+    fn hash_set_keys() {
+        use std::collections::HashSet;
+
+        let a = Scalar::from(7u64);
+        let scalars = [
+            a,
+            Scalar::one(),
+            a * Scalar::one(),
+            Scalar::minus_one() + a + Scalar::one(),
+        ];
+        let set: HashSet<Scalar> = scalars.iter().copied().collect();
+        assert_eq!(set.len(), 2);
+        assert!(set.contains(&Scalar::from(7u64)));
+    }
+
+    #[test]
+    fn hex_parsing() {
+        use core::convert::TryFrom;
+
+        let k = Scalar::from(0xdead_beefu64) * Scalar::minus_one();
+        assert!(Scalar::from_hex(&format!("{:x}", k)).unwrap() == k);
+        assert!(Scalar::try_from(format!("{:X}", k).as_str()).unwrap() == k);
+
+        // `l` itself is not a canonical encoding.
+        let mut l = Scalar::minus_one().to_bytes();
+        l[0] += 1;
+        let l: String = l.iter().map(|byte| format!("{:02x}", byte)).collect();
+        assert!(Scalar::from_hex(&l) == Err(DecodingError::NonCanonicalScalar));
+        assert!(Scalar::from_hex("00") == Err(DecodingError::InvalidLength));
+        let bad = format!("0x{}", &format!("{:x}", k)[2..]);
+        assert!(Scalar::from_hex(&bad) == Err(DecodingError::InvalidHex));
+    }
+
+    #[test]
+    fn bytes_mod_order() {
+        let bytes: Vec<u8> = (0..96u32).map(|i| (i * 53 + 7) as u8).collect();
+        let mut wide = [0u8; 64];
+        wide.copy_from_slice(&bytes[..64]);
+        assert!(Scalar::from_bytes_mod_order(&bytes[..64]) == Scalar::from_bytes_wide(&wide));
+
+        // 96 bytes: `lo + hi * 2^512`.
+        let lo = Scalar::from_bytes_mod_order(&bytes[..64]);
+        let hi = Scalar::from_bytes_mod_order(&bytes[64..]);
+        let two_pow_256 = Scalar::two_pow_k(128) * Scalar::two_pow_k(128);
+        let expected = lo + hi * two_pow_256 * two_pow_256;
+        assert!(Scalar::from_bytes_mod_order(&bytes) == expected);
+
+        assert!(Scalar::from_bytes_mod_order(&[]) == Scalar::zero());
+        let mut l = Scalar::minus_one().to_bytes();
+        l[0] += 1;
+        assert!(Scalar::from_bytes_mod_order(&l) == Scalar::zero());
+        assert!(Scalar::from_bytes_mod_order(&[5, 0, 0]) == Scalar::from(5u8));
+    }
+}
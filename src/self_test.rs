@@ -0,0 +1,112 @@
+//! Known-answer self-test of the arithmetic, meant to be run at
+//! startup by the integrators that must check the library before
+//! using it (eg. FIPS 140-style power-up tests).
+//!
+//! The expected answers were computed independently of the library,
+//! with Python's integers, from inputs derived as
+//! `Sha512("zerocaf-self-test-*")` reduced modulo `p` or `l`:
+//!
+//! - The product, inverse and square root of `FieldElement`s.
+//! - The scalar multiplication `k * B` through both `EdwardsPoint::mul_base`
+//!   and the variable-base multiplication, checked in affine coordinates.
+//!
+//! # Examples
+//! ```rust
+//! zerocaf::self_test().expect("the arithmetic is broken");
+//! ```
+
+use crate::constants;
+use crate::edwards::EdwardsPoint;
+use crate::errors::Error;
+use crate::field::FieldElement;
+use crate::scalar::Scalar;
+
+/// The `FieldElement` `a`.
+const FIELD_A: &str = "9e5469a40e92d4d1d1c1e5fc7645b21f73ecc3090ff7b42c34eb5fd16c29e008";
+
+/// The `FieldElement` `b`.
+const FIELD_B: &str = "6ce37a93eef926dcfb05bcc63dd628ebcd01d627e844aaa8923cb06e0e15370a";
+
+/// `a * b (mod p)`.
+const FIELD_A_TIMES_B: &str = "47598283f262e49c6088e822565d6d78b6c5d45e3b1032d50d8472e150a66f04";
+
+/// `1 / a (mod p)`.
+const FIELD_A_INVERSE: &str = "f37f8cd8079c65d57ba9830b25fa38d601d3d53e494ba7f90af33a365b68b607";
+
+/// `a^2 (mod p)`, whose square roots are `a` and `-a`.
+const FIELD_A_SQUARE: &str = "4b28b3eef66747f6feabfda3d69456400a02d959236efaf48c1ed78247484404";
+
+/// The `Scalar` `k`.
+const SCALAR_K: &str = "214af3b220b39a8f01b3680e9ce303a842040060af37aff55449778b10ebf301";
+
+/// Affine X-coordinate of `k * B`.
+const K_TIMES_B_X: &str = "d8ed91bb9a81bc4db1953ac8e9949871a06f61a296d307cfc421831339367d09";
+
+/// Affine Y-coordinate of `k * B`.
+const K_TIMES_B_Y: &str = "6ea49ba2c37c1c9b3d02d3b1fc7568b0ab3239c1e466c75f5ff7ecda4ad28304";
+
+/// Turn a failed check into `Error::SelfTestFailed`.
+fn check(passed: bool) -> Result<(), Error> {
+    if !passed {
+        return Err(Error::SelfTestFailed);
+    };
+    Ok(())
+}
+
+fn field_element(hex: &str) -> Result<FieldElement, Error> {
+    FieldElement::from_hex(hex).map_err(|_| Error::SelfTestFailed)
+}
+
+/// Check the `FieldElement` multiplication, inversion and square root.
+fn field_arithmetic() -> Result<(), Error> {
+    let a = field_element(FIELD_A)?;
+    let b = field_element(FIELD_B)?;
+    check(a * b == field_element(FIELD_A_TIMES_B)?)?;
+    check(a.inverse() == field_element(FIELD_A_INVERSE)?)?;
+
+    let square = field_element(FIELD_A_SQUARE)?;
+    check(a * a == square)?;
+    let root = Option::<FieldElement>::from(square.sqrt()).ok_or(Error::SelfTestFailed)?;
+    check(root == a || root == -a)
+}
+
+/// Check `k * B` with the basepoint table and with the
+/// variable-base multiplication.
+fn scalar_multiplication() -> Result<(), Error> {
+    let k = Scalar::from_hex(SCALAR_K).map_err(|_| Error::SelfTestFailed)?;
+    let x = field_element(K_TIMES_B_X)?;
+    let y = field_element(K_TIMES_B_Y)?;
+
+    for point in [EdwardsPoint::mul_base(&k), constants::BASEPOINT * k].iter() {
+        let affine = point.to_affine();
+        check(affine.X == x && affine.Y == y)?;
+    }
+    Ok(())
+}
+
+/// Run all of the known-answer tests.
+///
+/// # Returns
+/// - `Err(Error::SelfTestFailed)` if any of the results doesn't
+///   match its known answer.
+/// - `Ok(())` otherways.
+pub fn self_test() -> Result<(), Error> {
+    field_arithmetic()?;
+    scalar_multiplication()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn passes() {
+        assert_eq!(self_test(), Ok(()));
+    }
+
+    #[test]
+    fn detects_wrong_answers() {
+        assert!(check(false) == Err(Error::SelfTestFailed));
+        assert!(field_element("00").is_err());
+    }
+}
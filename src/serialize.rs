@@ -0,0 +1,233 @@
+//! `serde` support for the field elements, scalars and compressed
+//! points of this library.
+//!
+//! All of them are serialized as their 32-byte canonical encodings:
+//!
+//! - Human-readable formats (JSON, TOML...) get a lowercase hex
+//!   string of 64 characters.
+//! - Binary formats get a tuple of 32 bytes, which doesn't carry a
+//!   length prefix.
+//!
+//! Deserialization accepts the same shapes and rejects the
+//! encodings that are not canonical.
+
+use crate::edwards::CompressedEdwardsY;
+use crate::field::FieldElement;
+use crate::ristretto::CompressedRistretto;
+use crate::scalar::Scalar;
+
+use core::fmt;
+use core::str;
+
+use serde::de::{self, Deserialize, Deserializer, SeqAccess, Visitor};
+use serde::ser::{Serialize, SerializeTuple, Serializer};
+
+const HEX_CHARS: &[u8; 16] = b"0123456789abcdef";
+
+/// Serialize 32 bytes as a hex string or as a tuple of bytes,
+/// depending on the format.
+fn serialize_bytes<S>(bytes: &[u8; 32], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    if serializer.is_human_readable() {
+        let mut hex = [0u8; 64];
+        for (i, byte) in bytes.iter().enumerate() {
+            hex[2 * i] = HEX_CHARS[(byte >> 4) as usize];
+            hex[2 * i + 1] = HEX_CHARS[(byte & 0x0f) as usize];
+        }
+        // The buffer only holds ASCII chars.
+        serializer.serialize_str(str::from_utf8(&hex).unwrap())
+    } else {
+        let mut tup = serializer.serialize_tuple(32)?;
+        for byte in bytes.iter() {
+            tup.serialize_element(byte)?;
+        }
+        tup.end()
+    }
+}
+
+struct BytesVisitor(&'static str);
+
+impl<'de> Visitor<'de> for BytesVisitor {
+    type Value = [u8; 32];
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a 32-byte encoding of a {}", self.0)
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<[u8; 32], E>
+    where
+        E: de::Error,
+    {
+        crate::macros::decode_hex(v).map_err(E::custom)
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<[u8; 32], E>
+    where
+        E: de::Error,
+    {
+        if v.len() != 32 {
+            return Err(E::invalid_length(v.len(), &self));
+        };
+        let mut bytes = [0u8; 32];
+        bytes.copy_from_slice(v);
+        Ok(bytes)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<[u8; 32], A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut bytes = [0u8; 32];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = seq
+                .next_element()?
+                .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+        }
+        Ok(bytes)
+    }
+}
+
+/// Deserialize 32 bytes from a hex string or from a tuple of bytes,
+/// depending on the format.
+fn deserialize_bytes<'de, D>(deserializer: D, name: &'static str) -> Result<[u8; 32], D::Error>
+where
+    D: Deserializer<'de>,
+{
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_str(BytesVisitor(name))
+    } else {
+        deserializer.deserialize_tuple(32, BytesVisitor(name))
+    }
+}
+
+impl Serialize for FieldElement {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_bytes(&self.to_bytes(), serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for FieldElement {
+    fn deserialize<D>(deserializer: D) -> Result<FieldElement, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let bytes = deserialize_bytes(deserializer, "FieldElement")?;
+        FieldElement::from_canonical_bytes(&bytes).map_err(de::Error::custom)
+    }
+}
+
+impl Serialize for Scalar {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_bytes(&self.to_bytes(), serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Scalar {
+    fn deserialize<D>(deserializer: D) -> Result<Scalar, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let bytes = deserialize_bytes(deserializer, "Scalar")?;
+        Scalar::from_canonical_bytes(&bytes).map_err(de::Error::custom)
+    }
+}
+
+impl Serialize for CompressedRistretto {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_bytes(&self.as_bytes(), serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for CompressedRistretto {
+    /// The point is not decompressed, so invalid encodings are
+    /// only detected when calling `decompress`.
+    fn deserialize<D>(deserializer: D) -> Result<CompressedRistretto, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_bytes(deserializer, "CompressedRistretto").map(CompressedRistretto)
+    }
+}
+
+impl Serialize for CompressedEdwardsY {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_bytes(&self.to_bytes(), serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for CompressedEdwardsY {
+    /// The point is not decompressed, so invalid encodings are
+    /// only detected when calling `decompress`.
+    fn deserialize<D>(deserializer: D) -> Result<CompressedEdwardsY, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_bytes(deserializer, "CompressedEdwardsY").map(CompressedEdwardsY)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants;
+
+    #[test]
+    fn human_readable_hex() {
+        let one = FieldElement::one();
+        let json = serde_json::to_string(&one).unwrap();
+        assert_eq!(
+            json,
+            "\"0100000000000000000000000000000000000000000000000000000000000000\""
+        );
+        assert!(serde_json::from_str::<FieldElement>(&json).unwrap() == one);
+
+        // Uppercase hex is accepted too.
+        let upper = json.to_uppercase();
+        assert!(serde_json::from_str::<FieldElement>(&upper).unwrap() == one);
+
+        let point = constants::RISTRETTO_BASEPOINT.compress();
+        let json = serde_json::to_string(&point).unwrap();
+        assert!(serde_json::from_str::<CompressedRistretto>(&json).unwrap() == point);
+
+        assert!(serde_json::from_str::<Scalar>("\"0102\"").is_err());
+        assert!(serde_json::from_str::<Scalar>(&format!("\"{}\"", "zz".repeat(32))).is_err());
+    }
+
+    #[test]
+    fn binary_bytes() {
+        let k = Scalar::minus_one();
+        let cbor = serde_cbor::to_vec(&k).unwrap();
+        assert!(serde_cbor::from_slice::<Scalar>(&cbor).unwrap() == k);
+
+        let point = constants::BASEPOINT.compress();
+        let cbor = serde_cbor::to_vec(&point).unwrap();
+        assert!(serde_cbor::from_slice::<CompressedEdwardsY>(&cbor).unwrap() == point);
+    }
+
+    #[test]
+    fn reject_non_canonical() {
+        // `p` itself can be encoded, but not decoded back.
+        let json = serde_json::to_string(&constants::FIELD_L).unwrap();
+        assert!(serde_json::from_str::<FieldElement>(&json).is_err());
+        let cbor = serde_cbor::to_vec(&constants::FIELD_L).unwrap();
+        assert!(serde_cbor::from_slice::<FieldElement>(&cbor).is_err());
+
+        let hex = format!("\"{}\"", "ff".repeat(32));
+        assert!(serde_json::from_str::<Scalar>(&hex).is_err());
+        assert!(serde_json::from_str::<FieldElement>(&hex).is_err());
+    }
+}
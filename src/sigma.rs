@@ -0,0 +1,371 @@
+#![allow(non_snake_case)]
+//! Implementation of Schnorr's sigma protocol for the proof of
+//! knowledge of a discrete log over the Ristretto-encoded
+//! prime-order group of the Sonny curve.
+//!
+//! Given the generator `G` and the point `X = x * G`, the prover
+//! shows that it knows `x` without revealing it:
+//!
+//! - Commitment: `R = k * G` for a fresh nonce `k`.
+//! - Challenge: a `Scalar` `c` picked by the verifier.
+//! - Response: `s = k + c * x (mod l)`.
+//!
+//! The verifier accepts if `s * G == R + c * X`.
+//!
+//! The interactive protocol runs through `DlogProver` and
+//! `verify_response`. `prove` and `verify` compile it into a
+//! non-interactive `DlogProof` with the Fiat-Shamir transform,
+//! taking the challenge as
+//! `c = H("zerocaf-dlog" || len(ctx) || ctx || G || X || R) (mod l)`
+//! for a context string `ctx` of at most 255 bytes, which binds the
+//! proof to its purpose (eg. a wallet attestation or a proof of
+//! key ownership) so it can't be replayed on another one.
+//!
+//! # Examples
+//! ```rust
+//! use zerocaf::constants::RISTRETTO_BASEPOINT;
+//! use zerocaf::scalar::Scalar;
+//! use zerocaf::sigma::*;
+//!
+//! use rand::rngs::OsRng;
+//!
+//! let x = Scalar::random(&mut OsRng);
+//! let statement = DlogStatement::new(&x, &RISTRETTO_BASEPOINT);
+//!
+//! // Interactive.
+//! let (prover, R) = DlogProver::commit(&statement, &x, &mut OsRng);
+//! let c = random_challenge(&mut OsRng);
+//! let s = prover.respond(&c);
+//! assert!(verify_response(&statement, &R, &c, &s));
+//!
+//! // Non-interactive.
+//! let proof = prove(&statement, &x, b"my-wallet-v1");
+//! assert!(verify(&statement, &proof, b"my-wallet-v1"));
+//! assert!(!verify(&statement, &proof, b"another-wallet-v1"));
+//!
+//! // Proofs can be sent as 64 bytes.
+//! assert!(DlogProof::from_bytes(&proof.to_bytes()).unwrap() == proof);
+//! ```
+
+use crate::nonce::{derive_hedged_nonce, derive_nonce};
+use crate::ristretto::{CompressedRistretto, RistrettoPoint};
+use crate::scalar::Scalar;
+use crate::traits::MultiscalarMul;
+
+use rand::{CryptoRng, RngCore};
+use sha2::{Digest, Sha512};
+use zeroize::Zeroize;
+
+/// Domain separator used on the challenge and nonce derivations.
+pub(crate) const DLOG_DOMAIN: &[u8] = b"zerocaf-dlog";
+
+/// The public statement `X = x * G`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DlogStatement {
+    pub G: RistrettoPoint,
+    pub X: RistrettoPoint,
+}
+
+impl DlogStatement {
+    /// Build the statement `(G, x * G)` of the secret `x`.
+    pub fn new(x: &Scalar, G: &RistrettoPoint) -> DlogStatement {
+        DlogStatement {
+            G: *G,
+            X: RistrettoPoint::multiscalar_mul(&[*x], &[*G]),
+        }
+    }
+
+    /// Encode the whole statement `G || X`.
+    fn statement_bytes(&self) -> [u8; 64] {
+        let mut bytes = [0u8; 64];
+        bytes[..32].copy_from_slice(&self.G.compress().as_bytes());
+        bytes[32..].copy_from_slice(&self.X.compress().as_bytes());
+        bytes
+    }
+}
+
+/// The prover of the interactive protocol, which keeps the
+/// secret and the nonce between the commitment and the response.
+///
+/// Both get zeroized when the `DlogProver` is dropped.
+pub struct DlogProver {
+    x: Scalar,
+    k: Scalar,
+}
+
+impl Drop for DlogProver {
+    fn drop(&mut self) {
+        self.x.zeroize();
+        self.k.zeroize();
+    }
+}
+
+impl DlogProver {
+    /// Start the protocol for the secret `x` of the `statement`,
+    /// returning the prover and the commitment `R = k * G` to send
+    /// to the verifier.
+    ///
+    /// The nonce `k` is sampled from `rng`, since an interactive
+    /// prover can't derive it from the challenge.
+    ///
+    /// By `rng` we mean any Rng that implements: `RngCore` + `CryptoRng`.
+    pub fn commit<T>(
+        statement: &DlogStatement,
+        x: &Scalar,
+        rng: &mut T,
+    ) -> (DlogProver, RistrettoPoint)
+    where
+        T: RngCore + CryptoRng,
+    {
+        let k = Scalar::random(rng);
+        let R = RistrettoPoint::multiscalar_mul(&[k], &[statement.G]);
+        (DlogProver { x: *x, k }, R)
+    }
+
+    /// Compute the response `s = k + c * x` to the challenge `c`.
+    ///
+    /// It consumes the prover, since answering two challenges with
+    /// the same nonce reveals `x`.
+    pub fn respond(self, c: &Scalar) -> Scalar {
+        self.k + c * self.x
+    }
+}
+
+/// Pick the challenge of the interactive protocol.
+///
+/// By `rng` we mean any Rng that implements: `RngCore` + `CryptoRng`.
+pub fn random_challenge<T>(rng: &mut T) -> Scalar
+where
+    T: RngCore + CryptoRng,
+{
+    Scalar::random(rng)
+}
+
+/// Check the response `s` to the challenge `c` for the
+/// commitment `R`, ie. that `s * G == R + c * X`.
+pub fn verify_response(
+    statement: &DlogStatement,
+    R: &RistrettoPoint,
+    c: &Scalar,
+    s: &Scalar,
+) -> bool {
+    statement.G * s == R + statement.X * c
+}
+
+/// A non-interactive proof `(R, s)`, where `R` is the nonce
+/// commitment and `s` the response to the Fiat-Shamir challenge.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DlogProof {
+    pub R: CompressedRistretto,
+    pub s: Scalar,
+}
+
+impl DlogProof {
+    /// Encode the `DlogProof` as 64 bytes: `R || s`.
+    pub fn to_bytes(&self) -> [u8; 64] {
+        let mut bytes = [0u8; 64];
+        bytes[..32].copy_from_slice(&self.R.as_bytes());
+        bytes[32..].copy_from_slice(&self.s.to_bytes());
+        bytes
+    }
+
+    /// Decode a `DlogProof` from 64 bytes: `R || s`.
+    ///
+    /// # Returns
+    /// - `None` if `s` is not the canonical encoding of a `Scalar`.
+    /// - `Some(DlogProof)` otherways.
+    pub fn from_bytes(bytes: &[u8; 64]) -> Option<DlogProof> {
+        let R = CompressedRistretto::copy_from_slice(&bytes[..32]);
+        let mut s_bytes = [0u8; 32];
+        s_bytes.copy_from_slice(&bytes[32..]);
+        let s = Scalar::from_canonical_bytes(&s_bytes).ok()?;
+
+        Some(DlogProof { R, s })
+    }
+}
+
+/// Compute the Fiat-Shamir challenge
+/// `c = H("zerocaf-dlog" || len(ctx) || ctx || G || X || R) (mod l)`.
+///
+/// # Panics
+/// If `ctx` is longer than 255 bytes.
+pub(crate) fn challenge(statement: &DlogStatement, ctx: &[u8], R: &CompressedRistretto) -> Scalar {
+    assert!(
+        ctx.len() <= 255,
+        "the context can't be longer than 255 bytes"
+    );
+    let hasher = Sha512::new()
+        .chain(DLOG_DOMAIN)
+        .chain([ctx.len() as u8])
+        .chain(ctx)
+        .chain(&statement.statement_bytes()[..])
+        .chain(R.as_bytes());
+    Scalar::from_hash(hasher)
+}
+
+/// Encode `G || X || len(ctx) || ctx`, which together with the
+/// secret fix the whole proof, as the message of the nonce
+/// derivation, so proving under a different statement or context,
+/// which have different challenges, never reuses the nonce.
+///
+/// # Panics
+/// If `ctx` is longer than 255 bytes.
+fn nonce_message(statement: &DlogStatement, ctx: &[u8]) -> Vec<u8> {
+    assert!(
+        ctx.len() <= 255,
+        "the context can't be longer than 255 bytes"
+    );
+    let mut msg = Vec::with_capacity(65 + ctx.len());
+    msg.extend_from_slice(&statement.statement_bytes());
+    msg.push(ctx.len() as u8);
+    msg.extend_from_slice(ctx);
+    msg
+}
+
+/// Compute the `DlogProof` with the given nonce.
+fn prove_with_nonce(statement: &DlogStatement, x: &Scalar, ctx: &[u8], k: Scalar) -> DlogProof {
+    let R = RistrettoPoint::multiscalar_mul(&[k], &[statement.G]).compress();
+    let c = challenge(statement, ctx, &R);
+    DlogProof { R, s: k + c * *x }
+}
+
+/// Prove the knowledge of the `x` of the `statement` under the
+/// context `ctx`.
+///
+/// The nonce is derived deterministically from `x`, the whole
+/// statement and the context.
+///
+/// # Panics
+/// If `ctx` is longer than 255 bytes.
+pub fn prove(statement: &DlogStatement, x: &Scalar, ctx: &[u8]) -> DlogProof {
+    let k = derive_nonce(DLOG_DOMAIN, x, &nonce_message(statement, ctx), None);
+    prove_with_nonce(statement, x, ctx, k)
+}
+
+/// Prove the knowledge of the `x` of the `statement` under the
+/// context `ctx`, mixing extra randomness taken from `rng` into
+/// the nonce derivation.
+///
+/// By `rng` we mean any Rng that implements: `RngCore` + `CryptoRng`.
+///
+/// # Panics
+/// If `ctx` is longer than 255 bytes.
+pub fn prove_with_rng<T>(
+    statement: &DlogStatement,
+    x: &Scalar,
+    ctx: &[u8],
+    rng: &mut T,
+) -> DlogProof
+where
+    T: RngCore + CryptoRng,
+{
+    let k = derive_hedged_nonce(DLOG_DOMAIN, x, &nonce_message(statement, ctx), rng);
+    prove_with_nonce(statement, x, ctx, k)
+}
+
+/// Verify a `DlogProof` for the given `statement` under the
+/// context `ctx`.
+///
+/// # Returns
+/// - `true` if the proof is valid.
+/// - `false` if the proof is invalid or `R` can't be decompressed.
+///
+/// # Panics
+/// If `ctx` is longer than 255 bytes.
+pub fn verify(statement: &DlogStatement, proof: &DlogProof, ctx: &[u8]) -> bool {
+    let R = match proof.R.decompress() {
+        None => return false,
+        Some(R) => R,
+    };
+
+    let c = challenge(statement, ctx, &proof.R);
+    verify_response(statement, &R, &c, &proof.s)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants;
+
+    use rand::rngs::OsRng;
+
+    const CTX: &[u8] = b"zerocaf-sigma-tests";
+
+    fn random_statement() -> (Scalar, DlogStatement) {
+        let x = Scalar::random(&mut OsRng);
+        (x, DlogStatement::new(&x, &constants::RISTRETTO_BASEPOINT))
+    }
+
+    #[test]
+    fn interactive() {
+        let (x, statement) = random_statement();
+        let (prover, R) = DlogProver::commit(&statement, &x, &mut OsRng);
+        let c = random_challenge(&mut OsRng);
+        let s = prover.respond(&c);
+        assert!(verify_response(&statement, &R, &c, &s));
+        assert!(!verify_response(&statement, &R, &(c + Scalar::one()), &s));
+
+        // A prover that doesn't know `x` can't answer.
+        let (prover, R) = DlogProver::commit(&statement, &(x + Scalar::one()), &mut OsRng);
+        let s = prover.respond(&c);
+        assert!(!verify_response(&statement, &R, &c, &s));
+    }
+
+    #[test]
+    fn prove_and_verify() {
+        let (x, statement) = random_statement();
+
+        let proof = prove(&statement, &x, CTX);
+        assert!(verify(&statement, &proof, CTX));
+        assert!(prove(&statement, &x, CTX) == proof);
+
+        let hedged = prove_with_rng(&statement, &x, CTX, &mut OsRng);
+        assert!(hedged != proof);
+        assert!(verify(&statement, &hedged, CTX));
+    }
+
+    #[test]
+    fn nonce_depends_on_the_statement() {
+        let (x, statement) = random_statement();
+        let proof = prove(&statement, &x, CTX);
+
+        // Proving the same `x` against a wrong `X` must not reuse
+        // the nonce, or the two responses would reveal `x`.
+        let wrong = DlogStatement {
+            X: statement.X + constants::RISTRETTO_BASEPOINT,
+            ..statement
+        };
+        assert!(prove(&wrong, &x, CTX).R != proof.R);
+        assert!(prove(&statement, &x, b"").R != proof.R);
+    }
+
+    #[test]
+    fn reject_invalid_proofs() {
+        let (x, statement) = random_statement();
+        let proof = prove(&statement, &x, CTX);
+
+        // Different context.
+        assert!(!verify(&statement, &proof, b""));
+        // Different statement.
+        let (_, other) = random_statement();
+        assert!(!verify(&other, &proof, CTX));
+        // Tampered response.
+        let tampered = DlogProof {
+            s: proof.s + Scalar::one(),
+            ..proof
+        };
+        assert!(!verify(&statement, &tampered, CTX));
+    }
+
+    #[test]
+    fn proof_encoding() {
+        let (x, statement) = random_statement();
+        let proof = prove(&statement, &x, CTX);
+        assert!(DlogProof::from_bytes(&proof.to_bytes()).unwrap() == proof);
+
+        // Non-canonical `s` gets rejected.
+        let mut bytes = proof.to_bytes();
+        bytes[63] = 0xff;
+        assert!(DlogProof::from_bytes(&bytes).is_none());
+    }
+}
@@ -0,0 +1,484 @@
+#![allow(non_snake_case)]
+//! Implementation of the Schnorr signature scheme over the
+//! Ristretto-encoded prime-order group of the Sonny curve.
+//!
+//! A signature over a message `m` with the secret key `sk`
+//! (whose public key is `PK = sk * B`) is the pair `(R, s)` where:
+//!
+//! - `R = k * B` for a nonce `k` derived from `sk`, `ctx` and `m`
+//!   (see the `nonce` module).
+//! - `c = H("zerocaf-schnorr" || len(ctx) || ctx || R || PK || m) (mod l)`
+//!   for a context string `ctx` of at most 255 bytes.
+//! - `s = k + c * sk (mod l)`.
+//!
+//! The verifier accepts if `s * B == R + c * PK`.
+//!
+//! `H` is `Sha512` and `R`, `PK` are hashed on their
+//! `CompressedRistretto` encoding, so any implementation that
+//! follows the same challenge derivation will be compatible.
+//!
+//! `sign` and `verify` use the empty `DEFAULT_CONTEXT`. Protocols
+//! built on top of these signatures should pick their own context
+//! with the `_with_context` variants, so a signature produced for
+//! one of them is never valid on another.
+//!
+//! # Examples
+//! ```rust
+//! use zerocaf::signatures::*;
+//!
+//! use rand::rngs::OsRng;
+//!
+//! let (sk, pk) = keygen(&mut OsRng);
+//! let msg = b"Sonny loves Schnorr";
+//!
+//! let sig = sign(msg, &sk);
+//! assert!(verify(msg, &sig, &pk));
+//!
+//! // Signing is deterministic unless extra randomness is requested.
+//! assert!(sign(msg, &sk) == sig);
+//! assert!(verify(msg, &sign_with_rng(msg, &sk, &mut OsRng), &pk));
+//!
+//! // Signatures are bound to their context.
+//! let ctx_sig = sign_with_context(msg, b"my-protocol-v1", &sk);
+//! assert!(verify_with_context(msg, b"my-protocol-v1", &ctx_sig, &pk));
+//! assert!(!verify(msg, &ctx_sig, &pk));
+//! assert!(verify_batch_with_context(
+//!     &[&msg[..]],
+//!     b"my-protocol-v1",
+//!     &[ctx_sig],
+//!     &[pk],
+//! ));
+//!
+//! // Many signatures can be verified at once.
+//! let (sk2, pk2) = keygen(&mut OsRng);
+//! let sig2 = sign(b"Sonny loves batches", &sk2);
+//! assert!(verify_batch(
+//!     &[&msg[..], &b"Sonny loves batches"[..]],
+//!     &[sig, sig2],
+//!     &[pk, pk2],
+//! ));
+//!
+//! // Signatures can be sent as 64 bytes.
+//! let sig_bytes = sig.to_bytes();
+//! assert!(Signature::from_bytes(&sig_bytes).unwrap() == sig);
+//! ```
+
+use crate::constants;
+use crate::edwards::vartime_multiscalar_mul;
+use crate::nonce::{derive_hedged_nonce, derive_nonce};
+use crate::ristretto::{CompressedRistretto, RistrettoPoint};
+use crate::scalar::Scalar;
+use crate::traits::Identity;
+
+use rand::{thread_rng, CryptoRng, RngCore};
+use sha2::{Digest, Sha512};
+
+/// Domain separator used on the challenge derivation.
+pub(crate) const CHALLENGE_DOMAIN: &[u8] = b"zerocaf-schnorr";
+
+/// Context used by `sign`, `sign_with_rng`, `verify` and
+/// `verify_batch`.
+pub const DEFAULT_CONTEXT: &[u8] = b"";
+
+/// A Schnorr signature `(R, s)`, where `R` is the nonce
+/// commitment and `s` the response to the challenge.
+///
+/// Its layout is not stable, since `s` holds the limbs of the
+/// backend. Use `SignatureBytes` to cross FFI boundaries.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Signature {
+    pub R: CompressedRistretto,
+    pub s: Scalar,
+}
+
+/// The 64-byte encoding `R || s` of a `Signature`, see
+/// `Signature::to_bytes`.
+///
+/// It is `#[repr(transparent)]`, so it has the layout of a
+/// `[u8; 64]` and can cross FFI boundaries as such.
+#[derive(Copy, Clone)]
+#[repr(transparent)]
+pub struct SignatureBytes(pub [u8; 64]);
+
+impl SignatureBytes {
+    /// Decode the `Signature`, see `Signature::from_bytes`.
+    pub fn to_signature(&self) -> Option<Signature> {
+        Signature::from_bytes(&self.0)
+    }
+}
+
+impl<'a> From<&'a Signature> for SignatureBytes {
+    fn from(sig: &'a Signature) -> SignatureBytes {
+        SignatureBytes(sig.to_bytes())
+    }
+}
+
+impl From<Signature> for SignatureBytes {
+    fn from(sig: Signature) -> SignatureBytes {
+        SignatureBytes(sig.to_bytes())
+    }
+}
+
+impl Signature {
+    /// Encode the `Signature` as 64 bytes: `R || s`.
+    pub fn to_bytes(&self) -> [u8; 64] {
+        let mut bytes = [0u8; 64];
+        bytes[..32].copy_from_slice(&self.R.as_bytes());
+        bytes[32..].copy_from_slice(&self.s.to_bytes());
+        bytes
+    }
+
+    /// Decode a `Signature` from 64 bytes: `R || s`.
+    ///
+    /// # Returns
+    /// - `None` if `s` is not the canonical encoding of a `Scalar`.
+    /// - `Some(Signature)` otherways.
+    pub fn from_bytes(bytes: &[u8; 64]) -> Option<Signature> {
+        let R = CompressedRistretto::copy_from_slice(&bytes[..32]);
+        let mut s_bytes = [0u8; 32];
+        s_bytes.copy_from_slice(&bytes[32..]);
+        let s = Scalar::from_canonical_bytes(&s_bytes).ok()?;
+
+        Some(Signature { R, s })
+    }
+}
+
+/// Generate a new random secret key and it's corresponding
+/// public key: `(sk, sk * B)`.
+///
+/// By `rng` we mean any Rng that implements: `RngCore` + `CryptoRng`.
+pub fn keygen<T>(rng: &mut T) -> (Scalar, RistrettoPoint)
+where
+    T: RngCore + CryptoRng,
+{
+    let sk = Scalar::random(rng);
+    (sk, RistrettoPoint::mul_base(&sk))
+}
+
+/// Compute the challenge
+/// `c = H("zerocaf-schnorr" || len(ctx) || ctx || R || PK || m) (mod l)`.
+///
+/// # Panics
+/// If `ctx` is longer than 255 bytes.
+pub(crate) fn challenge(
+    ctx: &[u8],
+    R: &CompressedRistretto,
+    pk: &CompressedRistretto,
+    msg: &[u8],
+) -> Scalar {
+    assert!(ctx.len() <= 255, "the context can't be longer than 255 bytes");
+    let hasher = Sha512::new()
+        .chain(CHALLENGE_DOMAIN)
+        .chain([ctx.len() as u8])
+        .chain(ctx)
+        .chain(R.as_bytes())
+        .chain(pk.as_bytes())
+        .chain(msg);
+    Scalar::from_hash(hasher)
+}
+
+/// Encode `len(ctx) || ctx || m` as the message of the nonce
+/// derivation, so signing the same message under two contexts,
+/// which have different challenges, never reuses the nonce.
+///
+/// # Panics
+/// If `ctx` is longer than 255 bytes.
+fn nonce_message(ctx: &[u8], msg: &[u8]) -> Vec<u8> {
+    assert!(ctx.len() <= 255, "the context can't be longer than 255 bytes");
+    let mut nonce_msg = Vec::with_capacity(1 + ctx.len() + msg.len());
+    nonce_msg.push(ctx.len() as u8);
+    nonce_msg.extend_from_slice(ctx);
+    nonce_msg.extend_from_slice(msg);
+    nonce_msg
+}
+
+/// Compute the `Signature` with the given nonce.
+fn sign_with_nonce(msg: &[u8], ctx: &[u8], sk: &Scalar, k: Scalar) -> Signature {
+    let R = RistrettoPoint::mul_base(&k).compress();
+    let pk = RistrettoPoint::mul_base(sk).compress();
+
+    let c = challenge(ctx, &R, &pk, msg);
+    Signature { R, s: k + c * *sk }
+}
+
+/// Sign the given message with the provided secret key.
+///
+/// The nonce is derived deterministically from the secret key,
+/// the context and the message, so the same inputs always produce
+/// the same `Signature`.
+pub fn sign(msg: &[u8], sk: &Scalar) -> Signature {
+    sign_with_context(msg, DEFAULT_CONTEXT, sk)
+}
+
+/// Sign the given message under the context `ctx` with the
+/// provided secret key.
+///
+/// The `Signature` only verifies with `verify_with_context`
+/// under the same context.
+///
+/// # Panics
+/// If `ctx` is longer than 255 bytes.
+pub fn sign_with_context(msg: &[u8], ctx: &[u8], sk: &Scalar) -> Signature {
+    let k = derive_nonce(CHALLENGE_DOMAIN, sk, &nonce_message(ctx, msg), None);
+    sign_with_nonce(msg, ctx, sk, k)
+}
+
+/// Sign the given message with the provided secret key, mixing
+/// extra randomness taken from `rng` into the nonce derivation.
+///
+/// By `rng` we mean any Rng that implements: `RngCore` + `CryptoRng`.
+pub fn sign_with_rng<T>(msg: &[u8], sk: &Scalar, rng: &mut T) -> Signature
+where
+    T: RngCore + CryptoRng,
+{
+    sign_with_context_and_rng(msg, DEFAULT_CONTEXT, sk, rng)
+}
+
+/// Sign the given message under the context `ctx` with the
+/// provided secret key, mixing extra randomness taken from `rng`
+/// into the nonce derivation.
+///
+/// # Panics
+/// If `ctx` is longer than 255 bytes.
+pub fn sign_with_context_and_rng<T>(msg: &[u8], ctx: &[u8], sk: &Scalar, rng: &mut T) -> Signature
+where
+    T: RngCore + CryptoRng,
+{
+    let k = derive_hedged_nonce(CHALLENGE_DOMAIN, sk, &nonce_message(ctx, msg), rng);
+    sign_with_nonce(msg, ctx, sk, k)
+}
+
+/// Verify a `Signature` over a message against the given public key
+/// by checking that `s * B == R + c * PK`.
+///
+/// # Returns
+/// - `true` if the signature is valid.
+/// - `false` if the signature is invalid or `R` can't be decompressed.
+pub fn verify(msg: &[u8], sig: &Signature, pk: &RistrettoPoint) -> bool {
+    verify_with_context(msg, DEFAULT_CONTEXT, sig, pk)
+}
+
+/// Verify a `Signature` produced by `sign_with_context` over a
+/// message under the context `ctx` against the given public key.
+///
+/// # Returns
+/// - `true` if the signature is valid.
+/// - `false` if the signature is invalid or `R` can't be decompressed.
+///
+/// # Panics
+/// If `ctx` is longer than 255 bytes.
+pub fn verify_with_context(
+    msg: &[u8],
+    ctx: &[u8],
+    sig: &Signature,
+    pk: &RistrettoPoint,
+) -> bool {
+    let R = match sig.R.decompress() {
+        None => return false,
+        Some(R) => R,
+    };
+
+    let c = challenge(ctx, &sig.R, &pk.compress(), msg);
+    constants::RISTRETTO_BASEPOINT * sig.s == R + pk * c
+}
+
+/// Verify a batch of `Signature`s, where `signatures[i]` is
+/// checked over `messages[i]` against `public_keys[i]`.
+///
+/// For random weights `z_i` the verifier checks that:
+/// `-(sum z_i * s_i) * B + sum z_i * R_i + sum (z_i * c_i) * PK_i == 0`
+/// with a single multiscalar multiplication, which is much
+/// faster than verifying every `Signature` separately.
+///
+/// The weights are sampled from the thread-local rng.
+///
+/// # Returns
+/// - `true` if all of the signatures are valid.
+/// - `false` if any signature is invalid, any `R` can't be
+///   decompressed or the input lengths don't match.
+pub fn verify_batch(
+    messages: &[&[u8]],
+    signatures: &[Signature],
+    public_keys: &[RistrettoPoint],
+) -> bool {
+    verify_batch_with_context(messages, DEFAULT_CONTEXT, signatures, public_keys)
+}
+
+/// Verify a batch of `Signature`s produced by `sign_with_context`
+/// under the context `ctx`, see `verify_batch`.
+///
+/// # Panics
+/// If `ctx` is longer than 255 bytes.
+pub fn verify_batch_with_context(
+    messages: &[&[u8]],
+    ctx: &[u8],
+    signatures: &[Signature],
+    public_keys: &[RistrettoPoint],
+) -> bool {
+    if messages.len() != signatures.len() || signatures.len() != public_keys.len() {
+        return false;
+    };
+
+    let mut rng = thread_rng();
+    let n = signatures.len();
+    let mut scalars = Vec::with_capacity(2 * n + 1);
+    let mut points = Vec::with_capacity(2 * n + 1);
+    let mut B_coeff = Scalar::zero();
+
+    for ((msg, sig), pk) in messages.iter().zip(signatures).zip(public_keys) {
+        let R = match sig.R.decompress() {
+            None => return false,
+            Some(R) => R,
+        };
+        let z = Scalar::random(&mut rng);
+        let c = challenge(ctx, &sig.R, &pk.compress(), msg);
+
+        B_coeff -= z * sig.s;
+        scalars.push(z);
+        points.push(R);
+        scalars.push(z * c);
+        points.push(*pk);
+    }
+    scalars.push(B_coeff);
+    points.push(constants::RISTRETTO_BASEPOINT);
+
+    vartime_multiscalar_mul(&scalars, &points) == RistrettoPoint::identity()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rand::rngs::OsRng;
+
+    #[test]
+    fn ffi_layout() {
+        use std::mem::{align_of, size_of};
+
+        assert_eq!(size_of::<SignatureBytes>(), 64);
+        assert_eq!(align_of::<SignatureBytes>(), 1);
+        let (sk, _) = keygen(&mut OsRng);
+        let sig = sign(b"Sonny crosses the FFI", &sk);
+        let bytes = SignatureBytes::from(&sig);
+        assert!(bytes.0[..32] == sig.R.as_bytes()[..]);
+        assert!(bytes.0[32..] == sig.s.to_bytes()[..]);
+        assert!(bytes.to_signature() == Some(sig));
+
+        let mut bytes = bytes;
+        bytes.0[63] = 0xff;
+        assert!(bytes.to_signature().is_none());
+    }
+
+    #[test]
+    fn sign_and_verify() {
+        let (sk, pk) = keygen(&mut OsRng);
+        let msg = b"Sonny loves Schnorr";
+
+        let sig = sign(msg, &sk);
+        assert!(verify(msg, &sig, &pk));
+    }
+
+    #[test]
+    fn deterministic_signing() {
+        let (sk, pk) = keygen(&mut OsRng);
+        let msg = b"Sonny loves Schnorr";
+
+        let sig = sign(msg, &sk);
+        assert!(sign(msg, &sk) == sig);
+
+        let hedged = sign_with_rng(msg, &sk, &mut OsRng);
+        assert!(hedged != sig);
+        assert!(verify(msg, &hedged, &pk));
+    }
+
+    #[test]
+    fn signing_contexts() {
+        let (sk, pk) = keygen(&mut OsRng);
+        let msg = b"Sonny loves Schnorr";
+
+        let sig = sign_with_context(msg, b"protocol-a", &sk);
+        assert!(verify_with_context(msg, b"protocol-a", &sig, &pk));
+        assert!(!verify_with_context(msg, b"protocol-b", &sig, &pk));
+        assert!(!verify(msg, &sig, &pk));
+        assert!(sign_with_context(msg, DEFAULT_CONTEXT, &sk) == sign(msg, &sk));
+
+        let hedged = sign_with_context_and_rng(msg, b"protocol-a", &sk, &mut OsRng);
+        assert!(verify_with_context(msg, b"protocol-a", &hedged, &pk));
+        assert!(!verify(msg, &hedged, &pk));
+    }
+
+    #[test]
+    fn context_separated_nonces() {
+        let (sk, _) = keygen(&mut OsRng);
+        let msg = b"Sonny loves Schnorr";
+
+        // Reusing `R` under two challenges would reveal `sk`.
+        let sig_a = sign_with_context(msg, b"protocol-a", &sk);
+        let sig_b = sign_with_context(msg, b"protocol-b", &sk);
+        assert!(sig_a.R != sig_b.R);
+        assert!(sig_a.R != sign(msg, &sk).R);
+        // The context length is part of the nonce message.
+        assert!(sign_with_context(b"bc", b"a", &sk).R != sign_with_context(b"c", b"ab", &sk).R);
+    }
+
+    #[test]
+    fn reject_forgeries() {
+        let (sk, pk) = keygen(&mut OsRng);
+        let (_, other_pk) = keygen(&mut OsRng);
+        let msg = b"Sonny loves Schnorr";
+        let sig = sign(msg, &sk);
+
+        // Different message.
+        assert!(!verify(b"Sonny hates Schnorr", &sig, &pk));
+        // Different public key.
+        assert!(!verify(msg, &sig, &other_pk));
+        // Tampered response.
+        let tampered = Signature {
+            R: sig.R,
+            s: sig.s + Scalar::one(),
+        };
+        assert!(!verify(msg, &tampered, &pk));
+    }
+
+    #[test]
+    fn batch_verification() {
+        let msgs: Vec<Vec<u8>> = (0..8u8).map(|i| vec![i; i as usize]).collect();
+        let keys: Vec<(Scalar, RistrettoPoint)> = (0..8).map(|_| keygen(&mut OsRng)).collect();
+        let mut sigs: Vec<Signature> = msgs
+            .iter()
+            .zip(keys.iter())
+            .map(|(msg, (sk, _))| sign(msg, sk))
+            .collect();
+        let msgs: Vec<&[u8]> = msgs.iter().map(|msg| &msg[..]).collect();
+        let pks: Vec<RistrettoPoint> = keys.iter().map(|(_, pk)| *pk).collect();
+
+        assert!(verify_batch(&msgs, &sigs, &pks));
+        assert!(verify_batch(&[], &[], &[]));
+        // Length mismatch.
+        assert!(!verify_batch(&msgs[1..], &sigs, &pks));
+        // A single invalid signature invalidates the batch.
+        sigs[3].s += Scalar::one();
+        assert!(!verify_batch(&msgs, &sigs, &pks));
+
+        let ctx_sigs: Vec<Signature> = msgs
+            .iter()
+            .zip(keys.iter())
+            .map(|(msg, (sk, _))| sign_with_context(msg, b"protocol-a", sk))
+            .collect();
+        assert!(verify_batch_with_context(&msgs, b"protocol-a", &ctx_sigs, &pks));
+        assert!(!verify_batch_with_context(&msgs, b"protocol-b", &ctx_sigs, &pks));
+        assert!(!verify_batch(&msgs, &ctx_sigs, &pks));
+    }
+
+    #[test]
+    fn signature_encoding() {
+        let (sk, _) = keygen(&mut OsRng);
+        let sig = sign(b"", &sk);
+        assert!(Signature::from_bytes(&sig.to_bytes()).unwrap() == sig);
+
+        // Non-canonical `s` gets rejected.
+        let mut bytes = sig.to_bytes();
+        bytes[63] = 0xff;
+        assert!(Signature::from_bytes(&bytes).is_none());
+    }
+}
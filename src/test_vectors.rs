@@ -0,0 +1,241 @@
+//! Known-answer test vectors for implementations of the Sonny
+//! curve in other languages.
+//!
+//! The inputs are derived deterministically from `Sha512`, so
+//! `generate` always returns the same vectors, and every value is
+//! given as the lowercase hex of its canonical LE encoding.
+//!
+//! The `gen_test_vectors` binary prints them as JSON:
+//!
+//! ```sh
+//! cargo run --features test_vectors --bin gen_test_vectors > vectors.json
+//! ```
+
+use crate::constants;
+use crate::field::FieldElement;
+use crate::scalar::Scalar;
+use crate::signatures;
+
+use serde::Serialize;
+use sha2::{Digest, Sha512};
+
+/// Domain separator used to derive the inputs of the vectors.
+const VECTORS_DOMAIN: &[u8] = b"zerocaf-test-vectors";
+
+/// Number of random vectors of each kind, on top of the edge cases.
+const VECTORS_PER_KIND: u8 = 8;
+
+/// Field arithmetic over two `FieldElement`s `a` and `b`.
+#[derive(Clone, Debug, Serialize)]
+pub struct FieldVector {
+    pub a: String,
+    pub b: String,
+    pub add: String,
+    pub sub: String,
+    pub mul: String,
+    pub square: String,
+    /// Empty if `a = 0`.
+    pub inverse: String,
+}
+
+/// Encodings of the point `scalar * B`.
+#[derive(Clone, Debug, Serialize)]
+pub struct EncodingVector {
+    pub scalar: String,
+    pub edwards: String,
+    pub ristretto: String,
+}
+
+/// The scalar multiplication `scalar * point` over the Edwards form.
+#[derive(Clone, Debug, Serialize)]
+pub struct ScalarMulVector {
+    pub scalar: String,
+    pub point: String,
+    pub result: String,
+}
+
+/// A deterministic Schnorr signature, see `signatures::sign`.
+#[derive(Clone, Debug, Serialize)]
+pub struct SignatureVector {
+    pub secret_key: String,
+    pub public_key: String,
+    pub message: String,
+    pub signature: String,
+}
+
+/// All the known-answer vectors of the library.
+#[derive(Clone, Debug, Serialize)]
+pub struct TestVectors {
+    pub field: Vec<FieldVector>,
+    pub encodings: Vec<EncodingVector>,
+    pub scalar_mul: Vec<ScalarMulVector>,
+    pub signatures: Vec<SignatureVector>,
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Hash the label and the index of a vector input.
+fn derive_bytes(label: &[u8], i: u8) -> [u8; 64] {
+    let hasher = Sha512::new()
+        .chain(VECTORS_DOMAIN)
+        .chain([label.len() as u8])
+        .chain(label)
+        .chain([i]);
+    let mut bytes = [0u8; 64];
+    bytes.copy_from_slice(hasher.result().as_slice());
+    bytes
+}
+
+fn derive_field_element(label: &[u8], i: u8) -> FieldElement {
+    let mut bytes = [0u8; 32];
+    bytes.copy_from_slice(&derive_bytes(label, i)[..32]);
+    // Values under `2^252` are always canonical.
+    bytes[31] &= 0b0000_1111;
+    FieldElement::from_bytes(&bytes)
+}
+
+fn derive_scalar(label: &[u8], i: u8) -> Scalar {
+    Scalar::from_bytes_wide(&derive_bytes(label, i))
+}
+
+fn field_vector(a: FieldElement, b: FieldElement) -> FieldVector {
+    let inverse = if a.is_zero().into() {
+        String::new()
+    } else {
+        format!("{:x}", a.inverse())
+    };
+    FieldVector {
+        a: format!("{:x}", a),
+        b: format!("{:x}", b),
+        add: format!("{:x}", a + b),
+        sub: format!("{:x}", a - b),
+        mul: format!("{:x}", a * b),
+        square: format!("{:x}", a * a),
+        inverse,
+    }
+}
+
+fn encoding_vector(k: Scalar) -> EncodingVector {
+    EncodingVector {
+        scalar: format!("{:x}", k),
+        edwards: format!("{:x}", (constants::BASEPOINT * k).compress()),
+        ristretto: format!("{:x}", (constants::RISTRETTO_BASEPOINT * k).compress()),
+    }
+}
+
+fn signature_vector(sk: Scalar, msg: &[u8]) -> SignatureVector {
+    SignatureVector {
+        secret_key: format!("{:x}", sk),
+        public_key: format!("{:x}", (constants::RISTRETTO_BASEPOINT * sk).compress()),
+        message: hex(msg),
+        signature: hex(&signatures::sign(msg, &sk).to_bytes()),
+    }
+}
+
+/// Generate the known-answer vectors.
+pub fn generate() -> TestVectors {
+    let edge_elems = [
+        FieldElement::zero(),
+        FieldElement::one(),
+        FieldElement::minus_one(),
+    ];
+    let edge_scalars = [Scalar::zero(), Scalar::one(), Scalar::minus_one()];
+
+    let mut field = Vec::new();
+    for a in edge_elems.iter() {
+        for b in edge_elems.iter() {
+            field.push(field_vector(*a, *b));
+        }
+    }
+    for i in 0..VECTORS_PER_KIND {
+        field.push(field_vector(
+            derive_field_element(b"field-a", i),
+            derive_field_element(b"field-b", i),
+        ));
+    }
+
+    let mut encodings: Vec<EncodingVector> =
+        edge_scalars.iter().map(|k| encoding_vector(*k)).collect();
+    for i in 0..VECTORS_PER_KIND {
+        encodings.push(encoding_vector(derive_scalar(b"encoding", i)));
+    }
+
+    let scalar_mul = (0..VECTORS_PER_KIND)
+        .map(|i| {
+            let k = derive_scalar(b"scalar-mul", i);
+            let point = constants::BASEPOINT * derive_scalar(b"scalar-mul-point", i);
+            ScalarMulVector {
+                scalar: format!("{:x}", k),
+                point: format!("{:x}", point.compress()),
+                result: format!("{:x}", (point * k).compress()),
+            }
+        })
+        .collect();
+
+    let mut signatures = vec![signature_vector(Scalar::one(), b"")];
+    for i in 0..VECTORS_PER_KIND {
+        let msg = derive_bytes(b"signature-msg", i);
+        signatures.push(signature_vector(
+            derive_scalar(b"signature-sk", i),
+            &msg[..(i as usize) * 8],
+        ));
+    }
+
+    TestVectors {
+        field,
+        encodings,
+        scalar_mul,
+        signatures,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ristretto::{CompressedRistretto, RistrettoPoint};
+    use crate::signatures::Signature;
+
+    fn unhex<const N: usize>(s: &str) -> [u8; N] {
+        let mut bytes = [0u8; N];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&s[2 * i..2 * i + 2], 16).unwrap();
+        }
+        bytes
+    }
+
+    #[test]
+    fn vectors_are_deterministic() {
+        let first = serde_json::to_string(&generate()).unwrap();
+        let second = serde_json::to_string(&generate()).unwrap();
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn vectors_are_consistent() {
+        let vectors = generate();
+
+        for v in vectors.field.iter() {
+            let a = FieldElement::from_canonical_bytes(&unhex(&v.a)).unwrap();
+            let b = FieldElement::from_canonical_bytes(&unhex(&v.b)).unwrap();
+            let add = FieldElement::from_canonical_bytes(&unhex(&v.add)).unwrap();
+            assert!(add - b == a);
+            if !v.inverse.is_empty() {
+                let inv = FieldElement::from_canonical_bytes(&unhex(&v.inverse)).unwrap();
+                assert!(a * inv == FieldElement::one());
+            }
+        }
+
+        for v in vectors.signatures.iter() {
+            let pk: RistrettoPoint = CompressedRistretto(unhex(&v.public_key))
+                .decompress()
+                .unwrap();
+            let sig = Signature::from_bytes(&unhex(&v.signature)).unwrap();
+            let msg: Vec<u8> = (0..v.message.len() / 2)
+                .map(|i| u8::from_str_radix(&v.message[2 * i..2 * i + 2], 16).unwrap())
+                .collect();
+            assert!(signatures::verify(&msg, &sig, &pk));
+        }
+    }
+}
@@ -0,0 +1,572 @@
+#![allow(non_snake_case)]
+//! Building blocks for FROST-style threshold signatures over the
+//! Schnorr signatures of the `signatures` module: any `t` of the
+//! `n` holders of a share of the secret key `sk` can produce a
+//! `Signature` that verifies with `signatures::verify` against
+//! `PK = sk * B`.
+//!
+//! - Key shares: `sk` is split with Shamir's secret sharing over a
+//!   random polynomial `f` of degree `t - 1` with `f(0) = sk`. The
+//!   signer `i` holds `sk_i = f(i)`, and `Y_i = sk_i * B` is its
+//!   verification share. `generate_shares` does it as a trusted
+//!   dealer.
+//! - Nonce commitments: for every signature, each signer samples
+//!   the nonces `(d_i, e_i)` and shares `(D_i, E_i) = (d_i * B, e_i * B)`.
+//!   With the list `C` of the commitments of all of the signers,
+//!   `rho_i = H("zerocaf-frost-binding" || PK || i || len(m) || m || C) (mod l)`
+//!   binds every nonce to the group key, the message and the set of
+//!   signers (RFC 9591, Section 4.4).
+//! - Partial signatures: with `R = sum D_i + rho_i * E_i` and the
+//!   challenge `c` of the `signatures` module computed over
+//!   `(R, PK, m)`, the signer `i` produces
+//!   `z_i = d_i + rho_i * e_i + lambda_i * c * sk_i (mod l)`, where
+//!   `lambda_i` is its Lagrange coefficient within the signers.
+//!   It is checked with `z_i * B == D_i + rho_i * E_i + c * lambda_i * Y_i`.
+//! - Aggregation: the signature is `(R, sum z_i)`.
+//!
+//! The nonces must never be reused, which `partial_sign` enforces
+//! by consuming them. It also refuses to sign when the commitments
+//! it gets lack or alter the ones of its nonces (RFC 9591, Section
+//! 5.2).
+//!
+//! # Examples
+//! ```rust
+//! use zerocaf::signatures::{keygen, verify};
+//! use zerocaf::threshold::*;
+//!
+//! use rand::rngs::OsRng;
+//!
+//! // 2-of-3 shares of a secret key.
+//! let (sk, pk) = keygen(&mut OsRng);
+//! let shares = generate_shares(&sk, 2, 3, &mut OsRng);
+//! let msg = b"Sonny loves thresholds";
+//!
+//! // The signers 1 and 3 commit to their nonces.
+//! let (nonces1, C1) = commit_nonces(shares[0].index, &mut OsRng);
+//! let (nonces3, C3) = commit_nonces(shares[2].index, &mut OsRng);
+//! let commitments = [C1, C3];
+//!
+//! let z1 = partial_sign(msg, &shares[0], nonces1, &commitments, &pk).unwrap();
+//! let z3 = partial_sign(msg, &shares[2], nonces3, &commitments, &pk).unwrap();
+//! let Y1 = shares[0].verification_share();
+//! assert!(verify_partial_signature(msg, &z1, 1, &Y1, &commitments, &pk));
+//!
+//! let sig = aggregate_partial_signatures(msg, &commitments, &[z1, z3], &pk);
+//! assert!(verify(msg, &sig, &pk));
+//! ```
+
+use crate::constants;
+use crate::errors::Error;
+use crate::ristretto::RistrettoPoint;
+use crate::scalar::Scalar;
+use crate::signatures::{challenge, Signature, DEFAULT_CONTEXT};
+
+use rand::{CryptoRng, RngCore};
+use sha2::{Digest, Sha512};
+use zeroize::Zeroize;
+
+/// Domain separator used on the binding factors.
+pub(crate) const BINDING_DOMAIN: &[u8] = b"zerocaf-frost-binding";
+
+/// The share `sk_i = f(i)` of the secret key held by the signer `i`.
+///
+/// The share is zeroized when the `KeyShare` is dropped.
+#[derive(Clone)]
+pub struct KeyShare {
+    pub index: u32,
+    secret: Scalar,
+}
+
+impl Drop for KeyShare {
+    fn drop(&mut self) {
+        self.secret.zeroize();
+    }
+}
+
+impl KeyShare {
+    /// Build the `KeyShare` `sk_i` of the signer `i`.
+    ///
+    /// # Panics
+    /// If `index` is zero, since `f(0)` is the secret key itself.
+    pub fn new(index: u32, secret: Scalar) -> KeyShare {
+        assert!(index != 0, "The index of a share can't be zero");
+        KeyShare { index, secret }
+    }
+
+    /// Get the share `sk_i`.
+    pub fn as_scalar(&self) -> &Scalar {
+        &self.secret
+    }
+
+    /// Compute the verification share `Y_i = sk_i * B`.
+    pub fn verification_share(&self) -> RistrettoPoint {
+        RistrettoPoint::mul_base(&self.secret)
+    }
+}
+
+/// Split `sk` into the `n` shares `f(1), ..., f(n)` of a random
+/// polynomial `f` of degree `threshold - 1` with `f(0) = sk`, so
+/// any `threshold` of them can sign.
+///
+/// By `rng` we mean any Rng that implements: `RngCore` + `CryptoRng`.
+///
+/// # Panics
+/// If `threshold` is not in `1..=n`.
+pub fn generate_shares<T>(sk: &Scalar, threshold: u32, n: u32, rng: &mut T) -> Vec<KeyShare>
+where
+    T: RngCore + CryptoRng,
+{
+    assert!(
+        threshold >= 1 && threshold <= n,
+        "The threshold must be in 1..=n"
+    );
+    let mut coefficients = vec![*sk];
+    coefficients.extend((1..threshold).map(|_| Scalar::random(rng)));
+
+    let shares = (1..=n)
+        .map(|i| {
+            let x = Scalar::from(i);
+            let secret = coefficients
+                .iter()
+                .rev()
+                .fold(Scalar::zero(), |acc, coeff| acc * x + coeff);
+            KeyShare::new(i, secret)
+        })
+        .collect();
+    coefficients.iter_mut().for_each(|coeff| coeff.zeroize());
+    shares
+}
+
+/// Compute the Lagrange coefficient at zero of the signer `index`
+/// within the signers `indices`:
+/// `lambda_i = prod_{j != i} j / (j - i) (mod l)`.
+///
+/// # Panics
+/// If `indices` contains zero or repeated indices.
+pub fn lagrange_coefficient(index: u32, indices: &[u32]) -> Scalar {
+    assert!(!indices.contains(&0), "The index of a share can't be zero");
+    assert!(
+        indices
+            .iter()
+            .enumerate()
+            .all(|(n, j)| !indices[..n].contains(j)),
+        "The indices of the signers must be distinct"
+    );
+
+    let i = Scalar::from(index);
+    let (num, den) = indices
+        .iter()
+        .filter(|j| **j != index)
+        .fold((Scalar::one(), Scalar::one()), |(num, den), j| {
+            (num * Scalar::from(*j), den * (Scalar::from(*j) - i))
+        });
+    num * den.unpack().invert().pack()
+}
+
+/// The secret nonces `(d_i, e_i)` of a signer for a single
+/// signature, together with their commitment.
+///
+/// Both get zeroized when the `SigningNonces` are dropped.
+pub struct SigningNonces {
+    d: Scalar,
+    e: Scalar,
+    commitment: NonceCommitment,
+}
+
+impl Drop for SigningNonces {
+    fn drop(&mut self) {
+        self.d.zeroize();
+        self.e.zeroize();
+    }
+}
+
+/// The commitments `(D_i, E_i)` to the nonces of the signer `i`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct NonceCommitment {
+    pub index: u32,
+    pub D: RistrettoPoint,
+    pub E: RistrettoPoint,
+}
+
+/// Generate fresh random nonces for the signer `index` and their
+/// commitments: `((d_i, e_i), (d_i * B, e_i * B))`.
+///
+/// By `rng` we mean any Rng that implements: `RngCore` + `CryptoRng`.
+pub fn commit_nonces<T>(index: u32, rng: &mut T) -> (SigningNonces, NonceCommitment)
+where
+    T: RngCore + CryptoRng,
+{
+    let d = Scalar::random(rng);
+    let e = Scalar::random(rng);
+    let commitment = NonceCommitment {
+        index,
+        D: RistrettoPoint::mul_base(&d),
+        E: RistrettoPoint::mul_base(&e),
+    };
+    (SigningNonces { d, e, commitment }, commitment)
+}
+
+/// Compute the binding factor of the signer `index`:
+/// `rho_i = H("zerocaf-frost-binding" || PK || i || len(m) || m || C) (mod l)`,
+/// where `PK` is the public key of the group and `C` is the list of
+/// `i || D_i || E_i` of all of the signers.
+///
+/// The order of `commitments` matters, so all of the signers must
+/// agree on it.
+pub fn binding_factor(
+    index: u32,
+    msg: &[u8],
+    commitments: &[NonceCommitment],
+    pk: &RistrettoPoint,
+) -> Scalar {
+    let mut hasher = Sha512::new()
+        .chain(BINDING_DOMAIN)
+        .chain(pk.compress().as_bytes())
+        .chain(index.to_le_bytes())
+        .chain((msg.len() as u64).to_le_bytes())
+        .chain(msg);
+    for commitment in commitments {
+        hasher = hasher
+            .chain(commitment.index.to_le_bytes())
+            .chain(commitment.D.compress().as_bytes())
+            .chain(commitment.E.compress().as_bytes());
+    }
+    Scalar::from_hash(hasher)
+}
+
+/// Compute the group commitment `R = sum D_i + rho_i * E_i`.
+pub fn group_commitment(
+    msg: &[u8],
+    commitments: &[NonceCommitment],
+    pk: &RistrettoPoint,
+) -> RistrettoPoint {
+    commitments
+        .iter()
+        .map(|C| C.D + C.E * binding_factor(C.index, msg, commitments, pk))
+        .sum()
+}
+
+/// Compute the challenge `c` of the `signatures` module over
+/// `(R, PK, m)` together with `R`.
+fn signing_challenge(
+    msg: &[u8],
+    commitments: &[NonceCommitment],
+    pk: &RistrettoPoint,
+) -> (RistrettoPoint, Scalar) {
+    let R = group_commitment(msg, commitments, pk);
+    let c = challenge(DEFAULT_CONTEXT, &R.compress(), &pk.compress(), msg);
+    (R, c)
+}
+
+/// Get the indices of the signers behind `commitments`.
+///
+/// # Returns
+/// - `None` if any of the indices is zero or repeated, since they
+///   come from the other signers and can't be trusted.
+/// - `Some(indices)` otherways.
+fn signers(commitments: &[NonceCommitment]) -> Option<Vec<u32>> {
+    let indices: Vec<u32> = commitments.iter().map(|C| C.index).collect();
+    let distinct = indices
+        .iter()
+        .enumerate()
+        .all(|(n, j)| *j != 0 && !indices[..n].contains(j));
+    if distinct {
+        Some(indices)
+    } else {
+        None
+    }
+}
+
+/// Compute the partial signature
+/// `z_i = d_i + rho_i * e_i + lambda_i * c * sk_i (mod l)` of the
+/// signer holding `share`, consuming its `nonces`.
+///
+/// `commitments` are the nonce commitments of all of the signers,
+/// including this one, and `pk` the public key of the group.
+///
+/// # Returns
+/// - `Error::InvalidIndices` if `commitments` has zero or repeated
+///   indices.
+/// - `Error::CommitmentMismatch` if `commitments` has no commitment
+///   of the signer, or if it isn't the one of `nonces`.
+///
+/// On error the nonces get dropped anyway, so the signer must commit
+/// to new ones.
+pub fn partial_sign(
+    msg: &[u8],
+    share: &KeyShare,
+    nonces: SigningNonces,
+    commitments: &[NonceCommitment],
+    pk: &RistrettoPoint,
+) -> Result<Scalar, Error> {
+    let indices = signers(commitments).ok_or(Error::InvalidIndices)?;
+    let own = commitments.iter().find(|C| C.index == share.index);
+    if own != Some(&nonces.commitment) {
+        return Err(Error::CommitmentMismatch);
+    };
+
+    let (_, c) = signing_challenge(msg, commitments, pk);
+    let rho = binding_factor(share.index, msg, commitments, pk);
+    let lambda = lagrange_coefficient(share.index, &indices);
+    Ok(nonces.d + rho * nonces.e + lambda * c * share.secret)
+}
+
+/// Verify the partial signature `z_i` of the signer `index` with
+/// the verification share `Y_i` by checking that
+/// `z_i * B == D_i + rho_i * E_i + c * lambda_i * Y_i`.
+///
+/// It allows to find which signer misbehaved when the aggregate
+/// signature is not valid.
+///
+/// # Returns
+/// - `true` if the partial signature is valid.
+/// - `false` if it is invalid, `index` has no commitment or
+///   `commitments` has zero or repeated indices.
+pub fn verify_partial_signature(
+    msg: &[u8],
+    z_i: &Scalar,
+    index: u32,
+    Y_i: &RistrettoPoint,
+    commitments: &[NonceCommitment],
+    pk: &RistrettoPoint,
+) -> bool {
+    let indices = match signers(commitments) {
+        None => return false,
+        Some(indices) => indices,
+    };
+    let C = match commitments.iter().find(|C| C.index == index) {
+        None => return false,
+        Some(C) => C,
+    };
+
+    let (_, c) = signing_challenge(msg, commitments, pk);
+    let rho = binding_factor(index, msg, commitments, pk);
+    let lambda = lagrange_coefficient(index, &indices);
+    constants::RISTRETTO_BASEPOINT * z_i == C.D + C.E * rho + Y_i * (c * lambda)
+}
+
+/// Aggregate the partial signatures of all of the signers into
+/// the `Signature` `(R, sum z_i)`.
+pub fn aggregate_partial_signatures(
+    msg: &[u8],
+    commitments: &[NonceCommitment],
+    partials: &[Scalar],
+    pk: &RistrettoPoint,
+) -> Signature {
+    let (R, _) = signing_challenge(msg, commitments, pk);
+    Signature {
+        R: R.compress(),
+        s: partials.iter().fold(Scalar::zero(), |acc, z_i| acc + z_i),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signatures::{keygen, verify};
+
+    use rand::rngs::OsRng;
+
+    const MSG: &[u8] = b"Sonny loves thresholds";
+
+    /// Sign `MSG` with the shares of the given positions.
+    fn sign_with(shares: &[KeyShare], signers: &[usize], pk: &RistrettoPoint) -> Signature {
+        let (nonces, commitments): (Vec<SigningNonces>, Vec<NonceCommitment>) = signers
+            .iter()
+            .map(|i| commit_nonces(shares[*i].index, &mut OsRng))
+            .unzip();
+        let partials: Vec<Scalar> = signers
+            .iter()
+            .zip(nonces)
+            .map(|(i, nonces)| partial_sign(MSG, &shares[*i], nonces, &commitments, pk).unwrap())
+            .collect();
+        aggregate_partial_signatures(MSG, &commitments, &partials, pk)
+    }
+
+    #[test]
+    fn shares_interpolate_the_secret() {
+        let (sk, _) = keygen(&mut OsRng);
+        let shares = generate_shares(&sk, 3, 5, &mut OsRng);
+        for signers in [[0usize, 1, 2], [4, 2, 0], [1, 3, 4]].iter() {
+            let indices: Vec<u32> = signers.iter().map(|i| shares[*i].index).collect();
+            let secret = signers.iter().fold(Scalar::zero(), |acc, i| {
+                let share = &shares[*i];
+                acc + lagrange_coefficient(share.index, &indices) * share.as_scalar()
+            });
+            assert!(secret == sk);
+        }
+    }
+
+    #[test]
+    fn threshold_signing() {
+        let (sk, pk) = keygen(&mut OsRng);
+        let shares = generate_shares(&sk, 3, 5, &mut OsRng);
+        assert!(verify(MSG, &sign_with(&shares, &[0, 1, 2], &pk), &pk));
+        assert!(verify(MSG, &sign_with(&shares, &[3, 0, 4, 1], &pk), &pk));
+        // Fewer signers than the threshold can't sign.
+        assert!(!verify(MSG, &sign_with(&shares, &[0, 1], &pk), &pk));
+
+        // A 1-of-1 sharing is a plain key.
+        let shares = generate_shares(&sk, 1, 1, &mut OsRng);
+        assert!(*shares[0].as_scalar() == sk);
+        assert!(verify(MSG, &sign_with(&shares, &[0], &pk), &pk));
+    }
+
+    #[test]
+    fn partial_signature_verification() {
+        let (sk, pk) = keygen(&mut OsRng);
+        let shares = generate_shares(&sk, 2, 3, &mut OsRng);
+        let (nonces1, C1) = commit_nonces(shares[0].index, &mut OsRng);
+        let (nonces2, C2) = commit_nonces(shares[1].index, &mut OsRng);
+        let commitments = [C1, C2];
+
+        let z1 = partial_sign(MSG, &shares[0], nonces1, &commitments, &pk).unwrap();
+        let z2 = partial_sign(MSG, &shares[1], nonces2, &commitments, &pk).unwrap();
+        let (Y1, Y2) = (
+            shares[0].verification_share(),
+            shares[1].verification_share(),
+        );
+        assert!(verify_partial_signature(
+            MSG,
+            &z1,
+            1,
+            &Y1,
+            &commitments,
+            &pk
+        ));
+        assert!(verify_partial_signature(
+            MSG,
+            &z2,
+            2,
+            &Y2,
+            &commitments,
+            &pk
+        ));
+
+        // Misbehaving signers get caught.
+        assert!(!verify_partial_signature(
+            MSG,
+            &(z1 + Scalar::one()),
+            1,
+            &Y1,
+            &commitments,
+            &pk
+        ));
+        assert!(!verify_partial_signature(
+            MSG,
+            &z1,
+            2,
+            &Y2,
+            &commitments,
+            &pk
+        ));
+        assert!(!verify_partial_signature(
+            MSG,
+            &z1,
+            3,
+            &Y1,
+            &commitments,
+            &pk
+        ));
+        assert!(!verify_partial_signature(
+            b"other",
+            &z1,
+            1,
+            &Y1,
+            &commitments,
+            &pk
+        ));
+    }
+
+    #[test]
+    fn own_commitment_is_checked() {
+        let (sk, pk) = keygen(&mut OsRng);
+        let shares = generate_shares(&sk, 2, 3, &mut OsRng);
+        let (_, C2) = commit_nonces(shares[1].index, &mut OsRng);
+        let (_, C3) = commit_nonces(shares[2].index, &mut OsRng);
+
+        // The commitment of the signer is missing.
+        let (nonces, _) = commit_nonces(shares[0].index, &mut OsRng);
+        let res = partial_sign(MSG, &shares[0], nonces, &[C2, C3], &pk);
+        assert!(res == Err(Error::CommitmentMismatch));
+
+        // The commitment of the signer got altered.
+        let (nonces, C1) = commit_nonces(shares[0].index, &mut OsRng);
+        let altered = NonceCommitment { E: C2.E, ..C1 };
+        let res = partial_sign(MSG, &shares[0], nonces, &[altered, C2], &pk);
+        assert!(res == Err(Error::CommitmentMismatch));
+        let (nonces, C1) = commit_nonces(shares[0].index, &mut OsRng);
+        let swapped = NonceCommitment { D: C1.E, E: C1.D, ..C1 };
+        let res = partial_sign(MSG, &shares[0], nonces, &[swapped, C2], &pk);
+        assert!(res == Err(Error::CommitmentMismatch));
+
+        // The nonces of another signer.
+        let (nonces, _) = commit_nonces(shares[0].index, &mut OsRng);
+        let res = partial_sign(MSG, &shares[1], nonces, &[C2, C3], &pk);
+        assert!(res == Err(Error::CommitmentMismatch));
+    }
+
+    #[test]
+    fn invalid_indices_are_rejected() {
+        let (sk, pk) = keygen(&mut OsRng);
+        let shares = generate_shares(&sk, 2, 3, &mut OsRng);
+        let (_, C2) = commit_nonces(shares[1].index, &mut OsRng);
+
+        // A signer repeating its commitment.
+        let (nonces, C1) = commit_nonces(shares[0].index, &mut OsRng);
+        let res = partial_sign(MSG, &shares[0], nonces, &[C1, C2, C2], &pk);
+        assert!(res == Err(Error::InvalidIndices));
+        // A signer with the index of another.
+        let (nonces, C1) = commit_nonces(shares[0].index, &mut OsRng);
+        let impostor = NonceCommitment { index: 1, ..C2 };
+        let res = partial_sign(MSG, &shares[0], nonces, &[C1, impostor], &pk);
+        assert!(res == Err(Error::InvalidIndices));
+        // A signer with index zero.
+        let (nonces, C1) = commit_nonces(shares[0].index, &mut OsRng);
+        let zero = NonceCommitment { index: 0, ..C2 };
+        let res = partial_sign(MSG, &shares[0], nonces, &[C1, zero], &pk);
+        assert!(res == Err(Error::InvalidIndices));
+
+        let (nonces, C1) = commit_nonces(shares[0].index, &mut OsRng);
+        let z1 = partial_sign(MSG, &shares[0], nonces, &[C1, C2], &pk).unwrap();
+        let Y1 = shares[0].verification_share();
+        assert!(!verify_partial_signature(
+            MSG,
+            &z1,
+            1,
+            &Y1,
+            &[C1, C2, C2],
+            &pk
+        ));
+        assert!(!verify_partial_signature(
+            MSG,
+            &z1,
+            1,
+            &Y1,
+            &[C1, impostor],
+            &pk
+        ));
+    }
+
+    #[test]
+    fn binding_factors_depend_on_the_group_key() {
+        let (_, pk) = keygen(&mut OsRng);
+        let (_, other_pk) = keygen(&mut OsRng);
+        let (_, C1) = commit_nonces(1, &mut OsRng);
+        let (_, C2) = commit_nonces(2, &mut OsRng);
+        let commitments = [C1, C2];
+
+        let rho = binding_factor(1, MSG, &commitments, &pk);
+        assert!(rho != binding_factor(1, MSG, &commitments, &other_pk));
+        assert!(rho != binding_factor(2, MSG, &commitments, &pk));
+        assert!(rho != binding_factor(1, b"other", &commitments, &pk));
+        let R = group_commitment(MSG, &commitments, &pk);
+        assert!(R != group_commitment(MSG, &commitments, &other_pk));
+    }
+
+    #[test]
+    #[should_panic]
+    fn repeated_signers() {
+        lagrange_coefficient(1, &[1, 2, 2]);
+    }
+}
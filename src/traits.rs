@@ -1,5 +1,9 @@
 //! Module for Public Trait implementations.
 
+use crate::scalar::Scalar;
+
+use core::borrow::Borrow;
+
 use subtle::Choice;
 
 /// Gives the Identity element for the
@@ -31,6 +35,156 @@ pub trait ValidityCheck {
     fn is_valid(&self) -> Choice;
 }
 
+/// Constant-time multiscalar multiplication, ie. the computation
+/// of `sum(scalars[i] * points[i])`.
+///
+/// The time taken only depends on the number of terms, so this is
+/// the trait to use when any of the scalars is secret.
+pub trait MultiscalarMul {
+    /// The type of point being multiplied.
+    type Point;
+
+    #[must_use]
+    /// Returns `sum(scalars[i] * points[i])`.
+    ///
+    /// Panics if `scalars` and `points` have different lengths.
+    fn multiscalar_mul<I, J>(scalars: I, points: J) -> Self::Point
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Scalar>,
+        J: IntoIterator,
+        J::Item: Borrow<Self::Point>;
+}
+
+/// Variable-time multiscalar multiplication, ie. the computation
+/// of `sum(scalars[i] * points[i])`.
+///
+/// This is faster than `MultiscalarMul` but leaks the scalars
+/// through timing, so it must only be used over public data,
+/// such as when verifying signatures or proofs.
+pub trait VartimeMultiscalarMul {
+    /// The type of point being multiplied.
+    type Point: Clone;
+
+    #[must_use]
+    /// Returns `Some(sum(scalars[i] * points[i]))`, or `None` if any
+    /// of the `points` is `None`.
+    ///
+    /// This lets the callers that decompress the points on the fly
+    /// bail out on the first invalid one.
+    ///
+    /// Panics if `scalars` and `points` have different lengths.
+    fn optional_multiscalar_mul<I, J>(scalars: I, points: J) -> Option<Self::Point>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Scalar>,
+        J: IntoIterator<Item = Option<Self::Point>>;
+
+    #[must_use]
+    /// Returns `sum(scalars[i] * points[i])`.
+    ///
+    /// Panics if `scalars` and `points` have different lengths.
+    fn vartime_multiscalar_mul<I, J>(scalars: I, points: J) -> Self::Point
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Scalar>,
+        J: IntoIterator,
+        J::Item: Borrow<Self::Point>,
+    {
+        Self::optional_multiscalar_mul(
+            scalars,
+            points.into_iter().map(|point| Some(point.borrow().clone())),
+        )
+        .unwrap()
+    }
+}
+
+/// Variable-time multiscalar multiplication with a set of static
+/// points known in advance, ie. the computation of
+/// `sum(static_scalars[i] * static_points[i]) + sum(dynamic_scalars[j] * dynamic_points[j])`.
+///
+/// The implementors precompute tables for the static points once,
+/// which pays off when the same generators appear in many
+/// equations, such as when verifying proofs.
+pub trait VartimePrecomputedMultiscalarMul: Sized {
+    /// The type of point being multiplied.
+    type Point: Clone;
+
+    /// Precompute the tables of the `static_points`.
+    fn new<I>(static_points: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Self::Point>;
+
+    #[must_use]
+    /// Returns `sum(static_scalars[i] * static_points[i])`.
+    ///
+    /// Panics if there are not as many `static_scalars` as static
+    /// points.
+    fn vartime_multiscalar_mul<I>(&self, static_scalars: I) -> Self::Point
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Scalar>,
+    {
+        self.optional_mixed_multiscalar_mul(
+            static_scalars,
+            core::iter::empty::<Scalar>(),
+            core::iter::empty(),
+        )
+        .unwrap()
+    }
+
+    #[must_use]
+    /// Returns the sum of the static and the dynamic terms.
+    ///
+    /// Panics if there are not as many `static_scalars` as static
+    /// points, or if `dynamic_scalars` and `dynamic_points` have
+    /// different lengths.
+    fn vartime_mixed_multiscalar_mul<I, J, K>(
+        &self,
+        static_scalars: I,
+        dynamic_scalars: J,
+        dynamic_points: K,
+    ) -> Self::Point
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Scalar>,
+        J: IntoIterator,
+        J::Item: Borrow<Scalar>,
+        K: IntoIterator,
+        K::Item: Borrow<Self::Point>,
+    {
+        self.optional_mixed_multiscalar_mul(
+            static_scalars,
+            dynamic_scalars,
+            dynamic_points
+                .into_iter()
+                .map(|point| Some(point.borrow().clone())),
+        )
+        .unwrap()
+    }
+
+    #[must_use]
+    /// Returns `Some` sum of the static and the dynamic terms, or
+    /// `None` if any of the `dynamic_points` is `None`.
+    ///
+    /// Panics if there are not as many `static_scalars` as static
+    /// points, or if `dynamic_scalars` and `dynamic_points` have
+    /// different lengths.
+    fn optional_mixed_multiscalar_mul<I, J, K>(
+        &self,
+        static_scalars: I,
+        dynamic_scalars: J,
+        dynamic_points: K,
+    ) -> Option<Self::Point>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Scalar>,
+        J: IntoIterator,
+        J::Item: Borrow<Scalar>,
+        K: IntoIterator<Item = Option<Self::Point>>;
+}
+
 pub mod ops {
     use super::*;
 
@@ -107,6 +261,7 @@ pub mod ops {
         ///
         /// `Some(symb_choosen_result)` if the input is a QR for the prime modulo.
         /// Otherways it returns `None`
+        #[deprecated(note = "use `FieldElement::sqrt`, which returns a `CtOption`")]
         fn mod_sqrt(self, choice: Choice) -> Self::Output;
     }
 
@@ -0,0 +1,136 @@
+//! Helpers to use the zerocaf types on `merlin` transcripts.
+//!
+//! Every value gets appended as a single message with its canonical
+//! encoding: the 32 LE bytes of `FieldElement::to_bytes` and
+//! `Scalar::to_bytes`, or the 32 bytes of the compressed points.
+//! Challenges are taken as 64 bytes from the transcript and reduced
+//! modulo `l` or `p`, so their bias is negligible.
+//!
+//! Any protocol that builds its Fiat-Shamir transcripts through the
+//! `TranscriptProtocol` trait will be byte-compatible with any other
+//! one doing the same.
+//!
+//! # Examples
+//! ```rust
+//! use merlin::Transcript;
+//! use zerocaf::constants::BASEPOINT;
+//! use zerocaf::scalar::Scalar;
+//! use zerocaf::transcript::TranscriptProtocol;
+//!
+//! let mut prover = Transcript::new(b"Sonny's protocol");
+//! let mut verifier = Transcript::new(b"Sonny's protocol");
+//! for transcript in [&mut prover, &mut verifier].iter_mut() {
+//!     transcript.append_point(b"B", &BASEPOINT.compress());
+//!     transcript.append_scalar(b"k", &Scalar::one());
+//! }
+//! assert!(prover.challenge_scalar(b"c") == verifier.challenge_scalar(b"c"));
+//! ```
+
+use crate::edwards::CompressedEdwardsY;
+use crate::field::FieldElement;
+use crate::ristretto::CompressedRistretto;
+use crate::scalar::Scalar;
+
+use merlin::Transcript;
+
+/// Extension trait to append the zerocaf types to a transcript
+/// and to get challenges out of it.
+pub trait TranscriptProtocol {
+    /// Append a `FieldElement` with the given `label`.
+    fn append_field_element(&mut self, label: &'static [u8], element: &FieldElement);
+
+    /// Append a `Scalar` with the given `label`.
+    fn append_scalar(&mut self, label: &'static [u8], scalar: &Scalar);
+
+    /// Append a `CompressedEdwardsY` point with the given `label`.
+    fn append_point(&mut self, label: &'static [u8], point: &CompressedEdwardsY);
+
+    /// Append a `CompressedRistretto` point with the given `label`.
+    fn append_ristretto_point(&mut self, label: &'static [u8], point: &CompressedRistretto);
+
+    /// Get a `Scalar` challenge with the given `label`.
+    fn challenge_scalar(&mut self, label: &'static [u8]) -> Scalar;
+
+    /// Get a `FieldElement` challenge with the given `label`.
+    fn challenge_field_element(&mut self, label: &'static [u8]) -> FieldElement;
+}
+
+impl TranscriptProtocol for Transcript {
+    fn append_field_element(&mut self, label: &'static [u8], element: &FieldElement) {
+        self.append_message(label, &element.to_bytes());
+    }
+
+    fn append_scalar(&mut self, label: &'static [u8], scalar: &Scalar) {
+        self.append_message(label, &scalar.to_bytes());
+    }
+
+    fn append_point(&mut self, label: &'static [u8], point: &CompressedEdwardsY) {
+        self.append_message(label, &point.to_bytes());
+    }
+
+    fn append_ristretto_point(&mut self, label: &'static [u8], point: &CompressedRistretto) {
+        self.append_message(label, &point.as_bytes());
+    }
+
+    fn challenge_scalar(&mut self, label: &'static [u8]) -> Scalar {
+        let mut bytes = [0u8; 64];
+        self.challenge_bytes(label, &mut bytes);
+        Scalar::from_bytes_wide(&bytes)
+    }
+
+    fn challenge_field_element(&mut self, label: &'static [u8]) -> FieldElement {
+        let mut bytes = [0u8; 64];
+        self.challenge_bytes(label, &mut bytes);
+        let mut words = [0u64; 8];
+        for (word, chunk) in words.iter_mut().zip(bytes.chunks(8)) {
+            let mut buf = [0u8; 8];
+            buf.copy_from_slice(chunk);
+            *word = u64::from_le_bytes(buf);
+        }
+        FieldElement::reduce_wide_words(words)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants;
+
+    #[test]
+    fn appends_the_canonical_encodings() {
+        let element = FieldElement::minus_one();
+        let scalar = Scalar::minus_one();
+        let point = constants::BASEPOINT.compress();
+        let ristretto = constants::RISTRETTO_BASEPOINT.compress();
+
+        let mut transcript = Transcript::new(b"test");
+        transcript.append_field_element(b"a", &element);
+        transcript.append_scalar(b"b", &scalar);
+        transcript.append_point(b"c", &point);
+        transcript.append_ristretto_point(b"d", &ristretto);
+
+        let mut raw = Transcript::new(b"test");
+        raw.append_message(b"a", &element.to_bytes());
+        raw.append_message(b"b", &scalar.to_bytes());
+        raw.append_message(b"c", &point.to_bytes());
+        raw.append_message(b"d", &ristretto.as_bytes());
+
+        assert!(transcript.challenge_scalar(b"e") == raw.challenge_scalar(b"e"));
+    }
+
+    #[test]
+    fn challenges() {
+        let mut transcript = Transcript::new(b"test");
+        transcript.append_scalar(b"k", &Scalar::one());
+        let mut copy = transcript.clone();
+
+        let mut bytes = [0u8; 64];
+        copy.challenge_bytes(b"c", &mut bytes);
+        assert!(transcript.challenge_scalar(b"c") == Scalar::from_bytes_wide(&bytes));
+
+        // Every challenge changes the state of the transcript.
+        let first = transcript.challenge_field_element(b"x");
+        let second = transcript.challenge_field_element(b"x");
+        assert!(first != second);
+    }
+}
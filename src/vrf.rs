@@ -0,0 +1,253 @@
+#![allow(non_snake_case)]
+//! Implementation of an ECVRF-style verifiable random function
+//! over the Ristretto-encoded prime-order group of the Sonny curve.
+//!
+//! Given the secret key `sk` (whose public key is `PK = sk * B`)
+//! and an input `alpha`, the prover computes:
+//!
+//! - `H = hash_to_group(PK || alpha)`.
+//! - `Gamma = sk * H`.
+//! - `c = H("zerocaf-vrf" || H || Gamma || k * B || k * H) (mod l)`
+//!   for a nonce `k` derived from `sk` and `alpha`.
+//! - `s = k + c * sk (mod l)`.
+//!
+//! The proof is `(Gamma, c, s)`, and the VRF output is the hash of
+//! `Gamma`. The verifier recomputes `U = s * B - c * PK` and
+//! `V = s * H - c * Gamma` and accepts if the challenge derived
+//! from `(H, Gamma, U, V)` is equal to `c`.
+//!
+//! Since the group has prime order, no cofactor clearing is needed
+//! before hashing `Gamma` into the output.
+//!
+//! # Examples
+//! ```rust
+//! use zerocaf::vrf::*;
+//! use zerocaf::signatures::keygen;
+//!
+//! use rand::rngs::OsRng;
+//!
+//! let (sk, pk) = keygen(&mut OsRng);
+//! let alpha = b"Sonny's lottery seed";
+//!
+//! let proof = prove(alpha, &sk);
+//! let output = verify(alpha, &proof, &pk).unwrap();
+//! assert!(output[..] == proof_to_hash(&proof)[..]);
+//! ```
+
+use crate::constants;
+use crate::nonce::{derive_hedged_nonce, derive_nonce};
+use crate::ristretto::{CompressedRistretto, RistrettoPoint};
+use crate::scalar::Scalar;
+use crate::traits::MultiscalarMul;
+
+use rand::{CryptoRng, RngCore};
+use sha2::{Digest, Sha512};
+
+/// Domain separator used on the VRF hash derivations.
+pub(crate) const VRF_DOMAIN: &[u8] = b"zerocaf-vrf";
+
+/// A VRF proof `(Gamma, c, s)`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct VrfProof {
+    pub Gamma: CompressedRistretto,
+    pub c: Scalar,
+    pub s: Scalar,
+}
+
+impl VrfProof {
+    /// Encode the `VrfProof` as 96 bytes: `Gamma || c || s`.
+    pub fn to_bytes(&self) -> [u8; 96] {
+        let mut bytes = [0u8; 96];
+        bytes[..32].copy_from_slice(&self.Gamma.as_bytes());
+        bytes[32..64].copy_from_slice(&self.c.to_bytes());
+        bytes[64..].copy_from_slice(&self.s.to_bytes());
+        bytes
+    }
+
+    /// Decode a `VrfProof` from 96 bytes: `Gamma || c || s`.
+    ///
+    /// # Returns
+    /// - `None` if `c` or `s` are not the canonical encoding of a `Scalar`.
+    /// - `Some(VrfProof)` otherways.
+    pub fn from_bytes(bytes: &[u8; 96]) -> Option<VrfProof> {
+        let Gamma = CompressedRistretto::copy_from_slice(&bytes[..32]);
+        let c = canonical_scalar(&bytes[32..64])?;
+        let s = canonical_scalar(&bytes[64..])?;
+
+        Some(VrfProof { Gamma, c, s })
+    }
+}
+
+/// Decode the given 32 bytes as a `Scalar`, rejecting
+/// non-canonical encodings.
+fn canonical_scalar(bytes: &[u8]) -> Option<Scalar> {
+    let mut buf = [0u8; 32];
+    buf.copy_from_slice(bytes);
+    Scalar::from_canonical_bytes(&buf).ok()
+}
+
+/// Hash the public key and the VRF input into a `RistrettoPoint`.
+pub(crate) fn hash_to_group(pk: &CompressedRistretto, alpha: &[u8]) -> RistrettoPoint {
+    let hasher = Sha512::new()
+        .chain(VRF_DOMAIN)
+        .chain([0x01])
+        .chain(pk.as_bytes())
+        .chain(alpha);
+    RistrettoPoint::from_hash(hasher)
+}
+
+/// Compute the challenge `c = H("zerocaf-vrf" || H || Gamma || U || V) (mod l)`.
+pub(crate) fn challenge(
+    H: &CompressedRistretto,
+    Gamma: &CompressedRistretto,
+    U: &CompressedRistretto,
+    V: &CompressedRistretto,
+) -> Scalar {
+    let hasher = Sha512::new()
+        .chain(VRF_DOMAIN)
+        .chain([0x02])
+        .chain(H.as_bytes())
+        .chain(Gamma.as_bytes())
+        .chain(U.as_bytes())
+        .chain(V.as_bytes());
+    Scalar::from_hash(hasher)
+}
+
+/// Compute the `VrfProof` with the given nonce.
+fn prove_with_nonce(alpha: &[u8], sk: &Scalar, k: Scalar) -> VrfProof {
+    let pk = RistrettoPoint::mul_base(sk).compress();
+    let H = hash_to_group(&pk, alpha);
+    let Gamma = RistrettoPoint::multiscalar_mul(&[*sk], &[H]);
+
+    let U = RistrettoPoint::mul_base(&k);
+    let V = RistrettoPoint::multiscalar_mul(&[k], &[H]);
+
+    let Gamma = Gamma.compress();
+    let c = challenge(&H.compress(), &Gamma, &U.compress(), &V.compress());
+    VrfProof {
+        Gamma,
+        c,
+        s: k + c * *sk,
+    }
+}
+
+/// Generate the VRF proof for the input `alpha` with the
+/// provided secret key.
+///
+/// The nonce is derived deterministically from the secret key
+/// and `alpha`.
+pub fn prove(alpha: &[u8], sk: &Scalar) -> VrfProof {
+    let k = derive_nonce(VRF_DOMAIN, sk, alpha, None);
+    prove_with_nonce(alpha, sk, k)
+}
+
+/// Generate the VRF proof for the input `alpha` with the
+/// provided secret key, mixing extra randomness taken from
+/// `rng` into the nonce derivation.
+///
+/// By `rng` we mean any Rng that implements: `RngCore` + `CryptoRng`.
+pub fn prove_with_rng<T>(alpha: &[u8], sk: &Scalar, rng: &mut T) -> VrfProof
+where
+    T: RngCore + CryptoRng,
+{
+    let k = derive_hedged_nonce(VRF_DOMAIN, sk, alpha, rng);
+    prove_with_nonce(alpha, sk, k)
+}
+
+/// Get the 64-byte VRF output of the given proof.
+///
+/// The output should only be trusted once the proof has
+/// been checked with `verify`.
+pub fn proof_to_hash(proof: &VrfProof) -> [u8; 64] {
+    let hasher = Sha512::new()
+        .chain(VRF_DOMAIN)
+        .chain([0x03])
+        .chain(proof.Gamma.as_bytes());
+
+    let mut output = [0u8; 64];
+    output.copy_from_slice(hasher.result().as_slice());
+    output
+}
+
+/// Verify a `VrfProof` for the input `alpha` against the given
+/// public key.
+///
+/// # Returns
+/// - `Some(output)` with the VRF output if the proof is valid.
+/// - `None` if the proof is invalid or `Gamma` can't be decompressed.
+pub fn verify(alpha: &[u8], proof: &VrfProof, pk: &RistrettoPoint) -> Option<[u8; 64]> {
+    let Gamma = proof.Gamma.decompress()?;
+    let H = hash_to_group(&pk.compress(), alpha);
+
+    let U = constants::RISTRETTO_BASEPOINT * proof.s - pk * proof.c;
+    let V = H * proof.s - Gamma * proof.c;
+
+    let c = challenge(&H.compress(), &proof.Gamma, &U.compress(), &V.compress());
+    if c != proof.c {
+        return None;
+    };
+    Some(proof_to_hash(proof))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signatures::keygen;
+
+    use rand::rngs::OsRng;
+
+    #[test]
+    fn prove_and_verify() {
+        let (sk, pk) = keygen(&mut OsRng);
+        let alpha = b"Sonny's lottery seed";
+
+        let proof = prove(alpha, &sk);
+        let output = verify(alpha, &proof, &pk).unwrap();
+        assert!(output[..] == proof_to_hash(&proof)[..]);
+    }
+
+    #[test]
+    fn output_is_unique() {
+        let (sk, _) = keygen(&mut OsRng);
+        let alpha = b"Sonny's lottery seed";
+
+        // Different nonces lead to the same output.
+        let first = proof_to_hash(&prove(alpha, &sk));
+        let second = proof_to_hash(&prove_with_rng(alpha, &sk, &mut OsRng));
+        assert!(first[..] == second[..]);
+
+        let other = proof_to_hash(&prove(b"Another seed", &sk));
+        assert!(first[..] != other[..]);
+    }
+
+    #[test]
+    fn reject_invalid_proofs() {
+        let (sk, pk) = keygen(&mut OsRng);
+        let (_, other_pk) = keygen(&mut OsRng);
+        let alpha = b"Sonny's lottery seed";
+        let proof = prove(alpha, &sk);
+
+        // Different input.
+        assert!(verify(b"Another seed", &proof, &pk).is_none());
+        // Different public key.
+        assert!(verify(alpha, &proof, &other_pk).is_none());
+        // Tampered response.
+        let tampered = VrfProof {
+            s: proof.s + Scalar::one(),
+            ..proof
+        };
+        assert!(verify(alpha, &tampered, &pk).is_none());
+    }
+
+    #[test]
+    fn proof_encoding() {
+        let (sk, _) = keygen(&mut OsRng);
+        let proof = prove(b"", &sk);
+        assert!(VrfProof::from_bytes(&proof.to_bytes()).unwrap() == proof);
+
+        // Non-canonical `s` gets rejected.
+        let mut bytes = proof.to_bytes();
+        bytes[95] = 0xff;
+        assert!(VrfProof::from_bytes(&bytes).is_none());
+    }
+}
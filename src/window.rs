@@ -0,0 +1,135 @@
+//! Lookup tables of the multiples of a point for the constant-time
+//! windowed multiplications.
+//!
+//! The scalars get recoded into signed digits `a_i` (see
+//! `Scalar::to_radix_2w`), so a table only needs the multiples
+//! `[P, 2P, ..., NP]`: the negative ones are obtained negating the
+//! selected entry.
+//!
+//! # Examples
+//! ```rust
+//! use zerocaf::constants::BASEPOINT;
+//! use zerocaf::scalar::Scalar;
+//! use zerocaf::window::LookupTable;
+//!
+//! let table = LookupTable::<_, 8>::from_multiples_of(&BASEPOINT);
+//! assert!(table.select(-3) == -(BASEPOINT * Scalar::from(3u8)));
+//! ```
+//!
+//! Arbitrary tables, eg. of precomputed field elements, can be read
+//! at a secret index with `ct_select_from`.
+
+use crate::traits::Identity;
+
+use subtle::{Choice, ConditionallyNegatable, ConditionallySelectable, ConstantTimeEq, CtOption};
+
+use std::ops::{Add, Neg};
+
+/// A table of the multiples `[P, 2P, ..., NP]` of a point `P`.
+///
+/// `select` scans all of the entries, so neither the memory accesses
+/// nor the operations performed depend on the index.
+#[derive(Copy, Clone, Debug)]
+pub struct LookupTable<T, const N: usize>(pub(crate) [T; N]);
+
+impl<T, const N: usize> LookupTable<T, N>
+where
+    T: Copy,
+    for<'a> &'a T: Add<Output = T>,
+{
+    /// Compute the table of multiples of `point`.
+    pub fn from_multiples_of(point: &T) -> LookupTable<T, N> {
+        let mut table = [*point; N];
+        for k in 1..N {
+            table[k] = &table[k - 1] + point;
+        }
+        LookupTable(table)
+    }
+}
+
+impl<T, const N: usize> LookupTable<T, N>
+where
+    T: Identity + ConditionallySelectable,
+    for<'a> &'a T: Neg<Output = T>,
+{
+    /// Get `index * P` in constant time.
+    ///
+    /// `index` must be in `[-N, N]`, and `0` gives the identity.
+    pub fn select(&self, index: i8) -> T {
+        debug_assert!(index.unsigned_abs() as usize <= N);
+        let is_negative = Choice::from((index as u8) >> 7);
+        let abs = index.unsigned_abs();
+
+        let mut res = T::identity();
+        for (k, point) in self.0.iter().enumerate() {
+            res.conditional_assign(point, ((k + 1) as u8).ct_eq(&abs));
+        }
+        res.conditional_negate(is_negative);
+        res
+    }
+}
+
+impl<T, const N: usize> LookupTable<T, N> {
+    /// Get the point `P` whose multiples are in the table.
+    pub fn point(&self) -> &T {
+        &self.0[0]
+    }
+}
+
+/// Get `slice[index]` in constant time.
+///
+/// Every entry of the slice gets read and conditionally selected,
+/// so neither the memory accesses nor the operations performed
+/// depend on `index`. Only the length of the slice is leaked.
+///
+/// # Returns
+/// - `None` if `index` is out of the bounds of the slice.
+/// - `Some(slice[index])` otherways.
+///
+/// # Panics
+/// If the slice is empty.
+pub fn ct_select_from<T>(slice: &[T], index: usize) -> CtOption<T>
+where
+    T: ConditionallySelectable,
+{
+    let mut res = slice[0];
+    let mut found = Choice::from(0u8);
+    for (k, entry) in slice.iter().enumerate() {
+        let is_index = (k as u64).ct_eq(&(index as u64));
+        res.conditional_assign(entry, is_index);
+        found |= is_index;
+    }
+    CtOption::new(res, found)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants;
+    use crate::edwards::EdwardsPoint;
+    use crate::scalar::Scalar;
+
+    #[test]
+    fn select_every_index() {
+        let point = constants::BASEPOINT * Scalar::from(1234u64);
+        let table = LookupTable::<EdwardsPoint, 16>::from_multiples_of(&point);
+        assert!(*table.point() == point);
+
+        assert!(table.select(0) == EdwardsPoint::identity());
+        for k in 1..=16i8 {
+            let multiple = point * Scalar::from(k as u8);
+            assert!(table.select(k) == multiple);
+            assert!(table.select(-k) == -multiple);
+        }
+    }
+
+    #[test]
+    fn select_from_slice() {
+        let slice: Vec<u64> = (0..10).map(|k| 3 * k + 1).collect();
+        for (k, entry) in slice.iter().enumerate() {
+            assert!(ct_select_from(&slice, k).unwrap() == *entry);
+        }
+        assert!(bool::from(ct_select_from(&slice, 10).is_none()));
+        assert!(bool::from(ct_select_from(&slice, usize::MAX).is_none()));
+    }
+}
@@ -0,0 +1,130 @@
+#![allow(non_snake_case)]
+#![cfg(not(target_arch = "wasm32"))]
+//! Property-based tests of the algebraic laws that the field,
+//! scalar and point arithmetic must satisfy.
+//!
+//! `proptest` shrinks any failing input to a minimal one, which
+//! makes carry-handling regressions much easier to track down.
+use proptest::prelude::*;
+
+use zerocaf::constants;
+use zerocaf::field::FieldElement;
+use zerocaf::ristretto::RistrettoPoint;
+use zerocaf::scalar::Scalar;
+use zerocaf::traits::ops::*;
+use zerocaf::traits::Identity;
+
+/// Canonical `FieldElement`s, including the edge values.
+fn field_element() -> impl Strategy<Value = FieldElement> {
+    prop_oneof![
+        1 => Just(FieldElement::zero()),
+        1 => Just(FieldElement::one()),
+        1 => Just(FieldElement::minus_one()),
+        20 => any::<[u8; 32]>().prop_filter_map("non-canonical", |mut bytes| {
+            bytes[31] &= 0b0001_1111;
+            FieldElement::from_canonical_bytes(&bytes).ok()
+        }),
+    ]
+}
+
+/// Uniform `Scalar`s, including the edge values.
+fn scalar() -> impl Strategy<Value = Scalar> {
+    prop_oneof![
+        1 => Just(Scalar::zero()),
+        1 => Just(Scalar::one()),
+        1 => Just(Scalar::minus_one()),
+        20 => (any::<[u8; 32]>(), any::<[u8; 32]>()).prop_map(|(lo, hi)| {
+            let mut bytes = [0u8; 64];
+            bytes[..32].copy_from_slice(&lo);
+            bytes[32..].copy_from_slice(&hi);
+            Scalar::from_bytes_wide(&bytes)
+        }),
+    ]
+}
+
+proptest! {
+    #[test]
+    fn field_ring_laws(a in field_element(), b in field_element(), c in field_element()) {
+        prop_assert!((a + b) + c == a + (b + c));
+        prop_assert!(a + b == b + a);
+        prop_assert!((a * b) * c == a * (b * c));
+        prop_assert!(a * b == b * a);
+        prop_assert!(a * (b + c) == a * b + a * c);
+        prop_assert!(a - b == a + (-b));
+        prop_assert!(a.square() == a * a);
+        prop_assert!(a.double() == a + a);
+    }
+
+    #[test]
+    fn field_mul_u64(a in field_element(), k in any::<u64>()) {
+        prop_assert!(a * k == a * FieldElement::from(k));
+    }
+
+    #[test]
+    fn field_inverse(a in field_element()) {
+        prop_assume!(a != FieldElement::zero());
+        prop_assert!(a * a.inverse() == FieldElement::one());
+        prop_assert!(FieldElement::one() / a == a.inverse());
+    }
+
+    #[test]
+    fn field_sqrt(a in field_element()) {
+        let sq = a.square();
+        let root = sq.sqrt();
+        prop_assert!(bool::from(root.is_some()));
+        prop_assert!(root.unwrap().square() == sq);
+        prop_assert!(bool::from(root.unwrap().is_positive()));
+
+        let root = a.sqrt();
+        prop_assert!(bool::from(root.is_some() ^ !a.is_square()));
+        if bool::from(root.is_some()) {
+            prop_assert!(root.unwrap().square() == a);
+        }
+    }
+
+    #[test]
+    fn field_encoding(a in field_element()) {
+        prop_assert!(FieldElement::from_canonical_bytes(&a.to_bytes()).unwrap() == a);
+    }
+
+    #[test]
+    fn scalar_ring_laws(a in scalar(), b in scalar(), c in scalar()) {
+        prop_assert!((a + b) + c == a + (b + c));
+        prop_assert!(a + b == b + a);
+        prop_assert!((a * b) * c == a * (b * c));
+        prop_assert!(a * b == b * a);
+        prop_assert!(a * (b + c) == a * b + a * c);
+        prop_assert!(a - b + b == a);
+        prop_assert!(a.square() == a * a);
+    }
+
+    #[test]
+    fn scalar_encoding(a in scalar()) {
+        prop_assert!(Scalar::from_canonical_bytes(&a.to_bytes()).unwrap() == a);
+    }
+}
+
+proptest! {
+    // Scalar multiplications are slow on debug builds.
+    #![proptest_config(ProptestConfig::with_cases(32))]
+
+    #[test]
+    fn edwards_laws(a in scalar(), b in scalar()) {
+        let P = constants::BASEPOINT * a;
+        let Q = constants::BASEPOINT * b;
+        prop_assert!(constants::BASEPOINT * (a + b) == P + Q);
+        prop_assert!(P - Q + Q == P);
+        prop_assert!(P.double() == P + P);
+        prop_assert!(P.compress().decompress_strict().unwrap() == P);
+    }
+
+    #[test]
+    fn ristretto_laws(a in scalar(), b in scalar()) {
+        let P = constants::RISTRETTO_BASEPOINT * a;
+        let Q = constants::RISTRETTO_BASEPOINT * b;
+        prop_assert!(constants::RISTRETTO_BASEPOINT * (a * b) == P * b);
+        prop_assert!(P + Q == Q + P);
+        prop_assert!(P.compress().decompress_strict().unwrap() == P);
+        prop_assert!(P + RistrettoPoint::identity() == P);
+    }
+}
@@ -0,0 +1,270 @@
+#![allow(non_snake_case)]
+#![cfg(feature = "dudect")]
+//! Statistical timing tests of the operations claimed to be
+//! constant time, following the fixed-vs-random approach of dudect:
+//!
+//! Oscar Reparaz, Josep Balasch and Ingrid Verbauwhede. Dude, is
+//! my code constant time? DATE 2017.
+//!
+//! Every operation gets timed over two classes of inputs, a fixed
+//! one and a random one, taken in random order. A Welch's t-test
+//! then checks whether both timing distributions share their mean.
+//! They are slow, so they only get built with the `dudect` feature:
+//!
+//! ```sh
+//! cargo test --release --features dudect --test constant_time
+//! ```
+use std::hint::black_box;
+use std::time::Instant;
+
+use zerocaf::constants;
+use zerocaf::edwards::{blinded_mul, EdwardsBasepointTableRadix16, EdwardsPoint};
+use zerocaf::field::FieldElement;
+use zerocaf::scalar::Scalar;
+use zerocaf::traits::MultiscalarMul;
+
+use rand::rngs::{OsRng, StdRng};
+use rand::{Rng, SeedableRng};
+use subtle::{ConditionallySelectable, ConstantTimeEq};
+
+/// Values of `|t|` over this bound mean that the operation is
+/// definitely not constant time.
+const T_THRESHOLD: f64 = 10.0;
+
+/// Only the measurements under this percentile get tested, so that
+/// interrupts and context switches don't show up as leaks.
+const CROP_PERCENTILE: f64 = 0.9;
+
+/// Input classes of the test.
+#[derive(Clone, Copy)]
+enum Class {
+    Fixed,
+    Random,
+}
+
+/// Welch's t-statistic of the two sets of measurements.
+fn welch_t(a: &[f64], b: &[f64]) -> f64 {
+    let mean_var = |xs: &[f64]| {
+        let n = xs.len() as f64;
+        let mean = xs.iter().sum::<f64>() / n;
+        let var = xs.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / (n - 1.0);
+        (mean, var, n)
+    };
+    let (mean_a, var_a, n_a) = mean_var(a);
+    let (mean_b, var_b, n_b) = mean_var(b);
+    (mean_a - mean_b) / (var_a / n_a + var_b / n_b).sqrt()
+}
+
+/// Time `op` over `samples` inputs given by `input`, running it
+/// `reps` times per input, and return the t-statistic of the
+/// fixed-vs-random test.
+fn t_statistic<T>(
+    samples: usize,
+    reps: usize,
+    mut input: impl FnMut(Class) -> T,
+    op: impl Fn(&T),
+) -> f64 {
+    let classes: Vec<Class> = (0..samples)
+        .map(|_| if OsRng.gen::<bool>() { Class::Fixed } else { Class::Random })
+        .collect();
+    let inputs: Vec<T> = classes.iter().map(|class| input(*class)).collect();
+
+    let mut timings = Vec::with_capacity(samples);
+    for value in inputs.iter() {
+        let start = Instant::now();
+        for _ in 0..reps {
+            op(black_box(value));
+        }
+        timings.push(start.elapsed().as_nanos() as f64);
+    }
+
+    let mut sorted = timings.clone();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let cutoff = sorted[((samples as f64) * CROP_PERCENTILE) as usize];
+
+    let (mut fixed, mut random) = (Vec::new(), Vec::new());
+    for (class, time) in classes.iter().zip(timings) {
+        if time > cutoff {
+            continue;
+        }
+        match class {
+            Class::Fixed => fixed.push(time),
+            Class::Random => random.push(time),
+        }
+    }
+    welch_t(&fixed, &random)
+}
+
+fn assert_constant_time(name: &str, t: f64) {
+    assert!(
+        t.abs() < T_THRESHOLD,
+        "{} looks variable time, |t| = {:.2}",
+        name,
+        t.abs()
+    );
+}
+
+fn random_point() -> EdwardsPoint {
+    constants::BASEPOINT * Scalar::random(&mut OsRng)
+}
+
+#[test]
+fn field_ct_eq() {
+    let fixed = FieldElement::random(&mut OsRng);
+    let t = t_statistic(
+        20_000,
+        64,
+        |class| match class {
+            Class::Fixed => (fixed, fixed),
+            Class::Random => (fixed, FieldElement::random(&mut OsRng)),
+        },
+        |(a, b)| {
+            black_box(a.ct_eq(b));
+        },
+    );
+    assert_constant_time("FieldElement::ct_eq", t);
+}
+
+// `==` goes through `ct_eq`, so it doesn't stop at the first
+// different limb.
+#[test]
+fn scalar_eq() {
+    let fixed = Scalar::random(&mut OsRng);
+    let t = t_statistic(
+        20_000,
+        64,
+        |class| match class {
+            Class::Fixed => (fixed, fixed),
+            Class::Random => (fixed, Scalar::random(&mut OsRng)),
+        },
+        |(a, b)| {
+            black_box(a == b);
+        },
+    );
+    assert_constant_time("Scalar::eq", t);
+}
+
+// `a - b` used to branch on whether it underflowed.
+#[test]
+fn field_sub() {
+    let b = FieldElement::random(&mut OsRng);
+    let t = t_statistic(
+        20_000,
+        64,
+        |class| match class {
+            Class::Fixed => FieldElement::zero(),
+            Class::Random => FieldElement::random(&mut OsRng),
+        },
+        |a| {
+            black_box(a - b);
+        },
+    );
+    assert_constant_time("FieldElement::sub", t);
+}
+
+#[test]
+fn point_conditional_select() {
+    let (P, Q) = (random_point(), random_point());
+    let t = t_statistic(
+        20_000,
+        64,
+        |class| match class {
+            Class::Fixed => 0u8,
+            Class::Random => OsRng.gen::<u8>() & 1,
+        },
+        |choice| {
+            black_box(EdwardsPoint::conditional_select(&P, &Q, (*choice).into()));
+        },
+    );
+    assert_constant_time("EdwardsPoint::conditional_select", t);
+}
+
+#[test]
+fn multiscalar_mul() {
+    let P = random_point();
+    let t = t_statistic(
+        2_000,
+        1,
+        |class| match class {
+            Class::Fixed => Scalar::zero(),
+            Class::Random => Scalar::random(&mut OsRng),
+        },
+        |k| {
+            black_box(EdwardsPoint::multiscalar_mul(&[*k], &[P]));
+        },
+    );
+    assert_constant_time("EdwardsPoint::multiscalar_mul", t);
+}
+
+// The fixed class picks `k = r` for the `r` drawn from a fixed seed,
+// so the share `k - r` is zero: a multiplication that stops early
+// or branches on the bits of the shares would show up.
+#[test]
+fn blinded_mul_shares() {
+    let P = random_point();
+    let seed = OsRng.gen::<u64>();
+    let r = Scalar::random(&mut StdRng::seed_from_u64(seed));
+    let t = t_statistic(
+        2_000,
+        1,
+        |class| match class {
+            Class::Fixed => (r, seed),
+            Class::Random => (Scalar::random(&mut OsRng), OsRng.gen::<u64>()),
+        },
+        |(k, seed)| {
+            let mut rng = StdRng::seed_from_u64(*seed);
+            black_box(blinded_mul(&P, k, &mut rng));
+        },
+    );
+    assert_constant_time("blinded_mul", t);
+}
+
+#[test]
+fn basepoint_table_mul() {
+    let table = EdwardsBasepointTableRadix16::create(&constants::BASEPOINT);
+    let t = t_statistic(
+        5_000,
+        1,
+        |class| match class {
+            Class::Fixed => Scalar::zero(),
+            Class::Random => Scalar::random(&mut OsRng),
+        },
+        |k| {
+            black_box(table.mul(k));
+        },
+    );
+    assert_constant_time("EdwardsBasepointTableRadix16::mul", t);
+}
+
+#[test]
+fn field_invert_nonzero() {
+    let t = t_statistic(
+        10_000,
+        4,
+        |class| match class {
+            Class::Fixed => FieldElement::one(),
+            Class::Random => FieldElement::random(&mut OsRng),
+        },
+        |a| {
+            black_box(a.invert_nonzero());
+        },
+    );
+    assert_constant_time("FieldElement::invert_nonzero", t);
+}
+
+#[test]
+fn field_ct_select_from() {
+    let table: Vec<FieldElement> = (0..64).map(|_| FieldElement::random(&mut OsRng)).collect();
+    let t = t_statistic(
+        20_000,
+        16,
+        |class| match class {
+            Class::Fixed => 0usize,
+            Class::Random => OsRng.gen_range(0, table.len()),
+        },
+        |index| {
+            black_box(FieldElement::ct_select_from(&table, *index));
+        },
+    );
+    assert_constant_time("FieldElement::ct_select_from", t);
+}
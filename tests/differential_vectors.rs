@@ -0,0 +1,143 @@
+#![allow(non_snake_case)]
+//! Differential tests against the vectors of
+//! `sage_codes/differential_vectors.py`, which models the Sonny
+//! curve on its Weierstrass form in plain Python, so a constant or
+//! a formula transcribed wrong into the library can't slip through
+//! the tests that only check it against itself.
+//!
+//! The script checks that the curve has order `8 * l` before
+//! printing the vectors, and checks them against the group law of
+//! Sage when run with `sage -python`. The committed ones were
+//! generated with `python3`, so the Sage check hasn't run on them
+//! yet. They get regenerated with:
+//!
+//! ```sh
+//! sage -python sage_codes/differential_vectors.py > tests/differential_vectors.json
+//! ```
+use num::BigUint;
+use serde_json::Value;
+
+use zerocaf::backend::u64::constants::{EDWARDS_A, EDWARDS_D, FIELD_L, L, SQRT_MINUS_ONE};
+use zerocaf::edwards::{AffinePoint, EdwardsPoint};
+use zerocaf::field::FieldElement;
+use zerocaf::scalar::Scalar;
+use zerocaf::traits::ops::{SqrtRatioI, Square};
+
+fn vectors(kind: &str) -> Value {
+    let vectors: Value = serde_json::from_str(include_str!("differential_vectors.json")).unwrap();
+    vectors[kind].clone()
+}
+
+/// Get the 32 LE bytes of a decimal string.
+fn decimal_bytes(value: &Value) -> [u8; 32] {
+    let digits = value.as_str().expect("values are decimal strings");
+    let le = BigUint::parse_bytes(digits.as_bytes(), 10)
+        .unwrap()
+        .to_bytes_le();
+    let mut bytes = [0u8; 32];
+    bytes[..le.len()].copy_from_slice(&le);
+    bytes
+}
+
+/// Get the value of radix `2^52` limbs.
+fn limbs_value(limbs: &[u64; 5]) -> BigUint {
+    limbs.iter().rev().fold(BigUint::from(0u8), |acc, limb| {
+        (acc << 52) + BigUint::from(*limb)
+    })
+}
+
+fn field_element(value: &Value) -> FieldElement {
+    FieldElement::from_canonical_bytes(&decimal_bytes(value)).unwrap()
+}
+
+fn scalar(value: &Value) -> Scalar {
+    Scalar::from_canonical_bytes(&decimal_bytes(value)).unwrap()
+}
+
+fn point(value: &Value) -> EdwardsPoint {
+    EdwardsPoint::from(AffinePoint {
+        X: field_element(&value[0]),
+        Y: field_element(&value[1]),
+    })
+}
+
+/// Check that `P` has the affine coordinates of `value`.
+fn assert_coordinates(P: EdwardsPoint, value: &Value) {
+    let affine = AffinePoint::from(P);
+    assert!(affine.X == field_element(&value[0]));
+    assert!(affine.Y == field_element(&value[1]));
+}
+
+#[test]
+fn constants() {
+    let constants = vectors("constants");
+    let decimal = |key: &str| BigUint::parse_bytes(constants[key].as_str().unwrap().as_bytes(), 10);
+    assert_eq!(Some(limbs_value(&FIELD_L.0)), decimal("p"));
+    assert_eq!(Some(limbs_value(&L.0)), decimal("l"));
+    assert!(EDWARDS_A == field_element(&constants["a"]));
+    assert!(EDWARDS_D == field_element(&constants["d"]));
+    assert!(SQRT_MINUS_ONE == field_element(&constants["sqrt_minus_one"]));
+}
+
+#[test]
+fn field_arithmetic() {
+    for vector in vectors("field").as_array().unwrap() {
+        let a = field_element(&vector["a"]);
+        let b = field_element(&vector["b"]);
+        assert!(a + b == field_element(&vector["add"]));
+        assert!(a - b == field_element(&vector["sub"]));
+        assert!(a * b == field_element(&vector["mul"]));
+        assert!(-a == field_element(&vector["neg"]));
+        assert!(a.square() == field_element(&vector["square"]));
+        match &vector["inverse"] {
+            Value::Null => assert!(bool::from(a.invert_nonzero().is_none())),
+            inverse => {
+                assert!(a.invert_nonzero().unwrap() == field_element(inverse));
+                assert!(a.inverse() == field_element(inverse));
+            }
+        }
+    }
+}
+
+#[test]
+fn square_roots() {
+    for vector in vectors("sqrt").as_array().unwrap() {
+        let a = field_element(&vector["a"]);
+        let root = a.sqrt();
+        match &vector["root"] {
+            Value::Null => assert!(bool::from(root.is_none()), "{}", vector),
+            expected => assert!(root.unwrap() == field_element(expected), "{}", vector),
+        }
+    }
+}
+
+#[test]
+fn sqrt_ratio_i() {
+    for vector in vectors("sqrt_ratio_i").as_array().unwrap() {
+        let u = field_element(&vector["u"]);
+        let v = field_element(&vector["v"]);
+        let (was_square, root) = u.sqrt_ratio_i(&v);
+        assert_eq!(Some(bool::from(was_square)), vector["was_square"].as_bool());
+        assert!(root == field_element(&vector["root"]), "{}", vector);
+    }
+}
+
+#[test]
+fn point_addition() {
+    for vector in vectors("point_add").as_array().unwrap() {
+        let P = point(&vector["p"]);
+        let Q = point(&vector["q"]);
+        assert_coordinates(P + Q, &vector["sum"]);
+        assert_coordinates(Q + P, &vector["sum"]);
+    }
+}
+
+#[test]
+fn scalar_multiplication() {
+    for vector in vectors("scalar_mul").as_array().unwrap() {
+        let k = scalar(&vector["scalar"]);
+        let P = point(&vector["point"]);
+        assert_coordinates(P * k, &vector["result"]);
+        assert_coordinates(P.vartime_mul(&k), &vector["result"]);
+    }
+}
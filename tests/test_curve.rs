@@ -0,0 +1,203 @@
+#![allow(non_snake_case)]
+#![cfg(feature = "test_curve")]
+//! Exhaustive tests of the field and curve arithmetic over the toy
+//! parameters of the `test_curve` feature, see `build.rs`: a field
+//! of order `p = 1021` and the curve `-x^2 + y^2 = 1 - 10 x^2 y^2`.
+//!
+//! The field is small enough to check every element, and every pair
+//! of them, against plain integer arithmetic, so the edge cases of
+//! the reductions, inversions and square roots can't be missed:
+//!
+//! ```sh
+//! cargo test --features test_curve --test test_curve
+//! ```
+use zerocaf::backend::u64::constants::{EDWARDS_A, EDWARDS_D, FIELD_L};
+use zerocaf::edwards::{AffinePoint, CompressedEdwardsY, EdwardsPoint};
+use zerocaf::field::FieldElement;
+use zerocaf::traits::ops::{Double, SqrtRatioI, Square};
+use zerocaf::traits::{Identity, ValidityCheck};
+
+/// Number of points of the toy curve, counted with Python.
+const CURVE_ORDER: u64 = 1032;
+
+/// Get the order of the toy field.
+fn field_order() -> u64 {
+    assert!(
+        FIELD_L.0[1..].iter().all(|limb| *limb == 0),
+        "the toy field fits on a single limb"
+    );
+    FIELD_L.0[0]
+}
+
+/// Get the squares of the field, computed with integer arithmetic.
+fn squares() -> Vec<bool> {
+    let p = field_order();
+    let mut is_square = vec![false; p as usize];
+    for x in 0..p {
+        is_square[(x * x % p) as usize] = true;
+    }
+    is_square
+}
+
+/// Get every point of the curve, solving the curve equation for
+/// every pair of coordinates.
+fn curve_points() -> Vec<AffinePoint> {
+    let p = field_order();
+    let mut points = Vec::new();
+    for x in 0..p {
+        let X = FieldElement::from(x);
+        let a_xx = EDWARDS_A * X.square();
+        let d_xx = EDWARDS_D * X.square();
+        for y in 0..p {
+            let Y = FieldElement::from(y);
+            let yy = Y.square();
+            if a_xx + yy == FieldElement::one() + d_xx * yy {
+                points.push(AffinePoint { X, Y });
+            }
+        }
+    }
+    points
+}
+
+#[test]
+fn parameters() {
+    let p = field_order();
+    assert_eq!(p, 1021);
+    assert!(EDWARDS_A == FieldElement::minus_one());
+    assert!(EDWARDS_D == -FieldElement::from(10u64));
+    assert!(FieldElement::minus_one() == FieldElement::from(p - 1));
+}
+
+#[test]
+fn canonical_reduction() {
+    let p = field_order() as u128;
+    for x in 0..(4 * p as u64) {
+        let res = FieldElement::reduce([x, 0, 0, 0, 0]);
+        assert!(res == FieldElement::from(x % p as u64));
+
+        // Check a value spanning the two lowest limbs as well.
+        let wide = ((x as u128) << 52) + x as u128;
+        let res = FieldElement::reduce([x, x, 0, 0, 0]);
+        assert!(res == FieldElement::from((wide % p) as u64));
+
+        let bytes = FieldElement::from(x).to_bytes();
+        let decoded = FieldElement::from_canonical_bytes(&bytes);
+        assert_eq!(decoded.is_ok(), (x as u128) < p);
+    }
+}
+
+#[test]
+fn arithmetic() {
+    let p = field_order();
+    for x in 0..p {
+        let a = FieldElement::from(x);
+        assert!(-a == FieldElement::from((p - x) % p));
+        assert!(a.square() == FieldElement::from(x * x % p));
+        for y in 0..p {
+            let b = FieldElement::from(y);
+            assert!(a + b == FieldElement::from((x + y) % p));
+            assert!(a - b == FieldElement::from((x + p - y) % p));
+            assert!(a * b == FieldElement::from(x * y % p));
+        }
+    }
+}
+
+#[test]
+fn inversion() {
+    let p = field_order();
+    for x in 1..p {
+        let a = FieldElement::from(x);
+        let inv = a.invert_nonzero().unwrap();
+        assert!(a * inv == FieldElement::one());
+        assert!(a.inverse() == inv);
+    }
+    assert!(bool::from(FieldElement::zero().invert_nonzero().is_none()));
+}
+
+#[test]
+fn square_roots() {
+    let p = field_order();
+    let is_square = squares();
+    for x in 0..p {
+        let a = FieldElement::from(x);
+        let root = a.sqrt();
+        assert_eq!(bool::from(root.is_some()), is_square[x as usize]);
+        if x != 0 {
+            assert_eq!(bool::from(a.legendre_symbol()), is_square[x as usize]);
+        }
+        if is_square[x as usize] {
+            let root = root.unwrap();
+            assert!(root.square() == a);
+            assert!(bool::from(root.is_positive()) || x == 0);
+        }
+    }
+}
+
+#[test]
+fn sqrt_ratio_i() {
+    let p = field_order();
+    let is_square = squares();
+    for u in 0..p {
+        let U = FieldElement::from(u);
+        for v in 0..p {
+            let V = FieldElement::from(v);
+            let (was_square, root) = U.sqrt_ratio_i(&V);
+            if u == 0 {
+                assert!(bool::from(was_square) && root == FieldElement::zero());
+            } else if v == 0 {
+                assert!(!bool::from(was_square) && root == FieldElement::zero());
+            } else {
+                let ratio = U * V.invert_nonzero().unwrap();
+                let ratio_is_square = is_square[ratio.0[0] as usize];
+                assert_eq!(bool::from(was_square), ratio_is_square);
+                assert!(root.square() * V == U || !ratio_is_square);
+            }
+        }
+    }
+}
+
+#[test]
+fn curve_order() {
+    let points = curve_points();
+    assert_eq!(points.len() as u64, CURVE_ORDER);
+
+    for point in points {
+        let P = EdwardsPoint::from(point);
+        assert!(bool::from(P.is_valid()));
+        assert!(P.mul_small(CURVE_ORDER) == EdwardsPoint::identity());
+        assert!(P + (-P) == EdwardsPoint::identity());
+        assert!(P.double() == P + P);
+    }
+}
+
+#[test]
+fn compression() {
+    for point in curve_points() {
+        let P = EdwardsPoint::from(point);
+        let decompressed = P.compress().decompress().unwrap();
+        assert!(decompressed == P);
+    }
+
+    // The Y-coordinates that don't belong to any point are rejected.
+    let points = curve_points();
+    for y in 0..field_order() {
+        let Y = FieldElement::from(y);
+        let on_curve = points.iter().any(|point| point.Y == Y);
+        let compressed = CompressedEdwardsY::from_slice(&Y.to_bytes());
+        assert_eq!(compressed.decompress().is_some(), on_curve);
+    }
+}
+
+#[test]
+fn addition() {
+    let points: Vec<EdwardsPoint> = curve_points().into_iter().map(EdwardsPoint::from).collect();
+    // Every pair involving a point of the sample.
+    for P in points.iter().step_by(37) {
+        for Q in points.iter() {
+            let sum = P + Q;
+            assert!(bool::from(sum.is_valid()));
+            assert!(sum == Q + P);
+            assert!(sum - Q == *P);
+        }
+    }
+}
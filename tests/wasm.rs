@@ -0,0 +1,43 @@
+#![allow(non_snake_case)]
+#![cfg(target_arch = "wasm32")]
+//! Smoke tests of the library on `wasm32-unknown-unknown`, run on
+//! node by `wasm-bindgen-test-runner`:
+//!
+//! ```sh
+//! cargo test --target wasm32-unknown-unknown --features wasm
+//! ```
+use wasm_bindgen_test::*;
+
+use zerocaf::constants;
+use zerocaf::field::FieldElement;
+use zerocaf::ristretto::RistrettoPoint;
+use zerocaf::scalar::Scalar;
+use zerocaf::signatures;
+
+use rand::rngs::OsRng;
+
+#[wasm_bindgen_test]
+fn field_arithmetic() {
+    let a = FieldElement::random(&mut OsRng);
+    let b = FieldElement::from(1234567u64);
+    assert!((a + b) - b == a);
+    assert!(b * b.inverse() == FieldElement::one());
+    assert!(FieldElement::minus_one() + FieldElement::one() == FieldElement::zero());
+}
+
+#[wasm_bindgen_test]
+fn scalar_mul() {
+    let a = Scalar::from(2u8);
+    let P = constants::RISTRETTO_BASEPOINT * a;
+    assert!(P == constants::RISTRETTO_BASEPOINT + constants::RISTRETTO_BASEPOINT);
+    assert!(P.compress().decompress().unwrap() == P);
+    assert!(RistrettoPoint::compress_batch(&[P])[0] == P.compress());
+}
+
+#[wasm_bindgen_test]
+fn signatures() {
+    let (sk, pk) = signatures::keygen(&mut OsRng);
+    let sig = signatures::sign(b"Sonny on the browser", &sk);
+    assert!(signatures::verify(b"Sonny on the browser", &sig, &pk));
+    assert!(signatures::verify_batch(&[&b"Sonny on the browser"[..]], &[sig], &[pk]));
+}